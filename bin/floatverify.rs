@@ -308,6 +308,10 @@ fn display_float(float_type: FloatType, v: u64, prefix: &str) {
             println!("{prefix}bits: {:032b}", f.to_bits());
             println!("{prefix}hex: {:8x}", f.to_bits());
             println!("{prefix}value: {}", f32::from_bits(f.to_bits()));
+            println!("{prefix}exact: {}", f.to_exact());
+            if let Some(r) = f.to_rational() {
+                println!("{prefix}rational: {r}");
+            }
             println!("{prefix}fraction: {}", f.fraction());
             println!("{prefix}exponent: {}", f.exponent());
             println!("{prefix}is_nan: {}", f.is_nan());
@@ -335,6 +339,10 @@ fn display_float(float_type: FloatType, v: u64, prefix: &str) {
             println!("{prefix}bits: {:064b}", f.to_bits());
             println!("{prefix}hex: {:16x}", f.to_bits());
             println!("{prefix}value: {}", f64::from_bits(f.to_bits()));
+            println!("{prefix}exact: {}", f.to_exact());
+            if let Some(r) = f.to_rational() {
+                println!("{prefix}rational: {r}");
+            }
             println!("{prefix}fraction: {}", f.fraction());
             println!("{prefix}exponent: {}", f.exponent());
             println!("{prefix}is_nan: {}", f.is_nan());
@@ -566,9 +574,9 @@ fn process_single_operand(fpu: &mut FPU, config: &TestConfig, input: u64) -> Res
                     .to_bits(),
                 )),
                 FloatType::F64 => Ok(match int_type {
-                    IntType::Ui32 => fpu.f64_from_u32(input as u32),
+                    IntType::Ui32 => fpu.f64_from_u32(input as u32, rnd),
                     IntType::Ui64 => fpu.f64_from_u64(input, rnd),
-                    IntType::I32 => fpu.f64_from_i32(input as u32 as i32),
+                    IntType::I32 => fpu.f64_from_i32(input as u32 as i32, rnd),
                     IntType::I64 => fpu.f64_from_i64(input as i64, rnd),
                 }
                 .to_bits()),
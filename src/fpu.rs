@@ -1,29 +1,225 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use core::borrow::Borrow;
+use core::cmp::Ordering;
+
+mod f32;
+mod f64;
 
 use super::{
-    softfloat::{
-        float32_t, float64_t, i32_to_f32, i32_to_f64, i64_to_f32, i64_to_f64, init_detectTininess,
-        ui32_to_f32, ui32_to_f64, ui64_to_f32, ui64_to_f64,
+    math::{f32_fitsPrecision, Parts},
+    softfloat::{float32_t, float64_t},
+    text::{Decimal, Hex, ParseFloatError},
+    wrapper::{
+        ExceptionFlags, Float, Int, Precision, PrecisionError, RandomSource, RoundingMode,
+        TininessMode,
     },
-    wrapper::{ExceptionFlags, Float, RoundingMode, TininessMode},
 };
 
+/// The top 128 bits of the little-endian magnitude `a` as `significand ×
+/// 2^exponent`, with the bits below folded into the lowest one: that keeps
+/// every rounding decision, which looks at most 64 bits past a `float64_t`
+/// significand.
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+const fn limbs_parts(a: &[u64]) -> (u128, i32) {
+    let mut n = a.len();
+    while n > 0 && a[n - 1] == 0 {
+        n -= 1;
+    }
+    if n <= 2 {
+        let hi = if n == 2 { a[1] } else { 0 };
+        let lo = if n >= 1 { a[0] } else { 0 };
+        return (((hi as u128) << 64) | lo as u128, 0);
+    }
+    let top = ((a[n - 1] as u128) << 64) | a[n - 2] as u128;
+    let z = top.leading_zeros();
+    let (significand, rest) = if z == 0 {
+        (top, a[n - 3])
+    } else {
+        ((top << z) | (a[n - 3] >> (64 - z)) as u128, a[n - 3] << z)
+    };
+    let mut sticky = rest != 0;
+    let mut i = 0;
+    while i < n - 3 {
+        sticky |= a[i] != 0;
+        i += 1;
+    }
+    let exponent = 64 * (n - 2) - z as usize;
+    let exponent = if exponent > i32::MAX as usize {
+        i32::MAX
+    } else {
+        exponent as i32
+    };
+    (significand | sticky as u128, exponent)
+}
+
+/// Every operation is also an inherent `const fn` per format, such as
+/// [`FPU::f64_add`] or [`FPU::f32_to_i32`], so that constant evaluation
+/// rounds and raises flags exactly as at run time.
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "crate::serde::FpuState", into = "crate::serde::FpuState")
+)]
 pub struct FPU {
     pub flags: ExceptionFlags,
-    detect_tininess: u8,
+    tininess: TininessMode,
+    precision: Option<Precision>,
+    stochastic: Option<RandomSource>,
 }
 
 impl FPU {
     #[inline]
     #[must_use]
-    pub fn new(tininess: TininessMode) -> Self {
+    pub const fn new(tininess: TininessMode) -> Self {
         Self {
-            flags: ExceptionFlags::default(),
-            detect_tininess: tininess.to_softfloat(),
+            flags: ExceptionFlags::from_bits(0),
+            tininess,
+            precision: None,
+            stochastic: None,
+        }
+    }
+
+    /// When underflow detects tininess, before or after rounding.
+    #[inline]
+    #[must_use]
+    pub const fn tininess(&self) -> TininessMode {
+        self.tininess
+    }
+
+    /// The format every result is rounded to, if any.
+    #[inline]
+    #[must_use]
+    pub const fn precision(&self) -> Option<Precision> {
+        self.precision
+    }
+
+    /// Rounds the result of every later operation that takes a rounding
+    /// mode to `precision`, in that mode, emulating a narrower format; `None`
+    /// turns this off.
+    ///
+    /// Each result is still computed in `F` first, so for round to nearest
+    /// it may differ from a single rounding to the narrower format by
+    /// double rounding, as with chop or cpfloat.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PrecisionError`], leaving the precision unchanged, if
+    /// `precision` does not fit in `float32_t`, the narrowest format the FPU
+    /// operates on. Wider formats can still be emulated in `float64_t` with
+    /// [`Float::round_to_precision`].
+    #[inline]
+    pub const fn set_precision(
+        &mut self,
+        precision: Option<Precision>,
+    ) -> Result<(), PrecisionError> {
+        if let Some(p) = precision {
+            if !f32_fitsPrecision(p.sig_bits(), p.emin(), p.emax()) {
+                return Err(PrecisionError::INVALID);
+            }
         }
+        self.precision = precision;
+        Ok(())
+    }
+
+    /// The random source of stochastic rounding, if it is on, in its
+    /// current state.
+    #[inline]
+    #[must_use]
+    pub const fn stochastic_rounding(&self) -> Option<RandomSource> {
+        self.stochastic
+    }
+
+    /// Rounds stochastically, drawing one word from `source` per rounding,
+    /// instead of in the requested mode; `None` turns this off.
+    ///
+    /// This covers addition, subtraction, multiplication, division, square
+    /// root and fused multiply-add, narrowing arithmetic and conversions to
+    /// `float32_t`, and conversions from integers and fixed point. The
+    /// rounding to an emulated [`Precision`] is then stochastic as well, for
+    /// every operation. Other operations still round in the requested mode.
+    /// See [`crate::math::f64_addStochastic`].
+    #[inline]
+    pub const fn set_stochastic_rounding(&mut self, source: Option<RandomSource>) {
+        self.stochastic = source;
+    }
+}
+
+/// Flag management from IEEE 754 §9.3 and C's `<fenv.h>`. A mask selects the
+/// flags an operation acts on, as in
+/// `ExceptionFlags::INVALID | ExceptionFlags::INFINITE`.
+impl FPU {
+    /// The flags, all of them, to restore later (saveAllFlags).
+    #[inline]
+    #[must_use]
+    pub const fn save_all_flags(&self) -> ExceptionFlags {
+        self.flags
+    }
+
+    /// Sets the flags in `mask` to their state in `saved`, leaving the
+    /// others alone (restoreFlags).
+    #[inline]
+    pub const fn restore_flags(&mut self, saved: ExceptionFlags, mask: ExceptionFlags) {
+        let mask = mask.to_bits();
+        self.flags =
+            ExceptionFlags::from_bits((self.flags.to_bits() & !mask) | (saved.to_bits() & mask));
+    }
+
+    /// Whether any of the flags in `mask` is raised (testFlags).
+    #[inline]
+    #[must_use]
+    pub const fn test_flags(&self, mask: ExceptionFlags) -> bool {
+        self.flags.intersects(mask)
+    }
+
+    /// Whether any of the flags in `mask` is raised in `saved`
+    /// (testSavedFlags).
+    #[inline]
+    #[must_use]
+    pub const fn test_saved_flags(saved: ExceptionFlags, mask: ExceptionFlags) -> bool {
+        saved.intersects(mask)
+    }
+
+    /// Raises the flags in `mask` (raiseFlags, `feraiseexcept`).
+    #[inline]
+    pub const fn raise_flags(&mut self, mask: ExceptionFlags) {
+        self.flags.merge(mask.to_bits());
+    }
+
+    /// Lowers the flags in `mask` (lowerFlags, `feclearexcept`).
+    #[inline]
+    pub const fn lower_flags(&mut self, mask: ExceptionFlags) {
+        self.flags = ExceptionFlags::from_bits(self.flags.to_bits() & !mask.to_bits());
+    }
+
+    /// Saves the flags and clears them, like `feholdexcept`; pass the result
+    /// to [`FPU::update_flags`] to resume.
+    #[inline]
+    #[must_use]
+    pub const fn hold_flags(&mut self) -> ExceptionFlags {
+        let saved = self.flags;
+        self.flags.reset();
+        saved
+    }
+
+    /// Restores `saved` and raises again the flags raised since, like
+    /// `feupdateenv`.
+    #[inline]
+    pub const fn update_flags(&mut self, saved: ExceptionFlags) {
+        self.flags.merge(saved.to_bits());
+    }
+
+    /// Runs `f` with all flags lowered and returns its result with the flags
+    /// it raised. The flags from before are then restored, without those
+    /// raised by `f`; [`FPU::raise_flags`] passes them on.
+    #[inline]
+    pub fn isolate_flags<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> (R, ExceptionFlags) {
+        let saved = self.hold_flags();
+        let result = f(self);
+        let raised = self.flags;
+        self.flags = saved;
+        (result, raised)
     }
 }
 
@@ -32,31 +228,300 @@ impl Default for FPU {
     fn default() -> Self {
         Self {
             flags: ExceptionFlags::default(),
-            detect_tininess: init_detectTininess,
+            tininess: TininessMode::default(),
+            precision: None,
+            stochastic: None,
         }
     }
 }
 
 impl FPU {
+    /// The softfloat encoding of the tininess mode.
     #[inline]
-    const fn flagged_f64(&mut self, args: (float64_t, u8)) -> float64_t {
-        self.flags.merge(args.1);
-        args.0
+    const fn detect_tininess(&self) -> u8 {
+        self.tininess.to_softfloat()
     }
 
+    /// The next random word, if rounding stochastically.
     #[inline]
-    const fn flagged_f32(&mut self, args: (float32_t, u8)) -> float32_t {
-        self.flags.merge(args.1);
-        args.0
+    const fn draw(&mut self) -> Option<u64> {
+        match &mut self.stochastic {
+            Some(source) => Some(source.next_u64()),
+            None => None,
+        }
     }
 
+    /// Merges the flags of `args` and returns its value.
     #[inline]
-    fn flagged<X>(&mut self, args: (X, u8)) -> X {
+    const fn flagged_const<X: Copy>(&mut self, args: (X, u8)) -> X {
         self.flags.merge(args.1);
         args.0
     }
 }
 
+/// The per-format hooks that the generic methods of [`FPU`] dispatch to.
+///
+/// Each calls the `const` method of the same name for its format, such as
+/// [`FPU::f64_add`] for `fpu_add`, so that both share one implementation.
+pub trait FpuFormat: Copy {
+    fn fpu_to_i32(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> i32;
+
+    fn fpu_to_i64(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> i64;
+
+    fn fpu_to_u64(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> u64;
+
+    fn fpu_to_u32(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> u32;
+
+    fn fpu_to_i8(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> i8;
+
+    fn fpu_to_i16(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> i16;
+
+    fn fpu_to_i128(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> i128;
+
+    fn fpu_to_u8(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> u8;
+
+    fn fpu_to_u16(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> u16;
+
+    fn fpu_to_u128(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> u128;
+
+    fn fpu_to_limbs(fpu: &mut FPU, a: Self, z: &mut [u64], rnd: RoundingMode, exact: bool) -> bool;
+
+    fn fpu_from_parts(
+        fpu: &mut FPU,
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        rnd: RoundingMode,
+    ) -> Self;
+
+    fn fpu_from_i8(fpu: &mut FPU, a: i8, rnd: RoundingMode) -> Self;
+
+    fn fpu_from_i16(fpu: &mut FPU, a: i16, rnd: RoundingMode) -> Self;
+
+    fn fpu_from_i32(fpu: &mut FPU, a: i32, rnd: RoundingMode) -> Self;
+
+    fn fpu_from_i64(fpu: &mut FPU, a: i64, rnd: RoundingMode) -> Self;
+
+    fn fpu_from_i128(fpu: &mut FPU, a: i128, rnd: RoundingMode) -> Self;
+
+    fn fpu_from_u8(fpu: &mut FPU, a: u8, rnd: RoundingMode) -> Self;
+
+    fn fpu_from_u16(fpu: &mut FPU, a: u16, rnd: RoundingMode) -> Self;
+
+    fn fpu_from_u32(fpu: &mut FPU, a: u32, rnd: RoundingMode) -> Self;
+
+    fn fpu_from_u64(fpu: &mut FPU, a: u64, rnd: RoundingMode) -> Self;
+
+    fn fpu_from_u128(fpu: &mut FPU, a: u128, rnd: RoundingMode) -> Self;
+
+    fn fpu_to_f32(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> float32_t;
+
+    fn fpu_to_f64(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> float64_t;
+
+    fn fpu_to_parts(fpu: &mut FPU, a: Self) -> Parts;
+
+    fn fpu_is_signaling_nan(fpu: &mut FPU, a: Self) -> bool;
+
+    fn fpu_nan_payload(fpu: &mut FPU, a: Self) -> Self;
+
+    fn fpu_from_nan_payload(fpu: &mut FPU, a: Self) -> Self;
+
+    fn fpu_from_nan_payload_signaling(fpu: &mut FPU, a: Self) -> Self;
+
+    fn fpu_canonicalize(fpu: &mut FPU, a: Self) -> Self;
+
+    fn fpu_is_canonical(fpu: &mut FPU, a: Self) -> bool;
+
+    fn fpu_lt(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_le(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_eq(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_eq_signaling(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_lt_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_le_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_gt(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_ge(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_gt_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_ge_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_ne(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_ne_signaling(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_unordered(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_unordered_signaling(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_ordered(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_ordered_signaling(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_not_gt(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_not_gt_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_lt_unordered(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_lt_unordered_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_not_lt(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_not_lt_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_gt_unordered(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_gt_unordered_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool;
+
+    fn fpu_compare_quiet(fpu: &mut FPU, a: Self, b: Self) -> Option<Ordering>;
+
+    fn fpu_compare_signaling(fpu: &mut FPU, a: Self, b: Self) -> Option<Ordering>;
+
+    fn fpu_add(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_mul_add(fpu: &mut FPU, a: Self, b: Self, c: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_div(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_mul(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_rem(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_sub(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_round_to_int(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> Self;
+
+    fn fpu_sqrt(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_exp(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_exp2(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_expm1(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_log(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_log2(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_log10(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_log1p(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_sin(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_cos(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_tan(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_asin(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_acos(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_atan(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_atan2(fpu: &mut FPU, y: Self, x: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_atan2pi(fpu: &mut FPU, y: Self, x: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_sinh(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_cosh(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_tanh(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_asinh(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_acosh(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_atanh(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_pow(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_pown(fpu: &mut FPU, a: Self, n: i64, rnd: RoundingMode) -> Self;
+
+    fn fpu_powr(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_rootn(fpu: &mut FPU, a: Self, n: i64, rnd: RoundingMode) -> Self;
+
+    fn fpu_compound(fpu: &mut FPU, a: Self, n: i64, rnd: RoundingMode) -> Self;
+
+    fn fpu_cbrt(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_hypot(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_rsqrt(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_erf(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_erfc(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_tgamma(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_lgamma(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> (Self, i8);
+
+    fn fpu_recip7(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self;
+
+    fn fpu_rsqrte7(fpu: &mut FPU, a: Self) -> Self;
+
+    fn fpu_to_fix16(fpu: &mut FPU, a: Self, fbits: u8, rnd: RoundingMode, exact: bool) -> i16;
+
+    fn fpu_to_ufix16(fpu: &mut FPU, a: Self, fbits: u8, rnd: RoundingMode, exact: bool) -> u16;
+
+    fn fpu_to_fix32(fpu: &mut FPU, a: Self, fbits: u8, rnd: RoundingMode, exact: bool) -> i32;
+
+    fn fpu_to_ufix32(fpu: &mut FPU, a: Self, fbits: u8, rnd: RoundingMode, exact: bool) -> u32;
+
+    fn fpu_to_fix64(fpu: &mut FPU, a: Self, fbits: u8, rnd: RoundingMode, exact: bool) -> i64;
+
+    fn fpu_to_ufix64(fpu: &mut FPU, a: Self, fbits: u8, rnd: RoundingMode, exact: bool) -> u64;
+
+    /// # Errors
+    ///
+    /// As [`FPU::parse`].
+    fn fpu_parse(fpu: &mut FPU, s: &str, rnd: RoundingMode) -> Result<Self, ParseFloatError>;
+
+    fn fpu_to_fixed(fpu: &mut FPU, a: Self, digits: usize, rnd: RoundingMode) -> Decimal;
+
+    fn fpu_to_exponent(fpu: &mut FPU, a: Self, digits: usize, rnd: RoundingMode) -> Decimal;
+
+    /// # Errors
+    ///
+    /// As [`FPU::parse_hex`].
+    fn fpu_parse_hex(fpu: &mut FPU, s: &str, rnd: RoundingMode) -> Result<Self, ParseFloatError>;
+
+    fn fpu_to_hex_digits(fpu: &mut FPU, a: Self, digits: usize, rnd: RoundingMode) -> Hex;
+
+    fn fpu_sum(fpu: &mut FPU, a: &[Self], rnd: RoundingMode) -> Self;
+
+    fn fpu_dot(fpu: &mut FPU, a: &[Self], b: &[Self], rnd: RoundingMode) -> Self;
+
+    fn fpu_sum_square(fpu: &mut FPU, a: &[Self], rnd: RoundingMode) -> Self;
+
+    fn fpu_sum_abs(fpu: &mut FPU, a: &[Self], rnd: RoundingMode) -> Self;
+
+    fn fpu_scaled_prod(fpu: &mut FPU, a: &[Self], rnd: RoundingMode) -> (Self, i64);
+
+    fn fpu_scaled_prod_sum(fpu: &mut FPU, a: &[Self], b: &[Self], rnd: RoundingMode)
+        -> (Self, i64);
+
+    fn fpu_scaled_prod_diff(
+        fpu: &mut FPU,
+        a: &[Self],
+        b: &[Self],
+        rnd: RoundingMode,
+    ) -> (Self, i64);
+
+    /// `a` converted to `Self`, as in [`FPU::convert`].
+    fn fpu_from_float<G: FpuFormat>(fpu: &mut FPU, a: G, rnd: RoundingMode) -> Self;
+}
+
 impl FPU {
     #[inline]
     #[must_use]
@@ -65,7 +530,7 @@ impl FPU {
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(x.borrow().to_i32(rnd, exact))
+        F::fpu_to_i32(self, *x.borrow(), rnd, exact)
     }
 
     #[inline]
@@ -75,7 +540,7 @@ impl FPU {
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().to_i64(rnd, exact))
+        F::fpu_to_i64(self, *a.borrow(), rnd, exact)
     }
 
     #[inline]
@@ -85,7 +550,7 @@ impl FPU {
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().to_u64(rnd, exact))
+        F::fpu_to_u64(self, *a.borrow(), rnd, exact)
     }
 
     #[inline]
@@ -95,228 +560,1112 @@ impl FPU {
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().to_u32(rnd, exact))
+        F::fpu_to_u32(self, *a.borrow(), rnd, exact)
     }
 
     #[inline]
     #[must_use]
-    pub fn to_f64<F, T>(&mut self, a: T, rnd: RoundingMode) -> float64_t
+    pub fn to_i8<F, T>(&mut self, a: T, rnd: RoundingMode, exact: bool) -> i8
     where
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().to_f64(rnd, self.detect_tininess))
+        F::fpu_to_i8(self, *a.borrow(), rnd, exact)
     }
 
     #[inline]
     #[must_use]
-    pub fn to_f32<F, T>(&mut self, a: T, rnd: RoundingMode) -> float32_t
+    pub fn to_i16<F, T>(&mut self, a: T, rnd: RoundingMode, exact: bool) -> i16
     where
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().to_f32(rnd, self.detect_tininess))
+        F::fpu_to_i16(self, *a.borrow(), rnd, exact)
     }
 
     #[inline]
     #[must_use]
-    pub fn is_signaling_nan<F, T>(&mut self, a: T) -> bool
+    pub fn to_i128<F, T>(&mut self, a: T, rnd: RoundingMode, exact: bool) -> i128
     where
         F: Float,
         T: Borrow<F>,
     {
-        a.borrow().is_signaling_nan()
+        F::fpu_to_i128(self, *a.borrow(), rnd, exact)
     }
 
     #[inline]
     #[must_use]
-    pub fn lt<F, T>(&mut self, a: T, b: T) -> bool
+    pub fn to_u8<F, T>(&mut self, a: T, rnd: RoundingMode, exact: bool) -> u8
     where
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().lt(b.borrow()))
+        F::fpu_to_u8(self, *a.borrow(), rnd, exact)
     }
 
     #[inline]
     #[must_use]
-    pub fn le<F, T>(&mut self, a: T, b: T) -> bool
+    pub fn to_u16<F, T>(&mut self, a: T, rnd: RoundingMode, exact: bool) -> u16
     where
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().le(b.borrow()))
+        F::fpu_to_u16(self, *a.borrow(), rnd, exact)
     }
 
     #[inline]
     #[must_use]
-    pub fn eq<F, T>(&mut self, a: T, b: T) -> bool
+    pub fn to_u128<F, T>(&mut self, a: T, rnd: RoundingMode, exact: bool) -> u128
     where
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().eq(b.borrow()))
+        F::fpu_to_u128(self, *a.borrow(), rnd, exact)
     }
 
+    /// Converts `a` to an integer of arbitrary length, stored in `z` as
+    /// little-endian 64-bit limbs in sign-magnitude form, and returns the
+    /// sign. Values that do not fit saturate `z` and raise invalid.
     #[inline]
     #[must_use]
-    pub fn eq_signaling<F, T>(&mut self, a: T, b: T) -> bool
+    pub fn to_limbs<F, T>(&mut self, a: T, z: &mut [u64], rnd: RoundingMode, exact: bool) -> bool
     where
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().eq_signaling(b.borrow()))
+        F::fpu_to_limbs(self, *a.borrow(), z, rnd, exact)
     }
 
+    /// Rounds `(-1)^sign × significand × 2^exponent` once to `F`, as in
+    /// `fpu.from_parts::<float32_t>(false, 3, -1, rnd)`.
     #[inline]
     #[must_use]
-    pub fn lt_quiet<F, T>(&mut self, a: T, b: T) -> bool
+    pub fn from_parts<F: Float>(
+        &mut self,
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        rnd: RoundingMode,
+    ) -> F {
+        F::fpu_from_parts(self, sign, significand, exponent, rnd)
+    }
+
+    /// Decomposes `a` into its class, sign, exponent and integer
+    /// significand.
+    #[inline]
+    #[must_use]
+    pub fn to_parts<F, T>(&mut self, a: T) -> Parts
     where
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().lt_quiet(b))
+        F::fpu_to_parts(self, *a.borrow())
     }
 
     #[inline]
     #[must_use]
-    pub fn le_quiet<F, T>(&mut self, a: T, b: T) -> bool
+    pub fn to_f64<F, T>(&mut self, a: T, rnd: RoundingMode) -> float64_t
     where
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().le_quiet(b))
+        F::fpu_to_f64(self, *a.borrow(), rnd)
     }
 
     #[inline]
     #[must_use]
-    pub fn add<F, T>(&mut self, a: T, b: T, rnd: RoundingMode) -> F
+    pub fn to_f32<F, T>(&mut self, a: T, rnd: RoundingMode) -> float32_t
     where
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().add(b, rnd, self.detect_tininess))
+        F::fpu_to_f32(self, *a.borrow(), rnd)
     }
 
+    /// Converts `a` between any two formats, as in
+    /// `fpu.convert::<float32_t, float64_t>(a, rnd)`.
     #[inline]
     #[must_use]
-    pub fn mul_add<F, T>(&mut self, a: T, b: T, c: T, rnd: RoundingMode) -> F
+    pub fn convert<F: Float, G: Float>(&mut self, a: impl Borrow<F>, rnd: RoundingMode) -> G {
+        G::fpu_from_float(self, *a.borrow(), rnd)
+    }
+
+    /// Converts an integer of any width to `F`, as in
+    /// `fpu.from_int::<float64_t, _>(a, rnd)`.
+    #[inline]
+    #[must_use]
+    pub fn from_int<F: Float, I: Int>(&mut self, a: I, rnd: RoundingMode) -> F {
+        a.fpu_to_float(self, rnd)
+    }
+
+    /// Rounds `a` to an integer of any width, as in
+    /// `fpu.to_int::<i32, _>(a, rnd, exact)`.
+    #[inline]
+    #[must_use]
+    pub fn to_int<I: Int, F: Float>(
+        &mut self,
+        a: impl Borrow<F>,
+        rnd: RoundingMode,
+        exact: bool,
+    ) -> I {
+        I::fpu_from_float(self, *a.borrow(), rnd, exact)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_signaling_nan<F, T>(&mut self, a: T) -> bool
     where
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().fused_mul_add(b, c, rnd, self.detect_tininess))
+        F::fpu_is_signaling_nan(self, *a.borrow())
     }
 
+    /// The NaN payload of `a`, or `-1` if `a` is not a NaN (getPayload).
     #[inline]
     #[must_use]
-    pub fn div<F, T>(&mut self, a: T, b: T, rnd: RoundingMode) -> F
+    pub fn nan_payload<F, T>(&mut self, a: T) -> F
     where
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().div(b, rnd, self.detect_tininess))
+        F::fpu_nan_payload(self, *a.borrow())
     }
 
+    /// A quiet NaN with payload `a`, or `+0` if `a` is not an admissible
+    /// payload (setPayload).
     #[inline]
     #[must_use]
-    pub fn mul<F, T>(&mut self, a: T, b: T, rnd: RoundingMode) -> F
+    pub fn from_nan_payload<F, T>(&mut self, a: T) -> F
     where
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().mul(b, rnd, self.detect_tininess))
+        F::fpu_from_nan_payload(self, *a.borrow())
     }
 
+    /// A signaling NaN with payload `a`, or `+0` if `a` is not an admissible
+    /// payload (setPayloadSignaling).
     #[inline]
     #[must_use]
-    pub fn rem<F, T>(&mut self, a: T, b: T, rnd: RoundingMode) -> F
+    pub fn from_nan_payload_signaling<F, T>(&mut self, a: T) -> F
     where
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().rem(b, rnd, self.detect_tininess))
+        F::fpu_from_nan_payload_signaling(self, *a.borrow())
     }
 
+    /// The canonical encoding of `a`, quieting signaling NaNs.
     #[inline]
     #[must_use]
-    pub fn sub<F, T>(&mut self, a: T, b: T, rnd: RoundingMode) -> F
+    pub fn canonicalize<F, T>(&mut self, a: T) -> F
     where
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().sub(b, rnd, self.detect_tininess))
+        F::fpu_canonicalize(self, *a.borrow())
     }
 
     #[inline]
     #[must_use]
-    pub fn round_to_int<F, T>(&mut self, a: T, rnd: RoundingMode, exact: bool) -> F
+    pub fn is_canonical<F, T>(&mut self, a: T) -> bool
     where
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().round_to_integral(rnd, exact))
+        F::fpu_is_canonical(self, *a.borrow())
     }
 
     #[inline]
     #[must_use]
-    pub fn sqrt<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    pub fn lt<F, T>(&mut self, a: T, b: T) -> bool
     where
         F: Float,
         T: Borrow<F>,
     {
-        self.flagged(a.borrow().sqrt(rnd, self.detect_tininess))
+        F::fpu_lt(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn le<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_le(self, *a.borrow(), *b.borrow())
     }
-}
 
-impl FPU {
     #[inline]
     #[must_use]
-    pub fn f32_from_i64(&mut self, a: i64, rnd: RoundingMode) -> float32_t {
-        self.flagged_f32(i64_to_f32(a, rnd.to_softfloat(), self.detect_tininess))
+    pub fn eq<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_eq(self, *a.borrow(), *b.borrow())
     }
 
     #[inline]
     #[must_use]
-    pub fn f32_from_i32(&mut self, a: i32, rnd: RoundingMode) -> float32_t {
-        self.flagged_f32(i32_to_f32(a, rnd.to_softfloat(), self.detect_tininess))
+    pub fn eq_signaling<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_eq_signaling(self, *a.borrow(), *b.borrow())
     }
 
     #[inline]
     #[must_use]
-    pub fn f32_from_u64(&mut self, a: u64, rnd: RoundingMode) -> float32_t {
-        self.flagged_f32(ui64_to_f32(a, rnd.to_softfloat(), self.detect_tininess))
+    pub fn lt_quiet<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_lt_quiet(self, *a.borrow(), *b.borrow())
     }
 
     #[inline]
     #[must_use]
-    pub fn f32_from_u32(&mut self, a: u32, rnd: RoundingMode) -> float32_t {
-        self.flagged_f32(ui32_to_f32(a, rnd.to_softfloat(), self.detect_tininess))
+    pub fn le_quiet<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_le_quiet(self, *a.borrow(), *b.borrow())
     }
-}
 
-impl FPU {
     #[inline]
     #[must_use]
-    pub fn f64_from_i64(&mut self, a: i64, rnd: RoundingMode) -> float64_t {
-        self.flagged_f64(i64_to_f64(a, rnd.to_softfloat(), self.detect_tininess))
+    pub fn gt<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_gt(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ge<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_ge(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn gt_quiet<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_gt_quiet(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ge_quiet<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_ge_quiet(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ne<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_ne(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ne_signaling<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_ne_signaling(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn unordered<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_unordered(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn unordered_signaling<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_unordered_signaling(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ordered<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_ordered(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ordered_signaling<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_ordered_signaling(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn not_gt<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_not_gt(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn not_gt_quiet<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_not_gt_quiet(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn lt_unordered<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_lt_unordered(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn lt_unordered_quiet<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_lt_unordered_quiet(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn not_lt<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_not_lt(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn not_lt_quiet<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_not_lt_quiet(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn gt_unordered<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_gt_unordered(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn gt_unordered_quiet<F, T>(&mut self, a: T, b: T) -> bool
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_gt_unordered_quiet(self, *a.borrow(), *b.borrow())
+    }
+
+    /// Orders `a` and `b`, or `None` if either is a NaN; only signaling NaNs
+    /// raise invalid.
+    #[inline]
+    #[must_use]
+    pub fn compare_quiet<F, T>(&mut self, a: T, b: T) -> Option<Ordering>
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_compare_quiet(self, *a.borrow(), *b.borrow())
+    }
+
+    /// Orders `a` and `b`, or `None` if either is a NaN; any NaN raises
+    /// invalid.
+    #[inline]
+    #[must_use]
+    pub fn compare_signaling<F, T>(&mut self, a: T, b: T) -> Option<Ordering>
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_compare_signaling(self, *a.borrow(), *b.borrow())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn add<F, T>(&mut self, a: T, b: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_add(self, *a.borrow(), *b.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn mul_add<F, T>(&mut self, a: T, b: T, c: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_mul_add(self, *a.borrow(), *b.borrow(), *c.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn div<F, T>(&mut self, a: T, b: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_div(self, *a.borrow(), *b.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn mul<F, T>(&mut self, a: T, b: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_mul(self, *a.borrow(), *b.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn rem<F, T>(&mut self, a: T, b: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_rem(self, *a.borrow(), *b.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn sub<F, T>(&mut self, a: T, b: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_sub(self, *a.borrow(), *b.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn round_to_int<F, T>(&mut self, a: T, rnd: RoundingMode, exact: bool) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_round_to_int(self, *a.borrow(), rnd, exact)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn sqrt<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_sqrt(self, *a.borrow(), rnd)
+    }
+}
+
+impl FPU {
+    #[inline]
+    #[must_use]
+    pub fn exp<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_exp(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn exp2<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_exp2(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn expm1<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_expm1(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn log<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_log(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn log2<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_log2(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn log10<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_log10(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn log1p<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_log1p(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn sin<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_sin(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn cos<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_cos(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn tan<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_tan(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn asin<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_asin(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn acos<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_acos(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn atan<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_atan(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn atan2<F, T>(&mut self, y: T, x: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_atan2(self, *y.borrow(), *x.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn atan2pi<F, T>(&mut self, y: T, x: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_atan2pi(self, *y.borrow(), *x.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn sinh<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_sinh(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn cosh<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_cosh(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn tanh<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_tanh(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn asinh<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_asinh(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn acosh<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_acosh(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn atanh<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_atanh(self, *a.borrow(), rnd)
+    }
+}
+
+impl FPU {
+    #[inline]
+    #[must_use]
+    pub fn pow<F, T>(&mut self, a: T, b: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_pow(self, *a.borrow(), *b.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn pown<F, T>(&mut self, a: T, n: i64, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_pown(self, *a.borrow(), n, rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn powr<F, T>(&mut self, a: T, b: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_powr(self, *a.borrow(), *b.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn rootn<F, T>(&mut self, a: T, n: i64, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_rootn(self, *a.borrow(), n, rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn compound<F, T>(&mut self, a: T, n: i64, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_compound(self, *a.borrow(), n, rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn cbrt<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_cbrt(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn hypot<F, T>(&mut self, a: T, b: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_hypot(self, *a.borrow(), *b.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn rsqrt<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_rsqrt(self, *a.borrow(), rnd)
+    }
+}
+
+impl FPU {
+    #[inline]
+    #[must_use]
+    pub fn erf<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_erf(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn erfc<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_erfc(self, *a.borrow(), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn tgamma<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_tgamma(self, *a.borrow(), rnd)
+    }
+
+    /// `ln |Γ(a)|` together with the sign of `Γ(a)`.
+    #[inline]
+    #[must_use]
+    pub fn lgamma<F, T>(&mut self, a: T, rnd: RoundingMode) -> (F, i8)
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_lgamma(self, *a.borrow(), rnd)
+    }
+}
+
+impl FPU {
+    /// Reciprocal estimate to 7 bits, as computed by the RISC-V `vfrec7.v`
+    /// instruction.
+    #[inline]
+    #[must_use]
+    pub fn recip7<F, T>(&mut self, a: T, rnd: RoundingMode) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_recip7(self, *a.borrow(), rnd)
+    }
+
+    /// Reciprocal square root estimate to 7 bits, as computed by the RISC-V
+    /// `vfrsqrt7.v` instruction.
+    #[inline]
+    #[must_use]
+    pub fn rsqrte7<F, T>(&mut self, a: T) -> F
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_rsqrte7(self, *a.borrow())
+    }
+}
+
+impl FPU {
+    /// Converts `a` to fixed point with `fbits` fractional bits, rounding
+    /// `a * 2^fbits` to an integer as ARM `FCVTZS #fbits` does for the
+    /// rounding mode `RtzTowardZero`. Out-of-range values saturate and raise
+    /// invalid.
+    #[inline]
+    #[must_use]
+    pub fn to_fix16<F, T>(&mut self, a: T, fbits: u8, rnd: RoundingMode, exact: bool) -> i16
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_to_fix16(self, *a.borrow(), fbits, rnd, exact)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn to_ufix16<F, T>(&mut self, a: T, fbits: u8, rnd: RoundingMode, exact: bool) -> u16
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_to_ufix16(self, *a.borrow(), fbits, rnd, exact)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn to_fix32<F, T>(&mut self, a: T, fbits: u8, rnd: RoundingMode, exact: bool) -> i32
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_to_fix32(self, *a.borrow(), fbits, rnd, exact)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn to_ufix32<F, T>(&mut self, a: T, fbits: u8, rnd: RoundingMode, exact: bool) -> u32
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_to_ufix32(self, *a.borrow(), fbits, rnd, exact)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn to_fix64<F, T>(&mut self, a: T, fbits: u8, rnd: RoundingMode, exact: bool) -> i64
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_to_fix64(self, *a.borrow(), fbits, rnd, exact)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn to_ufix64<F, T>(&mut self, a: T, fbits: u8, rnd: RoundingMode, exact: bool) -> u64
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_to_ufix64(self, *a.borrow(), fbits, rnd, exact)
+    }
+}
+
+impl FPU {
+    /// Parses a decimal literal, correctly rounded under `rnd`. See
+    /// [`crate::text::f64_fromDecimal`] for the accepted syntax.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseFloatError`] if `s` is not a valid literal; no flags
+    /// are raised then.
+    #[inline]
+    pub fn parse<F: Float>(&mut self, s: &str, rnd: RoundingMode) -> Result<F, ParseFloatError> {
+        F::fpu_parse(self, s, rnd)
+    }
+
+    /// Formats `a` with `digits` digits after the decimal point, correctly
+    /// rounded under `rnd`, like C's `%.*f`.
+    #[inline]
+    #[must_use]
+    pub fn to_fixed<F, T>(&mut self, a: T, digits: usize, rnd: RoundingMode) -> Decimal
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_to_fixed(self, *a.borrow(), digits, rnd)
+    }
+
+    /// Formats `a` in scientific notation with `digits` digits after the
+    /// decimal point, correctly rounded under `rnd`, like C's `%.*e`.
+    #[inline]
+    #[must_use]
+    pub fn to_exponent<F, T>(&mut self, a: T, digits: usize, rnd: RoundingMode) -> Decimal
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_to_exponent(self, *a.borrow(), digits, rnd)
+    }
+
+    /// Parses a hexadecimal literal such as `0x1.8p3`, correctly rounded
+    /// under `rnd`. See [`crate::text::f64_fromHex`] for the accepted syntax.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseFloatError`] if `s` is not a valid literal; no flags
+    /// are raised then.
+    #[inline]
+    pub fn parse_hex<F: Float>(
+        &mut self,
+        s: &str,
+        rnd: RoundingMode,
+    ) -> Result<F, ParseFloatError> {
+        F::fpu_parse_hex(self, s, rnd)
+    }
+
+    /// Formats `a` in hexadecimal scientific notation with `digits` digits
+    /// after the point, correctly rounded under `rnd`, like C's `%.*a`.
+    #[inline]
+    #[must_use]
+    pub fn to_hex_digits<F, T>(&mut self, a: T, digits: usize, rnd: RoundingMode) -> Hex
+    where
+        F: Float,
+        T: Borrow<F>,
+    {
+        F::fpu_to_hex_digits(self, *a.borrow(), digits, rnd)
+    }
+}
+
+impl FPU {
+    /// Sum of `a`, accumulated exactly and rounded once under `rnd`.
+    #[inline]
+    #[must_use]
+    pub fn sum<F: Float>(&mut self, a: &[F], rnd: RoundingMode) -> F {
+        F::fpu_sum(self, a, rnd)
+    }
+
+    /// Dot product of `a` and `b`, accumulated exactly and rounded once
+    /// under `rnd`.
+    #[inline]
+    #[must_use]
+    pub fn dot<F: Float>(&mut self, a: &[F], b: &[F], rnd: RoundingMode) -> F {
+        F::fpu_dot(self, a, b, rnd)
+    }
+
+    /// Sum of the squares of `a`, rounded once under `rnd`.
+    #[inline]
+    #[must_use]
+    pub fn sum_square<F: Float>(&mut self, a: &[F], rnd: RoundingMode) -> F {
+        F::fpu_sum_square(self, a, rnd)
+    }
+
+    /// Sum of the magnitudes of `a`, rounded once under `rnd`.
+    #[inline]
+    #[must_use]
+    pub fn sum_abs<F: Float>(&mut self, a: &[F], rnd: RoundingMode) -> F {
+        F::fpu_sum_abs(self, a, rnd)
     }
 
+    /// Product of `a` as `(pr, sf)` with `pr × 2^sf` the product and
+    /// `1 <= |pr| < 2`, so that it cannot overflow or underflow.
     #[inline]
     #[must_use]
-    pub fn f64_from_i32(&mut self, a: i32) -> float64_t {
-        i32_to_f64(a)
+    pub fn scaled_prod<F: Float>(&mut self, a: &[F], rnd: RoundingMode) -> (F, i64) {
+        F::fpu_scaled_prod(self, a, rnd)
     }
 
+    /// Product of `a[i] + b[i]`, scaled like [`FPU::scaled_prod`].
     #[inline]
     #[must_use]
-    pub fn f64_from_u64(&mut self, a: u64, rnd: RoundingMode) -> float64_t {
-        self.flagged_f64(ui64_to_f64(a, rnd.to_softfloat(), self.detect_tininess))
+    pub fn scaled_prod_sum<F: Float>(&mut self, a: &[F], b: &[F], rnd: RoundingMode) -> (F, i64) {
+        F::fpu_scaled_prod_sum(self, a, b, rnd)
     }
 
+    /// Product of `a[i] - b[i]`, scaled like [`FPU::scaled_prod`].
     #[inline]
     #[must_use]
-    pub fn f64_from_u32(&mut self, a: u32) -> float64_t {
-        ui32_to_f64(a)
+    pub fn scaled_prod_diff<F: Float>(&mut self, a: &[F], b: &[F], rnd: RoundingMode) -> (F, i64) {
+        F::fpu_scaled_prod_diff(self, a, b, rnd)
     }
 }
@@ -0,0 +1,1970 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! `const` operations on `float32_t`.
+
+use core::cmp::Ordering;
+
+use super::{limbs_parts, FpuFormat, FPU};
+use crate::math::{
+    f32_acos, f32_acosh, f32_addF64, f32_addF64Stochastic, f32_addStochastic, f32_asin, f32_asinh,
+    f32_atan, f32_atan2, f32_atan2pi, f32_atanh, f32_canonicalize, f32_cbrt, f32_compound, f32_cos,
+    f32_cosh, f32_divF64, f32_divF64Stochastic, f32_divStochastic, f32_dot, f32_erf, f32_erfc,
+    f32_exp, f32_exp2, f32_expm1, f32_fromParts, f32_fromPartsStochastic, f32_gammaSign,
+    f32_getPayload, f32_hypot, f32_isCanonical, f32_lgamma, f32_log, f32_log10, f32_log1p,
+    f32_log2, f32_mulAddF64, f32_mulAddF64Stochastic, f32_mulAddStochastic, f32_mulF64,
+    f32_mulF64Stochastic, f32_mulStochastic, f32_pow, f32_pown, f32_powr, f32_rSqrt, f32_rootn,
+    f32_roundToPrecision, f32_roundToPrecisionStochastic, f32_scaledProd, f32_scaledProdDiff,
+    f32_scaledProdSum, f32_setPayload, f32_setPayloadSignaling, f32_sin, f32_sinh, f32_sqrtF64,
+    f32_sqrtF64Stochastic, f32_sqrtStochastic, f32_subF64, f32_subF64Stochastic, f32_subStochastic,
+    f32_sum, f32_sumAbs, f32_sumSquare, f32_tan, f32_tanh, f32_tgamma, f32_toParts, Parts,
+};
+use crate::softfloat::{
+    f32_add, f32_div, f32_eq, f32_eq_signaling, f32_isSignalingNaN, f32_le, f32_le_quiet, f32_lt,
+    f32_lt_quiet, f32_mul, f32_mulAdd, f32_recip7, f32_rem, f32_roundToInt, f32_rsqrte7, f32_sqrt,
+    f32_sub, f32_to_f64, f32_to_fix16, f32_to_fix32, f32_to_fix64, f32_to_i128, f32_to_i16,
+    f32_to_i32, f32_to_i64, f32_to_i8, f32_to_limbs, f32_to_ufix16, f32_to_ufix32, f32_to_ufix64,
+    f32_to_ui128, f32_to_ui16, f32_to_ui32, f32_to_ui64, f32_to_ui8, fix32_to_f32, fix64_to_f32,
+    float32_t, float64_t, i128_to_f32, i32_to_f32, i64_to_f32, isNaNF32UI, limbs_to_f32,
+    ufix32_to_f32, ufix64_to_f32, ui128_to_f32, ui32_to_f32, ui64_to_f32,
+};
+use crate::text::{
+    f32_fromDecimal, f32_fromHex, f32_toExponent, f32_toFixed, f32_toHexDigits, Decimal, Hex,
+    ParseFloatError,
+};
+use crate::wrapper::RoundingMode;
+
+/// Orders `a` and `b`, raising invalid only for signaling NaNs.
+const fn compare_quiet(a: float32_t, b: float32_t) -> (Option<Ordering>, u8) {
+    let (eq, flags) = f32_eq(a, b);
+    let (lt, _) = f32_lt_quiet(a, b);
+    if isNaNF32UI(a.v) || isNaNF32UI(b.v) {
+        (None, flags)
+    } else if eq {
+        (Some(Ordering::Equal), flags)
+    } else if lt {
+        (Some(Ordering::Less), flags)
+    } else {
+        (Some(Ordering::Greater), flags)
+    }
+}
+
+/// Orders `a` and `b`, raising invalid for any NaN.
+const fn compare_signaling(a: float32_t, b: float32_t) -> (Option<Ordering>, u8) {
+    let (ord, _) = compare_quiet(a, b);
+    let (_, flags) = f32_lt(a, b);
+    (ord, flags)
+}
+
+impl FPU {
+    /// Like [`FPU::flagged_const`], then rounds to the emulated precision.
+    #[inline]
+    pub(super) const fn rounded_f32(
+        &mut self,
+        args: (float32_t, u8),
+        rnd: RoundingMode,
+    ) -> float32_t {
+        let x = self.flagged_const(args);
+        match self.precision {
+            Some(p) => {
+                let r = match self.draw() {
+                    Some(random) => f32_roundToPrecisionStochastic(
+                        x,
+                        p.sig_bits(),
+                        p.emin(),
+                        p.emax(),
+                        random,
+                        self.detect_tininess(),
+                    ),
+                    None => f32_roundToPrecision(
+                        x,
+                        p.sig_bits(),
+                        p.emin(),
+                        p.emax(),
+                        rnd.to_softfloat(),
+                        self.detect_tininess(),
+                    ),
+                };
+                self.flagged_const(r)
+            }
+            None => x,
+        }
+    }
+
+    /// Like [`FPU::rounded_f32`] for `(-1)^sign × significand × 2^exponent`,
+    /// computed as `args` unless rounding stochastically.
+    #[inline]
+    const fn converted_f32(
+        &mut self,
+        args: (float32_t, u8),
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        rnd: RoundingMode,
+    ) -> float32_t {
+        let args = match self.draw() {
+            Some(random) => {
+                f32_fromPartsStochastic(sign, significand, exponent, random, self.detect_tininess())
+            }
+            None => args,
+        };
+        self.rounded_f32(args, rnd)
+    }
+}
+
+impl FPU {
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_i32(&mut self, a: float32_t, rnd: RoundingMode, exact: bool) -> i32 {
+        self.flagged_const(f32_to_i32(a, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_i64(&mut self, a: float32_t, rnd: RoundingMode, exact: bool) -> i64 {
+        self.flagged_const(f32_to_i64(a, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_u64(&mut self, a: float32_t, rnd: RoundingMode, exact: bool) -> u64 {
+        self.flagged_const(f32_to_ui64(a, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_u32(&mut self, a: float32_t, rnd: RoundingMode, exact: bool) -> u32 {
+        self.flagged_const(f32_to_ui32(a, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_i8(&mut self, a: float32_t, rnd: RoundingMode, exact: bool) -> i8 {
+        self.flagged_const(f32_to_i8(a, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_i16(&mut self, a: float32_t, rnd: RoundingMode, exact: bool) -> i16 {
+        self.flagged_const(f32_to_i16(a, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_i128(&mut self, a: float32_t, rnd: RoundingMode, exact: bool) -> i128 {
+        self.flagged_const(f32_to_i128(a, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_u8(&mut self, a: float32_t, rnd: RoundingMode, exact: bool) -> u8 {
+        self.flagged_const(f32_to_ui8(a, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_u16(&mut self, a: float32_t, rnd: RoundingMode, exact: bool) -> u16 {
+        self.flagged_const(f32_to_ui16(a, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_u128(&mut self, a: float32_t, rnd: RoundingMode, exact: bool) -> u128 {
+        self.flagged_const(f32_to_ui128(a, rnd.to_softfloat(), exact))
+    }
+
+    /// Converts `a` to an integer of arbitrary length, stored in `z` as
+    /// little-endian 64-bit limbs in sign-magnitude form, and returns the
+    /// sign. Values that do not fit saturate `z` and raise invalid.
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_limbs(
+        &mut self,
+        a: float32_t,
+        z: &mut [u64],
+        rnd: RoundingMode,
+        exact: bool,
+    ) -> bool {
+        self.flagged_const(f32_to_limbs(a, z, rnd.to_softfloat(), exact))
+    }
+
+    /// Rounds `(-1)^sign × significand × 2^exponent` once to `float32_t`.
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_parts(
+        &mut self,
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        rnd: RoundingMode,
+    ) -> float32_t {
+        self.rounded_f32(
+            f32_fromParts(
+                sign,
+                significand,
+                exponent,
+                rnd.to_softfloat(),
+                self.detect_tininess(),
+            ),
+            rnd,
+        )
+    }
+
+    /// Decomposes `a` into its class, sign, exponent and integer
+    /// significand.
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_parts(&mut self, a: float32_t) -> Parts {
+        f32_toParts(a)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_f64(&mut self, a: float32_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f32_to_f64(a), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_is_signaling_nan(&mut self, a: float32_t) -> bool {
+        f32_isSignalingNaN(a)
+    }
+
+    /// The NaN payload of `a`, or `-1` if `a` is not a NaN (getPayload).
+    #[inline]
+    #[must_use]
+    pub const fn f32_nan_payload(&mut self, a: float32_t) -> float32_t {
+        f32_getPayload(a)
+    }
+
+    /// A quiet NaN with payload `a`, or `+0` if `a` is not an admissible
+    /// payload (setPayload).
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_nan_payload(&mut self, a: float32_t) -> float32_t {
+        f32_setPayload(a)
+    }
+
+    /// A signaling NaN with payload `a`, or `+0` if `a` is not an admissible
+    /// payload (setPayloadSignaling).
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_nan_payload_signaling(&mut self, a: float32_t) -> float32_t {
+        f32_setPayloadSignaling(a)
+    }
+
+    /// The canonical encoding of `a`, quieting signaling NaNs.
+    #[inline]
+    #[must_use]
+    pub const fn f32_canonicalize(&mut self, a: float32_t) -> float32_t {
+        self.flagged_const(f32_canonicalize(a))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_is_canonical(&mut self, a: float32_t) -> bool {
+        f32_isCanonical(a)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_lt(&mut self, a: float32_t, b: float32_t) -> bool {
+        self.flagged_const(f32_lt(a, b))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_le(&mut self, a: float32_t, b: float32_t) -> bool {
+        self.flagged_const(f32_le(a, b))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_eq(&mut self, a: float32_t, b: float32_t) -> bool {
+        self.flagged_const(f32_eq(a, b))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_eq_signaling(&mut self, a: float32_t, b: float32_t) -> bool {
+        self.flagged_const(f32_eq_signaling(a, b))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_lt_quiet(&mut self, a: float32_t, b: float32_t) -> bool {
+        self.flagged_const(f32_lt_quiet(a, b))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_le_quiet(&mut self, a: float32_t, b: float32_t) -> bool {
+        self.flagged_const(f32_le_quiet(a, b))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_gt(&mut self, a: float32_t, b: float32_t) -> bool {
+        self.flagged_const(f32_lt(b, a))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_ge(&mut self, a: float32_t, b: float32_t) -> bool {
+        self.flagged_const(f32_le(b, a))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_gt_quiet(&mut self, a: float32_t, b: float32_t) -> bool {
+        self.flagged_const(f32_lt_quiet(b, a))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_ge_quiet(&mut self, a: float32_t, b: float32_t) -> bool {
+        self.flagged_const(f32_le_quiet(b, a))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_ne(&mut self, a: float32_t, b: float32_t) -> bool {
+        let (r, flags) = f32_eq(a, b);
+        self.flagged_const((!r, flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_ne_signaling(&mut self, a: float32_t, b: float32_t) -> bool {
+        let (r, flags) = f32_eq_signaling(a, b);
+        self.flagged_const((!r, flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_unordered(&mut self, a: float32_t, b: float32_t) -> bool {
+        let (ord, flags) = compare_quiet(a, b);
+        self.flagged_const((ord.is_none(), flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_unordered_signaling(&mut self, a: float32_t, b: float32_t) -> bool {
+        let (ord, flags) = compare_signaling(a, b);
+        self.flagged_const((ord.is_none(), flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_ordered(&mut self, a: float32_t, b: float32_t) -> bool {
+        let (ord, flags) = compare_quiet(a, b);
+        self.flagged_const((ord.is_some(), flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_ordered_signaling(&mut self, a: float32_t, b: float32_t) -> bool {
+        let (ord, flags) = compare_signaling(a, b);
+        self.flagged_const((ord.is_some(), flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_not_gt(&mut self, a: float32_t, b: float32_t) -> bool {
+        let (r, flags) = f32_lt(b, a);
+        self.flagged_const((!r, flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_not_gt_quiet(&mut self, a: float32_t, b: float32_t) -> bool {
+        let (r, flags) = f32_lt_quiet(b, a);
+        self.flagged_const((!r, flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_lt_unordered(&mut self, a: float32_t, b: float32_t) -> bool {
+        let (r, flags) = f32_le(b, a);
+        self.flagged_const((!r, flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_lt_unordered_quiet(&mut self, a: float32_t, b: float32_t) -> bool {
+        let (r, flags) = f32_le_quiet(b, a);
+        self.flagged_const((!r, flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_not_lt(&mut self, a: float32_t, b: float32_t) -> bool {
+        let (r, flags) = f32_lt(a, b);
+        self.flagged_const((!r, flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_not_lt_quiet(&mut self, a: float32_t, b: float32_t) -> bool {
+        let (r, flags) = f32_lt_quiet(a, b);
+        self.flagged_const((!r, flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_gt_unordered(&mut self, a: float32_t, b: float32_t) -> bool {
+        let (r, flags) = f32_le(a, b);
+        self.flagged_const((!r, flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_gt_unordered_quiet(&mut self, a: float32_t, b: float32_t) -> bool {
+        let (r, flags) = f32_le_quiet(a, b);
+        self.flagged_const((!r, flags))
+    }
+
+    /// Orders `a` and `b`, or `None` if either is a NaN; only signaling NaNs
+    /// raise invalid.
+    #[inline]
+    #[must_use]
+    pub const fn f32_compare_quiet(&mut self, a: float32_t, b: float32_t) -> Option<Ordering> {
+        self.flagged_const(compare_quiet(a, b))
+    }
+
+    /// Orders `a` and `b`, or `None` if either is a NaN; any NaN raises
+    /// invalid.
+    #[inline]
+    #[must_use]
+    pub const fn f32_compare_signaling(&mut self, a: float32_t, b: float32_t) -> Option<Ordering> {
+        self.flagged_const(compare_signaling(a, b))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_add(&mut self, a: float32_t, b: float32_t, rnd: RoundingMode) -> float32_t {
+        let args = match self.draw() {
+            Some(random) => f32_addStochastic(a, b, random, self.detect_tininess()),
+            None => f32_add(a, b, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f32(args, rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_mul_add(
+        &mut self,
+        a: float32_t,
+        b: float32_t,
+        c: float32_t,
+        rnd: RoundingMode,
+    ) -> float32_t {
+        let args = match self.draw() {
+            Some(random) => f32_mulAddStochastic(a, b, c, random, self.detect_tininess()),
+            None => f32_mulAdd(a, b, c, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f32(args, rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_div(&mut self, a: float32_t, b: float32_t, rnd: RoundingMode) -> float32_t {
+        let args = match self.draw() {
+            Some(random) => f32_divStochastic(a, b, random, self.detect_tininess()),
+            None => f32_div(a, b, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f32(args, rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_mul(&mut self, a: float32_t, b: float32_t, rnd: RoundingMode) -> float32_t {
+        let args = match self.draw() {
+            Some(random) => f32_mulStochastic(a, b, random, self.detect_tininess()),
+            None => f32_mul(a, b, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f32(args, rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_rem(&mut self, a: float32_t, b: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(
+            f32_rem(a, b, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_sub(&mut self, a: float32_t, b: float32_t, rnd: RoundingMode) -> float32_t {
+        let args = match self.draw() {
+            Some(random) => f32_subStochastic(a, b, random, self.detect_tininess()),
+            None => f32_sub(a, b, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f32(args, rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_round_to_int(
+        &mut self,
+        a: float32_t,
+        rnd: RoundingMode,
+        exact: bool,
+    ) -> float32_t {
+        self.rounded_f32(f32_roundToInt(a, rnd.to_softfloat(), exact), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_sqrt(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        let args = match self.draw() {
+            Some(random) => f32_sqrtStochastic(a, random, self.detect_tininess()),
+            None => f32_sqrt(a, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f32(args, rnd)
+    }
+}
+
+impl FPU {
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_i64(&mut self, a: i64, rnd: RoundingMode) -> float32_t {
+        self.converted_f32(
+            i64_to_f32(a, rnd.to_softfloat(), self.detect_tininess()),
+            a < 0,
+            a.unsigned_abs() as u128,
+            0,
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_i32(&mut self, a: i32, rnd: RoundingMode) -> float32_t {
+        self.converted_f32(
+            i32_to_f32(a, rnd.to_softfloat(), self.detect_tininess()),
+            a < 0,
+            a.unsigned_abs() as u128,
+            0,
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_u64(&mut self, a: u64, rnd: RoundingMode) -> float32_t {
+        self.converted_f32(
+            ui64_to_f32(a, rnd.to_softfloat(), self.detect_tininess()),
+            false,
+            a as u128,
+            0,
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_u32(&mut self, a: u32, rnd: RoundingMode) -> float32_t {
+        self.converted_f32(
+            ui32_to_f32(a, rnd.to_softfloat(), self.detect_tininess()),
+            false,
+            a as u128,
+            0,
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_i8(&mut self, a: i8, rnd: RoundingMode) -> float32_t {
+        self.converted_f32(
+            i32_to_f32(a as i32, rnd.to_softfloat(), self.detect_tininess()),
+            a < 0,
+            a.unsigned_abs() as u128,
+            0,
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_i16(&mut self, a: i16, rnd: RoundingMode) -> float32_t {
+        self.converted_f32(
+            i32_to_f32(a as i32, rnd.to_softfloat(), self.detect_tininess()),
+            a < 0,
+            a.unsigned_abs() as u128,
+            0,
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_i128(&mut self, a: i128, rnd: RoundingMode) -> float32_t {
+        self.converted_f32(
+            i128_to_f32(a, rnd.to_softfloat(), self.detect_tininess()),
+            a < 0,
+            a.unsigned_abs(),
+            0,
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_u8(&mut self, a: u8, rnd: RoundingMode) -> float32_t {
+        self.converted_f32(
+            ui32_to_f32(a as u32, rnd.to_softfloat(), self.detect_tininess()),
+            false,
+            a as u128,
+            0,
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_u16(&mut self, a: u16, rnd: RoundingMode) -> float32_t {
+        self.converted_f32(
+            ui32_to_f32(a as u32, rnd.to_softfloat(), self.detect_tininess()),
+            false,
+            a as u128,
+            0,
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_u128(&mut self, a: u128, rnd: RoundingMode) -> float32_t {
+        self.converted_f32(
+            ui128_to_f32(a, rnd.to_softfloat(), self.detect_tininess()),
+            false,
+            a,
+            0,
+            rnd,
+        )
+    }
+
+    /// Converts the integer `(-1)^sign * a`, whose magnitude is given as
+    /// little-endian 64-bit limbs.
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_limbs(&mut self, sign: bool, a: &[u64], rnd: RoundingMode) -> float32_t {
+        let (significand, exponent) = limbs_parts(a);
+        self.converted_f32(
+            limbs_to_f32(sign, a, rnd.to_softfloat(), self.detect_tininess()),
+            sign,
+            significand,
+            exponent,
+            rnd,
+        )
+    }
+}
+
+impl FPU {
+    /// `a + b` rounded once to `float32_t`, like C23 `fadd`.
+    #[inline]
+    #[must_use]
+    pub const fn f32_add_f64(
+        &mut self,
+        a: float64_t,
+        b: float64_t,
+        rnd: RoundingMode,
+    ) -> float32_t {
+        let args = match self.draw() {
+            Some(random) => f32_addF64Stochastic(a, b, random, self.detect_tininess()),
+            None => f32_addF64(a, b, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f32(args, rnd)
+    }
+
+    /// `a - b` rounded once to `float32_t`, like C23 `fsub`.
+    #[inline]
+    #[must_use]
+    pub const fn f32_sub_f64(
+        &mut self,
+        a: float64_t,
+        b: float64_t,
+        rnd: RoundingMode,
+    ) -> float32_t {
+        let args = match self.draw() {
+            Some(random) => f32_subF64Stochastic(a, b, random, self.detect_tininess()),
+            None => f32_subF64(a, b, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f32(args, rnd)
+    }
+
+    /// `a × b` rounded once to `float32_t`, like C23 `fmul`.
+    #[inline]
+    #[must_use]
+    pub const fn f32_mul_f64(
+        &mut self,
+        a: float64_t,
+        b: float64_t,
+        rnd: RoundingMode,
+    ) -> float32_t {
+        let args = match self.draw() {
+            Some(random) => f32_mulF64Stochastic(a, b, random, self.detect_tininess()),
+            None => f32_mulF64(a, b, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f32(args, rnd)
+    }
+
+    /// `a / b` rounded once to `float32_t`, like C23 `fdiv`.
+    #[inline]
+    #[must_use]
+    pub const fn f32_div_f64(
+        &mut self,
+        a: float64_t,
+        b: float64_t,
+        rnd: RoundingMode,
+    ) -> float32_t {
+        let args = match self.draw() {
+            Some(random) => f32_divF64Stochastic(a, b, random, self.detect_tininess()),
+            None => f32_divF64(a, b, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f32(args, rnd)
+    }
+
+    /// `√a` rounded once to `float32_t`, like C23 `fsqrt`.
+    #[inline]
+    #[must_use]
+    pub const fn f32_sqrt_f64(&mut self, a: float64_t, rnd: RoundingMode) -> float32_t {
+        let args = match self.draw() {
+            Some(random) => f32_sqrtF64Stochastic(a, random, self.detect_tininess()),
+            None => f32_sqrtF64(a, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f32(args, rnd)
+    }
+
+    /// `a × b + c` rounded once to `float32_t`, like C23 `ffma`.
+    #[inline]
+    #[must_use]
+    pub const fn f32_mul_add_f64(
+        &mut self,
+        a: float64_t,
+        b: float64_t,
+        c: float64_t,
+        rnd: RoundingMode,
+    ) -> float32_t {
+        let args = match self.draw() {
+            Some(random) => f32_mulAddF64Stochastic(a, b, c, random, self.detect_tininess()),
+            None => f32_mulAddF64(a, b, c, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f32(args, rnd)
+    }
+}
+
+impl FPU {
+    #[inline]
+    #[must_use]
+    pub const fn f32_exp(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(f32_exp(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_exp2(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(f32_exp2(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_expm1(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(
+            f32_expm1(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_log(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(f32_log(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_log2(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(f32_log2(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_log10(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(
+            f32_log10(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_log1p(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(
+            f32_log1p(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_sin(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(f32_sin(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_cos(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(f32_cos(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_tan(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(f32_tan(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_asin(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(f32_asin(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_acos(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(f32_acos(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_atan(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(f32_atan(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_atan2(&mut self, y: float32_t, x: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(
+            f32_atan2(y, x, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_atan2pi(
+        &mut self,
+        y: float32_t,
+        x: float32_t,
+        rnd: RoundingMode,
+    ) -> float32_t {
+        self.rounded_f32(
+            f32_atan2pi(y, x, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_sinh(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(f32_sinh(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_cosh(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(f32_cosh(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_tanh(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(f32_tanh(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_asinh(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(
+            f32_asinh(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_acosh(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(
+            f32_acosh(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_atanh(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(
+            f32_atanh(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+}
+
+impl FPU {
+    #[inline]
+    #[must_use]
+    pub const fn f32_pow(&mut self, a: float32_t, b: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(
+            f32_pow(a, b, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_pown(&mut self, a: float32_t, n: i64, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(
+            f32_pown(a, n, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_powr(&mut self, a: float32_t, b: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(
+            f32_powr(a, b, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_rootn(&mut self, a: float32_t, n: i64, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(
+            f32_rootn(a, n, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_compound(&mut self, a: float32_t, n: i64, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(
+            f32_compound(a, n, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_cbrt(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(f32_cbrt(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_hypot(&mut self, a: float32_t, b: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(
+            f32_hypot(a, b, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_rsqrt(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(
+            f32_rSqrt(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+}
+
+impl FPU {
+    #[inline]
+    #[must_use]
+    pub const fn f32_erf(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(f32_erf(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_erfc(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(f32_erfc(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_tgamma(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(
+            f32_tgamma(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    /// `ln |Γ(a)|` together with the sign of `Γ(a)`.
+    #[inline]
+    #[must_use]
+    pub const fn f32_lgamma(&mut self, a: float32_t, rnd: RoundingMode) -> (float32_t, i8) {
+        (
+            self.rounded_f32(
+                f32_lgamma(a, rnd.to_softfloat(), self.detect_tininess()),
+                rnd,
+            ),
+            f32_gammaSign(a),
+        )
+    }
+}
+
+impl FPU {
+    /// Reciprocal estimate to 7 bits, as computed by the RISC-V `vfrec7.v`
+    /// instruction.
+    #[inline]
+    #[must_use]
+    pub const fn f32_recip7(&mut self, a: float32_t, rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(f32_recip7(a, rnd.to_softfloat()), rnd)
+    }
+
+    /// Reciprocal square root estimate to 7 bits, as computed by the RISC-V
+    /// `vfrsqrt7.v` instruction.
+    #[inline]
+    #[must_use]
+    pub const fn f32_rsqrte7(&mut self, a: float32_t) -> float32_t {
+        self.flagged_const(f32_rsqrte7(a))
+    }
+}
+
+impl FPU {
+    /// Converts `a` to fixed point with `fbits` fractional bits, rounding
+    /// `a * 2^fbits` to an integer as ARM `FCVTZS #fbits` does for the
+    /// rounding mode `RtzTowardZero`. Out-of-range values saturate and raise
+    /// invalid.
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_fix16(
+        &mut self,
+        a: float32_t,
+        fbits: u8,
+        rnd: RoundingMode,
+        exact: bool,
+    ) -> i16 {
+        self.flagged_const(f32_to_fix16(a, fbits, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_ufix16(
+        &mut self,
+        a: float32_t,
+        fbits: u8,
+        rnd: RoundingMode,
+        exact: bool,
+    ) -> u16 {
+        self.flagged_const(f32_to_ufix16(a, fbits, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_fix32(
+        &mut self,
+        a: float32_t,
+        fbits: u8,
+        rnd: RoundingMode,
+        exact: bool,
+    ) -> i32 {
+        self.flagged_const(f32_to_fix32(a, fbits, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_ufix32(
+        &mut self,
+        a: float32_t,
+        fbits: u8,
+        rnd: RoundingMode,
+        exact: bool,
+    ) -> u32 {
+        self.flagged_const(f32_to_ufix32(a, fbits, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_fix64(
+        &mut self,
+        a: float32_t,
+        fbits: u8,
+        rnd: RoundingMode,
+        exact: bool,
+    ) -> i64 {
+        self.flagged_const(f32_to_fix64(a, fbits, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_ufix64(
+        &mut self,
+        a: float32_t,
+        fbits: u8,
+        rnd: RoundingMode,
+        exact: bool,
+    ) -> u64 {
+        self.flagged_const(f32_to_ufix64(a, fbits, rnd.to_softfloat(), exact))
+    }
+}
+
+impl FPU {
+    /// Converts the fixed-point number `a` with `fbits` fractional bits,
+    /// that is `a * 2^-fbits`, as ARM `SCVTF #fbits` does.
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_fix16(&mut self, a: i16, fbits: u8, rnd: RoundingMode) -> float32_t {
+        self.converted_f32(
+            fix32_to_f32(a as i32, fbits, rnd.to_softfloat(), self.detect_tininess()),
+            a < 0,
+            a.unsigned_abs() as u128,
+            -(fbits as i32),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_ufix16(&mut self, a: u16, fbits: u8, rnd: RoundingMode) -> float32_t {
+        self.converted_f32(
+            ufix32_to_f32(a as u32, fbits, rnd.to_softfloat(), self.detect_tininess()),
+            false,
+            a as u128,
+            -(fbits as i32),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_fix32(&mut self, a: i32, fbits: u8, rnd: RoundingMode) -> float32_t {
+        self.converted_f32(
+            fix32_to_f32(a, fbits, rnd.to_softfloat(), self.detect_tininess()),
+            a < 0,
+            a.unsigned_abs() as u128,
+            -(fbits as i32),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_ufix32(&mut self, a: u32, fbits: u8, rnd: RoundingMode) -> float32_t {
+        self.converted_f32(
+            ufix32_to_f32(a, fbits, rnd.to_softfloat(), self.detect_tininess()),
+            false,
+            a as u128,
+            -(fbits as i32),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_fix64(&mut self, a: i64, fbits: u8, rnd: RoundingMode) -> float32_t {
+        self.converted_f32(
+            fix64_to_f32(a, fbits, rnd.to_softfloat(), self.detect_tininess()),
+            a < 0,
+            a.unsigned_abs() as u128,
+            -(fbits as i32),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f32_from_ufix64(&mut self, a: u64, fbits: u8, rnd: RoundingMode) -> float32_t {
+        self.converted_f32(
+            ufix64_to_f32(a, fbits, rnd.to_softfloat(), self.detect_tininess()),
+            false,
+            a as u128,
+            -(fbits as i32),
+            rnd,
+        )
+    }
+}
+
+impl FPU {
+    /// Parses a decimal literal, correctly rounded under `rnd`. See
+    /// [`crate::text::f32_fromDecimal`] for the accepted syntax.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseFloatError`] if `s` is not a valid literal; no flags
+    /// are raised then.
+    #[inline]
+    pub const fn f32_parse(
+        &mut self,
+        s: &str,
+        rnd: RoundingMode,
+    ) -> Result<float32_t, ParseFloatError> {
+        match f32_fromDecimal(s, rnd.to_softfloat(), self.detect_tininess()) {
+            Ok(r) => Ok(self.rounded_f32(r, rnd)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Formats `a` with `digits` digits after the decimal point, correctly
+    /// rounded under `rnd`, like C's `%.*f`.
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_fixed(
+        &mut self,
+        a: float32_t,
+        digits: usize,
+        rnd: RoundingMode,
+    ) -> Decimal {
+        self.flagged_const(f32_toFixed(a, digits, rnd.to_softfloat()))
+    }
+
+    /// Formats `a` in scientific notation with `digits` digits after the
+    /// decimal point, correctly rounded under `rnd`, like C's `%.*e`.
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_exponent(
+        &mut self,
+        a: float32_t,
+        digits: usize,
+        rnd: RoundingMode,
+    ) -> Decimal {
+        self.flagged_const(f32_toExponent(a, digits, rnd.to_softfloat()))
+    }
+
+    /// Parses a hexadecimal literal such as `0x1.8p3`, correctly rounded
+    /// under `rnd`. See [`crate::text::f32_fromHex`] for the accepted syntax.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseFloatError`] if `s` is not a valid literal; no flags
+    /// are raised then.
+    #[inline]
+    pub const fn f32_parse_hex(
+        &mut self,
+        s: &str,
+        rnd: RoundingMode,
+    ) -> Result<float32_t, ParseFloatError> {
+        match f32_fromHex(s, rnd.to_softfloat(), self.detect_tininess()) {
+            Ok(r) => Ok(self.rounded_f32(r, rnd)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Formats `a` in hexadecimal scientific notation with `digits` digits
+    /// after the point, correctly rounded under `rnd`, like C's `%.*a`.
+    #[inline]
+    #[must_use]
+    pub const fn f32_to_hex_digits(
+        &mut self,
+        a: float32_t,
+        digits: usize,
+        rnd: RoundingMode,
+    ) -> Hex {
+        self.flagged_const(f32_toHexDigits(a, digits, rnd.to_softfloat()))
+    }
+}
+
+impl FPU {
+    /// Sum of `a`, accumulated exactly and rounded once under `rnd`.
+    #[inline]
+    #[must_use]
+    pub const fn f32_sum(&mut self, a: &[float32_t], rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(f32_sum(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    /// Dot product of `a` and `b`, accumulated exactly and rounded once
+    /// under `rnd`.
+    #[inline]
+    #[must_use]
+    pub const fn f32_dot(
+        &mut self,
+        a: &[float32_t],
+        b: &[float32_t],
+        rnd: RoundingMode,
+    ) -> float32_t {
+        self.rounded_f32(
+            f32_dot(a, b, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    /// Sum of the squares of `a`, rounded once under `rnd`.
+    #[inline]
+    #[must_use]
+    pub const fn f32_sum_square(&mut self, a: &[float32_t], rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(
+            f32_sumSquare(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    /// Sum of the magnitudes of `a`, rounded once under `rnd`.
+    #[inline]
+    #[must_use]
+    pub const fn f32_sum_abs(&mut self, a: &[float32_t], rnd: RoundingMode) -> float32_t {
+        self.rounded_f32(
+            f32_sumAbs(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    /// Product of `a` as `(pr, sf)` with `pr × 2^sf` the product and
+    /// `1 <= |pr| < 2`, so that it cannot overflow or underflow.
+    #[inline]
+    #[must_use]
+    pub const fn f32_scaled_prod(
+        &mut self,
+        a: &[float32_t],
+        rnd: RoundingMode,
+    ) -> (float32_t, i64) {
+        let (pr, sf, flags) = f32_scaledProd(a, rnd.to_softfloat(), self.detect_tininess());
+        (self.flagged_const((pr, flags)), sf)
+    }
+
+    /// Product of `a[i] + b[i]`, scaled like [`FPU::f32_scaled_prod`].
+    #[inline]
+    #[must_use]
+    pub const fn f32_scaled_prod_sum(
+        &mut self,
+        a: &[float32_t],
+        b: &[float32_t],
+        rnd: RoundingMode,
+    ) -> (float32_t, i64) {
+        let (pr, sf, flags) = f32_scaledProdSum(a, b, rnd.to_softfloat(), self.detect_tininess());
+        (self.flagged_const((pr, flags)), sf)
+    }
+
+    /// Product of `a[i] - b[i]`, scaled like [`FPU::f32_scaled_prod`].
+    #[inline]
+    #[must_use]
+    pub const fn f32_scaled_prod_diff(
+        &mut self,
+        a: &[float32_t],
+        b: &[float32_t],
+        rnd: RoundingMode,
+    ) -> (float32_t, i64) {
+        let (pr, sf, flags) = f32_scaledProdDiff(a, b, rnd.to_softfloat(), self.detect_tininess());
+        (self.flagged_const((pr, flags)), sf)
+    }
+}
+
+impl FpuFormat for float32_t {
+    #[inline]
+    fn fpu_to_i32(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> i32 {
+        fpu.f32_to_i32(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_i64(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> i64 {
+        fpu.f32_to_i64(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_u64(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> u64 {
+        fpu.f32_to_u64(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_u32(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> u32 {
+        fpu.f32_to_u32(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_i8(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> i8 {
+        fpu.f32_to_i8(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_i16(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> i16 {
+        fpu.f32_to_i16(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_i128(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> i128 {
+        fpu.f32_to_i128(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_u8(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> u8 {
+        fpu.f32_to_u8(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_u16(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> u16 {
+        fpu.f32_to_u16(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_u128(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> u128 {
+        fpu.f32_to_u128(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_limbs(fpu: &mut FPU, a: Self, z: &mut [u64], rnd: RoundingMode, exact: bool) -> bool {
+        fpu.f32_to_limbs(a, z, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_from_parts(
+        fpu: &mut FPU,
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        rnd: RoundingMode,
+    ) -> Self {
+        fpu.f32_from_parts(sign, significand, exponent, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_i8(fpu: &mut FPU, a: i8, rnd: RoundingMode) -> Self {
+        fpu.f32_from_i8(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_i16(fpu: &mut FPU, a: i16, rnd: RoundingMode) -> Self {
+        fpu.f32_from_i16(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_i32(fpu: &mut FPU, a: i32, rnd: RoundingMode) -> Self {
+        fpu.f32_from_i32(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_i64(fpu: &mut FPU, a: i64, rnd: RoundingMode) -> Self {
+        fpu.f32_from_i64(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_i128(fpu: &mut FPU, a: i128, rnd: RoundingMode) -> Self {
+        fpu.f32_from_i128(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_u8(fpu: &mut FPU, a: u8, rnd: RoundingMode) -> Self {
+        fpu.f32_from_u8(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_u16(fpu: &mut FPU, a: u16, rnd: RoundingMode) -> Self {
+        fpu.f32_from_u16(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_u32(fpu: &mut FPU, a: u32, rnd: RoundingMode) -> Self {
+        fpu.f32_from_u32(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_u64(fpu: &mut FPU, a: u64, rnd: RoundingMode) -> Self {
+        fpu.f32_from_u64(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_u128(fpu: &mut FPU, a: u128, rnd: RoundingMode) -> Self {
+        fpu.f32_from_u128(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_to_f32(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> float32_t {
+        fpu.rounded_f32((a, 0), rnd)
+    }
+
+    #[inline]
+    fn fpu_to_f64(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> float64_t {
+        fpu.f32_to_f64(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_to_parts(fpu: &mut FPU, a: Self) -> Parts {
+        fpu.f32_to_parts(a)
+    }
+
+    #[inline]
+    fn fpu_is_signaling_nan(fpu: &mut FPU, a: Self) -> bool {
+        fpu.f32_is_signaling_nan(a)
+    }
+
+    #[inline]
+    fn fpu_nan_payload(fpu: &mut FPU, a: Self) -> Self {
+        fpu.f32_nan_payload(a)
+    }
+
+    #[inline]
+    fn fpu_from_nan_payload(fpu: &mut FPU, a: Self) -> Self {
+        fpu.f32_from_nan_payload(a)
+    }
+
+    #[inline]
+    fn fpu_from_nan_payload_signaling(fpu: &mut FPU, a: Self) -> Self {
+        fpu.f32_from_nan_payload_signaling(a)
+    }
+
+    #[inline]
+    fn fpu_canonicalize(fpu: &mut FPU, a: Self) -> Self {
+        fpu.f32_canonicalize(a)
+    }
+
+    #[inline]
+    fn fpu_is_canonical(fpu: &mut FPU, a: Self) -> bool {
+        fpu.f32_is_canonical(a)
+    }
+
+    #[inline]
+    fn fpu_lt(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_lt(a, b)
+    }
+
+    #[inline]
+    fn fpu_le(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_le(a, b)
+    }
+
+    #[inline]
+    fn fpu_eq(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_eq(a, b)
+    }
+
+    #[inline]
+    fn fpu_eq_signaling(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_eq_signaling(a, b)
+    }
+
+    #[inline]
+    fn fpu_lt_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_lt_quiet(a, b)
+    }
+
+    #[inline]
+    fn fpu_le_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_le_quiet(a, b)
+    }
+
+    #[inline]
+    fn fpu_gt(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_gt(a, b)
+    }
+
+    #[inline]
+    fn fpu_ge(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_ge(a, b)
+    }
+
+    #[inline]
+    fn fpu_gt_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_gt_quiet(a, b)
+    }
+
+    #[inline]
+    fn fpu_ge_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_ge_quiet(a, b)
+    }
+
+    #[inline]
+    fn fpu_ne(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_ne(a, b)
+    }
+
+    #[inline]
+    fn fpu_ne_signaling(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_ne_signaling(a, b)
+    }
+
+    #[inline]
+    fn fpu_unordered(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_unordered(a, b)
+    }
+
+    #[inline]
+    fn fpu_unordered_signaling(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_unordered_signaling(a, b)
+    }
+
+    #[inline]
+    fn fpu_ordered(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_ordered(a, b)
+    }
+
+    #[inline]
+    fn fpu_ordered_signaling(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_ordered_signaling(a, b)
+    }
+
+    #[inline]
+    fn fpu_not_gt(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_not_gt(a, b)
+    }
+
+    #[inline]
+    fn fpu_not_gt_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_not_gt_quiet(a, b)
+    }
+
+    #[inline]
+    fn fpu_lt_unordered(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_lt_unordered(a, b)
+    }
+
+    #[inline]
+    fn fpu_lt_unordered_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_lt_unordered_quiet(a, b)
+    }
+
+    #[inline]
+    fn fpu_not_lt(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_not_lt(a, b)
+    }
+
+    #[inline]
+    fn fpu_not_lt_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_not_lt_quiet(a, b)
+    }
+
+    #[inline]
+    fn fpu_gt_unordered(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_gt_unordered(a, b)
+    }
+
+    #[inline]
+    fn fpu_gt_unordered_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f32_gt_unordered_quiet(a, b)
+    }
+
+    #[inline]
+    fn fpu_compare_quiet(fpu: &mut FPU, a: Self, b: Self) -> Option<Ordering> {
+        fpu.f32_compare_quiet(a, b)
+    }
+
+    #[inline]
+    fn fpu_compare_signaling(fpu: &mut FPU, a: Self, b: Self) -> Option<Ordering> {
+        fpu.f32_compare_signaling(a, b)
+    }
+
+    #[inline]
+    fn fpu_add(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_add(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_mul_add(fpu: &mut FPU, a: Self, b: Self, c: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_mul_add(a, b, c, rnd)
+    }
+
+    #[inline]
+    fn fpu_div(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_div(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_mul(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_mul(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_rem(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_rem(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_sub(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_sub(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_round_to_int(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> Self {
+        fpu.f32_round_to_int(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_sqrt(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_sqrt(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_exp(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_exp(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_exp2(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_exp2(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_expm1(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_expm1(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_log(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_log(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_log2(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_log2(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_log10(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_log10(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_log1p(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_log1p(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_sin(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_sin(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_cos(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_cos(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_tan(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_tan(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_asin(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_asin(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_acos(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_acos(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_atan(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_atan(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_atan2(fpu: &mut FPU, y: Self, x: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_atan2(y, x, rnd)
+    }
+
+    #[inline]
+    fn fpu_atan2pi(fpu: &mut FPU, y: Self, x: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_atan2pi(y, x, rnd)
+    }
+
+    #[inline]
+    fn fpu_sinh(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_sinh(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_cosh(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_cosh(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_tanh(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_tanh(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_asinh(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_asinh(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_acosh(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_acosh(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_atanh(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_atanh(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_pow(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_pow(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_pown(fpu: &mut FPU, a: Self, n: i64, rnd: RoundingMode) -> Self {
+        fpu.f32_pown(a, n, rnd)
+    }
+
+    #[inline]
+    fn fpu_powr(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_powr(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_rootn(fpu: &mut FPU, a: Self, n: i64, rnd: RoundingMode) -> Self {
+        fpu.f32_rootn(a, n, rnd)
+    }
+
+    #[inline]
+    fn fpu_compound(fpu: &mut FPU, a: Self, n: i64, rnd: RoundingMode) -> Self {
+        fpu.f32_compound(a, n, rnd)
+    }
+
+    #[inline]
+    fn fpu_cbrt(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_cbrt(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_hypot(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_hypot(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_rsqrt(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_rsqrt(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_erf(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_erf(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_erfc(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_erfc(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_tgamma(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_tgamma(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_lgamma(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> (Self, i8) {
+        fpu.f32_lgamma(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_recip7(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f32_recip7(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_rsqrte7(fpu: &mut FPU, a: Self) -> Self {
+        fpu.f32_rsqrte7(a)
+    }
+
+    #[inline]
+    fn fpu_to_fix16(fpu: &mut FPU, a: Self, fbits: u8, rnd: RoundingMode, exact: bool) -> i16 {
+        fpu.f32_to_fix16(a, fbits, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_ufix16(fpu: &mut FPU, a: Self, fbits: u8, rnd: RoundingMode, exact: bool) -> u16 {
+        fpu.f32_to_ufix16(a, fbits, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_fix32(fpu: &mut FPU, a: Self, fbits: u8, rnd: RoundingMode, exact: bool) -> i32 {
+        fpu.f32_to_fix32(a, fbits, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_ufix32(fpu: &mut FPU, a: Self, fbits: u8, rnd: RoundingMode, exact: bool) -> u32 {
+        fpu.f32_to_ufix32(a, fbits, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_fix64(fpu: &mut FPU, a: Self, fbits: u8, rnd: RoundingMode, exact: bool) -> i64 {
+        fpu.f32_to_fix64(a, fbits, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_ufix64(fpu: &mut FPU, a: Self, fbits: u8, rnd: RoundingMode, exact: bool) -> u64 {
+        fpu.f32_to_ufix64(a, fbits, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_parse(fpu: &mut FPU, s: &str, rnd: RoundingMode) -> Result<Self, ParseFloatError> {
+        fpu.f32_parse(s, rnd)
+    }
+
+    #[inline]
+    fn fpu_to_fixed(fpu: &mut FPU, a: Self, digits: usize, rnd: RoundingMode) -> Decimal {
+        fpu.f32_to_fixed(a, digits, rnd)
+    }
+
+    #[inline]
+    fn fpu_to_exponent(fpu: &mut FPU, a: Self, digits: usize, rnd: RoundingMode) -> Decimal {
+        fpu.f32_to_exponent(a, digits, rnd)
+    }
+
+    #[inline]
+    fn fpu_parse_hex(fpu: &mut FPU, s: &str, rnd: RoundingMode) -> Result<Self, ParseFloatError> {
+        fpu.f32_parse_hex(s, rnd)
+    }
+
+    #[inline]
+    fn fpu_to_hex_digits(fpu: &mut FPU, a: Self, digits: usize, rnd: RoundingMode) -> Hex {
+        fpu.f32_to_hex_digits(a, digits, rnd)
+    }
+
+    #[inline]
+    fn fpu_sum(fpu: &mut FPU, a: &[Self], rnd: RoundingMode) -> Self {
+        fpu.f32_sum(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_dot(fpu: &mut FPU, a: &[Self], b: &[Self], rnd: RoundingMode) -> Self {
+        fpu.f32_dot(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_sum_square(fpu: &mut FPU, a: &[Self], rnd: RoundingMode) -> Self {
+        fpu.f32_sum_square(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_sum_abs(fpu: &mut FPU, a: &[Self], rnd: RoundingMode) -> Self {
+        fpu.f32_sum_abs(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_scaled_prod(fpu: &mut FPU, a: &[Self], rnd: RoundingMode) -> (Self, i64) {
+        fpu.f32_scaled_prod(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_scaled_prod_sum(
+        fpu: &mut FPU,
+        a: &[Self],
+        b: &[Self],
+        rnd: RoundingMode,
+    ) -> (Self, i64) {
+        fpu.f32_scaled_prod_sum(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_scaled_prod_diff(
+        fpu: &mut FPU,
+        a: &[Self],
+        b: &[Self],
+        rnd: RoundingMode,
+    ) -> (Self, i64) {
+        fpu.f32_scaled_prod_diff(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_float<G: FpuFormat>(fpu: &mut FPU, a: G, rnd: RoundingMode) -> Self {
+        G::fpu_to_f32(fpu, a, rnd)
+    }
+}
@@ -0,0 +1,1854 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! `const` operations on `float64_t`.
+
+use core::cmp::Ordering;
+
+use super::{limbs_parts, FpuFormat, FPU};
+use crate::math::{
+    f64_acos, f64_acosh, f64_addStochastic, f64_asin, f64_asinh, f64_atan, f64_atan2, f64_atan2pi,
+    f64_atanh, f64_canonicalize, f64_cbrt, f64_compound, f64_cos, f64_cosh, f64_divStochastic,
+    f64_dot, f64_erf, f64_erfc, f64_exp, f64_exp2, f64_expm1, f64_fromParts,
+    f64_fromPartsStochastic, f64_gammaSign, f64_getPayload, f64_hypot, f64_isCanonical, f64_lgamma,
+    f64_log, f64_log10, f64_log1p, f64_log2, f64_mulAddStochastic, f64_mulStochastic, f64_pow,
+    f64_pown, f64_powr, f64_rSqrt, f64_rootn, f64_roundToPrecision, f64_roundToPrecisionStochastic,
+    f64_scaledProd, f64_scaledProdDiff, f64_scaledProdSum, f64_setPayload, f64_setPayloadSignaling,
+    f64_sin, f64_sinh, f64_sqrtStochastic, f64_subStochastic, f64_sum, f64_sumAbs, f64_sumSquare,
+    f64_tan, f64_tanh, f64_tgamma, f64_toParts, f64_to_f32Stochastic, Parts,
+};
+use crate::softfloat::{
+    f64_add, f64_div, f64_eq, f64_eq_signaling, f64_isSignalingNaN, f64_le, f64_le_quiet, f64_lt,
+    f64_lt_quiet, f64_mul, f64_mulAdd, f64_recip7, f64_rem, f64_roundToInt, f64_rsqrte7, f64_sqrt,
+    f64_sub, f64_to_f32, f64_to_fix16, f64_to_fix32, f64_to_fix64, f64_to_i128, f64_to_i16,
+    f64_to_i32, f64_to_i64, f64_to_i8, f64_to_limbs, f64_to_ufix16, f64_to_ufix32, f64_to_ufix64,
+    f64_to_ui128, f64_to_ui16, f64_to_ui32, f64_to_ui64, f64_to_ui8, fix32_to_f64, fix64_to_f64,
+    float32_t, float64_t, i128_to_f64, i32_to_f64, i64_to_f64, isNaNF64UI, limbs_to_f64,
+    ufix32_to_f64, ufix64_to_f64, ui128_to_f64, ui32_to_f64, ui64_to_f64,
+};
+use crate::text::{
+    f64_fromDecimal, f64_fromHex, f64_toExponent, f64_toFixed, f64_toHexDigits, Decimal, Hex,
+    ParseFloatError,
+};
+use crate::wrapper::RoundingMode;
+
+/// Orders `a` and `b`, raising invalid only for signaling NaNs.
+const fn compare_quiet(a: float64_t, b: float64_t) -> (Option<Ordering>, u8) {
+    let (eq, flags) = f64_eq(a, b);
+    let (lt, _) = f64_lt_quiet(a, b);
+    if isNaNF64UI(a.v) || isNaNF64UI(b.v) {
+        (None, flags)
+    } else if eq {
+        (Some(Ordering::Equal), flags)
+    } else if lt {
+        (Some(Ordering::Less), flags)
+    } else {
+        (Some(Ordering::Greater), flags)
+    }
+}
+
+/// Orders `a` and `b`, raising invalid for any NaN.
+const fn compare_signaling(a: float64_t, b: float64_t) -> (Option<Ordering>, u8) {
+    let (ord, _) = compare_quiet(a, b);
+    let (_, flags) = f64_lt(a, b);
+    (ord, flags)
+}
+
+impl FPU {
+    /// Like [`FPU::flagged_const`], then rounds to the emulated precision.
+    #[inline]
+    pub(super) const fn rounded_f64(
+        &mut self,
+        args: (float64_t, u8),
+        rnd: RoundingMode,
+    ) -> float64_t {
+        let x = self.flagged_const(args);
+        match self.precision {
+            Some(p) => {
+                let r = match self.draw() {
+                    Some(random) => f64_roundToPrecisionStochastic(
+                        x,
+                        p.sig_bits(),
+                        p.emin(),
+                        p.emax(),
+                        random,
+                        self.detect_tininess(),
+                    ),
+                    None => f64_roundToPrecision(
+                        x,
+                        p.sig_bits(),
+                        p.emin(),
+                        p.emax(),
+                        rnd.to_softfloat(),
+                        self.detect_tininess(),
+                    ),
+                };
+                self.flagged_const(r)
+            }
+            None => x,
+        }
+    }
+
+    /// Like [`FPU::rounded_f64`] for `(-1)^sign × significand × 2^exponent`,
+    /// computed as `args` unless rounding stochastically.
+    #[inline]
+    const fn converted_f64(
+        &mut self,
+        args: (float64_t, u8),
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        rnd: RoundingMode,
+    ) -> float64_t {
+        let args = match self.draw() {
+            Some(random) => {
+                f64_fromPartsStochastic(sign, significand, exponent, random, self.detect_tininess())
+            }
+            None => args,
+        };
+        self.rounded_f64(args, rnd)
+    }
+}
+
+impl FPU {
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_i32(&mut self, a: float64_t, rnd: RoundingMode, exact: bool) -> i32 {
+        self.flagged_const(f64_to_i32(a, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_i64(&mut self, a: float64_t, rnd: RoundingMode, exact: bool) -> i64 {
+        self.flagged_const(f64_to_i64(a, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_u64(&mut self, a: float64_t, rnd: RoundingMode, exact: bool) -> u64 {
+        self.flagged_const(f64_to_ui64(a, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_u32(&mut self, a: float64_t, rnd: RoundingMode, exact: bool) -> u32 {
+        self.flagged_const(f64_to_ui32(a, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_i8(&mut self, a: float64_t, rnd: RoundingMode, exact: bool) -> i8 {
+        self.flagged_const(f64_to_i8(a, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_i16(&mut self, a: float64_t, rnd: RoundingMode, exact: bool) -> i16 {
+        self.flagged_const(f64_to_i16(a, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_i128(&mut self, a: float64_t, rnd: RoundingMode, exact: bool) -> i128 {
+        self.flagged_const(f64_to_i128(a, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_u8(&mut self, a: float64_t, rnd: RoundingMode, exact: bool) -> u8 {
+        self.flagged_const(f64_to_ui8(a, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_u16(&mut self, a: float64_t, rnd: RoundingMode, exact: bool) -> u16 {
+        self.flagged_const(f64_to_ui16(a, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_u128(&mut self, a: float64_t, rnd: RoundingMode, exact: bool) -> u128 {
+        self.flagged_const(f64_to_ui128(a, rnd.to_softfloat(), exact))
+    }
+
+    /// Converts `a` to an integer of arbitrary length, stored in `z` as
+    /// little-endian 64-bit limbs in sign-magnitude form, and returns the
+    /// sign. Values that do not fit saturate `z` and raise invalid.
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_limbs(
+        &mut self,
+        a: float64_t,
+        z: &mut [u64],
+        rnd: RoundingMode,
+        exact: bool,
+    ) -> bool {
+        self.flagged_const(f64_to_limbs(a, z, rnd.to_softfloat(), exact))
+    }
+
+    /// Rounds `(-1)^sign × significand × 2^exponent` once to `float64_t`.
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_parts(
+        &mut self,
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        rnd: RoundingMode,
+    ) -> float64_t {
+        self.rounded_f64(
+            f64_fromParts(
+                sign,
+                significand,
+                exponent,
+                rnd.to_softfloat(),
+                self.detect_tininess(),
+            ),
+            rnd,
+        )
+    }
+
+    /// Decomposes `a` into its class, sign, exponent and integer
+    /// significand.
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_parts(&mut self, a: float64_t) -> Parts {
+        f64_toParts(a)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_f32(&mut self, a: float64_t, rnd: RoundingMode) -> float32_t {
+        let args = match self.draw() {
+            Some(random) => f64_to_f32Stochastic(a, random, self.detect_tininess()),
+            None => f64_to_f32(a, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f32(args, rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_is_signaling_nan(&mut self, a: float64_t) -> bool {
+        f64_isSignalingNaN(a)
+    }
+
+    /// The NaN payload of `a`, or `-1` if `a` is not a NaN (getPayload).
+    #[inline]
+    #[must_use]
+    pub const fn f64_nan_payload(&mut self, a: float64_t) -> float64_t {
+        f64_getPayload(a)
+    }
+
+    /// A quiet NaN with payload `a`, or `+0` if `a` is not an admissible
+    /// payload (setPayload).
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_nan_payload(&mut self, a: float64_t) -> float64_t {
+        f64_setPayload(a)
+    }
+
+    /// A signaling NaN with payload `a`, or `+0` if `a` is not an admissible
+    /// payload (setPayloadSignaling).
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_nan_payload_signaling(&mut self, a: float64_t) -> float64_t {
+        f64_setPayloadSignaling(a)
+    }
+
+    /// The canonical encoding of `a`, quieting signaling NaNs.
+    #[inline]
+    #[must_use]
+    pub const fn f64_canonicalize(&mut self, a: float64_t) -> float64_t {
+        self.flagged_const(f64_canonicalize(a))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_is_canonical(&mut self, a: float64_t) -> bool {
+        f64_isCanonical(a)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_lt(&mut self, a: float64_t, b: float64_t) -> bool {
+        self.flagged_const(f64_lt(a, b))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_le(&mut self, a: float64_t, b: float64_t) -> bool {
+        self.flagged_const(f64_le(a, b))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_eq(&mut self, a: float64_t, b: float64_t) -> bool {
+        self.flagged_const(f64_eq(a, b))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_eq_signaling(&mut self, a: float64_t, b: float64_t) -> bool {
+        self.flagged_const(f64_eq_signaling(a, b))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_lt_quiet(&mut self, a: float64_t, b: float64_t) -> bool {
+        self.flagged_const(f64_lt_quiet(a, b))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_le_quiet(&mut self, a: float64_t, b: float64_t) -> bool {
+        self.flagged_const(f64_le_quiet(a, b))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_gt(&mut self, a: float64_t, b: float64_t) -> bool {
+        self.flagged_const(f64_lt(b, a))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_ge(&mut self, a: float64_t, b: float64_t) -> bool {
+        self.flagged_const(f64_le(b, a))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_gt_quiet(&mut self, a: float64_t, b: float64_t) -> bool {
+        self.flagged_const(f64_lt_quiet(b, a))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_ge_quiet(&mut self, a: float64_t, b: float64_t) -> bool {
+        self.flagged_const(f64_le_quiet(b, a))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_ne(&mut self, a: float64_t, b: float64_t) -> bool {
+        let (r, flags) = f64_eq(a, b);
+        self.flagged_const((!r, flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_ne_signaling(&mut self, a: float64_t, b: float64_t) -> bool {
+        let (r, flags) = f64_eq_signaling(a, b);
+        self.flagged_const((!r, flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_unordered(&mut self, a: float64_t, b: float64_t) -> bool {
+        let (ord, flags) = compare_quiet(a, b);
+        self.flagged_const((ord.is_none(), flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_unordered_signaling(&mut self, a: float64_t, b: float64_t) -> bool {
+        let (ord, flags) = compare_signaling(a, b);
+        self.flagged_const((ord.is_none(), flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_ordered(&mut self, a: float64_t, b: float64_t) -> bool {
+        let (ord, flags) = compare_quiet(a, b);
+        self.flagged_const((ord.is_some(), flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_ordered_signaling(&mut self, a: float64_t, b: float64_t) -> bool {
+        let (ord, flags) = compare_signaling(a, b);
+        self.flagged_const((ord.is_some(), flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_not_gt(&mut self, a: float64_t, b: float64_t) -> bool {
+        let (r, flags) = f64_lt(b, a);
+        self.flagged_const((!r, flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_not_gt_quiet(&mut self, a: float64_t, b: float64_t) -> bool {
+        let (r, flags) = f64_lt_quiet(b, a);
+        self.flagged_const((!r, flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_lt_unordered(&mut self, a: float64_t, b: float64_t) -> bool {
+        let (r, flags) = f64_le(b, a);
+        self.flagged_const((!r, flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_lt_unordered_quiet(&mut self, a: float64_t, b: float64_t) -> bool {
+        let (r, flags) = f64_le_quiet(b, a);
+        self.flagged_const((!r, flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_not_lt(&mut self, a: float64_t, b: float64_t) -> bool {
+        let (r, flags) = f64_lt(a, b);
+        self.flagged_const((!r, flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_not_lt_quiet(&mut self, a: float64_t, b: float64_t) -> bool {
+        let (r, flags) = f64_lt_quiet(a, b);
+        self.flagged_const((!r, flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_gt_unordered(&mut self, a: float64_t, b: float64_t) -> bool {
+        let (r, flags) = f64_le(a, b);
+        self.flagged_const((!r, flags))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_gt_unordered_quiet(&mut self, a: float64_t, b: float64_t) -> bool {
+        let (r, flags) = f64_le_quiet(a, b);
+        self.flagged_const((!r, flags))
+    }
+
+    /// Orders `a` and `b`, or `None` if either is a NaN; only signaling NaNs
+    /// raise invalid.
+    #[inline]
+    #[must_use]
+    pub const fn f64_compare_quiet(&mut self, a: float64_t, b: float64_t) -> Option<Ordering> {
+        self.flagged_const(compare_quiet(a, b))
+    }
+
+    /// Orders `a` and `b`, or `None` if either is a NaN; any NaN raises
+    /// invalid.
+    #[inline]
+    #[must_use]
+    pub const fn f64_compare_signaling(&mut self, a: float64_t, b: float64_t) -> Option<Ordering> {
+        self.flagged_const(compare_signaling(a, b))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_add(&mut self, a: float64_t, b: float64_t, rnd: RoundingMode) -> float64_t {
+        let args = match self.draw() {
+            Some(random) => f64_addStochastic(a, b, random, self.detect_tininess()),
+            None => f64_add(a, b, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f64(args, rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_mul_add(
+        &mut self,
+        a: float64_t,
+        b: float64_t,
+        c: float64_t,
+        rnd: RoundingMode,
+    ) -> float64_t {
+        let args = match self.draw() {
+            Some(random) => f64_mulAddStochastic(a, b, c, random, self.detect_tininess()),
+            None => f64_mulAdd(a, b, c, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f64(args, rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_div(&mut self, a: float64_t, b: float64_t, rnd: RoundingMode) -> float64_t {
+        let args = match self.draw() {
+            Some(random) => f64_divStochastic(a, b, random, self.detect_tininess()),
+            None => f64_div(a, b, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f64(args, rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_mul(&mut self, a: float64_t, b: float64_t, rnd: RoundingMode) -> float64_t {
+        let args = match self.draw() {
+            Some(random) => f64_mulStochastic(a, b, random, self.detect_tininess()),
+            None => f64_mul(a, b, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f64(args, rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_rem(&mut self, a: float64_t, b: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(
+            f64_rem(a, b, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_sub(&mut self, a: float64_t, b: float64_t, rnd: RoundingMode) -> float64_t {
+        let args = match self.draw() {
+            Some(random) => f64_subStochastic(a, b, random, self.detect_tininess()),
+            None => f64_sub(a, b, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f64(args, rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_round_to_int(
+        &mut self,
+        a: float64_t,
+        rnd: RoundingMode,
+        exact: bool,
+    ) -> float64_t {
+        self.rounded_f64(f64_roundToInt(a, rnd.to_softfloat(), exact), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_sqrt(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        let args = match self.draw() {
+            Some(random) => f64_sqrtStochastic(a, random, self.detect_tininess()),
+            None => f64_sqrt(a, rnd.to_softfloat(), self.detect_tininess()),
+        };
+        self.rounded_f64(args, rnd)
+    }
+}
+
+impl FPU {
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_i64(&mut self, a: i64, rnd: RoundingMode) -> float64_t {
+        self.converted_f64(
+            i64_to_f64(a, rnd.to_softfloat(), self.detect_tininess()),
+            a < 0,
+            a.unsigned_abs() as u128,
+            0,
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_i32(&mut self, a: i32, rnd: RoundingMode) -> float64_t {
+        self.converted_f64((i32_to_f64(a), 0), a < 0, a.unsigned_abs() as u128, 0, rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_u64(&mut self, a: u64, rnd: RoundingMode) -> float64_t {
+        self.converted_f64(
+            ui64_to_f64(a, rnd.to_softfloat(), self.detect_tininess()),
+            false,
+            a as u128,
+            0,
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_u32(&mut self, a: u32, rnd: RoundingMode) -> float64_t {
+        self.converted_f64((ui32_to_f64(a), 0), false, a as u128, 0, rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_i8(&mut self, a: i8, rnd: RoundingMode) -> float64_t {
+        self.converted_f64(
+            (i32_to_f64(a as i32), 0),
+            a < 0,
+            a.unsigned_abs() as u128,
+            0,
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_i16(&mut self, a: i16, rnd: RoundingMode) -> float64_t {
+        self.converted_f64(
+            (i32_to_f64(a as i32), 0),
+            a < 0,
+            a.unsigned_abs() as u128,
+            0,
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_i128(&mut self, a: i128, rnd: RoundingMode) -> float64_t {
+        self.converted_f64(
+            i128_to_f64(a, rnd.to_softfloat(), self.detect_tininess()),
+            a < 0,
+            a.unsigned_abs(),
+            0,
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_u8(&mut self, a: u8, rnd: RoundingMode) -> float64_t {
+        self.converted_f64((ui32_to_f64(a as u32), 0), false, a as u128, 0, rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_u16(&mut self, a: u16, rnd: RoundingMode) -> float64_t {
+        self.converted_f64((ui32_to_f64(a as u32), 0), false, a as u128, 0, rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_u128(&mut self, a: u128, rnd: RoundingMode) -> float64_t {
+        self.converted_f64(
+            ui128_to_f64(a, rnd.to_softfloat(), self.detect_tininess()),
+            false,
+            a,
+            0,
+            rnd,
+        )
+    }
+
+    /// Converts the integer `(-1)^sign * a`, whose magnitude is given as
+    /// little-endian 64-bit limbs.
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_limbs(&mut self, sign: bool, a: &[u64], rnd: RoundingMode) -> float64_t {
+        let (significand, exponent) = limbs_parts(a);
+        self.converted_f64(
+            limbs_to_f64(sign, a, rnd.to_softfloat(), self.detect_tininess()),
+            sign,
+            significand,
+            exponent,
+            rnd,
+        )
+    }
+}
+
+impl FPU {
+    #[inline]
+    #[must_use]
+    pub const fn f64_exp(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f64_exp(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_exp2(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f64_exp2(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_expm1(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(
+            f64_expm1(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_log(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f64_log(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_log2(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f64_log2(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_log10(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(
+            f64_log10(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_log1p(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(
+            f64_log1p(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_sin(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f64_sin(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_cos(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f64_cos(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_tan(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f64_tan(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_asin(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f64_asin(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_acos(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f64_acos(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_atan(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f64_atan(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_atan2(&mut self, y: float64_t, x: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(
+            f64_atan2(y, x, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_atan2pi(
+        &mut self,
+        y: float64_t,
+        x: float64_t,
+        rnd: RoundingMode,
+    ) -> float64_t {
+        self.rounded_f64(
+            f64_atan2pi(y, x, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_sinh(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f64_sinh(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_cosh(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f64_cosh(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_tanh(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f64_tanh(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_asinh(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(
+            f64_asinh(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_acosh(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(
+            f64_acosh(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_atanh(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(
+            f64_atanh(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+}
+
+impl FPU {
+    #[inline]
+    #[must_use]
+    pub const fn f64_pow(&mut self, a: float64_t, b: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(
+            f64_pow(a, b, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_pown(&mut self, a: float64_t, n: i64, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(
+            f64_pown(a, n, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_powr(&mut self, a: float64_t, b: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(
+            f64_powr(a, b, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_rootn(&mut self, a: float64_t, n: i64, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(
+            f64_rootn(a, n, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_compound(&mut self, a: float64_t, n: i64, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(
+            f64_compound(a, n, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_cbrt(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f64_cbrt(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_hypot(&mut self, a: float64_t, b: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(
+            f64_hypot(a, b, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_rsqrt(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(
+            f64_rSqrt(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+}
+
+impl FPU {
+    #[inline]
+    #[must_use]
+    pub const fn f64_erf(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f64_erf(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_erfc(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f64_erfc(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_tgamma(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(
+            f64_tgamma(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    /// `ln |Γ(a)|` together with the sign of `Γ(a)`.
+    #[inline]
+    #[must_use]
+    pub const fn f64_lgamma(&mut self, a: float64_t, rnd: RoundingMode) -> (float64_t, i8) {
+        (
+            self.rounded_f64(
+                f64_lgamma(a, rnd.to_softfloat(), self.detect_tininess()),
+                rnd,
+            ),
+            f64_gammaSign(a),
+        )
+    }
+}
+
+impl FPU {
+    /// Reciprocal estimate to 7 bits, as computed by the RISC-V `vfrec7.v`
+    /// instruction.
+    #[inline]
+    #[must_use]
+    pub const fn f64_recip7(&mut self, a: float64_t, rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f64_recip7(a, rnd.to_softfloat()), rnd)
+    }
+
+    /// Reciprocal square root estimate to 7 bits, as computed by the RISC-V
+    /// `vfrsqrt7.v` instruction.
+    #[inline]
+    #[must_use]
+    pub const fn f64_rsqrte7(&mut self, a: float64_t) -> float64_t {
+        self.flagged_const(f64_rsqrte7(a))
+    }
+}
+
+impl FPU {
+    /// Converts `a` to fixed point with `fbits` fractional bits, rounding
+    /// `a * 2^fbits` to an integer as ARM `FCVTZS #fbits` does for the
+    /// rounding mode `RtzTowardZero`. Out-of-range values saturate and raise
+    /// invalid.
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_fix16(
+        &mut self,
+        a: float64_t,
+        fbits: u8,
+        rnd: RoundingMode,
+        exact: bool,
+    ) -> i16 {
+        self.flagged_const(f64_to_fix16(a, fbits, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_ufix16(
+        &mut self,
+        a: float64_t,
+        fbits: u8,
+        rnd: RoundingMode,
+        exact: bool,
+    ) -> u16 {
+        self.flagged_const(f64_to_ufix16(a, fbits, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_fix32(
+        &mut self,
+        a: float64_t,
+        fbits: u8,
+        rnd: RoundingMode,
+        exact: bool,
+    ) -> i32 {
+        self.flagged_const(f64_to_fix32(a, fbits, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_ufix32(
+        &mut self,
+        a: float64_t,
+        fbits: u8,
+        rnd: RoundingMode,
+        exact: bool,
+    ) -> u32 {
+        self.flagged_const(f64_to_ufix32(a, fbits, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_fix64(
+        &mut self,
+        a: float64_t,
+        fbits: u8,
+        rnd: RoundingMode,
+        exact: bool,
+    ) -> i64 {
+        self.flagged_const(f64_to_fix64(a, fbits, rnd.to_softfloat(), exact))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_ufix64(
+        &mut self,
+        a: float64_t,
+        fbits: u8,
+        rnd: RoundingMode,
+        exact: bool,
+    ) -> u64 {
+        self.flagged_const(f64_to_ufix64(a, fbits, rnd.to_softfloat(), exact))
+    }
+}
+
+impl FPU {
+    /// Converts the fixed-point number `a` with `fbits` fractional bits,
+    /// that is `a * 2^-fbits`, as ARM `SCVTF #fbits` does.
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_fix16(&mut self, a: i16, fbits: u8, rnd: RoundingMode) -> float64_t {
+        self.converted_f64(
+            fix32_to_f64(a as i32, fbits, rnd.to_softfloat(), self.detect_tininess()),
+            a < 0,
+            a.unsigned_abs() as u128,
+            -(fbits as i32),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_ufix16(&mut self, a: u16, fbits: u8, rnd: RoundingMode) -> float64_t {
+        self.converted_f64(
+            ufix32_to_f64(a as u32, fbits, rnd.to_softfloat(), self.detect_tininess()),
+            false,
+            a as u128,
+            -(fbits as i32),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_fix32(&mut self, a: i32, fbits: u8, rnd: RoundingMode) -> float64_t {
+        self.converted_f64(
+            fix32_to_f64(a, fbits, rnd.to_softfloat(), self.detect_tininess()),
+            a < 0,
+            a.unsigned_abs() as u128,
+            -(fbits as i32),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_ufix32(&mut self, a: u32, fbits: u8, rnd: RoundingMode) -> float64_t {
+        self.converted_f64(
+            ufix32_to_f64(a, fbits, rnd.to_softfloat(), self.detect_tininess()),
+            false,
+            a as u128,
+            -(fbits as i32),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_fix64(&mut self, a: i64, fbits: u8, rnd: RoundingMode) -> float64_t {
+        self.converted_f64(
+            fix64_to_f64(a, fbits, rnd.to_softfloat(), self.detect_tininess()),
+            a < 0,
+            a.unsigned_abs() as u128,
+            -(fbits as i32),
+            rnd,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn f64_from_ufix64(&mut self, a: u64, fbits: u8, rnd: RoundingMode) -> float64_t {
+        self.converted_f64(
+            ufix64_to_f64(a, fbits, rnd.to_softfloat(), self.detect_tininess()),
+            false,
+            a as u128,
+            -(fbits as i32),
+            rnd,
+        )
+    }
+}
+
+impl FPU {
+    /// Parses a decimal literal, correctly rounded under `rnd`. See
+    /// [`crate::text::f64_fromDecimal`] for the accepted syntax.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseFloatError`] if `s` is not a valid literal; no flags
+    /// are raised then.
+    #[inline]
+    pub const fn f64_parse(
+        &mut self,
+        s: &str,
+        rnd: RoundingMode,
+    ) -> Result<float64_t, ParseFloatError> {
+        match f64_fromDecimal(s, rnd.to_softfloat(), self.detect_tininess()) {
+            Ok(r) => Ok(self.rounded_f64(r, rnd)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Formats `a` with `digits` digits after the decimal point, correctly
+    /// rounded under `rnd`, like C's `%.*f`.
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_fixed(
+        &mut self,
+        a: float64_t,
+        digits: usize,
+        rnd: RoundingMode,
+    ) -> Decimal {
+        self.flagged_const(f64_toFixed(a, digits, rnd.to_softfloat()))
+    }
+
+    /// Formats `a` in scientific notation with `digits` digits after the
+    /// decimal point, correctly rounded under `rnd`, like C's `%.*e`.
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_exponent(
+        &mut self,
+        a: float64_t,
+        digits: usize,
+        rnd: RoundingMode,
+    ) -> Decimal {
+        self.flagged_const(f64_toExponent(a, digits, rnd.to_softfloat()))
+    }
+
+    /// Parses a hexadecimal literal such as `0x1.8p3`, correctly rounded
+    /// under `rnd`. See [`crate::text::f64_fromHex`] for the accepted syntax.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseFloatError`] if `s` is not a valid literal; no flags
+    /// are raised then.
+    #[inline]
+    pub const fn f64_parse_hex(
+        &mut self,
+        s: &str,
+        rnd: RoundingMode,
+    ) -> Result<float64_t, ParseFloatError> {
+        match f64_fromHex(s, rnd.to_softfloat(), self.detect_tininess()) {
+            Ok(r) => Ok(self.rounded_f64(r, rnd)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Formats `a` in hexadecimal scientific notation with `digits` digits
+    /// after the point, correctly rounded under `rnd`, like C's `%.*a`.
+    #[inline]
+    #[must_use]
+    pub const fn f64_to_hex_digits(
+        &mut self,
+        a: float64_t,
+        digits: usize,
+        rnd: RoundingMode,
+    ) -> Hex {
+        self.flagged_const(f64_toHexDigits(a, digits, rnd.to_softfloat()))
+    }
+}
+
+impl FPU {
+    /// Sum of `a`, accumulated exactly and rounded once under `rnd`.
+    #[inline]
+    #[must_use]
+    pub const fn f64_sum(&mut self, a: &[float64_t], rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(f64_sum(a, rnd.to_softfloat(), self.detect_tininess()), rnd)
+    }
+
+    /// Dot product of `a` and `b`, accumulated exactly and rounded once
+    /// under `rnd`.
+    #[inline]
+    #[must_use]
+    pub const fn f64_dot(
+        &mut self,
+        a: &[float64_t],
+        b: &[float64_t],
+        rnd: RoundingMode,
+    ) -> float64_t {
+        self.rounded_f64(
+            f64_dot(a, b, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    /// Sum of the squares of `a`, rounded once under `rnd`.
+    #[inline]
+    #[must_use]
+    pub const fn f64_sum_square(&mut self, a: &[float64_t], rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(
+            f64_sumSquare(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    /// Sum of the magnitudes of `a`, rounded once under `rnd`.
+    #[inline]
+    #[must_use]
+    pub const fn f64_sum_abs(&mut self, a: &[float64_t], rnd: RoundingMode) -> float64_t {
+        self.rounded_f64(
+            f64_sumAbs(a, rnd.to_softfloat(), self.detect_tininess()),
+            rnd,
+        )
+    }
+
+    /// Product of `a` as `(pr, sf)` with `pr × 2^sf` the product and
+    /// `1 <= |pr| < 2`, so that it cannot overflow or underflow.
+    #[inline]
+    #[must_use]
+    pub const fn f64_scaled_prod(
+        &mut self,
+        a: &[float64_t],
+        rnd: RoundingMode,
+    ) -> (float64_t, i64) {
+        let (pr, sf, flags) = f64_scaledProd(a, rnd.to_softfloat(), self.detect_tininess());
+        (self.flagged_const((pr, flags)), sf)
+    }
+
+    /// Product of `a[i] + b[i]`, scaled like [`FPU::f64_scaled_prod`].
+    #[inline]
+    #[must_use]
+    pub const fn f64_scaled_prod_sum(
+        &mut self,
+        a: &[float64_t],
+        b: &[float64_t],
+        rnd: RoundingMode,
+    ) -> (float64_t, i64) {
+        let (pr, sf, flags) = f64_scaledProdSum(a, b, rnd.to_softfloat(), self.detect_tininess());
+        (self.flagged_const((pr, flags)), sf)
+    }
+
+    /// Product of `a[i] - b[i]`, scaled like [`FPU::f64_scaled_prod`].
+    #[inline]
+    #[must_use]
+    pub const fn f64_scaled_prod_diff(
+        &mut self,
+        a: &[float64_t],
+        b: &[float64_t],
+        rnd: RoundingMode,
+    ) -> (float64_t, i64) {
+        let (pr, sf, flags) = f64_scaledProdDiff(a, b, rnd.to_softfloat(), self.detect_tininess());
+        (self.flagged_const((pr, flags)), sf)
+    }
+}
+
+impl FpuFormat for float64_t {
+    #[inline]
+    fn fpu_to_i32(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> i32 {
+        fpu.f64_to_i32(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_i64(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> i64 {
+        fpu.f64_to_i64(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_u64(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> u64 {
+        fpu.f64_to_u64(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_u32(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> u32 {
+        fpu.f64_to_u32(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_i8(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> i8 {
+        fpu.f64_to_i8(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_i16(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> i16 {
+        fpu.f64_to_i16(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_i128(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> i128 {
+        fpu.f64_to_i128(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_u8(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> u8 {
+        fpu.f64_to_u8(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_u16(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> u16 {
+        fpu.f64_to_u16(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_u128(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> u128 {
+        fpu.f64_to_u128(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_limbs(fpu: &mut FPU, a: Self, z: &mut [u64], rnd: RoundingMode, exact: bool) -> bool {
+        fpu.f64_to_limbs(a, z, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_from_parts(
+        fpu: &mut FPU,
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        rnd: RoundingMode,
+    ) -> Self {
+        fpu.f64_from_parts(sign, significand, exponent, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_i8(fpu: &mut FPU, a: i8, rnd: RoundingMode) -> Self {
+        fpu.f64_from_i8(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_i16(fpu: &mut FPU, a: i16, rnd: RoundingMode) -> Self {
+        fpu.f64_from_i16(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_i32(fpu: &mut FPU, a: i32, rnd: RoundingMode) -> Self {
+        fpu.f64_from_i32(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_i64(fpu: &mut FPU, a: i64, rnd: RoundingMode) -> Self {
+        fpu.f64_from_i64(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_i128(fpu: &mut FPU, a: i128, rnd: RoundingMode) -> Self {
+        fpu.f64_from_i128(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_u8(fpu: &mut FPU, a: u8, rnd: RoundingMode) -> Self {
+        fpu.f64_from_u8(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_u16(fpu: &mut FPU, a: u16, rnd: RoundingMode) -> Self {
+        fpu.f64_from_u16(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_u32(fpu: &mut FPU, a: u32, rnd: RoundingMode) -> Self {
+        fpu.f64_from_u32(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_u64(fpu: &mut FPU, a: u64, rnd: RoundingMode) -> Self {
+        fpu.f64_from_u64(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_u128(fpu: &mut FPU, a: u128, rnd: RoundingMode) -> Self {
+        fpu.f64_from_u128(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_to_f32(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> float32_t {
+        fpu.f64_to_f32(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_to_f64(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> float64_t {
+        fpu.rounded_f64((a, 0), rnd)
+    }
+
+    #[inline]
+    fn fpu_to_parts(fpu: &mut FPU, a: Self) -> Parts {
+        fpu.f64_to_parts(a)
+    }
+
+    #[inline]
+    fn fpu_is_signaling_nan(fpu: &mut FPU, a: Self) -> bool {
+        fpu.f64_is_signaling_nan(a)
+    }
+
+    #[inline]
+    fn fpu_nan_payload(fpu: &mut FPU, a: Self) -> Self {
+        fpu.f64_nan_payload(a)
+    }
+
+    #[inline]
+    fn fpu_from_nan_payload(fpu: &mut FPU, a: Self) -> Self {
+        fpu.f64_from_nan_payload(a)
+    }
+
+    #[inline]
+    fn fpu_from_nan_payload_signaling(fpu: &mut FPU, a: Self) -> Self {
+        fpu.f64_from_nan_payload_signaling(a)
+    }
+
+    #[inline]
+    fn fpu_canonicalize(fpu: &mut FPU, a: Self) -> Self {
+        fpu.f64_canonicalize(a)
+    }
+
+    #[inline]
+    fn fpu_is_canonical(fpu: &mut FPU, a: Self) -> bool {
+        fpu.f64_is_canonical(a)
+    }
+
+    #[inline]
+    fn fpu_lt(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_lt(a, b)
+    }
+
+    #[inline]
+    fn fpu_le(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_le(a, b)
+    }
+
+    #[inline]
+    fn fpu_eq(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_eq(a, b)
+    }
+
+    #[inline]
+    fn fpu_eq_signaling(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_eq_signaling(a, b)
+    }
+
+    #[inline]
+    fn fpu_lt_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_lt_quiet(a, b)
+    }
+
+    #[inline]
+    fn fpu_le_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_le_quiet(a, b)
+    }
+
+    #[inline]
+    fn fpu_gt(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_gt(a, b)
+    }
+
+    #[inline]
+    fn fpu_ge(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_ge(a, b)
+    }
+
+    #[inline]
+    fn fpu_gt_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_gt_quiet(a, b)
+    }
+
+    #[inline]
+    fn fpu_ge_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_ge_quiet(a, b)
+    }
+
+    #[inline]
+    fn fpu_ne(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_ne(a, b)
+    }
+
+    #[inline]
+    fn fpu_ne_signaling(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_ne_signaling(a, b)
+    }
+
+    #[inline]
+    fn fpu_unordered(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_unordered(a, b)
+    }
+
+    #[inline]
+    fn fpu_unordered_signaling(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_unordered_signaling(a, b)
+    }
+
+    #[inline]
+    fn fpu_ordered(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_ordered(a, b)
+    }
+
+    #[inline]
+    fn fpu_ordered_signaling(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_ordered_signaling(a, b)
+    }
+
+    #[inline]
+    fn fpu_not_gt(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_not_gt(a, b)
+    }
+
+    #[inline]
+    fn fpu_not_gt_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_not_gt_quiet(a, b)
+    }
+
+    #[inline]
+    fn fpu_lt_unordered(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_lt_unordered(a, b)
+    }
+
+    #[inline]
+    fn fpu_lt_unordered_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_lt_unordered_quiet(a, b)
+    }
+
+    #[inline]
+    fn fpu_not_lt(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_not_lt(a, b)
+    }
+
+    #[inline]
+    fn fpu_not_lt_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_not_lt_quiet(a, b)
+    }
+
+    #[inline]
+    fn fpu_gt_unordered(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_gt_unordered(a, b)
+    }
+
+    #[inline]
+    fn fpu_gt_unordered_quiet(fpu: &mut FPU, a: Self, b: Self) -> bool {
+        fpu.f64_gt_unordered_quiet(a, b)
+    }
+
+    #[inline]
+    fn fpu_compare_quiet(fpu: &mut FPU, a: Self, b: Self) -> Option<Ordering> {
+        fpu.f64_compare_quiet(a, b)
+    }
+
+    #[inline]
+    fn fpu_compare_signaling(fpu: &mut FPU, a: Self, b: Self) -> Option<Ordering> {
+        fpu.f64_compare_signaling(a, b)
+    }
+
+    #[inline]
+    fn fpu_add(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_add(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_mul_add(fpu: &mut FPU, a: Self, b: Self, c: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_mul_add(a, b, c, rnd)
+    }
+
+    #[inline]
+    fn fpu_div(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_div(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_mul(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_mul(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_rem(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_rem(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_sub(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_sub(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_round_to_int(fpu: &mut FPU, a: Self, rnd: RoundingMode, exact: bool) -> Self {
+        fpu.f64_round_to_int(a, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_sqrt(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_sqrt(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_exp(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_exp(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_exp2(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_exp2(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_expm1(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_expm1(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_log(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_log(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_log2(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_log2(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_log10(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_log10(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_log1p(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_log1p(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_sin(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_sin(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_cos(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_cos(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_tan(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_tan(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_asin(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_asin(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_acos(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_acos(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_atan(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_atan(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_atan2(fpu: &mut FPU, y: Self, x: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_atan2(y, x, rnd)
+    }
+
+    #[inline]
+    fn fpu_atan2pi(fpu: &mut FPU, y: Self, x: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_atan2pi(y, x, rnd)
+    }
+
+    #[inline]
+    fn fpu_sinh(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_sinh(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_cosh(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_cosh(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_tanh(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_tanh(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_asinh(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_asinh(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_acosh(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_acosh(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_atanh(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_atanh(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_pow(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_pow(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_pown(fpu: &mut FPU, a: Self, n: i64, rnd: RoundingMode) -> Self {
+        fpu.f64_pown(a, n, rnd)
+    }
+
+    #[inline]
+    fn fpu_powr(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_powr(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_rootn(fpu: &mut FPU, a: Self, n: i64, rnd: RoundingMode) -> Self {
+        fpu.f64_rootn(a, n, rnd)
+    }
+
+    #[inline]
+    fn fpu_compound(fpu: &mut FPU, a: Self, n: i64, rnd: RoundingMode) -> Self {
+        fpu.f64_compound(a, n, rnd)
+    }
+
+    #[inline]
+    fn fpu_cbrt(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_cbrt(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_hypot(fpu: &mut FPU, a: Self, b: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_hypot(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_rsqrt(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_rsqrt(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_erf(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_erf(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_erfc(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_erfc(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_tgamma(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_tgamma(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_lgamma(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> (Self, i8) {
+        fpu.f64_lgamma(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_recip7(fpu: &mut FPU, a: Self, rnd: RoundingMode) -> Self {
+        fpu.f64_recip7(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_rsqrte7(fpu: &mut FPU, a: Self) -> Self {
+        fpu.f64_rsqrte7(a)
+    }
+
+    #[inline]
+    fn fpu_to_fix16(fpu: &mut FPU, a: Self, fbits: u8, rnd: RoundingMode, exact: bool) -> i16 {
+        fpu.f64_to_fix16(a, fbits, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_ufix16(fpu: &mut FPU, a: Self, fbits: u8, rnd: RoundingMode, exact: bool) -> u16 {
+        fpu.f64_to_ufix16(a, fbits, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_fix32(fpu: &mut FPU, a: Self, fbits: u8, rnd: RoundingMode, exact: bool) -> i32 {
+        fpu.f64_to_fix32(a, fbits, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_ufix32(fpu: &mut FPU, a: Self, fbits: u8, rnd: RoundingMode, exact: bool) -> u32 {
+        fpu.f64_to_ufix32(a, fbits, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_fix64(fpu: &mut FPU, a: Self, fbits: u8, rnd: RoundingMode, exact: bool) -> i64 {
+        fpu.f64_to_fix64(a, fbits, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_to_ufix64(fpu: &mut FPU, a: Self, fbits: u8, rnd: RoundingMode, exact: bool) -> u64 {
+        fpu.f64_to_ufix64(a, fbits, rnd, exact)
+    }
+
+    #[inline]
+    fn fpu_parse(fpu: &mut FPU, s: &str, rnd: RoundingMode) -> Result<Self, ParseFloatError> {
+        fpu.f64_parse(s, rnd)
+    }
+
+    #[inline]
+    fn fpu_to_fixed(fpu: &mut FPU, a: Self, digits: usize, rnd: RoundingMode) -> Decimal {
+        fpu.f64_to_fixed(a, digits, rnd)
+    }
+
+    #[inline]
+    fn fpu_to_exponent(fpu: &mut FPU, a: Self, digits: usize, rnd: RoundingMode) -> Decimal {
+        fpu.f64_to_exponent(a, digits, rnd)
+    }
+
+    #[inline]
+    fn fpu_parse_hex(fpu: &mut FPU, s: &str, rnd: RoundingMode) -> Result<Self, ParseFloatError> {
+        fpu.f64_parse_hex(s, rnd)
+    }
+
+    #[inline]
+    fn fpu_to_hex_digits(fpu: &mut FPU, a: Self, digits: usize, rnd: RoundingMode) -> Hex {
+        fpu.f64_to_hex_digits(a, digits, rnd)
+    }
+
+    #[inline]
+    fn fpu_sum(fpu: &mut FPU, a: &[Self], rnd: RoundingMode) -> Self {
+        fpu.f64_sum(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_dot(fpu: &mut FPU, a: &[Self], b: &[Self], rnd: RoundingMode) -> Self {
+        fpu.f64_dot(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_sum_square(fpu: &mut FPU, a: &[Self], rnd: RoundingMode) -> Self {
+        fpu.f64_sum_square(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_sum_abs(fpu: &mut FPU, a: &[Self], rnd: RoundingMode) -> Self {
+        fpu.f64_sum_abs(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_scaled_prod(fpu: &mut FPU, a: &[Self], rnd: RoundingMode) -> (Self, i64) {
+        fpu.f64_scaled_prod(a, rnd)
+    }
+
+    #[inline]
+    fn fpu_scaled_prod_sum(
+        fpu: &mut FPU,
+        a: &[Self],
+        b: &[Self],
+        rnd: RoundingMode,
+    ) -> (Self, i64) {
+        fpu.f64_scaled_prod_sum(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_scaled_prod_diff(
+        fpu: &mut FPU,
+        a: &[Self],
+        b: &[Self],
+        rnd: RoundingMode,
+    ) -> (Self, i64) {
+        fpu.f64_scaled_prod_diff(a, b, rnd)
+    }
+
+    #[inline]
+    fn fpu_from_float<G: FpuFormat>(fpu: &mut FPU, a: G, rnd: RoundingMode) -> Self {
+        G::fpu_to_f64(fpu, a, rnd)
+    }
+}
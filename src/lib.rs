@@ -1,7 +1,15 @@
 pub mod fpu;
+pub mod math;
+#[cfg(feature = "serde")]
+pub mod serde;
 pub mod softfloat;
+pub mod text;
 pub mod wrapper;
 
-pub use fpu::FPU;
+pub use fpu::{FpuFormat, FPU};
 pub use softfloat::{float32_t, float64_t};
-pub use wrapper::{ExceptionFlags, Float, RoundingMode, TininessMode};
+pub use text::ParseFloatError;
+pub use wrapper::{
+    ExceptionFlags, Float, Int, Precision, PrecisionError, RandomSource, Rounded, RoundingMode,
+    TininessMode,
+};
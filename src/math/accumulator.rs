@@ -0,0 +1,367 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Exact (Kulisch) accumulation of sums of floats and their products.
+//!
+//! The accumulator is a two's complement fixed-point integer wide enough to
+//! hold any product of two `f64` values exactly, with headroom for more
+//! terms than can ever be added, so sums are exact until the single final
+//! rounding and do not depend on the order of the terms.
+
+use super::format::Format;
+use super::mp::Mp;
+use crate::softfloat::{float32_t, float64_t, softfloat_flag_invalid, softfloat_round_min};
+
+/// Weight of the least significant bit, that of the smallest product of two
+/// subnormal `f64` values.
+const LSB: i64 = -2148;
+
+/// Enough limbs for products up to `2^2048` plus 92 bits of headroom.
+const LIMBS: usize = 67;
+
+/// An exact running sum of `f32` and `f64` values and of pairwise products.
+///
+/// Infinities and NaNs are tracked alongside the finite sum, and signaling
+/// NaNs and `0 × ∞` raise invalid as the corresponding operations would. The
+/// sum is rounded once, to either format, by [`Accumulator::to_f32`] or
+/// [`Accumulator::to_f64`], which also return the flags raised so far.
+#[derive(Copy, Clone, Debug)]
+pub struct Accumulator {
+    d: [u64; LIMBS],
+    flags: u8,
+    seen: u8,
+}
+
+impl Default for Accumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Significand and exponent of a finite, non-zero bit pattern. Subnormals
+/// keep the exponent of the smallest subnormal, so no product falls below
+/// [`LSB`].
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+const fn split(fmt: Format, a: u64) -> (u64, i64) {
+    let x = fmt.to_mp(a);
+    let m = x.d[0] >> (64 - fmt.precision());
+    let e = x.exp - fmt.precision() as i64;
+    if e < fmt.min_exp() {
+        // The bits shifted out are zero.
+        (m >> (fmt.min_exp() - e) as u64, fmt.min_exp())
+    } else {
+        (m, e)
+    }
+}
+
+// Kinds of term seen so far, as bits of `Accumulator::seen`.
+const NAN: u8 = 1;
+const POS_INF: u8 = 2;
+const NEG_INF: u8 = 4;
+const POS: u8 = 8;
+const NEG: u8 = 16;
+
+/// Limb `i` of `mag`, or zero past the top.
+const fn limb(mag: &[u64; LIMBS], i: usize) -> u64 {
+    if i < LIMBS {
+        mag[i]
+    } else {
+        0
+    }
+}
+
+impl Accumulator {
+    /// An empty sum, which rounds to `+0`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            d: [0; LIMBS],
+            flags: 0,
+            seen: 0,
+        }
+    }
+
+    /// Adds `(-1)^neg × m × 2^e` to the fixed-point sum.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    const fn add_term(&mut self, neg: bool, m: u128, e: i64) {
+        let offset = (e - LSB) as u64;
+        let mut i = (offset / 64) as usize;
+        let sh = (offset % 64) as u32;
+        let lo = m as u64;
+        let hi = (m >> 64) as u64;
+        let w = if sh == 0 {
+            [lo, hi, 0]
+        } else {
+            [lo << sh, (hi << sh) | (lo >> (64 - sh)), hi >> (64 - sh)]
+        };
+        let mut carry = false;
+        let mut k = 0;
+        while i < LIMBS && (k < 3 || carry) {
+            let t = if k < 3 { w[k] } else { 0 };
+            if neg {
+                let (r, b1) = self.d[i].overflowing_sub(t);
+                let (r, b2) = r.overflowing_sub(carry as u64);
+                self.d[i] = r;
+                carry = b1 || b2;
+            } else {
+                let (r, c1) = self.d[i].overflowing_add(t);
+                let (r, c2) = r.overflowing_add(carry as u64);
+                self.d[i] = r;
+                carry = c1 || c2;
+            }
+            i += 1;
+            k += 1;
+        }
+    }
+
+    /// Notes the sign of a term, which decides the sign of an exact zero.
+    const fn note_sign(&mut self, neg: bool) {
+        self.seen |= if neg { NEG } else { POS };
+    }
+
+    const fn note_nan(&mut self, fmt: Format, a: u64) {
+        if fmt.is_signaling_nan(a) {
+            self.flags |= softfloat_flag_invalid;
+        }
+        self.seen |= NAN;
+    }
+
+    const fn add_bits(&mut self, fmt: Format, a: u64) {
+        if fmt.is_nan(a) {
+            self.note_nan(fmt, a);
+            return;
+        }
+        let neg = fmt.sign(a);
+        if fmt.is_inf(a) {
+            self.seen |= if neg { NEG_INF } else { POS_INF };
+            return;
+        }
+        self.note_sign(neg);
+        if !fmt.is_zero(a) {
+            let (m, e) = split(fmt, a);
+            self.add_term(neg, m as u128, e);
+        }
+    }
+
+    const fn add_product_bits(&mut self, fmt: Format, a: u64, b: u64) {
+        let a_nan = fmt.is_nan(a);
+        let b_nan = fmt.is_nan(b);
+        if a_nan || b_nan {
+            if a_nan {
+                self.note_nan(fmt, a);
+            }
+            if b_nan {
+                self.note_nan(fmt, b);
+            }
+            return;
+        }
+        let neg = fmt.sign(a) != fmt.sign(b);
+        if fmt.is_inf(a) || fmt.is_inf(b) {
+            if fmt.is_zero(a) || fmt.is_zero(b) {
+                self.flags |= softfloat_flag_invalid;
+                self.seen |= NAN;
+            } else {
+                self.seen |= if neg { NEG_INF } else { POS_INF };
+            }
+            return;
+        }
+        self.note_sign(neg);
+        if !fmt.is_zero(a) && !fmt.is_zero(b) {
+            let (ma, ea) = split(fmt, a);
+            let (mb, eb) = split(fmt, b);
+            self.add_term(neg, ma as u128 * mb as u128, ea + eb);
+        }
+    }
+
+    /// Adds `a` exactly.
+    #[inline]
+    pub const fn add_f32(&mut self, a: float32_t) {
+        self.add_bits(Format::F32, a.v as u64);
+    }
+
+    /// Adds `a` exactly.
+    #[inline]
+    pub const fn add_f64(&mut self, a: float64_t) {
+        self.add_bits(Format::F64, a.v);
+    }
+
+    /// Adds the exact product `a × b`.
+    #[inline]
+    pub const fn add_product_f32(&mut self, a: float32_t, b: float32_t) {
+        self.add_product_bits(Format::F32, a.v as u64, b.v as u64);
+    }
+
+    /// Adds the exact product `a × b`.
+    #[inline]
+    pub const fn add_product_f64(&mut self, a: float64_t, b: float64_t) {
+        self.add_product_bits(Format::F64, a.v, b.v);
+    }
+
+    /// Adds everything accumulated in `other`, as if its terms had been added
+    /// to `self` directly.
+    pub const fn merge(&mut self, other: &Self) {
+        let mut carry = false;
+        let mut i = 0;
+        while i < LIMBS {
+            let (r, c1) = self.d[i].overflowing_add(other.d[i]);
+            let (r, c2) = r.overflowing_add(carry as u64);
+            self.d[i] = r;
+            carry = c1 || c2;
+            i += 1;
+        }
+        self.flags |= other.flags;
+        self.seen |= other.seen;
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    const fn round(&self, fmt: Format, roundingMode: u8, detectTininess: u8) -> (u64, u8) {
+        if (self.seen & (POS_INF | NEG_INF)) == POS_INF | NEG_INF {
+            return (fmt.default_nan(), self.flags | softfloat_flag_invalid);
+        }
+        if (self.seen & NAN) != 0 {
+            return (fmt.default_nan(), self.flags);
+        }
+        if (self.seen & (POS_INF | NEG_INF)) != 0 {
+            return (fmt.inf((self.seen & NEG_INF) != 0), self.flags);
+        }
+        let neg = (self.d[LIMBS - 1] >> 63) != 0;
+        let mut mag = self.d;
+        if neg {
+            let mut borrow = true;
+            let mut i = 0;
+            while i < LIMBS {
+                let (r, b) = (!mag[i]).overflowing_add(borrow as u64);
+                mag[i] = r;
+                borrow = b;
+                i += 1;
+            }
+        }
+        let mut h = LIMBS;
+        while h > 0 && mag[h - 1] == 0 {
+            h -= 1;
+        }
+        if h == 0 {
+            let sign = if (self.seen & (POS | NEG)) == POS | NEG {
+                roundingMode == softfloat_round_min
+            } else {
+                (self.seen & NEG) != 0
+            };
+            return (fmt.zero(sign), self.flags);
+        }
+        // Take the top 128 bits, starting at bit `s`, and note whether any
+        // bit below them is set.
+        let t = 64 * h as u64 - 1 - mag[h - 1].leading_zeros() as u64;
+        let s = t.saturating_sub(127);
+        let q = (s / 64) as usize;
+        let r = (s % 64) as u32;
+        let low = (limb(&mag, q) as u128) | ((limb(&mag, q + 1) as u128) << 64);
+        let mut x = low >> r;
+        if r != 0 {
+            x |= (limb(&mag, q + 2) as u128) << (128 - r);
+        }
+        let mut sticky = r != 0 && (mag[q] & ((1 << r) - 1)) != 0;
+        let mut i = 0;
+        while i < q {
+            sticky |= mag[i] != 0;
+            i += 1;
+        }
+        let mut v = Mp::from_u128(x).mul_2exp(s as i64 + LSB);
+        v.neg = neg;
+        let dir = if !sticky {
+            0
+        } else if neg {
+            -1
+        } else {
+            1
+        };
+        let (z, flags) = fmt.round(&v, dir, roundingMode, detectTininess);
+        (z, self.flags | flags)
+    }
+
+    /// The sum rounded once to `float32_t`, with every flag raised by the
+    /// terms and by the rounding.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn to_f32(&self, roundingMode: u8, detectTininess: u8) -> (float32_t, u8) {
+        let (v, flags) = self.round(Format::F32, roundingMode, detectTininess);
+        (float32_t { v: v as u32 }, flags)
+    }
+
+    /// The sum rounded once to `float64_t`, with every flag raised by the
+    /// terms and by the rounding.
+    #[must_use]
+    pub const fn to_f64(&self, roundingMode: u8, detectTininess: u8) -> (float64_t, u8) {
+        let (v, flags) = self.round(Format::F64, roundingMode, detectTininess);
+        (float64_t { v }, flags)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::softfloat::{
+        softfloat_flag_inexact, softfloat_flag_underflow, softfloat_round_max,
+        softfloat_round_near_even,
+    };
+
+    #[test]
+    fn test_accumulator() {
+        let max = float64_t {
+            v: 0x7FEF_FFFF_FFFF_FFFF,
+        };
+        let tiny = float64_t { v: 1 };
+        let rne = softfloat_round_near_even;
+
+        // Far more than enough to overflow any intermediate, but the
+        // terms cancel down to one `max` and one `tiny`.
+        let mut acc = Accumulator::new();
+        let mut other = Accumulator::new();
+        for _ in 0..1000 {
+            acc.add_f64(max);
+            acc.add_product_f64(tiny, tiny);
+            other.add_product_f64(
+                max,
+                float64_t {
+                    v: 0xBFF0_0000_0000_0000,
+                },
+            );
+        }
+        other.add_f64(tiny);
+        other.add_f64(max);
+        acc.merge(&other);
+        assert_eq!(acc.to_f64(rne, 1), (max, softfloat_flag_inexact));
+        assert_eq!(
+            acc.to_f64(softfloat_round_min, 1),
+            (max, softfloat_flag_inexact)
+        );
+
+        // Terms of both formats mix, and round to either.
+        let mut acc = Accumulator::default();
+        acc.add_f32(float32_t { v: 0x3DCC_CCCD });
+        acc.add_f64(float64_t {
+            v: 0x3FB9_9999_9999_999A,
+        });
+        assert_eq!(
+            acc.to_f32(rne, 1),
+            (float32_t { v: 0x3E4C_CCCD }, softfloat_flag_inexact)
+        );
+        assert_eq!(acc.to_f64(rne, 1).1, 0);
+
+        // Products of subnormals are not lost below the fixed point.
+        let mut acc = Accumulator::new();
+        acc.add_product_f64(tiny, tiny);
+        assert_eq!(
+            acc.to_f64(softfloat_round_max, 1),
+            (tiny, softfloat_flag_inexact | softfloat_flag_underflow)
+        );
+
+        // A NaN product does not hide the invalid `∞ - ∞`.
+        let mut acc = Accumulator::new();
+        acc.add_f32(float32_t { v: 0x7F80_0000 });
+        acc.add_product_f32(float32_t { v: 0x7FC0_0000 }, float32_t { v: 0 });
+        acc.add_f32(float32_t { v: 0xFF80_0000 });
+        assert_eq!(
+            acc.to_f32(rne, 1),
+            (float32_t { v: 0x7FC0_0000 }, softfloat_flag_invalid)
+        );
+    }
+}
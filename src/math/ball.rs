@@ -0,0 +1,452 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Midpoint-radius interval ("ball") arithmetic over [`Mp`].
+//!
+//! A [`Ball`] encloses the exact value of a computation: every operation
+//! widens the radius by the propagated input error plus the truncation error
+//! of its midpoint, so the true result always lies in `[mid - rad, mid + rad]`.
+
+use super::consts::{LN2, PI};
+use super::mp::{Mp, MAX_LIMBS};
+
+/// An upper or lower bound on a magnitude, `m × 2^e` with a 32-bit `m`.
+#[derive(Copy, Clone, Debug)]
+pub struct Mag {
+    m: u64,
+    e: i64,
+}
+
+impl Mag {
+    pub const ZERO: Self = Self { m: 0, e: 0 };
+
+    /// Stands in for an unbounded radius.
+    pub const INF: Self = Self {
+        m: 1 << 31,
+        e: 1 << 40,
+    };
+
+    #[allow(clippy::cast_possible_truncation)]
+    const fn norm(m: u128, e: i64, up: bool) -> Self {
+        if m == 0 {
+            return Self::ZERO;
+        }
+        let bits = 128 - m.leading_zeros() as i64;
+        if bits <= 32 {
+            return Self {
+                m: (m << (32 - bits)) as u64,
+                e: e.saturating_sub(32 - bits),
+            };
+        }
+        let s = bits - 32;
+        let mut r = (m >> s) as u64;
+        let mut e = e.saturating_add(s);
+        if up && (m & ((1u128 << s) - 1)) != 0 {
+            r += 1;
+            if r == 1 << 32 {
+                r >>= 1;
+                e = e.saturating_add(1);
+            }
+        }
+        Self { m: r, e }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.m == 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_inf(&self) -> bool {
+        self.m != 0 && self.e >= Self::INF.e
+    }
+
+    /// Exponent `k` such that the value is below `2^k`.
+    #[inline]
+    #[must_use]
+    pub const fn log2_upper(&self) -> i64 {
+        if self.m == 0 {
+            i64::MIN / 2
+        } else {
+            self.e.saturating_add(32)
+        }
+    }
+
+    #[must_use]
+    pub const fn from_u64_upper(v: u64) -> Self {
+        Self::norm(v as u128, 0, true)
+    }
+
+    #[must_use]
+    pub const fn from_u64_lower(v: u64) -> Self {
+        Self::norm(v as u128, 0, false)
+    }
+
+    /// Upper bound on `|x|`.
+    #[must_use]
+    pub const fn from_mp_upper(x: &Mp) -> Self {
+        if x.is_zero() {
+            return Self::ZERO;
+        }
+        Self::norm(((x.d[0] >> 32) + 1) as u128, x.exp.saturating_sub(32), true)
+    }
+
+    /// Lower bound on `|x|`.
+    #[must_use]
+    pub const fn from_mp_lower(x: &Mp) -> Self {
+        if x.is_zero() {
+            return Self::ZERO;
+        }
+        Self::norm((x.d[0] >> 32) as u128, x.exp.saturating_sub(32), false)
+    }
+
+    /// One unit in the last place of `x` at `n` limbs.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn ulp(x: &Mp, n: usize) -> Self {
+        if x.is_zero() {
+            return Self::ZERO;
+        }
+        Self {
+            m: 1 << 31,
+            e: x.exp.saturating_sub(64 * n as i64 + 31),
+        }
+    }
+
+    #[must_use]
+    pub const fn to_mp(self) -> Mp {
+        Mp::from_parts(false, self.m, self.e)
+    }
+
+    #[must_use]
+    pub const fn mul_2exp(mut self, k: i64) -> Self {
+        if self.m != 0 {
+            self.e = self.e.saturating_add(k);
+        }
+        self
+    }
+
+    #[must_use]
+    pub const fn add(a: Self, b: Self) -> Self {
+        if a.m == 0 {
+            return b;
+        }
+        if b.m == 0 {
+            return a;
+        }
+        let (big, small) = if a.e >= b.e { (a, b) } else { (b, a) };
+        let diff = big.e.saturating_sub(small.e);
+        if diff > 64 {
+            // `small` is below half an ulp of `big`.
+            return Self::norm(((big.m as u128) << 1) + 1, big.e - 1, true);
+        }
+        Self::norm(((big.m as u128) << diff) + small.m as u128, small.e, true)
+    }
+
+    #[must_use]
+    pub const fn mul(a: Self, b: Self) -> Self {
+        if a.m == 0 || b.m == 0 {
+            return Self::ZERO;
+        }
+        Self::norm((a.m as u128) * (b.m as u128), a.e.saturating_add(b.e), true)
+    }
+
+    /// Upper bound on `a / b`, with `b` non-zero.
+    #[must_use]
+    pub const fn div(a: Self, b: Self) -> Self {
+        if a.m == 0 {
+            return Self::ZERO;
+        }
+        if b.m == 0 {
+            return Self::INF;
+        }
+        let num = (a.m as u128) << 64;
+        let q = num.div_ceil(b.m as u128);
+        Self::norm(q, a.e.saturating_sub(b.e).saturating_sub(64), true)
+    }
+
+    /// Lower bound on `max(a - b, 0)`.
+    #[must_use]
+    pub const fn sub_lower(a: Self, b: Self) -> Self {
+        if b.m == 0 {
+            return a;
+        }
+        if a.m == 0 {
+            return Self::ZERO;
+        }
+        if a.e.saturating_sub(b.e) > 64 {
+            return Self::norm(((a.m as u128) << 1) - 1, a.e - 1, false);
+        }
+        if b.e.saturating_sub(a.e) > 64 {
+            return Self::ZERO;
+        }
+        let e = if a.e < b.e { a.e } else { b.e };
+        let x = (a.m as u128) << (a.e - e);
+        let y = (b.m as u128) << (b.e - e);
+        if x <= y {
+            return Self::ZERO;
+        }
+        Self::norm(x - y, e, false)
+    }
+
+    /// Whether `a < b`.
+    #[must_use]
+    pub const fn lt(a: Self, b: Self) -> bool {
+        if a.m == 0 || b.m == 0 {
+            return b.m != 0;
+        }
+        if a.e != b.e {
+            return a.e < b.e;
+        }
+        a.m < b.m
+    }
+}
+
+/// An enclosure `[mid - rad, mid + rad]` of an exact value.
+#[derive(Copy, Clone, Debug)]
+pub struct Ball {
+    pub mid: Mp,
+    pub rad: Mag,
+}
+
+impl Ball {
+    pub const ZERO: Self = Self::exact(Mp::ZERO);
+    pub const ONE: Self = Self::exact(Mp::ONE);
+
+    /// A ball that encloses every value.
+    pub const INDETERMINATE: Self = Self {
+        mid: Mp::ZERO,
+        rad: Mag::INF,
+    };
+
+    #[inline]
+    #[must_use]
+    pub const fn exact(mid: Mp) -> Self {
+        Self {
+            mid,
+            rad: Mag::ZERO,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn from_u64(v: u64) -> Self {
+        Self::exact(Mp::from_u64(v))
+    }
+
+    #[must_use]
+    pub const fn pi(n: usize) -> Self {
+        Self::constant(&PI, 2, n)
+    }
+
+    #[must_use]
+    pub const fn ln2(n: usize) -> Self {
+        Self::constant(&LN2, 0, n)
+    }
+
+    /// A constant stored to `MAX_LIMBS` limbs, truncated to `n`.
+    const fn constant(limbs: &[u64; MAX_LIMBS], exp: i64, n: usize) -> Self {
+        let mid = Mp::from_limbs(limbs, exp, n);
+        Self {
+            mid,
+            rad: Mag::ulp(&mid, n).mul_2exp(1),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_exact(&self) -> bool {
+        self.rad.is_zero()
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_indeterminate(&self) -> bool {
+        self.rad.is_inf()
+    }
+
+    /// Upper bound on the magnitude of every enclosed value.
+    #[must_use]
+    pub const fn upper(&self) -> Mag {
+        Mag::add(Mag::from_mp_upper(&self.mid), self.rad)
+    }
+
+    /// Lower bound on the magnitude of every enclosed value.
+    #[must_use]
+    pub const fn lower(&self) -> Mag {
+        Mag::sub_lower(Mag::from_mp_lower(&self.mid), self.rad)
+    }
+
+    /// Whether zero may be enclosed.
+    #[must_use]
+    pub const fn contains_zero(&self) -> bool {
+        self.lower().is_zero()
+    }
+
+    /// Whether every enclosed value is strictly positive.
+    #[must_use]
+    pub const fn is_positive(&self) -> bool {
+        !self.mid.neg && !self.contains_zero()
+    }
+
+    /// Whether `x` lies within the ball.
+    #[must_use]
+    pub const fn contains(&self, x: &Mp) -> bool {
+        let (diff, _) = Mp::sub(x, &self.mid, MAX_LIMBS);
+        Mp::cmp_abs(&diff, &self.rad.to_mp()) <= 0
+    }
+
+    /// Truncates the midpoint to `n` limbs, widening the radius to match.
+    #[must_use]
+    pub const fn trunc(self, n: usize) -> Self {
+        let (mid, inexact) = self.mid.trunc(n);
+        let rad = if inexact {
+            Mag::add(self.rad, Mag::ulp(&mid, n))
+        } else {
+            self.rad
+        };
+        Self { mid, rad }
+    }
+
+    /// Adds the error of an inexact midpoint operation to `rad`.
+    const fn with_error(mid: Mp, rad: Mag, inexact: bool, n: usize, ulps: i64) -> Self {
+        let rad = if inexact {
+            Mag::add(rad, Mag::ulp(&mid, n).mul_2exp(ulps))
+        } else {
+            rad
+        };
+        Self { mid, rad }
+    }
+
+    /// Adds `err` to the radius.
+    #[must_use]
+    pub const fn widen(mut self, err: Mag) -> Self {
+        self.rad = Mag::add(self.rad, err);
+        self
+    }
+
+    #[must_use]
+    pub const fn neg(mut self) -> Self {
+        self.mid = self.mid.neg();
+        self
+    }
+
+    #[must_use]
+    pub const fn abs(mut self) -> Self {
+        self.mid = self.mid.abs();
+        self
+    }
+
+    #[must_use]
+    pub const fn mul_2exp(mut self, k: i64) -> Self {
+        self.mid = self.mid.mul_2exp(k);
+        self.rad = self.rad.mul_2exp(k);
+        self
+    }
+
+    #[must_use]
+    pub const fn add(a: &Self, b: &Self, n: usize) -> Self {
+        let a = a.trunc(n);
+        let b = b.trunc(n);
+        let (mid, inexact) = Mp::add(&a.mid, &b.mid, n);
+        // Truncation after a cancelling subtraction may lose up to 2 ulps.
+        Self::with_error(mid, Mag::add(a.rad, b.rad), inexact, n, 1)
+    }
+
+    #[must_use]
+    pub const fn sub(a: &Self, b: &Self, n: usize) -> Self {
+        Self::add(a, &b.neg(), n)
+    }
+
+    #[must_use]
+    pub const fn mul(a: &Self, b: &Self, n: usize) -> Self {
+        let a = a.trunc(n);
+        let b = b.trunc(n);
+        let (mid, inexact) = Mp::mul(&a.mid, &b.mid, n);
+        let rad = Mag::add(
+            Mag::add(
+                Mag::mul(Mag::from_mp_upper(&a.mid), b.rad),
+                Mag::mul(Mag::from_mp_upper(&b.mid), a.rad),
+            ),
+            Mag::mul(a.rad, b.rad),
+        );
+        Self::with_error(mid, rad, inexact, n, 0)
+    }
+
+    #[must_use]
+    pub const fn sqr(a: &Self, n: usize) -> Self {
+        Self::mul(a, a, n)
+    }
+
+    #[must_use]
+    pub const fn div(a: &Self, b: &Self, n: usize) -> Self {
+        let a = a.trunc(n);
+        let b = b.trunc(n);
+        let blo = b.lower();
+        if blo.is_zero() || a.is_indeterminate() {
+            return Self::INDETERMINATE;
+        }
+        let (mid, inexact) = Mp::div(&a.mid, &b.mid, n);
+        // |a/b - ma/mb| <= (ra + |q| rb) / (|mb| - rb)
+        let rad = Mag::div(
+            Mag::add(a.rad, Mag::mul(Mag::from_mp_upper(&mid), b.rad)),
+            blo,
+        );
+        Self::with_error(mid, rad, inexact, n, 0)
+    }
+
+    #[must_use]
+    pub const fn sqrt(a: &Self, n: usize) -> Self {
+        let a = a.trunc(n);
+        if a.mid.is_zero() && a.rad.is_zero() {
+            return Self::ZERO;
+        }
+        if a.mid.neg || a.contains_zero() {
+            return Self::INDETERMINATE;
+        }
+        let (mid, inexact) = Mp::sqrt(&a.mid, n);
+        // |sqrt(x) - sqrt(m)| = |x - m| / (sqrt(x) + sqrt(m)) <= r / sqrt(m)
+        let rad = Mag::div(a.rad, Mag::from_mp_lower(&mid));
+        Self::with_error(mid, rad, inexact, n, 0)
+    }
+
+    #[must_use]
+    pub const fn div_u64(a: &Self, k: u64, n: usize) -> Self {
+        let a = a.trunc(n);
+        let (mid, inexact) = Mp::div_u64(&a.mid, k, n);
+        let rad = Mag::div(a.rad, Mag::from_u64_lower(k));
+        Self::with_error(mid, rad, inexact, n, 0)
+    }
+
+    #[must_use]
+    pub const fn mul_u64(a: &Self, k: u64, n: usize) -> Self {
+        let a = a.trunc(n);
+        let (mid, inexact) = Mp::mul_u64(&a.mid, k, n);
+        let rad = Mag::mul(a.rad, Mag::from_u64_upper(k));
+        Self::with_error(mid, rad, inexact, n, 0)
+    }
+
+    #[must_use]
+    pub const fn mul_i64(a: &Self, k: i64, n: usize) -> Self {
+        let r = Self::mul_u64(a, k.unsigned_abs(), n);
+        if k < 0 {
+            r.neg()
+        } else {
+            r
+        }
+    }
+
+    /// A ball enclosing both `a` and `b`, and so every value between them.
+    #[must_use]
+    pub const fn join(a: &Self, b: &Self, n: usize) -> Self {
+        a.widen(Self::sub(a, b, n).upper())
+    }
+
+    /// Whether the magnitude of `term` is negligible next to `sum` at `n` limbs.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn negligible(term: &Self, sum: &Self, n: usize) -> bool {
+        term.upper().log2_upper() < sum.mid.exp.saturating_sub(64 * n as i64 + 4)
+    }
+}
@@ -0,0 +1,316 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Floats from and to their sign, exponent and integer significand.
+//!
+//! `f32_fromParts` and `f64_fromParts` round the exact value
+//! `(-1)^sign × significand × 2^exponent` once, for any significand and
+//! exponent, unlike `softfloat_roundPackToF32` and friends which expect a
+//! significand already normalized to a fixed binary point. `f32_toParts` and
+//! `f64_toParts` give the inverse decomposition, in the integral significand
+//! view of IEEE 754 §3.3.
+
+use super::format::Format;
+use super::mp::Mp;
+use crate::softfloat::{float32_t, float64_t};
+
+/// The class of a float, as returned in [`Parts`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Class {
+    Zero,
+    Subnormal,
+    Normal,
+    Infinite,
+    QuietNaN,
+    SignalingNaN,
+}
+
+/// A float decomposed as `(-1)^sign × significand × 2^exponent`.
+///
+/// For finite values `significand` includes the hidden bit of normal
+/// numbers, so it is below `2^24` (`2^53` for `float64_t`), and `exponent`
+/// is the unbiased exponent less 23 (52), from -149 (-1074) up. Zeros have
+/// the subnormal exponent. Infinities and NaNs have the exponent one past
+/// the largest finite one, 105 (972), and their fraction field as
+/// `significand`, which is zero for infinities.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Parts {
+    pub class: Class,
+    pub sign: bool,
+    pub exponent: i32,
+    pub significand: u64,
+}
+
+const fn from_parts(
+    fmt: Format,
+    sign: bool,
+    significand: u128,
+    exponent: i32,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (u64, u8) {
+    if significand == 0 {
+        return (fmt.zero(sign), 0);
+    }
+    let mut x = Mp::from_u128(significand).mul_2exp(exponent as i64);
+    x.neg = sign;
+    fmt.round(&x, 0, roundingMode, detectTininess)
+}
+
+#[allow(clippy::cast_possible_truncation)]
+const fn to_parts(fmt: Format, a: u64) -> Parts {
+    let p = fmt.precision();
+    let field = (fmt.abs(a) >> (p - 1)) as i32;
+    let frac = a & (fmt.quiet_bit() * 2 - 1);
+    let bias = (fmt.max_biased_exp() >> 1) as i32;
+    let sub = 1 - bias - (p as i32 - 1);
+    let (class, exponent, significand) = if fmt.is_nan(a) {
+        let class = if fmt.is_signaling_nan(a) {
+            Class::SignalingNaN
+        } else {
+            Class::QuietNaN
+        };
+        (class, field - bias - (p as i32 - 1), frac)
+    } else if fmt.is_inf(a) {
+        (Class::Infinite, field - bias - (p as i32 - 1), 0)
+    } else if field != 0 {
+        (
+            Class::Normal,
+            field - bias - (p as i32 - 1),
+            frac | (fmt.quiet_bit() * 2),
+        )
+    } else if frac != 0 {
+        (Class::Subnormal, sub, frac)
+    } else {
+        (Class::Zero, sub, 0)
+    };
+    Parts {
+        class,
+        sign: fmt.sign(a),
+        exponent,
+        significand,
+    }
+}
+
+/// `(-1)^sign × significand × 2^exponent` rounded to `float32_t`.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_fromParts(
+    sign: bool,
+    significand: u128,
+    exponent: i32,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (v, flags) = from_parts(
+        Format::F32,
+        sign,
+        significand,
+        exponent,
+        roundingMode,
+        detectTininess,
+    );
+    (float32_t { v: v as u32 }, flags)
+}
+
+/// `(-1)^sign × significand × 2^exponent` rounded to `float64_t`.
+#[must_use]
+pub const fn f64_fromParts(
+    sign: bool,
+    significand: u128,
+    exponent: i32,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float64_t, u8) {
+    let (v, flags) = from_parts(
+        Format::F64,
+        sign,
+        significand,
+        exponent,
+        roundingMode,
+        detectTininess,
+    );
+    (float64_t { v }, flags)
+}
+
+/// Sign, exponent and integer significand of `a`.
+#[must_use]
+pub const fn f32_toParts(a: float32_t) -> Parts {
+    to_parts(Format::F32, a.v as u64)
+}
+
+/// Sign, exponent and integer significand of `a`.
+#[must_use]
+pub const fn f64_toParts(a: float64_t) -> Parts {
+    to_parts(Format::F64, a.v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f32_fromParts() {
+        struct TestCase {
+            sign: bool,
+            significand: u128,
+            exponent: i32,
+            result: u32,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                sign: false,
+                significand: 3,
+                exponent: -1,
+                result: 0x3FC0_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                sign: true,
+                significand: 0,
+                exponent: 1000,
+                result: 0x8000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                sign: false,
+                significand: u128::MAX,
+                exponent: 0,
+                result: 0x7F80_0000,
+                flags: 5,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                sign: false,
+                significand: u128::MAX,
+                exponent: 0,
+                result: 0x7F7F_FFFF,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                sign: false,
+                significand: 0x0100_0001,
+                exponent: 0,
+                result: 0x4B80_0000,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                sign: false,
+                significand: 0x0100_0001,
+                exponent: 0,
+                result: 0x4B80_0001,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                sign: true,
+                significand: 1 << 100,
+                exponent: -250,
+                result: 0x8000_0000,
+                flags: 3,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                sign: true,
+                significand: 1,
+                exponent: i32::MIN,
+                result: 0x8000_0001,
+                flags: 3,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                sign: false,
+                significand: 3,
+                exponent: -150,
+                result: 0x2,
+                flags: 3,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                sign: false,
+                significand: 0x00FF_FFFF,
+                exponent: 104,
+                result: 0x7F7F_FFFF,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = f32_fromParts(
+                c.sign,
+                c.significand,
+                c.exponent,
+                c.roundingMode,
+                c.detectTininess,
+            );
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_toParts() {
+        let parts = |class, sign, exponent, significand| Parts {
+            class,
+            sign,
+            exponent,
+            significand,
+        };
+        assert_eq!(
+            f32_toParts(float32_t { v: 0x3FC0_0000 }),
+            parts(Class::Normal, false, -23, 0x00C0_0000)
+        );
+        assert_eq!(
+            f32_toParts(float32_t { v: 0x8000_0003 }),
+            parts(Class::Subnormal, true, -149, 3)
+        );
+        assert_eq!(
+            f32_toParts(float32_t { v: 0x8000_0000 }),
+            parts(Class::Zero, true, -149, 0)
+        );
+        assert_eq!(
+            f32_toParts(float32_t { v: 0xFF80_0000 }),
+            parts(Class::Infinite, true, 105, 0)
+        );
+        assert_eq!(
+            f32_toParts(float32_t { v: 0x7F80_0001 }),
+            parts(Class::SignalingNaN, false, 105, 1)
+        );
+        assert_eq!(
+            f64_toParts(float64_t {
+                v: 0x7FF8_0000_0000_0000
+            }),
+            parts(Class::QuietNaN, false, 972, 0x0008_0000_0000_0000)
+        );
+        assert_eq!(
+            f64_toParts(float64_t {
+                v: 0x7FEF_FFFF_FFFF_FFFF
+            }),
+            parts(Class::Normal, false, 971, 0x001F_FFFF_FFFF_FFFF)
+        );
+
+        // Finite values round trip exactly.
+        for v in [0x0000_0001, 0x0010_0000_0000_0000, 0xC009_21FB_5444_2D18] {
+            let p = f64_toParts(float64_t { v });
+            let (r, flags) = f64_fromParts(p.sign, u128::from(p.significand), p.exponent, 0, 1);
+            assert_eq!((v, r.v, flags), (v, v, 0));
+        }
+    }
+}
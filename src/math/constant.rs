@@ -0,0 +1,630 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Mathematical constants correctly rounded to each format in every rounding
+//! mode, for interval arithmetic and function implementations that need a
+//! constant rounded down or up as well as to nearest.
+//!
+//! The tables have a row per [`Constant`] and a column per rounding mode, in
+//! the order of [`crate::RoundingMode`]: to nearest even, towards zero, down,
+//! up, to nearest away and to odd. Every constant is positive and
+//! irrational, so rounding towards zero and down agree, as do the two
+//! nearest modes.
+
+use crate::softfloat::{
+    float32_t, float64_t, softfloat_round_max, softfloat_round_min, softfloat_round_minMag,
+    softfloat_round_near_even, softfloat_round_near_maxMag, softfloat_round_odd,
+};
+
+/// A mathematical constant, named after its counterpart in
+/// `core::f64::consts`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Constant {
+    /// π
+    Pi,
+    /// 2π
+    Tau,
+    /// π/2
+    FracPi2,
+    /// π/3
+    FracPi3,
+    /// π/4
+    FracPi4,
+    /// π/6
+    FracPi6,
+    /// π/8
+    FracPi8,
+    /// 1/π
+    Frac1Pi,
+    /// 2/π
+    Frac2Pi,
+    /// 2/√π
+    Frac2SqrtPi,
+    /// √2
+    Sqrt2,
+    /// 1/√2
+    Frac1Sqrt2,
+    /// e
+    E,
+    /// log₂ e
+    Log2E,
+    /// log₂ 10
+    Log2Of10,
+    /// log₁₀ e
+    Log10E,
+    /// log₁₀ 2
+    Log10Of2,
+    /// ln 2
+    Ln2,
+    /// ln 10
+    Ln10,
+}
+
+impl Constant {
+    /// Every constant, in table order.
+    pub const ALL: [Self; 19] = [
+        Self::Pi,
+        Self::Tau,
+        Self::FracPi2,
+        Self::FracPi3,
+        Self::FracPi4,
+        Self::FracPi6,
+        Self::FracPi8,
+        Self::Frac1Pi,
+        Self::Frac2Pi,
+        Self::Frac2SqrtPi,
+        Self::Sqrt2,
+        Self::Frac1Sqrt2,
+        Self::E,
+        Self::Log2E,
+        Self::Log2Of10,
+        Self::Log10E,
+        Self::Log10Of2,
+        Self::Ln2,
+        Self::Ln10,
+    ];
+}
+
+const fn row32(v: [u32; 6]) -> [float32_t; 6] {
+    let mut r = [float32_t { v: 0 }; 6];
+    let mut i = 0;
+    while i < 6 {
+        r[i] = float32_t { v: v[i] };
+        i += 1;
+    }
+    r
+}
+
+const fn row64(v: [u64; 6]) -> [float64_t; 6] {
+    let mut r = [float64_t { v: 0 }; 6];
+    let mut i = 0;
+    while i < 6 {
+        r[i] = float64_t { v: v[i] };
+        i += 1;
+    }
+    r
+}
+
+/// Column of `roundingMode` in the tables.
+///
+/// # Panics
+///
+/// If `roundingMode` is not a softfloat rounding mode.
+const fn column(roundingMode: u8) -> usize {
+    if roundingMode == softfloat_round_near_even {
+        0
+    } else if roundingMode == softfloat_round_minMag {
+        1
+    } else if roundingMode == softfloat_round_min {
+        2
+    } else if roundingMode == softfloat_round_max {
+        3
+    } else if roundingMode == softfloat_round_near_maxMag {
+        4
+    } else if roundingMode == softfloat_round_odd {
+        5
+    } else {
+        panic!("invalid rounding mode")
+    }
+}
+
+/// Every [`Constant`] rounded to `float32_t` in each rounding mode.
+pub const F32_CONSTANTS: [[float32_t; 6]; 19] = [
+    // π
+    row32([
+        0x4049_0FDB,
+        0x4049_0FDA,
+        0x4049_0FDA,
+        0x4049_0FDB,
+        0x4049_0FDB,
+        0x4049_0FDB,
+    ]),
+    // 2π
+    row32([
+        0x40C9_0FDB,
+        0x40C9_0FDA,
+        0x40C9_0FDA,
+        0x40C9_0FDB,
+        0x40C9_0FDB,
+        0x40C9_0FDB,
+    ]),
+    // π/2
+    row32([
+        0x3FC9_0FDB,
+        0x3FC9_0FDA,
+        0x3FC9_0FDA,
+        0x3FC9_0FDB,
+        0x3FC9_0FDB,
+        0x3FC9_0FDB,
+    ]),
+    // π/3
+    row32([
+        0x3F86_0A92,
+        0x3F86_0A91,
+        0x3F86_0A91,
+        0x3F86_0A92,
+        0x3F86_0A92,
+        0x3F86_0A91,
+    ]),
+    // π/4
+    row32([
+        0x3F49_0FDB,
+        0x3F49_0FDA,
+        0x3F49_0FDA,
+        0x3F49_0FDB,
+        0x3F49_0FDB,
+        0x3F49_0FDB,
+    ]),
+    // π/6
+    row32([
+        0x3F06_0A92,
+        0x3F06_0A91,
+        0x3F06_0A91,
+        0x3F06_0A92,
+        0x3F06_0A92,
+        0x3F06_0A91,
+    ]),
+    // π/8
+    row32([
+        0x3EC9_0FDB,
+        0x3EC9_0FDA,
+        0x3EC9_0FDA,
+        0x3EC9_0FDB,
+        0x3EC9_0FDB,
+        0x3EC9_0FDB,
+    ]),
+    // 1/π
+    row32([
+        0x3EA2_F983,
+        0x3EA2_F983,
+        0x3EA2_F983,
+        0x3EA2_F984,
+        0x3EA2_F983,
+        0x3EA2_F983,
+    ]),
+    // 2/π
+    row32([
+        0x3F22_F983,
+        0x3F22_F983,
+        0x3F22_F983,
+        0x3F22_F984,
+        0x3F22_F983,
+        0x3F22_F983,
+    ]),
+    // 2/√π
+    row32([
+        0x3F90_6EBB,
+        0x3F90_6EBA,
+        0x3F90_6EBA,
+        0x3F90_6EBB,
+        0x3F90_6EBB,
+        0x3F90_6EBB,
+    ]),
+    // √2
+    row32([
+        0x3FB5_04F3,
+        0x3FB5_04F3,
+        0x3FB5_04F3,
+        0x3FB5_04F4,
+        0x3FB5_04F3,
+        0x3FB5_04F3,
+    ]),
+    // 1/√2
+    row32([
+        0x3F35_04F3,
+        0x3F35_04F3,
+        0x3F35_04F3,
+        0x3F35_04F4,
+        0x3F35_04F3,
+        0x3F35_04F3,
+    ]),
+    // e
+    row32([
+        0x402D_F854,
+        0x402D_F854,
+        0x402D_F854,
+        0x402D_F855,
+        0x402D_F854,
+        0x402D_F855,
+    ]),
+    // log₂ e
+    row32([
+        0x3FB8_AA3B,
+        0x3FB8_AA3B,
+        0x3FB8_AA3B,
+        0x3FB8_AA3C,
+        0x3FB8_AA3B,
+        0x3FB8_AA3B,
+    ]),
+    // log₂ 10
+    row32([
+        0x4054_9A78,
+        0x4054_9A78,
+        0x4054_9A78,
+        0x4054_9A79,
+        0x4054_9A78,
+        0x4054_9A79,
+    ]),
+    // log₁₀ e
+    row32([
+        0x3EDE_5BD9,
+        0x3EDE_5BD8,
+        0x3EDE_5BD8,
+        0x3EDE_5BD9,
+        0x3EDE_5BD9,
+        0x3EDE_5BD9,
+    ]),
+    // log₁₀ 2
+    row32([
+        0x3E9A_209B,
+        0x3E9A_209A,
+        0x3E9A_209A,
+        0x3E9A_209B,
+        0x3E9A_209B,
+        0x3E9A_209B,
+    ]),
+    // ln 2
+    row32([
+        0x3F31_7218,
+        0x3F31_7217,
+        0x3F31_7217,
+        0x3F31_7218,
+        0x3F31_7218,
+        0x3F31_7217,
+    ]),
+    // ln 10
+    row32([
+        0x4013_5D8E,
+        0x4013_5D8D,
+        0x4013_5D8D,
+        0x4013_5D8E,
+        0x4013_5D8E,
+        0x4013_5D8D,
+    ]),
+];
+
+/// Every [`Constant`] rounded to `float64_t` in each rounding mode.
+pub const F64_CONSTANTS: [[float64_t; 6]; 19] = [
+    // π
+    row64([
+        0x4009_21FB_5444_2D18,
+        0x4009_21FB_5444_2D18,
+        0x4009_21FB_5444_2D18,
+        0x4009_21FB_5444_2D19,
+        0x4009_21FB_5444_2D18,
+        0x4009_21FB_5444_2D19,
+    ]),
+    // 2π
+    row64([
+        0x4019_21FB_5444_2D18,
+        0x4019_21FB_5444_2D18,
+        0x4019_21FB_5444_2D18,
+        0x4019_21FB_5444_2D19,
+        0x4019_21FB_5444_2D18,
+        0x4019_21FB_5444_2D19,
+    ]),
+    // π/2
+    row64([
+        0x3FF9_21FB_5444_2D18,
+        0x3FF9_21FB_5444_2D18,
+        0x3FF9_21FB_5444_2D18,
+        0x3FF9_21FB_5444_2D19,
+        0x3FF9_21FB_5444_2D18,
+        0x3FF9_21FB_5444_2D19,
+    ]),
+    // π/3
+    row64([
+        0x3FF0_C152_382D_7366,
+        0x3FF0_C152_382D_7365,
+        0x3FF0_C152_382D_7365,
+        0x3FF0_C152_382D_7366,
+        0x3FF0_C152_382D_7366,
+        0x3FF0_C152_382D_7365,
+    ]),
+    // π/4
+    row64([
+        0x3FE9_21FB_5444_2D18,
+        0x3FE9_21FB_5444_2D18,
+        0x3FE9_21FB_5444_2D18,
+        0x3FE9_21FB_5444_2D19,
+        0x3FE9_21FB_5444_2D18,
+        0x3FE9_21FB_5444_2D19,
+    ]),
+    // π/6
+    row64([
+        0x3FE0_C152_382D_7366,
+        0x3FE0_C152_382D_7365,
+        0x3FE0_C152_382D_7365,
+        0x3FE0_C152_382D_7366,
+        0x3FE0_C152_382D_7366,
+        0x3FE0_C152_382D_7365,
+    ]),
+    // π/8
+    row64([
+        0x3FD9_21FB_5444_2D18,
+        0x3FD9_21FB_5444_2D18,
+        0x3FD9_21FB_5444_2D18,
+        0x3FD9_21FB_5444_2D19,
+        0x3FD9_21FB_5444_2D18,
+        0x3FD9_21FB_5444_2D19,
+    ]),
+    // 1/π
+    row64([
+        0x3FD4_5F30_6DC9_C883,
+        0x3FD4_5F30_6DC9_C882,
+        0x3FD4_5F30_6DC9_C882,
+        0x3FD4_5F30_6DC9_C883,
+        0x3FD4_5F30_6DC9_C883,
+        0x3FD4_5F30_6DC9_C883,
+    ]),
+    // 2/π
+    row64([
+        0x3FE4_5F30_6DC9_C883,
+        0x3FE4_5F30_6DC9_C882,
+        0x3FE4_5F30_6DC9_C882,
+        0x3FE4_5F30_6DC9_C883,
+        0x3FE4_5F30_6DC9_C883,
+        0x3FE4_5F30_6DC9_C883,
+    ]),
+    // 2/√π
+    row64([
+        0x3FF2_0DD7_5042_9B6D,
+        0x3FF2_0DD7_5042_9B6D,
+        0x3FF2_0DD7_5042_9B6D,
+        0x3FF2_0DD7_5042_9B6E,
+        0x3FF2_0DD7_5042_9B6D,
+        0x3FF2_0DD7_5042_9B6D,
+    ]),
+    // √2
+    row64([
+        0x3FF6_A09E_667F_3BCD,
+        0x3FF6_A09E_667F_3BCC,
+        0x3FF6_A09E_667F_3BCC,
+        0x3FF6_A09E_667F_3BCD,
+        0x3FF6_A09E_667F_3BCD,
+        0x3FF6_A09E_667F_3BCD,
+    ]),
+    // 1/√2
+    row64([
+        0x3FE6_A09E_667F_3BCD,
+        0x3FE6_A09E_667F_3BCC,
+        0x3FE6_A09E_667F_3BCC,
+        0x3FE6_A09E_667F_3BCD,
+        0x3FE6_A09E_667F_3BCD,
+        0x3FE6_A09E_667F_3BCD,
+    ]),
+    // e
+    row64([
+        0x4005_BF0A_8B14_5769,
+        0x4005_BF0A_8B14_5769,
+        0x4005_BF0A_8B14_5769,
+        0x4005_BF0A_8B14_576A,
+        0x4005_BF0A_8B14_5769,
+        0x4005_BF0A_8B14_5769,
+    ]),
+    // log₂ e
+    row64([
+        0x3FF7_1547_652B_82FE,
+        0x3FF7_1547_652B_82FE,
+        0x3FF7_1547_652B_82FE,
+        0x3FF7_1547_652B_82FF,
+        0x3FF7_1547_652B_82FE,
+        0x3FF7_1547_652B_82FF,
+    ]),
+    // log₂ 10
+    row64([
+        0x400A_934F_0979_A371,
+        0x400A_934F_0979_A371,
+        0x400A_934F_0979_A371,
+        0x400A_934F_0979_A372,
+        0x400A_934F_0979_A371,
+        0x400A_934F_0979_A371,
+    ]),
+    // log₁₀ e
+    row64([
+        0x3FDB_CB7B_1526_E50E,
+        0x3FDB_CB7B_1526_E50E,
+        0x3FDB_CB7B_1526_E50E,
+        0x3FDB_CB7B_1526_E50F,
+        0x3FDB_CB7B_1526_E50E,
+        0x3FDB_CB7B_1526_E50F,
+    ]),
+    // log₁₀ 2
+    row64([
+        0x3FD3_4413_509F_79FF,
+        0x3FD3_4413_509F_79FE,
+        0x3FD3_4413_509F_79FE,
+        0x3FD3_4413_509F_79FF,
+        0x3FD3_4413_509F_79FF,
+        0x3FD3_4413_509F_79FF,
+    ]),
+    // ln 2
+    row64([
+        0x3FE6_2E42_FEFA_39EF,
+        0x3FE6_2E42_FEFA_39EF,
+        0x3FE6_2E42_FEFA_39EF,
+        0x3FE6_2E42_FEFA_39F0,
+        0x3FE6_2E42_FEFA_39EF,
+        0x3FE6_2E42_FEFA_39EF,
+    ]),
+    // ln 10
+    row64([
+        0x4002_6BB1_BBB5_5516,
+        0x4002_6BB1_BBB5_5515,
+        0x4002_6BB1_BBB5_5515,
+        0x4002_6BB1_BBB5_5516,
+        0x4002_6BB1_BBB5_5516,
+        0x4002_6BB1_BBB5_5515,
+    ]),
+];
+
+/// `c` correctly rounded to `float32_t`.
+///
+/// # Panics
+///
+/// If `roundingMode` is not a softfloat rounding mode.
+#[must_use]
+pub const fn f32_constant(c: Constant, roundingMode: u8) -> float32_t {
+    F32_CONSTANTS[c as usize][column(roundingMode)]
+}
+
+/// `c` correctly rounded to `float64_t`.
+///
+/// # Panics
+///
+/// If `roundingMode` is not a softfloat rounding mode.
+#[must_use]
+pub const fn f64_constant(c: Constant, roundingMode: u8) -> float64_t {
+    F64_CONSTANTS[c as usize][column(roundingMode)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{f32_acos, f32_asin, f64_acos, f64_asin, f64_rSqrt};
+    use crate::softfloat::{f32_sqrt, f64_sqrt};
+
+    const MODES: [u8; 6] = [0, 1, 2, 3, 4, 6];
+
+    #[test]
+    fn test_constant_nearest() {
+        use core::{f32::consts as c32, f64::consts as c64};
+        let f32s = [
+            c32::PI,
+            c32::TAU,
+            c32::FRAC_PI_2,
+            c32::FRAC_PI_3,
+            c32::FRAC_PI_4,
+            c32::FRAC_PI_6,
+            c32::FRAC_PI_8,
+            c32::FRAC_1_PI,
+            c32::FRAC_2_PI,
+            c32::FRAC_2_SQRT_PI,
+            c32::SQRT_2,
+            c32::FRAC_1_SQRT_2,
+            c32::E,
+            c32::LOG2_E,
+            c32::LOG2_10,
+            c32::LOG10_E,
+            c32::LOG10_2,
+            c32::LN_2,
+            c32::LN_10,
+        ];
+        let f64s = [
+            c64::PI,
+            c64::TAU,
+            c64::FRAC_PI_2,
+            c64::FRAC_PI_3,
+            c64::FRAC_PI_4,
+            c64::FRAC_PI_6,
+            c64::FRAC_PI_8,
+            c64::FRAC_1_PI,
+            c64::FRAC_2_PI,
+            c64::FRAC_2_SQRT_PI,
+            c64::SQRT_2,
+            c64::FRAC_1_SQRT_2,
+            c64::E,
+            c64::LOG2_E,
+            c64::LOG2_10,
+            c64::LOG10_E,
+            c64::LOG10_2,
+            c64::LN_2,
+            c64::LN_10,
+        ];
+        for (i, c) in Constant::ALL.into_iter().enumerate() {
+            assert_eq!((c, f32_constant(c, 0).v), (c, f32s[i].to_bits()));
+            assert_eq!((c, f64_constant(c, 0).v), (c, f64s[i].to_bits()));
+            // Rounded down and up are neighbours, and round to odd picks
+            // the odd one.
+            let (lo, hi) = (f64_constant(c, 2).v, f64_constant(c, 3).v);
+            assert_eq!((c, hi), (c, lo + 1));
+            assert_eq!((c, f64_constant(c, 6).v), (c, lo | 1));
+            let (lo, hi) = (f32_constant(c, 2).v, f32_constant(c, 3).v);
+            assert_eq!((c, hi), (c, lo + 1));
+            assert_eq!((c, f32_constant(c, 6).v), (c, lo | 1));
+        }
+    }
+
+    #[test]
+    fn test_constant_modes() {
+        // Against correctly rounded functions at exact arguments.
+        let (half32, one32, two32) = (
+            float32_t { v: 0x3F00_0000 },
+            float32_t { v: 0x3F80_0000 },
+            float32_t { v: 0x4000_0000 },
+        );
+        let (half64, one64, two64) = (
+            float64_t {
+                v: 0x3FE0_0000_0000_0000,
+            },
+            float64_t {
+                v: 0x3FF0_0000_0000_0000,
+            },
+            float64_t {
+                v: 0x4000_0000_0000_0000,
+            },
+        );
+        for rm in MODES {
+            let neg = |x: float32_t| float32_t { v: x.v | 1 << 31 };
+            assert_eq!(
+                f32_constant(Constant::Pi, rm),
+                f32_acos(neg(one32), rm, 1).0
+            );
+            assert_eq!(
+                f32_constant(Constant::FracPi2, rm),
+                f32_asin(one32, rm, 1).0
+            );
+            assert_eq!(
+                f32_constant(Constant::FracPi3, rm),
+                f32_acos(half32, rm, 1).0
+            );
+            assert_eq!(
+                f32_constant(Constant::FracPi6, rm),
+                f32_asin(half32, rm, 1).0
+            );
+            assert_eq!(f32_constant(Constant::Sqrt2, rm), f32_sqrt(two32, rm, 1).0);
+
+            let neg = |x: float64_t| float64_t { v: x.v | 1 << 63 };
+            assert_eq!(
+                f64_constant(Constant::Pi, rm),
+                f64_acos(neg(one64), rm, 1).0
+            );
+            assert_eq!(
+                f64_constant(Constant::FracPi2, rm),
+                f64_asin(one64, rm, 1).0
+            );
+            assert_eq!(
+                f64_constant(Constant::FracPi3, rm),
+                f64_acos(half64, rm, 1).0
+            );
+            assert_eq!(
+                f64_constant(Constant::FracPi6, rm),
+                f64_asin(half64, rm, 1).0
+            );
+            assert_eq!(f64_constant(Constant::Sqrt2, rm), f64_sqrt(two64, rm, 1).0);
+            assert_eq!(
+                f64_constant(Constant::Frac1Sqrt2, rm),
+                f64_rSqrt(two64, rm, 1).0
+            );
+        }
+    }
+}
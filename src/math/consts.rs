@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Mathematical constants to 2560 bits, as big-endian limbs of `0.d × 2^exp`.
+
+use super::mp::MAX_LIMBS;
+
+/// `π = 0.d × 2^2`.
+pub const PI: [u64; MAX_LIMBS] = [
+    0xC90F_DAA2_2168_C234,
+    0xC4C6_628B_80DC_1CD1,
+    0x2902_4E08_8A67_CC74,
+    0x020B_BEA6_3B13_9B22,
+    0x514A_0879_8E34_04DD,
+    0xEF95_19B3_CD3A_431B,
+    0x302B_0A6D_F25F_1437,
+    0x4FE1_356D_6D51_C245,
+    0xE485_B576_625E_7EC6,
+    0xF44C_42E9_A637_ED6B,
+    0x0BFF_5CB6_F406_B7ED,
+    0xEE38_6BFB_5A89_9FA5,
+    0xAE9F_2411_7C4B_1FE6,
+    0x4928_6651_ECE4_5B3D,
+    0xC200_7CB8_A163_BF05,
+    0x98DA_4836_1C55_D39A,
+    0x6916_3FA8_FD24_CF5F,
+    0x8365_5D23_DCA3_AD96,
+    0x1C62_F356_2085_52BB,
+    0x9ED5_2907_7096_966D,
+    0x670C_354E_4ABC_9804,
+    0xF174_6C08_CA18_217C,
+    0x3290_5E46_2E36_CE3B,
+    0xE39E_772C_180E_8603,
+    0x9B27_83A2_EC07_A28F,
+    0xB5C5_5DF0_6F4C_52C9,
+    0xDE2B_CBF6_9558_1718,
+    0x3995_497C_EA95_6AE5,
+    0x15D2_2618_98FA_0510,
+    0x1572_8E5A_8AAA_C42D,
+    0xAD33_170D_0450_7A33,
+    0xA855_21AB_DF1C_BA64,
+    0xECFB_8504_58DB_EF0A,
+    0x8AEA_7157_5D06_0C7D,
+    0xB397_0F85_A6E1_E4C7,
+    0xABF5_AE8C_DB09_33D7,
+    0x1E8C_94E0_4A25_619D,
+    0xCEE3_D226_1AD2_EE6B,
+    0xF12F_FA06_D98A_0864,
+    0xD876_0273_3EC8_6A64,
+];
+
+/// `ln 2 = 0.d × 2^0`.
+pub const LN2: [u64; MAX_LIMBS] = [
+    0xB172_17F7_D1CF_79AB,
+    0xC9E3_B398_03F2_F6AF,
+    0x40F3_4326_7298_B62D,
+    0x8A0D_175B_8BAA_FA2B,
+    0xE7B8_7620_6DEB_AC98,
+    0x5595_52FB_4AFA_1B10,
+    0xED2E_AE35_C138_2144,
+    0x2757_3B29_1169_B825,
+    0x3E96_CA16_224A_E8C5,
+    0x1ACB_DA11_317C_387E,
+    0xB9EA_9BC3_B136_603B,
+    0x256F_A0EC_7657_F74B,
+    0x72CE_87B1_9D65_48CA,
+    0xF5DF_A6BD_3830_3248,
+    0x655F_A187_2F20_E3A2,
+    0xDA2D_97C5_0F3F_D5C6,
+    0x07F4_CA11_FB5B_FB90,
+    0x610D_30F8_8FE5_51A2,
+    0xEE56_9D6D_FC1E_FA15,
+    0x7D2E_23DE_1400_B396,
+    0x1746_0775_DB89_90E5,
+    0xC943_E732_B479_CD33,
+    0xCCCC_4E65_9393_514C,
+    0x4C1A_1E0B_D1D6_095D,
+    0x2566_9B33_3564_A337,
+    0x6A9C_7F8A_5E14_8E82,
+    0x074D_B601_5CFE_7AA3,
+    0x0C48_0A54_1735_0D2C,
+    0x955D_5179_B1E1_7B9D,
+    0xAE31_3CDB_6C60_6CB1,
+    0x078F_735D_1B2D_B31B,
+    0x5F50_B518_5064_C18B,
+    0x4D16_2DB3_B365_853D,
+    0x7598_A195_1AE2_73EE,
+    0x5570_B6C6_8F96_9834,
+    0x96D4_E6D3_30AF_889B,
+    0x44A0_2554_731C_DC8E,
+    0xA172_93D1_228A_4EF9,
+    0x8D6F_5177_FBCF_0755,
+    0x268A_5C1F_9538_B982,
+];
@@ -0,0 +1,235 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Enclosures of the elementary functions at a given working precision.
+//!
+//! Each kernel takes a [`Ball`] argument and returns a [`Ball`] enclosing the
+//! exact function value over it. The argument reductions and series used
+//! here favour simplicity over speed: they are only run to settle the last
+//! bit of a result.
+
+use super::ball::Ball;
+use super::mp::Mp;
+
+/// Upper bound on the number of terms summed by any series.
+pub const MAX_TERMS: u64 = 4096;
+
+/// `e^x`.
+#[must_use]
+#[allow(clippy::cast_possible_wrap)]
+pub const fn exp(x: &Ball, n: usize) -> Ball {
+    if x.is_indeterminate() {
+        return Ball::INDETERMINATE;
+    }
+    if x.mid.is_zero() && x.is_exact() {
+        return Ball::ONE;
+    }
+    // Far beyond any format's range: saturate to a huge or tiny value.
+    if x.mid.exp > 44 {
+        let e = if x.mid.neg { -(1 << 50) } else { 1 << 50 };
+        return Ball::exact(Mp::from_parts(false, 1, e));
+    }
+    let w = n + 1;
+    // x = k ln2 + r with |r| <= ln2 / 2 (plus rounding noise).
+    let log2e = Mp::from_parts(false, 0xB8AA_3B29_5C17_F0BC, -63);
+    let (t, _) = Mp::mul(&x.mid, &log2e, 1);
+    let k = t.nearest_i64();
+    let r = Ball::sub(x, &Ball::mul_i64(&Ball::ln2(w + 1), k, w + 1), w + 1);
+    let s = if 8 + 4 * n < 48 { 8 + 4 * n } else { 48 } as i64;
+    let r = r.mul_2exp(-s);
+    // Taylor series of e^r, with the tail bounded by the last term.
+    let mut sum = Ball::add(&Ball::ONE, &r, w);
+    let mut term = r;
+    let mut i = 2;
+    while i < MAX_TERMS {
+        term = Ball::div_u64(&Ball::mul(&term, &r, w), i, w);
+        sum = Ball::add(&sum, &term, w);
+        if Ball::negligible(&term, &sum, w) {
+            break;
+        }
+        i += 1;
+    }
+    sum = sum.widen(term.upper());
+    let mut j = 0;
+    while j < s {
+        sum = Ball::sqr(&sum, w);
+        j += 1;
+    }
+    sum.mul_2exp(k)
+}
+
+/// `e^x - 1`, accurate for small `x`.
+#[must_use]
+pub const fn expm1(x: &Ball, n: usize) -> Ball {
+    if x.is_indeterminate() {
+        return Ball::INDETERMINATE;
+    }
+    if x.mid.exp > -2 {
+        return Ball::sub(&exp(x, n + 1), &Ball::ONE, n + 1);
+    }
+    let w = n + 1;
+    let mut sum = *x;
+    let mut term = *x;
+    let mut i = 2;
+    while i < MAX_TERMS {
+        term = Ball::div_u64(&Ball::mul(&term, x, w), i, w);
+        sum = Ball::add(&sum, &term, w);
+        if Ball::negligible(&term, &sum, w) {
+            break;
+        }
+        i += 1;
+    }
+    sum.widen(term.upper())
+}
+
+/// `atanh(z) = z + z³/3 + z⁵/5 + …` for `|z| <= 1/2`.
+#[must_use]
+pub const fn atanh_series(z: &Ball, n: usize) -> Ball {
+    if z.mid.is_zero() && z.is_exact() {
+        return Ball::ZERO;
+    }
+    let z2 = Ball::sqr(z, n);
+    let mut sum = *z;
+    let mut t = *z;
+    let mut k = 1;
+    while k < MAX_TERMS {
+        t = Ball::mul(&t, &z2, n);
+        let term = Ball::div_u64(&t, 2 * k + 1, n);
+        sum = Ball::add(&sum, &term, n);
+        if Ball::negligible(&term, &sum, n) {
+            break;
+        }
+        k += 1;
+    }
+    // The tail is at most |t| z² / (1 - z²) <= |t|.
+    sum.widen(t.upper())
+}
+
+/// `ln x`.
+#[must_use]
+pub const fn log(x: &Ball, n: usize) -> Ball {
+    if !x.is_positive() {
+        return Ball::INDETERMINATE;
+    }
+    let w = n + 1;
+    // x = m 2^e with m in [1/√2, √2).
+    let mut e = x.mid.exp;
+    let mut m = x.mul_2exp(-e);
+    if m.mid.d[0] < 0xB504_F333_F9DE_6484 {
+        m = m.mul_2exp(1);
+        e -= 1;
+    }
+    let z = Ball::div(
+        &Ball::sub(&m, &Ball::ONE, w),
+        &Ball::add(&m, &Ball::ONE, w),
+        w,
+    );
+    let s = atanh_series(&z, w).mul_2exp(1);
+    if e == 0 {
+        return s;
+    }
+    Ball::add(&s, &Ball::mul_i64(&Ball::ln2(w + 1), e, w + 1), w)
+}
+
+/// `ln(1 + x)`, accurate for small `x`.
+#[must_use]
+pub const fn log1p(x: &Ball, n: usize) -> Ball {
+    if x.is_indeterminate() {
+        return Ball::INDETERMINATE;
+    }
+    let w = n + 1;
+    if x.mid.exp > -1 {
+        return log(&Ball::add(&Ball::ONE, x, w + 1), n);
+    }
+    // ln(1 + x) = 2 atanh(x / (2 + x))
+    let two = Ball::from_u64(2);
+    let z = Ball::div(x, &Ball::add(&two, x, w), w);
+    atanh_series(&z, w).mul_2exp(1)
+}
+
+/// `atan x`.
+#[must_use]
+pub const fn atan(x: &Ball, n: usize) -> Ball {
+    if x.is_indeterminate() {
+        return Ball::INDETERMINATE;
+    }
+    if x.mid.is_zero() && x.is_exact() {
+        return Ball::ZERO;
+    }
+    let w = n + 1;
+    if x.mid.exp > 1 {
+        // atan x = ±π/2 - atan(1/x)
+        if x.contains_zero() {
+            return Ball::INDETERMINATE;
+        }
+        let half_pi = Ball::pi(w + 1).mul_2exp(-1);
+        let half_pi = if x.mid.neg { half_pi.neg() } else { half_pi };
+        let inv = Ball::div(&Ball::ONE, x, w);
+        return Ball::sub(&half_pi, &atan(&inv, w), w);
+    }
+    // Halve the angle three times: atan x = 2 atan(x / (1 + √(1 + x²))).
+    let mut y = *x;
+    let mut i = 0;
+    while i < 3 {
+        let h = Ball::sqrt(&Ball::add(&Ball::ONE, &Ball::sqr(&y, w), w), w);
+        y = Ball::div(&y, &Ball::add(&Ball::ONE, &h, w), w);
+        i += 1;
+    }
+    let y2 = Ball::sqr(&y, w);
+    let mut sum = y;
+    let mut t = y;
+    let mut k = 1;
+    while k < MAX_TERMS {
+        t = Ball::mul(&t, &y2, w).neg();
+        let term = Ball::div_u64(&t, 2 * k + 1, w);
+        sum = Ball::add(&sum, &term, w);
+        if Ball::negligible(&term, &sum, w) {
+            break;
+        }
+        k += 1;
+    }
+    // Alternating with decreasing terms: the tail is below |t|.
+    sum.widen(t.upper()).mul_2exp(3)
+}
+
+/// `sinh x` for `|x| <= 1`, by its Taylor series.
+#[must_use]
+pub const fn sinh_series(x: &Ball, n: usize) -> Ball {
+    if x.mid.is_zero() && x.is_exact() {
+        return Ball::ZERO;
+    }
+    let x2 = Ball::sqr(x, n);
+    let mut sum = *x;
+    let mut t = *x;
+    let mut k = 1;
+    while k < MAX_TERMS {
+        t = Ball::div_u64(&Ball::mul(&t, &x2, n), (2 * k) * (2 * k + 1), n);
+        sum = Ball::add(&sum, &t, n);
+        if Ball::negligible(&t, &sum, n) {
+            break;
+        }
+        k += 1;
+    }
+    sum.widen(t.upper())
+}
+
+/// `sin x` for `|x| <= 2`, by its Taylor series.
+#[must_use]
+pub const fn sin_series(x: &Ball, n: usize) -> Ball {
+    if x.mid.is_zero() && x.is_exact() {
+        return Ball::ZERO;
+    }
+    let x2 = Ball::sqr(x, n);
+    let mut sum = *x;
+    let mut t = *x;
+    let mut k = 1;
+    while k < MAX_TERMS {
+        t = Ball::div_u64(&Ball::mul(&t, &x2, n), (2 * k) * (2 * k + 1), n).neg();
+        sum = Ball::add(&sum, &t, n);
+        if Ball::negligible(&t, &sum, n) {
+            break;
+        }
+        k += 1;
+    }
+    // Alternating with decreasing terms: the tail is below |t|.
+    sum.widen(t.upper())
+}
@@ -0,0 +1,501 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! The error function and the complementary error function.
+
+use super::ball::Ball;
+use super::elementary::{exp, MAX_TERMS};
+use super::format::Format;
+use super::mp::{Mp, MAX_LIMBS};
+use super::ziv::{never, round_nudged, ziv};
+
+/// `|x|` from which `erf x` is within half an ulp of `±1`, and `erfc x` of
+/// 0 or 2.
+const fn saturation(fmt: Format) -> u64 {
+    match fmt {
+        Format::F32 => 4,
+        Format::F64 => 6,
+    }
+}
+
+/// `x` from which `erfc x` is below half the smallest subnormal.
+const fn underflow(fmt: Format) -> u64 {
+    match fmt {
+        Format::F32 => 11,
+        Format::F64 => 28,
+    }
+}
+
+/// `erf x` for `|x| < 8`, as `2x/√π e^{-x²} Σ (2x²)^k / (1·3·…·(2k+1))`.
+///
+/// The terms are all positive, so no precision is lost to cancellation.
+const fn erf_series(x: &Mp, n: usize) -> Ball {
+    let w = n + 1;
+    let (x2, _) = Mp::mul(x, x, MAX_LIMBS);
+    let r = Ball::exact(x2.mul_2exp(1));
+    // Once 2k + 3 >= 4x² every further term is at most half the last.
+    let k_min = if x2.exp <= 0 { 0 } else { 1 << (x2.exp + 1) };
+    let mut sum = Ball::exact(*x);
+    let mut t = sum;
+    let mut k = 1;
+    while k < MAX_TERMS {
+        t = Ball::div_u64(&Ball::mul(&t, &r, w), 2 * k + 1, w);
+        sum = Ball::add(&sum, &t, w);
+        if k >= k_min && Ball::negligible(&t, &sum, w) {
+            break;
+        }
+        k += 1;
+    }
+    let sum = sum.widen(t.upper());
+    let e = exp(&Ball::exact(x2.neg()), w);
+    let s = Ball::div(&Ball::mul(&sum, &e, w), &Ball::sqrt(&Ball::pi(w), w), w);
+    s.mul_2exp(1)
+}
+
+/// `erfc x` for `x >= 4`, from Laplace's continued fraction
+/// `e^{-x²}/√π · 1/(x + (1/2)/(x + (2/2)/(x + (3/2)/(x + …))))`.
+///
+/// Successive convergents lie on either side of the limit, so two close
+/// convergents enclose it.
+const fn erfc_fraction(x: &Mp, n: usize) -> Ball {
+    let w = n + 1;
+    let xb = Ball::exact(*x);
+    // A_{j-2}, A_{j-1}, B_{j-2}, B_{j-1}, from A_0 = 0, A_1 = 1, B_0 = 1, B_1 = x.
+    let mut a0 = Ball::ZERO;
+    let mut a1 = Ball::ONE;
+    let mut b0 = Ball::ONE;
+    let mut b1 = xb;
+    let mut prev = Ball::div(&Ball::ONE, &xb, w);
+    let mut f = Ball::INDETERMINATE;
+    let mut j = 1;
+    while j < 2 * MAX_TERMS {
+        let a = Ball::add(
+            &Ball::mul(&xb, &a1, w),
+            &Ball::mul_u64(&a0, j, w).mul_2exp(-1),
+            w,
+        );
+        let b = Ball::add(
+            &Ball::mul(&xb, &b1, w),
+            &Ball::mul_u64(&b0, j, w).mul_2exp(-1),
+            w,
+        );
+        (a0, a1, b0, b1) = (a1, a, b1, b);
+        f = Ball::div(&a1, &b1, w);
+        let d = Ball::sub(&f, &prev, w);
+        if Ball::negligible(&d, &f, w) {
+            break;
+        }
+        prev = f;
+        j += 1;
+    }
+    let k = Ball::join(&f, &prev, w);
+    let (x2, _) = Mp::mul(x, x, MAX_LIMBS);
+    let e = exp(&Ball::exact(x2.neg()), w);
+    Ball::div(&Ball::mul(&k, &e, w), &Ball::sqrt(&Ball::pi(w), w), w)
+}
+
+const fn erf(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) {
+        return (a, 0);
+    }
+    if fmt.is_inf(a) {
+        return (fmt.one(fmt.sign(a)), 0);
+    }
+    let x = fmt.to_mp(a);
+    if Mp::cmp_abs(&x, &Mp::from_u64(saturation(fmt))) >= 0 {
+        let one = if x.neg { Mp::ONE.neg() } else { Mp::ONE };
+        return round_nudged(&one, false, fmt, rnd, tin);
+    }
+    ziv!(fmt, rnd, tin, |n| erf_series(&x, n), |c| never(&c))
+}
+
+#[allow(clippy::cast_possible_wrap)]
+const fn erfc(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    let two = Mp::from_u64(2);
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) {
+        return (fmt.one(false), 0);
+    }
+    if fmt.is_inf(a) {
+        return if fmt.sign(a) {
+            fmt.round(&two, 0, rnd, tin)
+        } else {
+            (fmt.zero(false), 0)
+        };
+    }
+    let x = fmt.to_mp(a);
+    // erfc x = 1 - 2x/√π + O(x³)
+    if x.exp <= -(fmt.precision() as i64 + 2) {
+        return round_nudged(&Mp::ONE, x.neg, fmt, rnd, tin);
+    }
+    if x.neg {
+        if Mp::cmp_abs(&x, &Mp::from_u64(saturation(fmt))) >= 0 {
+            return round_nudged(&two, false, fmt, rnd, tin);
+        }
+    } else if Mp::cmp(&x, &Mp::from_u64(underflow(fmt))) >= 0 {
+        return fmt.round(&Mp::ZERO, 1, rnd, tin);
+    } else if Mp::cmp(&x, &Mp::from_u64(4)) >= 0 {
+        return ziv!(fmt, rnd, tin, |n| erfc_fraction(&x, n), |c| never(&c));
+    }
+    // Below 4, erfc x >= 2^-27 and 1 - erf x loses at most one limb.
+    ziv!(
+        fmt,
+        rnd,
+        tin,
+        |n| Ball::sub(&Ball::ONE, &erf_series(&x, n + 1), n + 1),
+        |c| never(&c)
+    )
+}
+
+unary!(
+    /// Error function, `2/√π ∫₀ˣ e^{-t²} dt`.
+    f32_erf,
+    f64_erf,
+    erf
+);
+
+unary!(
+    /// Complementary error function, `1 - erf a`, accurate where `erf a`
+    /// is close to 1.
+    f32_erfc,
+    f64_erfc,
+    erfc
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::softfloat::{float32_t, float64_t};
+
+    #[test]
+    fn test_f64_erf() {
+        struct TestCase {
+            f: fn(float64_t, u8, u8) -> (float64_t, u8),
+            a: u64,
+            result: u64,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f64_erf,
+                a: 0x3FD0_0000_0000_0000,
+                result: 0x3FD1_AF54_E232_D609,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_erf,
+                a: 0xBFF8_0000_0000_0000,
+                result: 0xBFEE_EA55_5713_7AE0,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_erf,
+                a: 0x4009_9999_9999_999A,
+                result: 0x3FEF_FFF3_5CF1_B184,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_erf,
+                a: 0x39B4_484B_FEEB_C2A0,
+                result: 0x39B6_E2E1_2DC3_773D,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_erf,
+                a: 0xC01C_0000_0000_0000,
+                result: 0xBFEF_FFFF_FFFF_FFFF,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_erf,
+                a: 0x8000_0000_0000_0000,
+                result: 0x8000_0000_0000_0000,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_erf,
+                a: 0xFFF0_0000_0000_0000,
+                result: 0xBFF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_erf,
+                a: 0x7FF0_0000_0000_0001,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_erfc,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0x3FDE_B021_47CE_245B,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_erfc,
+                a: 0xC006_0000_0000_0000,
+                result: 0x3FFF_FF96_7D82_29ED,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_erfc,
+                a: 0x4012_0000_0000_0000,
+                result: 0x3DEB_05CF_E2E9_9435,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_erfc,
+                a: 0x4022_0000_0000_0000,
+                result: 0x3861_98D4_22BE_3F8D,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_erfc,
+                a: 0x403A_CCCC_CCCC_CCCD,
+                result: 0x0000_0001_2BC9_E6C8,
+                flags: 3,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_erfc,
+                a: 0x403E_0000_0000_0000,
+                result: 0x0000_0000_0000_0000,
+                flags: 3,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_erfc,
+                a: 0xBBC7_9CA1_0C92_4223,
+                result: 0x3FF0_0000_0000_0000,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_erfc,
+                a: 0x0000_0000_0000_0000,
+                result: 0x3FF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_erfc,
+                a: 0xFFF0_0000_0000_0000,
+                result: 0x4000_0000_0000_0000,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_erfc,
+                a: 0x7FF0_0000_0000_0000,
+                result: 0x0000_0000_0000_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(float64_t { v: c.a }, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f32_erf() {
+        struct TestCase {
+            f: fn(float32_t, u8, u8) -> (float32_t, u8),
+            a: u32,
+            result: u32,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f32_erf,
+                a: 0x3E80_0000,
+                result: 0x3E8D_7AA7,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_erf,
+                a: 0xBFC0_0000,
+                result: 0xBF77_52AB,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_erf,
+                a: 0x404C_CCCD,
+                result: 0x3F7F_FF9A,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_erf,
+                a: 0x0DA2_4260,
+                result: 0x0DB7_1709,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_erf,
+                a: 0xC0E0_0000,
+                result: 0xBF80_0000,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_erf,
+                a: 0x8000_0000,
+                result: 0x8000_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_erf,
+                a: 0xFF80_0000,
+                result: 0xBF80_0000,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_erf,
+                a: 0x7F80_0001,
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_erfc,
+                a: 0x3F00_0000,
+                result: 0x3EF5_810A,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_erfc,
+                a: 0xC030_0000,
+                result: 0x3FFF_FCB3,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_erfc,
+                a: 0x4090_0000,
+                result: 0x2F58_2E7F,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_erfc,
+                a: 0x4110_0000,
+                result: 0x030C_C6A1,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_erfc,
+                a: 0x41D6_6666,
+                result: 0x0000_0000,
+                flags: 3,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_erfc,
+                a: 0x41F0_0000,
+                result: 0x0000_0000,
+                flags: 3,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_erfc,
+                a: 0x9E3C_E508,
+                result: 0x3F80_0000,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_erfc,
+                a: 0x0000_0000,
+                result: 0x3F80_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_erfc,
+                a: 0xFF80_0000,
+                result: 0x4000_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_erfc,
+                a: 0x7F80_0000,
+                result: 0x0000_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(float32_t { v: c.a }, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+}
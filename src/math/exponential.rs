@@ -0,0 +1,745 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Exponentials and logarithms.
+
+use super::ball::Ball;
+use super::elementary::{exp, expm1, log, log1p};
+use super::format::Format;
+use super::mp::Mp;
+use super::ziv::{never, round_exact, round_exp_near_one, round_nudged, ziv};
+
+/// `k` such that `|x| < 2^k` makes `x` itself (suitably nudged) the
+/// correctly rounded value of `x (1 + c x)` for `|c| <= 1`.
+#[allow(clippy::cast_possible_wrap)]
+const fn first_order_exp(fmt: Format) -> i64 {
+    -(fmt.precision() as i64 + 1)
+}
+
+const fn exp_fn(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) {
+        return (fmt.one(false), 0);
+    }
+    if fmt.is_inf(a) {
+        return if fmt.sign(a) {
+            (fmt.zero(false), 0)
+        } else {
+            (a, 0)
+        };
+    }
+    let x = fmt.to_mp(a);
+    if let Some(r) = round_exp_near_one(&Ball::exact(x), false, fmt, rnd, tin) {
+        return r;
+    }
+    ziv!(fmt, rnd, tin, |n| exp(&Ball::exact(x), n), |c| never(&c))
+}
+
+const fn exp2_ball(x: &Mp, n: usize) -> Ball {
+    let w = n + 1;
+    exp(&Ball::mul(&Ball::exact(*x), &Ball::ln2(w + 1), w + 1), w)
+}
+
+const fn exp2(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) {
+        return (fmt.one(false), 0);
+    }
+    if fmt.is_inf(a) {
+        return if fmt.sign(a) {
+            (fmt.zero(false), 0)
+        } else {
+            (a, 0)
+        };
+    }
+    let x = fmt.to_mp(a);
+    // Integral powers are exact, or overflow or underflow.
+    if x.is_integer() {
+        return round_exact(&Mp::from_parts(false, 1, x.nearest_i64()), fmt, rnd, tin);
+    }
+    // |x ln 2| < |x|, so x bounds the exponent.
+    if let Some(r) = round_exp_near_one(&Ball::exact(x), false, fmt, rnd, tin) {
+        return r;
+    }
+    ziv!(fmt, rnd, tin, |n| exp2_ball(&x, n), |c| never(&c))
+}
+
+const fn expm1_fn(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) {
+        return (a, 0);
+    }
+    if fmt.is_inf(a) {
+        return if fmt.sign(a) {
+            (fmt.one(true), 0)
+        } else {
+            (a, 0)
+        };
+    }
+    let x = fmt.to_mp(a);
+    if x.exp <= first_order_exp(fmt) {
+        return round_nudged(&x, !x.neg, fmt, rnd, tin);
+    }
+    // Beyond this, e^x is below a quarter ulp of 1.
+    let limit = match fmt {
+        Format::F32 => 20,
+        Format::F64 => 40,
+    };
+    if x.neg && Mp::cmp_abs(&x, &Mp::from_u64(limit)) >= 0 {
+        return round_nudged(&Mp::ONE.neg(), false, fmt, rnd, tin);
+    }
+    ziv!(fmt, rnd, tin, |n| expm1(&Ball::exact(x), n), |c| never(&c))
+}
+
+/// Specials shared by the logarithms, or `None` for positive finite `a`
+/// other than 1.
+const fn log_special(fmt: Format, a: u64) -> Option<(u64, u8)> {
+    if fmt.is_nan(a) {
+        return Some(fmt.propagate_nan(a, 0));
+    }
+    if fmt.is_zero(a) {
+        return Some(fmt.pole(true));
+    }
+    if fmt.sign(a) {
+        return Some(fmt.invalid());
+    }
+    if fmt.is_inf(a) {
+        return Some((a, 0));
+    }
+    if a == fmt.one(false) {
+        return Some((fmt.zero(false), 0));
+    }
+    None
+}
+
+const fn log_fn(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if let Some(r) = log_special(fmt, a) {
+        return r;
+    }
+    let x = fmt.to_mp(a);
+    ziv!(fmt, rnd, tin, |n| log(&Ball::exact(x), n), |c| never(&c))
+}
+
+const fn log2_ball(x: &Mp, n: usize) -> Ball {
+    let w = n + 1;
+    Ball::div(&log(&Ball::exact(*x), w), &Ball::ln2(w + 1), w)
+}
+
+/// Whether the integer `c` is the exponent of `x = base^c`.
+#[allow(clippy::cast_sign_loss)]
+const fn is_log(c: &Mp, base: u64, x: &Mp) -> bool {
+    if !c.is_integer() || c.exp > 12 {
+        return false;
+    }
+    let k = c.nearest_i64();
+    if base == 2 {
+        return Mp::cmp(x, &Mp::from_parts(false, 1, k)) == 0;
+    }
+    if k <= 0 {
+        return false;
+    }
+    match Mp::pow_u64(&Mp::from_u64(base), k as u64) {
+        Some(p) => Mp::cmp(x, &p) == 0,
+        None => false,
+    }
+}
+
+const fn log2(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if let Some(r) = log_special(fmt, a) {
+        return r;
+    }
+    let x = fmt.to_mp(a);
+    ziv!(fmt, rnd, tin, |n| log2_ball(&x, n), |c| is_log(&c, 2, &x))
+}
+
+const fn log10_ball(x: &Mp, n: usize) -> Ball {
+    let w = n + 1;
+    let ln10 = log(&Ball::from_u64(10), w + 1);
+    Ball::div(&log(&Ball::exact(*x), w), &ln10, w)
+}
+
+const fn log10(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if let Some(r) = log_special(fmt, a) {
+        return r;
+    }
+    let x = fmt.to_mp(a);
+    ziv!(fmt, rnd, tin, |n| log10_ball(&x, n), |c| is_log(&c, 10, &x))
+}
+
+const fn log1p_fn(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) {
+        return (a, 0);
+    }
+    if fmt.is_inf(a) {
+        return if fmt.sign(a) { fmt.invalid() } else { (a, 0) };
+    }
+    let x = fmt.to_mp(a);
+    match Mp::cmp(&x, &Mp::ONE.neg()) {
+        -1 => return fmt.invalid(),
+        0 => return fmt.pole(true),
+        _ => {}
+    }
+    if x.exp <= first_order_exp(fmt) {
+        return round_nudged(&x, x.neg, fmt, rnd, tin);
+    }
+    ziv!(fmt, rnd, tin, |n| log1p(&Ball::exact(x), n), |c| never(&c))
+}
+
+unary!(
+    /// Exponential `e^a`.
+    f32_exp,
+    f64_exp,
+    exp_fn
+);
+
+unary!(
+    /// Base-2 exponential `2^a`.
+    f32_exp2,
+    f64_exp2,
+    exp2
+);
+
+unary!(
+    /// `e^a - 1`, accurate near zero.
+    f32_expm1,
+    f64_expm1,
+    expm1_fn
+);
+
+unary!(
+    /// Natural logarithm, defined for `a >= 0`.
+    f32_log,
+    f64_log,
+    log_fn
+);
+
+unary!(
+    /// Base-2 logarithm, defined for `a >= 0`.
+    f32_log2,
+    f64_log2,
+    log2
+);
+
+unary!(
+    /// Base-10 logarithm, defined for `a >= 0`.
+    f32_log10,
+    f64_log10,
+    log10
+);
+
+unary!(
+    /// `ln(1 + a)`, accurate near zero and defined for `a >= -1`.
+    f32_log1p,
+    f64_log1p,
+    log1p_fn
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::softfloat::{float32_t, float64_t};
+
+    #[test]
+    fn test_f64_exponential() {
+        struct TestCase {
+            f: fn(float64_t, u8, u8) -> (float64_t, u8),
+            a: u64,
+            result: u64,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f64_exp,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0x3FFA_6129_8E1E_069B,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_exp,
+                a: 0xC00A_0000_0000_0000,
+                result: 0x3FA3_DA36_8521_902E,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_exp,
+                a: 0x4086_2E00_0000_0000,
+                result: 0x7FEE_F85A_11E7_3F2D,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_exp,
+                a: 0xC087_4800_0000_0000,
+                result: 0x0000_0000_0000_0000,
+                flags: 3,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_exp,
+                a: 0x7FF0_0000_0000_0000,
+                result: 0x7FF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_exp2,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0x3FF6_A09E_667F_3BCD,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_exp2,
+                a: 0xC00A_0000_0000_0000,
+                result: 0x3FBA_E89F_995A_D3AD,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_exp2,
+                a: 0xC090_C800_0000_0000,
+                result: 0x0000_0000_0000_0001,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_exp2,
+                a: 0x4090_0000_0000_0000,
+                result: 0x7FF0_0000_0000_0000,
+                flags: 5,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_expm1,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0x3FE4_C253_1C3C_0D37,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_expm1,
+                a: 0xC00A_0000_0000_0000,
+                result: 0xBFEE_C25C_97AD_E6FD,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_expm1,
+                a: 0x3BC7_9CA1_0C92_4223,
+                result: 0x3BC7_9CA1_0C92_4223,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_expm1,
+                a: 0xC049_0000_0000_0000,
+                result: 0xBFEF_FFFF_FFFF_FFFF,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_log,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0xBFE6_2E42_FEFA_39EF,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_log,
+                a: 0x400A_0000_0000_0000,
+                result: 0x3FF2_DBC5_5768_DEB2,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_log,
+                a: 0x7E37_E43C_8800_759C,
+                result: 0x4085_9634_47F8_7FB5,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_log,
+                a: 0x8000_0000_0000_0000,
+                result: 0xFFF0_0000_0000_0000,
+                flags: 8,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_log2,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0xBFF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_log2,
+                a: 0x400A_0000_0000_0000,
+                result: 0x3FFB_3500_4723_C466,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_log2,
+                a: 0x0000_0000_0000_0001,
+                result: 0xC090_C800_0000_0000,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_log2,
+                a: 0xBFF0_0000_0000_0000,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_log10,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0xBFD3_4413_509F_79FF,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_log10,
+                a: 0x400A_0000_0000_0000,
+                result: 0x3FE0_6159_36D8_AA9F,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_log10,
+                a: 0x4480_F0CF_064D_D592,
+                result: 0x4036_0000_0000_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_log10,
+                a: 0x01A5_6E1F_C2F8_F359,
+                result: 0xC072_BFFF_FFFF_FFFF,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_log1p,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0x3FD9_F323_ECBF_984B,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_log1p,
+                a: 0xBFE8_0000_0000_0000,
+                result: 0xBFF6_2E42_FEFA_39EF,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_log1p,
+                a: 0x3BC7_9CA1_0C92_4223,
+                result: 0x3BC7_9CA1_0C92_4222,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_log1p,
+                a: 0xBFF0_0000_0000_0000,
+                result: 0xFFF0_0000_0000_0000,
+                flags: 8,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(float64_t { v: c.a }, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f32_exponential() {
+        struct TestCase {
+            f: fn(float32_t, u8, u8) -> (float32_t, u8),
+            a: u32,
+            result: u32,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f32_exp,
+                a: 0x3F00_0000,
+                result: 0x3FD3_094C,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_exp,
+                a: 0xC050_0000,
+                result: 0x3D1E_D1B4,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_exp,
+                a: 0x42B1_8000,
+                result: 0x7F80_0000,
+                flags: 5,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_exp,
+                a: 0xC2D0_0000,
+                result: 0x0000_0000,
+                flags: 3,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_exp2,
+                a: 0x3F00_0000,
+                result: 0x3FB5_04F4,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_exp2,
+                a: 0xC050_0000,
+                result: 0x3DD7_44FD,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_exp2,
+                a: 0xC315_0000,
+                result: 0x0000_0001,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_exp2,
+                a: 0x4300_0000,
+                result: 0x7F7F_FFFF,
+                flags: 5,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_expm1,
+                a: 0x3F00_0000,
+                result: 0x3F26_1299,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_expm1,
+                a: 0xC050_0000,
+                result: 0xBF76_12E5,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_expm1,
+                a: 0x2EDB_E6FF,
+                result: 0x2EDB_E700,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_expm1,
+                a: 0xC1C8_0000,
+                result: 0xBF80_0000,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_log,
+                a: 0x3F00_0000,
+                result: 0xBF31_7218,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_log,
+                a: 0x4050_0000,
+                result: 0x3F96_DE2A,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_log,
+                a: 0x7149_F2CA,
+                result: 0x428A_27B5,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_log,
+                a: 0xFF80_0000,
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_log2,
+                a: 0x3F00_0000,
+                result: 0xBF80_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_log2,
+                a: 0x4050_0000,
+                result: 0x3FD9_A802,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_log2,
+                a: 0x0000_0001,
+                result: 0xC315_0000,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_log2,
+                a: 0xBF80_0000,
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_log10,
+                a: 0x3F00_0000,
+                result: 0xBE9A_209B,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_log10,
+                a: 0x4050_0000,
+                result: 0x3F03_0ACA,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_log10,
+                a: 0x5015_02F9,
+                result: 0x4120_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_log10,
+                a: 0x0DA2_4260,
+                result: 0xC1EF_FFFF,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_log1p,
+                a: 0x3F00_0000,
+                result: 0x3ECF_991F,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_log1p,
+                a: 0xBF40_0000,
+                result: 0xBFB1_7218,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_log1p,
+                a: 0x2EDB_E6FF,
+                result: 0x2EDB_E6FF,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_log1p,
+                a: 0xBF80_0000,
+                result: 0xFF80_0000,
+                flags: 8,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(float32_t { v: c.a }, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+}
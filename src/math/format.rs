@@ -0,0 +1,275 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Per-format glue between raw bit patterns and [`Mp`] values.
+//!
+//! The functions in this module work on bit patterns widened to `u64` so that
+//! each algorithm is written once for both binary32 and binary64.
+
+use super::mp::Mp;
+use crate::softfloat::{
+    defaultNaNF32UI, defaultNaNF64UI, softfloat_flag_infinite, softfloat_flag_invalid,
+    softfloat_isSigNaNF32UI, softfloat_isSigNaNF64UI, softfloat_normRoundPackToF32,
+    softfloat_normRoundPackToF64, softfloat_propagateNaNF32UI, softfloat_propagateNaNF64UI,
+    softfloat_round_minMag,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Format {
+    F32,
+    F64,
+}
+
+impl Format {
+    /// Significand precision in bits, including the hidden bit.
+    #[must_use]
+    pub const fn precision(self) -> u64 {
+        match self {
+            Self::F32 => 24,
+            Self::F64 => 53,
+        }
+    }
+
+    /// Exponent of the smallest subnormal.
+    #[must_use]
+    pub const fn min_exp(self) -> i64 {
+        match self {
+            Self::F32 => -149,
+            Self::F64 => -1074,
+        }
+    }
+
+    /// Exponent `e` such that every finite value is below `2^e`.
+    #[must_use]
+    pub const fn max_exp(self) -> i64 {
+        match self {
+            Self::F32 => 128,
+            Self::F64 => 1024,
+        }
+    }
+
+    /// `k` such that `|x| < 2^k` makes `x` itself (suitably nudged) the
+    /// correctly rounded value of `x (1 + c x²)` for `|c| <= 1`.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn tiny_exp(self) -> i64 {
+        -((self.precision() as i64 + 3) >> 1)
+    }
+
+    const fn sign_bit(self) -> u64 {
+        match self {
+            Self::F32 => 1 << 31,
+            Self::F64 => 1 << 63,
+        }
+    }
+
+    const fn exp_mask(self) -> u64 {
+        match self {
+            Self::F32 => 0x7F80_0000,
+            Self::F64 => 0x7FF0_0000_0000_0000,
+        }
+    }
+
+    const fn frac_mask(self) -> u64 {
+        match self {
+            Self::F32 => 0x007F_FFFF,
+            Self::F64 => 0x000F_FFFF_FFFF_FFFF,
+        }
+    }
+
+    /// The most significant fraction bit, which tells quiet NaNs from
+    /// signaling ones.
+    #[must_use]
+    pub const fn quiet_bit(self) -> u64 {
+        (self.frac_mask() + 1) >> 1
+    }
+
+    /// The largest biased exponent, that of infinities and NaNs.
+    #[must_use]
+    pub const fn max_biased_exp(self) -> u64 {
+        self.exp_mask() >> (self.precision() - 1)
+    }
+
+    #[must_use]
+    pub const fn sign(self, a: u64) -> bool {
+        (a & self.sign_bit()) != 0
+    }
+
+    #[must_use]
+    pub const fn abs(self, a: u64) -> u64 {
+        a & !self.sign_bit()
+    }
+
+    #[must_use]
+    pub const fn negate(self, a: u64) -> u64 {
+        a ^ self.sign_bit()
+    }
+
+    #[must_use]
+    pub const fn is_nan(self, a: u64) -> bool {
+        (a & self.exp_mask()) == self.exp_mask() && (a & self.frac_mask()) != 0
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn is_signaling_nan(self, a: u64) -> bool {
+        match self {
+            Self::F32 => softfloat_isSigNaNF32UI(a as u32),
+            Self::F64 => softfloat_isSigNaNF64UI(a),
+        }
+    }
+
+    #[must_use]
+    pub const fn is_inf(self, a: u64) -> bool {
+        self.abs(a) == self.exp_mask()
+    }
+
+    #[must_use]
+    pub const fn is_zero(self, a: u64) -> bool {
+        self.abs(a) == 0
+    }
+
+    #[must_use]
+    pub const fn inf(self, sign: bool) -> u64 {
+        self.exp_mask() | if sign { self.sign_bit() } else { 0 }
+    }
+
+    #[must_use]
+    pub const fn zero(self, sign: bool) -> u64 {
+        if sign {
+            self.sign_bit()
+        } else {
+            0
+        }
+    }
+
+    #[must_use]
+    pub const fn one(self, sign: bool) -> u64 {
+        let one = match self {
+            Self::F32 => 0x3F80_0000,
+            Self::F64 => 0x3FF0_0000_0000_0000,
+        };
+        one | self.zero(sign)
+    }
+
+    #[must_use]
+    pub const fn default_nan(self) -> u64 {
+        match self {
+            Self::F32 => defaultNaNF32UI as u64,
+            Self::F64 => defaultNaNF64UI,
+        }
+    }
+
+    /// Result of an operation with at least one NaN operand.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn propagate_nan(self, a: u64, b: u64) -> (u64, u8) {
+        match self {
+            Self::F32 => {
+                let (v, flags) = softfloat_propagateNaNF32UI(a as u32, b as u32);
+                (v as u64, flags)
+            }
+            Self::F64 => softfloat_propagateNaNF64UI(a, b),
+        }
+    }
+
+    /// Default NaN with the invalid exception, for domain errors.
+    #[must_use]
+    pub const fn invalid(self) -> (u64, u8) {
+        (self.default_nan(), softfloat_flag_invalid)
+    }
+
+    /// Exact infinity from a finite operand, for poles.
+    #[must_use]
+    pub const fn pole(self, sign: bool) -> (u64, u8) {
+        (self.inf(sign), softfloat_flag_infinite)
+    }
+
+    /// Exact value of a finite bit pattern.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn to_mp(self, a: u64) -> Mp {
+        match self {
+            Self::F32 => Mp::from_f32(a as u32),
+            Self::F64 => Mp::from_f64(a),
+        }
+    }
+
+    /// Rounds `x` to this format.
+    ///
+    /// With `dir` non-zero, `x` stands for a value infinitesimally above
+    /// (`dir > 0`) or below (`dir < 0`) it, which is how the endpoints of an
+    /// enclosure and results known only to be strictly between two values
+    /// are rounded with the correct inexact flag.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn round(self, x: &Mp, dir: i32, roundingMode: u8, detectTininess: u8) -> (u64, u8) {
+        if x.is_zero() {
+            if dir == 0 {
+                return (0, 0);
+            }
+            // Far below the smallest subnormal, with the sign of `dir`.
+            return self.round(
+                &Mp::from_parts(dir < 0, 1, self.min_exp() - 64),
+                0,
+                roundingMode,
+                detectTininess,
+            );
+        }
+        let away = if dir == 0 {
+            0
+        } else if (dir > 0) != x.neg {
+            1
+        } else {
+            -1
+        };
+        let exp = if x.exp < -4000 {
+            -4000
+        } else if x.exp > 4000 {
+            4000
+        } else {
+            x.exp
+        };
+        match self {
+            Self::F32 => {
+                let mut sig = ((x.d[0] >> 33) as u32) & !1;
+                let rest = (x.d[0] & ((1 << 34) - 1)) != 0 || x.len() > 1;
+                if away < 0 && !rest {
+                    sig -= 1;
+                } else if rest || away != 0 {
+                    sig |= 1;
+                }
+                let (z, flags) = softfloat_normRoundPackToF32(
+                    x.neg,
+                    (exp + 0x7D) as i16,
+                    sig,
+                    roundingMode,
+                    detectTininess,
+                );
+                (z.v as u64, flags)
+            }
+            Self::F64 => {
+                let mut sig = (x.d[0] >> 1) & !1;
+                let rest = (x.d[0] & 3) != 0 || x.len() > 1;
+                if away < 0 && !rest {
+                    sig -= 1;
+                } else if rest || away != 0 {
+                    sig |= 1;
+                }
+                let (z, flags) = softfloat_normRoundPackToF64(
+                    x.neg,
+                    (exp + 0x3FD) as i16,
+                    sig,
+                    roundingMode,
+                    detectTininess,
+                );
+                (z.v, flags)
+            }
+        }
+    }
+
+    /// Rounds `x` towards zero, as a probe for representable values.
+    #[must_use]
+    pub const fn round_trunc(self, x: &Mp, dir: i32) -> u64 {
+        self.round(x, dir, softfloat_round_minMag, 0).0
+    }
+}
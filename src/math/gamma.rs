@@ -0,0 +1,721 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! The gamma function and the logarithm of its absolute value.
+//!
+//! Both are evaluated with Spouge's approximation
+//! `Γ(z + 1) = (z + a)^{z + 1/2} e^{-(z + a)} (c₀ + Σ_{k=1}^{a-1} c_k / (z + k) + ε)`
+//! for `z >= 0`, whose relative error `ε` is below
+//! `a^{-1/2} (2π)^{-(a + 1/2)}`, with the reflection formula
+//! `Γ(x) Γ(1 - x) = π / sin(πx)` for negative arguments.
+
+use super::ball::{Ball, Mag};
+use super::elementary::{exp, log, sin_series};
+use super::format::Format;
+use super::mp::{Mp, MAX_LIMBS};
+use super::ziv::{never, ziv};
+
+/// `x` from which `Γ(x)` overflows.
+const fn overflow(fmt: Format) -> u64 {
+    match fmt {
+        Format::F32 => 36,
+        Format::F64 => 172,
+    }
+}
+
+/// `-x` from which `|Γ(x)|` is below half the smallest subnormal for every
+/// non-integer `x`, however close to a pole.
+const fn underflow(fmt: Format) -> u64 {
+    match fmt {
+        Format::F32 => 50,
+        Format::F64 => 190,
+    }
+}
+
+/// Spouge's `a` for a relative error below `2^-(64n + 16)`.
+const fn spouge_a(n: usize) -> u64 {
+    (64 * n as u64 + 16) * 100 / 265 + 1
+}
+
+/// Working precision for [`spouge`]: the alternating sum of its
+/// coefficients cancels about `1.84 a` bits.
+#[allow(clippy::cast_possible_truncation)]
+const fn spouge_limbs(n: usize, a: u64) -> usize {
+    n + 2 + (a as usize * 185 / 100) / 64
+}
+
+/// `b^k` for an integer `k >= 0`.
+const fn pow_ball(b: &Ball, k: u64, w: usize) -> Ball {
+    let mut r = Ball::ONE;
+    let mut base = *b;
+    let mut k = k;
+    while k != 0 {
+        if (k & 1) != 0 {
+            r = Ball::mul(&r, &base, w);
+        }
+        k >>= 1;
+        if k != 0 {
+            base = Ball::sqr(&base, w);
+        }
+    }
+    r
+}
+
+/// `Γ(z + 1)` for `z >= 0`, as `(e^t, t, S)` where `t` is
+/// `(z + 1/2) ln(z + a) - (z + a)` and `S` the bracketed sum.
+#[allow(clippy::cast_possible_wrap)]
+const fn spouge(z: &Mp, n: usize) -> (Ball, Ball) {
+    let a = spouge_a(n);
+    let w = spouge_limbs(n, a);
+    let e = exp(&Ball::ONE, w);
+    // e^{a-k}, from k = 1 upwards.
+    let mut ek = exp(&Ball::from_u64(a - 1), w);
+    // (k - 1)!
+    let mut fact = Ball::ONE;
+    let mut sum = Ball::ZERO;
+    let mut k = 1;
+    while k < a {
+        let base = Ball::from_u64(a - k);
+        // c_k = (-1)^{k-1} (a - k)^{k-1/2} e^{a-k} / (k - 1)!
+        let c = Ball::mul(
+            &Ball::mul(&pow_ball(&base, k - 1, w), &Ball::sqrt(&base, w), w),
+            &Ball::div(&ek, &fact, w),
+            w,
+        );
+        let (zk, _) = Mp::add(z, &Mp::from_u64(k), MAX_LIMBS);
+        let term = Ball::div(&c, &Ball::exact(zk), w);
+        sum = if (k & 1) != 0 {
+            Ball::add(&sum, &term, w)
+        } else {
+            Ball::sub(&sum, &term, w)
+        };
+        ek = Ball::div(&ek, &e, w);
+        fact = Ball::mul_u64(&fact, k, w);
+        k += 1;
+    }
+    let c0 = Ball::sqrt(&Ball::pi(w).mul_2exp(1), w);
+    let s = Ball::add(&c0, &sum, w);
+    // 2^{-2.65 a} < (2π)^{-(a + 1/2)}
+    let s = s.widen(Mag::mul(
+        s.upper(),
+        Mag::from_u64_upper(1).mul_2exp(-((a * 265 / 100) as i64)),
+    ));
+    let (za, _) = Mp::add(z, &Mp::from_u64(a), MAX_LIMBS);
+    let (zh, _) = Mp::add(z, &Mp::from_parts(false, 1, -1), MAX_LIMBS);
+    let za = Ball::exact(za);
+    let t = Ball::sub(&Ball::mul(&Ball::exact(zh), &log(&za, w), w), &za, w);
+    (t, s)
+}
+
+/// `sin(πx)`, reducing `x` exactly into `[-1/2, 1/2]`.
+const fn sin_pi(x: &Mp, w: usize) -> Ball {
+    let k = x.mul_2exp(-1).nearest_i64();
+    let m = Mp::from_u64(2 * k.unsigned_abs());
+    let m = if k < 0 { m.neg() } else { m };
+    let (mut r, _) = Mp::sub(x, &m, MAX_LIMBS);
+    // sin(π(±1 - r)) = sin(πr)
+    if r.exp > 0 || (r.exp == 0 && r.any_from(1)) {
+        let one = if r.neg { Mp::ONE.neg() } else { Mp::ONE };
+        (r, _) = Mp::sub(&one, &r, MAX_LIMBS);
+    }
+    sin_series(&Ball::mul(&Ball::pi(w + 1), &Ball::exact(r), w + 1), w)
+}
+
+/// `Γ(x)` for finite `x` other than zero and the negative integers.
+const fn gamma_ball(x: &Mp, n: usize) -> Ball {
+    let w = spouge_limbs(n, spouge_a(n));
+    if x.neg {
+        // Γ(x) = π / (sin(πx) Γ(1 - x))
+        let (t, s) = spouge(&x.abs(), n);
+        let g = Ball::mul(&exp(&t, w), &s, w);
+        return Ball::div(&Ball::pi(w), &Ball::mul(&sin_pi(x, w), &g, w), w);
+    }
+    if Mp::cmp(x, &Mp::ONE) >= 0 {
+        let (z, _) = Mp::sub(x, &Mp::ONE, MAX_LIMBS);
+        let (t, s) = spouge(&z, n);
+        return Ball::mul(&exp(&t, w), &s, w);
+    }
+    // Γ(x) = Γ(x + 1) / x
+    let (t, s) = spouge(x, n);
+    Ball::div(&Ball::mul(&exp(&t, w), &s, w), &Ball::exact(*x), w)
+}
+
+/// `ln |Γ(x)|` for finite `x` other than zero and the negative integers.
+const fn lgamma_ball(x: &Mp, n: usize) -> Ball {
+    let w = spouge_limbs(n, spouge_a(n));
+    if x.neg {
+        // ln |Γ(x)| = ln π - ln |sin(πx)| - ln Γ(1 - x)
+        let (t, s) = spouge(&x.abs(), n);
+        let l = Ball::add(&t, &log(&s, w), w);
+        let d = Ball::sub(&log(&Ball::pi(w), w), &log(&sin_pi(x, w).abs(), w), w);
+        return Ball::sub(&d, &l, w);
+    }
+    if Mp::cmp(x, &Mp::ONE) >= 0 {
+        let (z, _) = Mp::sub(x, &Mp::ONE, MAX_LIMBS);
+        let (t, s) = spouge(&z, n);
+        return Ball::add(&t, &log(&s, w), w);
+    }
+    let (t, s) = spouge(x, n);
+    let l = Ball::add(&t, &log(&s, w), w);
+    Ball::sub(&l, &log(&Ball::exact(*x), w), w)
+}
+
+/// `(x - 1)!` for an integer `1 <= x <= 172`, exactly.
+const fn factorial(x: u64) -> Mp {
+    let mut r = Mp::ONE;
+    let mut k = 2;
+    while k < x {
+        (r, _) = Mp::mul_u64(&r, k, MAX_LIMBS);
+        k += 1;
+    }
+    r
+}
+
+/// Whether `x`, a negative non-integer, has `Γ(x) < 0`: `⌊x⌋` is odd.
+const fn negative_gamma(x: &Mp) -> bool {
+    (x.trunc_u64() & 1) == 0
+}
+
+const fn tgamma(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) {
+        return fmt.pole(fmt.sign(a));
+    }
+    if fmt.is_inf(a) {
+        return if fmt.sign(a) { fmt.invalid() } else { (a, 0) };
+    }
+    let x = fmt.to_mp(a);
+    if x.neg {
+        if x.is_integer() {
+            return fmt.invalid();
+        }
+        if Mp::cmp_abs(&x, &Mp::from_u64(underflow(fmt))) >= 0 {
+            let dir = if negative_gamma(&x) { -1 } else { 1 };
+            return fmt.round(&Mp::ZERO, dir, rnd, tin);
+        }
+    } else if Mp::cmp(&x, &Mp::from_u64(overflow(fmt))) >= 0 {
+        return fmt.round(&Mp::from_parts(false, 1, fmt.max_exp() + 1), 0, rnd, tin);
+    } else if x.is_integer() {
+        return fmt.round(&factorial(x.trunc_u64()), 0, rnd, tin);
+    }
+    ziv!(fmt, rnd, tin, |n| gamma_ball(&x, n), |c| never(&c))
+}
+
+const fn lgamma(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_inf(a) {
+        return (fmt.inf(false), 0);
+    }
+    if fmt.is_zero(a) {
+        return fmt.pole(false);
+    }
+    let x = fmt.to_mp(a);
+    if x.is_integer() {
+        if x.neg {
+            return fmt.pole(false);
+        }
+        // ln Γ(1) = ln Γ(2) = 0
+        if Mp::cmp(&x, &Mp::from_u64(2)) <= 0 {
+            return (fmt.zero(false), 0);
+        }
+    }
+    ziv!(fmt, rnd, tin, |n| lgamma_ball(&x, n), |c| never(&c))
+}
+
+const fn gamma_sign(fmt: Format, a: u64) -> i8 {
+    if fmt.is_nan(a) || !fmt.sign(a) || fmt.is_inf(a) {
+        return 1;
+    }
+    if fmt.is_zero(a) {
+        return -1;
+    }
+    let x = fmt.to_mp(a);
+    if !x.is_integer() && negative_gamma(&x) {
+        -1
+    } else {
+        1
+    }
+}
+
+unary!(
+    /// Gamma function, `∫₀^∞ t^{a-1} e^{-t} dt` continued to negative `a`.
+    f32_tgamma,
+    f64_tgamma,
+    tgamma
+);
+
+unary!(
+    /// Natural logarithm of the absolute value of the gamma function; the
+    /// sign of `Γ(a)` is given by `f32_gammaSign` and `f64_gammaSign`.
+    f32_lgamma,
+    f64_lgamma,
+    lgamma
+);
+
+/// Sign of `Γ(a)` as `1` or `-1`, the companion of [`f32_lgamma`]: negative
+/// for `-0` and for negative non-integers with an odd integer part below
+/// them, positive otherwise.
+#[must_use]
+pub const fn f32_gammaSign(a: crate::softfloat::float32_t) -> i8 {
+    gamma_sign(Format::F32, a.v as u64)
+}
+
+/// Sign of `Γ(a)` as `1` or `-1`, the companion of [`f64_lgamma`]: negative
+/// for `-0` and for negative non-integers with an odd integer part below
+/// them, positive otherwise.
+#[must_use]
+pub const fn f64_gammaSign(a: crate::softfloat::float64_t) -> i8 {
+    gamma_sign(Format::F64, a.v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::softfloat::{float32_t, float64_t};
+
+    #[test]
+    fn test_f64_gamma() {
+        struct TestCase {
+            f: fn(float64_t, u8, u8) -> (float64_t, u8),
+            a: u64,
+            result: u64,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f64_tgamma,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0x3FFC_5BF8_91B4_EF6B,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_tgamma,
+                a: 0x401D_0000_0000_0000,
+                result: 0x4092_0D86_2883_56B5,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_tgamma,
+                a: 0x3FF0_0000_1AD7_F29B,
+                result: 0x3FEF_FFFF_E102_CF46,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_tgamma,
+                a: 0x4018_0000_0000_0000,
+                result: 0x405E_0000_0000_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_tgamma,
+                a: 0x403E_0000_0000_0000,
+                result: 0x465B_E651_8687_A785,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_tgamma,
+                a: 0x4065_7000_0000_0000,
+                result: 0x7FE0_E186_3DCA_D789,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_tgamma,
+                a: 0xC004_0000_0000_0000,
+                result: 0xBFEE_3FF8_12E3_2182,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_tgamma,
+                a: 0xC008_0000_8637_BD06,
+                result: 0x4104_5853_A888_153B,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_tgamma,
+                a: 0xC065_5000_0000_0000,
+                result: 0x8017_D237_4DFC_DA79,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_tgamma,
+                a: 0x37A1_6C26_2777_579C,
+                result: 0x483D_6329_F1C3_5CA6,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_tgamma,
+                a: 0x8000_0000_0000_0000,
+                result: 0xFFF0_0000_0000_0000,
+                flags: 8,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_tgamma,
+                a: 0xC010_0000_0000_0000,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_tgamma,
+                a: 0xFFF0_0000_0000_0000,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_tgamma,
+                a: 0x7FF0_0000_0000_0000,
+                result: 0x7FF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_lgamma,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0x3FE2_50D0_48E7_A1BD,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_lgamma,
+                a: 0x4000_0000_1AD7_F29B,
+                result: 0x3E76_B2B4_5D1D_45B1,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_lgamma,
+                a: 0x3BC7_9CA1_0C92_4223,
+                result: 0x4047_069E_2AA2_AA5A,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_lgamma,
+                a: 0x4629_3E59_39A0_8CEA,
+                result: 0x468A_DA13_7E8A_D860,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_lgamma,
+                a: 0xC003_A7FC_90DF_B6B4,
+                result: 0x3E6F_1981_B065_90BB,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_lgamma,
+                a: 0xC08F_4200_0000_0000,
+                result: 0xC0B7_185D_2D24_063A,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_lgamma,
+                a: 0x4000_0000_0000_0000,
+                result: 0x0000_0000_0000_0000,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_lgamma,
+                a: 0xC008_0000_0000_0000,
+                result: 0x7FF0_0000_0000_0000,
+                flags: 8,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_lgamma,
+                a: 0xFFF0_0000_0000_0000,
+                result: 0x7FF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_lgamma,
+                a: 0x7FF0_0000_0000_0001,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(float64_t { v: c.a }, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f32_gamma() {
+        struct TestCase {
+            f: fn(float32_t, u8, u8) -> (float32_t, u8),
+            a: u32,
+            result: u32,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f32_tgamma,
+                a: 0x3F00_0000,
+                result: 0x3FE2_DFC4,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_tgamma,
+                a: 0x40E8_0000,
+                result: 0x4490_6C31,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_tgamma,
+                a: 0x3F80_0001,
+                result: 0x3F7F_FFFF,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_tgamma,
+                a: 0x40C0_0000,
+                result: 0x42F0_0000,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_tgamma,
+                a: 0x41F0_0000,
+                result: 0x72DF_328C,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_tgamma,
+                a: 0x432B_8000,
+                result: 0x7F80_0000,
+                flags: 5,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_tgamma,
+                a: 0xC020_0000,
+                result: 0xBF71_FFC1,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_tgamma,
+                a: 0xC040_0004,
+                result: 0x482A_AA9D,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_tgamma,
+                a: 0xC32A_8000,
+                result: 0x8000_0001,
+                flags: 3,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_tgamma,
+                a: 0x0001_16C2,
+                result: 0x7F80_0000,
+                flags: 5,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_tgamma,
+                a: 0x8000_0000,
+                result: 0xFF80_0000,
+                flags: 8,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_tgamma,
+                a: 0xC080_0000,
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_tgamma,
+                a: 0xFF80_0000,
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_tgamma,
+                a: 0x7F80_0000,
+                result: 0x7F80_0000,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_lgamma,
+                a: 0x3F00_0000,
+                result: 0x3F12_8682,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_lgamma,
+                a: 0x4000_0001,
+                result: 0x33D8_7732,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_lgamma,
+                a: 0x1E3C_E508,
+                result: 0x4238_34F1,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_lgamma,
+                a: 0x7149_F2CA,
+                result: 0x7456_D09C,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_lgamma,
+                a: 0xC01D_3FE5,
+                result: 0xB3F2_6792,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_lgamma,
+                a: 0xC47A_1000,
+                result: 0xC5B8_C2E9,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_lgamma,
+                a: 0x4000_0000,
+                result: 0x0000_0000,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_lgamma,
+                a: 0xC040_0000,
+                result: 0x7F80_0000,
+                flags: 8,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_lgamma,
+                a: 0xFF80_0000,
+                result: 0x7F80_0000,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_lgamma,
+                a: 0x7F80_0001,
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(float32_t { v: c.a }, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_gamma_sign() {
+        let cases: [(u64, i8); 8] = [
+            (0x3FE0_0000_0000_0000, 1),
+            (0x8000_0000_0000_0000, -1),
+            (0xBFE0_0000_0000_0000, -1),
+            (0xC004_0000_0000_0000, -1),
+            (0xC00C_0000_0000_0000, 1),
+            (0xC010_0000_0000_0000, 1),
+            (0xFFF0_0000_0000_0000, 1),
+            (0x7FF8_0000_0000_0000, 1),
+        ];
+        for (i, &(a, sign)) in cases.iter().enumerate() {
+            assert_eq!((i, f64_gammaSign(float64_t { v: a })), (i, sign));
+        }
+        assert_eq!(f32_gammaSign(float32_t { v: 0xC030_0000 }), -1);
+        assert_eq!(f32_gammaSign(float32_t { v: 0xC050_0000 }), 1);
+    }
+}
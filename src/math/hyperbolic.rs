@@ -0,0 +1,631 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Hyperbolic functions and their inverses.
+
+use super::ball::Ball;
+use super::elementary::{exp, log1p, sinh_series};
+use super::format::Format;
+use super::mp::Mp;
+use super::ziv::{never, round_nudged, ziv};
+
+/// `e^|x|` and `e^-|x|`.
+const fn exp_pair(x: &Mp, n: usize) -> (Ball, Ball) {
+    let e = exp(&Ball::exact(x.abs()), n);
+    (e, Ball::div(&Ball::ONE, &e, n))
+}
+
+const fn sinh_ball(x: &Mp, n: usize) -> Ball {
+    let w = n + 1;
+    if x.exp <= 0 {
+        return sinh_series(&Ball::exact(*x), w);
+    }
+    let (e, inv) = exp_pair(x, w);
+    let r = Ball::sub(&e, &inv, w).mul_2exp(-1);
+    if x.neg {
+        r.neg()
+    } else {
+        r
+    }
+}
+
+const fn sinh(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) || fmt.is_inf(a) {
+        return (a, 0);
+    }
+    let x = fmt.to_mp(a);
+    if x.exp <= fmt.tiny_exp() {
+        return round_nudged(&x, true, fmt, rnd, tin);
+    }
+    ziv!(fmt, rnd, tin, |n| sinh_ball(&x, n), |c| never(&c))
+}
+
+const fn cosh_ball(x: &Mp, n: usize) -> Ball {
+    let w = n + 1;
+    let (e, inv) = exp_pair(x, w);
+    Ball::add(&e, &inv, w).mul_2exp(-1)
+}
+
+const fn cosh(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) {
+        return (fmt.one(false), 0);
+    }
+    if fmt.is_inf(a) {
+        return (fmt.inf(false), 0);
+    }
+    let x = fmt.to_mp(a);
+    if x.exp <= fmt.tiny_exp() {
+        return round_nudged(&Mp::ONE, true, fmt, rnd, tin);
+    }
+    ziv!(fmt, rnd, tin, |n| cosh_ball(&x, n), |c| never(&c))
+}
+
+const fn tanh_ball(x: &Mp, n: usize) -> Ball {
+    let w = n + 1;
+    let r = if x.exp <= 0 {
+        // tanh x = sinh x / √(1 + sinh² x)
+        let s = sinh_series(&Ball::exact(x.abs()), w);
+        let c = Ball::sqrt(&Ball::add(&Ball::ONE, &Ball::sqr(&s, w), w), w);
+        Ball::div(&s, &c, w)
+    } else {
+        // tanh |x| = (1 - e^-2|x|) / (1 + e^-2|x|)
+        let t = exp(&Ball::exact(x.abs().mul_2exp(1).neg()), w);
+        Ball::div(
+            &Ball::sub(&Ball::ONE, &t, w),
+            &Ball::add(&Ball::ONE, &t, w),
+            w,
+        )
+    };
+    if x.neg {
+        r.neg()
+    } else {
+        r
+    }
+}
+
+const fn tanh(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) {
+        return (a, 0);
+    }
+    if fmt.is_inf(a) {
+        return (fmt.one(fmt.sign(a)), 0);
+    }
+    let x = fmt.to_mp(a);
+    if x.exp <= fmt.tiny_exp() {
+        return round_nudged(&x, false, fmt, rnd, tin);
+    }
+    // Beyond this, 1 - |tanh x| < 2e^-2|x| is below a quarter ulp of 1.
+    let limit = match fmt {
+        Format::F32 => 10,
+        Format::F64 => 20,
+    };
+    if Mp::cmp_abs(&x, &Mp::from_u64(limit)) >= 0 {
+        let one = if x.neg { Mp::ONE.neg() } else { Mp::ONE };
+        return round_nudged(&one, false, fmt, rnd, tin);
+    }
+    ziv!(fmt, rnd, tin, |n| tanh_ball(&x, n), |c| never(&c))
+}
+
+const fn asinh_ball(x: &Mp, n: usize) -> Ball {
+    let w = n + 1;
+    let ax = Ball::exact(x.abs());
+    // asinh |x| = log1p(|x| + x² / (1 + √(1 + x²)))
+    let x2 = Ball::sqr(&ax, w);
+    let h = Ball::sqrt(&Ball::add(&Ball::ONE, &x2, w), w);
+    let z = Ball::add(&ax, &Ball::div(&x2, &Ball::add(&Ball::ONE, &h, w), w), w);
+    let r = log1p(&z, w);
+    if x.neg {
+        r.neg()
+    } else {
+        r
+    }
+}
+
+const fn asinh(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) || fmt.is_inf(a) {
+        return (a, 0);
+    }
+    let x = fmt.to_mp(a);
+    if x.exp <= fmt.tiny_exp() {
+        return round_nudged(&x, false, fmt, rnd, tin);
+    }
+    ziv!(fmt, rnd, tin, |n| asinh_ball(&x, n), |c| never(&c))
+}
+
+const fn acosh_ball(x: &Mp, n: usize) -> Ball {
+    let w = n + 1;
+    let x = Ball::exact(*x);
+    // acosh x = log1p((x - 1) + √((x - 1)(x + 1)))
+    let xm1 = Ball::sub(&x, &Ball::ONE, w);
+    let s = Ball::sqrt(&Ball::mul(&xm1, &Ball::add(&x, &Ball::ONE, w), w), w);
+    log1p(&Ball::add(&xm1, &s, w), w)
+}
+
+const fn acosh(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.sign(a) && !fmt.is_zero(a) {
+        return fmt.invalid();
+    }
+    if fmt.is_inf(a) {
+        return (a, 0);
+    }
+    let x = fmt.to_mp(a);
+    match Mp::cmp(&x, &Mp::ONE) {
+        -1 => return fmt.invalid(),
+        0 => return (fmt.zero(false), 0),
+        _ => {}
+    }
+    ziv!(fmt, rnd, tin, |n| acosh_ball(&x, n), |c| never(&c))
+}
+
+const fn atanh_ball(x: &Mp, n: usize) -> Ball {
+    let w = n + 1;
+    let ax = Ball::exact(x.abs());
+    // atanh |x| = log1p(2|x| / (1 - |x|)) / 2
+    let q = Ball::div(&ax.mul_2exp(1), &Ball::sub(&Ball::ONE, &ax, w), w);
+    let r = log1p(&q, w).mul_2exp(-1);
+    if x.neg {
+        r.neg()
+    } else {
+        r
+    }
+}
+
+const fn atanh(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) {
+        return (a, 0);
+    }
+    if fmt.is_inf(a) {
+        return fmt.invalid();
+    }
+    let x = fmt.to_mp(a);
+    match Mp::cmp_abs(&x, &Mp::ONE) {
+        1 => return fmt.invalid(),
+        0 => return fmt.pole(x.neg),
+        _ => {}
+    }
+    if x.exp <= fmt.tiny_exp() {
+        return round_nudged(&x, true, fmt, rnd, tin);
+    }
+    ziv!(fmt, rnd, tin, |n| atanh_ball(&x, n), |c| never(&c))
+}
+
+unary!(
+    /// Hyperbolic sine.
+    f32_sinh,
+    f64_sinh,
+    sinh
+);
+
+unary!(
+    /// Hyperbolic cosine.
+    f32_cosh,
+    f64_cosh,
+    cosh
+);
+
+unary!(
+    /// Hyperbolic tangent.
+    f32_tanh,
+    f64_tanh,
+    tanh
+);
+
+unary!(
+    /// Inverse hyperbolic sine.
+    f32_asinh,
+    f64_asinh,
+    asinh
+);
+
+unary!(
+    /// Inverse hyperbolic cosine, defined for `a >= 1`.
+    f32_acosh,
+    f64_acosh,
+    acosh
+);
+
+unary!(
+    /// Inverse hyperbolic tangent, defined for `|a| <= 1`.
+    f32_atanh,
+    f64_atanh,
+    atanh
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::softfloat::{float32_t, float64_t};
+
+    #[test]
+    fn test_f64_hyperbolic() {
+        struct TestCase {
+            f: fn(float64_t, u8, u8) -> (float64_t, u8),
+            a: u64,
+            result: u64,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f64_sinh,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0x3FE0_ACD0_0FE6_3B96,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_sinh,
+                a: 0xC00A_0000_0000_0000,
+                result: 0xC029_C066_9C3E_8082,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_sinh,
+                a: 0x3F1A_36E2_EB1C_432D,
+                result: 0x3F1A_36E2_EBD7_E993,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_cosh,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0x3FF2_0AC1_862A_E8D0,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_cosh,
+                a: 0xC00A_0000_0000_0000,
+                result: 0x4029_D440_D2C3_A213,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_cosh,
+                a: 0x4059_0000_0000_0000,
+                result: 0x48E3_494A_9B17_1BF4,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_tanh,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0x3FDD_9353_D756_8AF3,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_tanh,
+                a: 0xC00A_0000_0000_0000,
+                result: 0xBFEF_E767_95D3_53A0,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_tanh,
+                a: 0x3F1A_36E2_EB1C_432D,
+                result: 0x3F1A_36E2_E9A4_F662,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_asinh,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0x3FDE_CC2C_AEC5_160A,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_asinh,
+                a: 0xC00A_0000_0000_0000,
+                result: 0xBFFE_5093_BAA4_8D15,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_asinh,
+                a: 0x4415_AF1D_78B5_8C40,
+                result: 0x4047_5F57_369E_9343,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_acosh,
+                a: 0x3FF8_0000_0000_0000,
+                result: 0x3FEE_CC2C_AEC5_160A,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_acosh,
+                a: 0x400A_0000_0000_0000,
+                result: 0x3FFD_8E51_E95C_E10F,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_acosh,
+                a: 0x4415_AF1D_78B5_8C40,
+                result: 0x4047_5F57_369E_9343,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_atanh,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0x3FE1_93EA_7AAD_030B,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_atanh,
+                a: 0xBFD0_0000_0000_0000,
+                result: 0xBFD0_58AE_FA81_1452,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_atanh,
+                a: 0x3F1A_36E2_EB1C_432D,
+                result: 0x3F1A_36E2_EC93_8FF7,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_atanh,
+                a: 0xBFF0_0000_0000_0000,
+                result: 0xFFF0_0000_0000_0000,
+                flags: 8,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_acosh,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_cosh,
+                a: 0x8000_0000_0000_0000,
+                result: 0x3FF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_sinh,
+                a: 0x408F_4000_0000_0000,
+                result: 0x7FF0_0000_0000_0000,
+                flags: 5,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_tanh,
+                a: 0xFFF0_0000_0000_0000,
+                result: 0xBFF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_tanh,
+                a: 0x4039_0000_0000_0000,
+                result: 0x3FEF_FFFF_FFFF_FFFF,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(float64_t { v: c.a }, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f32_hyperbolic() {
+        struct TestCase {
+            f: fn(float32_t, u8, u8) -> (float32_t, u8),
+            a: u32,
+            result: u32,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f32_sinh,
+                a: 0x3F00_0000,
+                result: 0x3F05_6681,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_sinh,
+                a: 0xC050_0000,
+                result: 0xC14E_0335,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_sinh,
+                a: 0x38D1_B717,
+                result: 0x38D1_B718,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_cosh,
+                a: 0x3F00_0000,
+                result: 0x3F90_560C,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_cosh,
+                a: 0xC050_0000,
+                result: 0x414E_A207,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_cosh,
+                a: 0x42C8_0000,
+                result: 0x7F80_0000,
+                flags: 5,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_tanh,
+                a: 0x3F00_0000,
+                result: 0x3EEC_9A9F,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_tanh,
+                a: 0xC050_0000,
+                result: 0xBF7F_3B3D,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_tanh,
+                a: 0x38D1_B717,
+                result: 0x38D1_B717,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_asinh,
+                a: 0x3F00_0000,
+                result: 0x3EF6_6165,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_asinh,
+                a: 0xC050_0000,
+                result: 0xBFF2_849E,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_asinh,
+                a: 0x60AD_78EC,
+                result: 0x423A_FABA,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_acosh,
+                a: 0x3FC0_0000,
+                result: 0x3F76_6165,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_acosh,
+                a: 0x4050_0000,
+                result: 0x3FEC_728F,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_acosh,
+                a: 0x60AD_78EC,
+                result: 0x423A_FABA,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_atanh,
+                a: 0x3F00_0000,
+                result: 0x3F0C_9F54,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_atanh,
+                a: 0xBE80_0000,
+                result: 0xBE82_C578,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_atanh,
+                a: 0x38D1_B717,
+                result: 0x38D1_B717,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(float32_t { v: c.a }, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+}
@@ -0,0 +1,677 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Inverse trigonometric functions.
+
+use super::ball::Ball;
+use super::elementary::atan as atan_ball;
+use super::format::Format;
+use super::mp::Mp;
+use super::ziv::{never, round_nudged, ziv};
+
+/// Rounds `±π × num / 4`.
+const fn pi_quarters(fmt: Format, neg: bool, num: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    ziv!(
+        fmt,
+        rnd,
+        tin,
+        |n| {
+            let b = Ball::mul_u64(&Ball::pi(n + 1), num, n + 1).mul_2exp(-2);
+            if neg {
+                b.neg()
+            } else {
+                b
+            }
+        },
+        |c| never(&c)
+    )
+}
+
+const fn asin_ball(x: &Mp, n: usize) -> Ball {
+    let w = n + 1;
+    let x = Ball::exact(*x);
+    // asin x = atan(x / √((1 - x)(1 + x)))
+    let d = Ball::mul(
+        &Ball::sub(&Ball::ONE, &x, w),
+        &Ball::add(&Ball::ONE, &x, w),
+        w,
+    );
+    atan_ball(&Ball::div(&x, &Ball::sqrt(&d, w), w), w)
+}
+
+const fn asin(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) {
+        return (a, 0);
+    }
+    if fmt.is_inf(a) {
+        return fmt.invalid();
+    }
+    let x = fmt.to_mp(a);
+    match Mp::cmp_abs(&x, &Mp::ONE) {
+        1 => return fmt.invalid(),
+        0 => return pi_quarters(fmt, x.neg, 2, rnd, tin),
+        _ => {}
+    }
+    if x.exp <= fmt.tiny_exp() {
+        return round_nudged(&x, true, fmt, rnd, tin);
+    }
+    ziv!(fmt, rnd, tin, |n| asin_ball(&x, n), |c| never(&c))
+}
+
+const fn acos_ball(x: &Mp, n: usize) -> Ball {
+    let w = n + 1;
+    let x = Ball::exact(*x);
+    // acos x = 2 atan(√((1 - x) / (1 + x)))
+    let q = Ball::div(
+        &Ball::sub(&Ball::ONE, &x, w),
+        &Ball::add(&Ball::ONE, &x, w),
+        w,
+    );
+    atan_ball(&Ball::sqrt(&q, w), w).mul_2exp(1)
+}
+
+const fn acos(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) {
+        return pi_quarters(fmt, false, 2, rnd, tin);
+    }
+    if fmt.is_inf(a) {
+        return fmt.invalid();
+    }
+    let x = fmt.to_mp(a);
+    match Mp::cmp_abs(&x, &Mp::ONE) {
+        1 => return fmt.invalid(),
+        0 if x.neg => return pi_quarters(fmt, false, 4, rnd, tin),
+        0 => return (fmt.zero(false), 0),
+        _ => {}
+    }
+    ziv!(fmt, rnd, tin, |n| acos_ball(&x, n), |c| never(&c))
+}
+
+const fn atan(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) {
+        return (a, 0);
+    }
+    if fmt.is_inf(a) {
+        return pi_quarters(fmt, fmt.sign(a), 2, rnd, tin);
+    }
+    let x = fmt.to_mp(a);
+    if x.exp <= fmt.tiny_exp() {
+        return round_nudged(&x, false, fmt, rnd, tin);
+    }
+    ziv!(fmt, rnd, tin, |n| atan_ball(&Ball::exact(x), n), |c| never(
+        &c
+    ))
+}
+
+/// `atan2(y, x)` for finite non-zero operands, optionally divided by `π`.
+const fn atan2_ball(y: &Mp, x: &Mp, over_pi: bool, n: usize) -> Ball {
+    let w = n + 1;
+    let q = Ball::div(&Ball::exact(*y), &Ball::exact(*x), w);
+    let mut r = if x.neg {
+        // atan2(y, x) = ±(π - atan|y/x|) in the left half-plane
+        let t = Ball::sub(&Ball::pi(w + 1), &atan_ball(&q.abs(), w), w);
+        if y.neg {
+            t.neg()
+        } else {
+            t
+        }
+    } else {
+        atan_ball(&q, w)
+    };
+    if over_pi {
+        r = Ball::div(&r, &Ball::pi(w + 1), w);
+    }
+    r
+}
+
+/// Shared special-value handling for `atan2` and `atan2pi`. The result is
+/// `±π × num / 4`, scaled by `1/π` when `over_pi` is set.
+const fn atan2_special(
+    fmt: Format,
+    y: u64,
+    x: u64,
+    over_pi: bool,
+    rnd: u8,
+    tin: u8,
+) -> Option<(u64, u8)> {
+    let sy = fmt.sign(y);
+    let num = if fmt.is_zero(y) {
+        if fmt.sign(x) {
+            4
+        } else {
+            return Some((fmt.zero(sy), 0));
+        }
+    } else if fmt.is_inf(y) {
+        if !fmt.is_inf(x) {
+            2
+        } else if fmt.sign(x) {
+            3
+        } else {
+            1
+        }
+    } else if fmt.is_zero(x) {
+        2
+    } else if fmt.is_inf(x) {
+        if fmt.sign(x) {
+            4
+        } else {
+            return Some((fmt.zero(sy), 0));
+        }
+    } else {
+        return None;
+    };
+    if !over_pi {
+        return Some(pi_quarters(fmt, sy, num, rnd, tin));
+    }
+    let q = Mp::from_parts(sy, num, -2);
+    Some(fmt.round(&q, 0, rnd, tin))
+}
+
+const fn atan2(fmt: Format, y: u64, x: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(y) || fmt.is_nan(x) {
+        return fmt.propagate_nan(y, x);
+    }
+    if let Some(r) = atan2_special(fmt, y, x, false, rnd, tin) {
+        return r;
+    }
+    let ym = fmt.to_mp(y);
+    let xm = fmt.to_mp(x);
+    ziv!(
+        fmt,
+        rnd,
+        tin,
+        |n| atan2_ball(&ym, &xm, false, n),
+        |c| never(&c)
+    )
+}
+
+const fn atan2pi(fmt: Format, y: u64, x: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(y) || fmt.is_nan(x) {
+        return fmt.propagate_nan(y, x);
+    }
+    if let Some(r) = atan2_special(fmt, y, x, true, rnd, tin) {
+        return r;
+    }
+    // On the diagonals the result is exactly ±1/4 or ±3/4.
+    if fmt.abs(y) == fmt.abs(x) {
+        let num = if fmt.sign(x) { 3 } else { 1 };
+        return fmt.round(&Mp::from_parts(fmt.sign(y), num, -2), 0, rnd, tin);
+    }
+    let ym = fmt.to_mp(y);
+    let xm = fmt.to_mp(x);
+    ziv!(fmt, rnd, tin, |n| atan2_ball(&ym, &xm, true, n), |c| never(
+        &c
+    ))
+}
+
+unary!(
+    /// Arcsine, in `[-π/2, π/2]`.
+    f32_asin,
+    f64_asin,
+    asin
+);
+
+unary!(
+    /// Arccosine, in `[0, π]`.
+    f32_acos,
+    f64_acos,
+    acos
+);
+
+unary!(
+    /// Arctangent, in `[-π/2, π/2]`.
+    f32_atan,
+    f64_atan,
+    atan
+);
+
+binary!(
+    /// Angle of the point `(b, a)` from the positive x-axis, in `[-π, π]`;
+    /// `a` is the y-coordinate as in C's `atan2(y, x)`.
+    f32_atan2,
+    f64_atan2,
+    atan2
+);
+
+binary!(
+    /// `atan2(a, b) / π`, in `[-1, 1]`.
+    f32_atan2pi,
+    f64_atan2pi,
+    atan2pi
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::softfloat::{float32_t, float64_t};
+
+    #[test]
+    fn test_f64_inverse_trig() {
+        struct TestCase {
+            f: fn(float64_t, u8, u8) -> (float64_t, u8),
+            a: u64,
+            result: u64,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f64_asin,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0x3FE0_C152_382D_7365,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_asin,
+                a: 0xBFE8_0000_0000_0000,
+                result: 0xBFEB_2353_15C6_80DC,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_asin,
+                a: 0x3EE4_F8B5_88E3_68F1,
+                result: 0x3EE4_F8B5_88E4_E940,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_asin,
+                a: 0x3FEF_FFFD_E721_0BE9,
+                result: 0x3FF9_1C30_6B2C_13AD,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_acos,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0x3FF0_C152_382D_7365,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_acos,
+                a: 0xBFEF_FFFD_E721_0BE9,
+                result: 0x4009_1F15_DFB8_2062,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_acos,
+                a: 0x3F50_624D_D2F1_A9FC,
+                result: 0x3FF9_1DE2_C0C3_FCA6,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_acos,
+                a: 0x3FD0_0000_0000_0000,
+                result: 0x3FF5_1700_E0C1_4B25,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_atan,
+                a: 0x400C_0000_0000_0000,
+                result: 0x3FF4_AE10_FC65_89A4,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_atan,
+                a: 0xC202_A05F_2000_0000,
+                result: 0xBFF9_21FB_543D_4DE0,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_atan,
+                a: 0x3F50_624D_D2F1_A9FC,
+                result: 0x3F50_624D_7751_6E16,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_atan,
+                a: 0x3FE8_0000_0000_0000,
+                result: 0x3FE4_978F_A326_9EE1,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_asin,
+                a: 0x3FF0_0000_0000_0000,
+                result: 0x3FF9_21FB_5444_2D18,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_asin,
+                a: 0x4000_0000_0000_0000,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_asin,
+                a: 0x7FF0_0000_0000_0001,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_acos,
+                a: 0xBFF0_0000_0000_0000,
+                result: 0x4009_21FB_5444_2D18,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_acos,
+                a: 0x3FF0_0000_0000_0000,
+                result: 0x0000_0000_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_atan,
+                a: 0x7FF0_0000_0000_0000,
+                result: 0x3FF9_21FB_5444_2D18,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_atan,
+                a: 0x8000_0000_0000_0000,
+                result: 0x8000_0000_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(float64_t { v: c.a }, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f32_inverse_trig() {
+        struct TestCase {
+            f: fn(float32_t, u8, u8) -> (float32_t, u8),
+            a: u32,
+            result: u32,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f32_asin,
+                a: 0x3F00_0000,
+                result: 0x3F06_0A92,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_asin,
+                a: 0xBF40_0000,
+                result: 0xBF59_1A99,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_asin,
+                a: 0x3727_C5AC,
+                result: 0x3727_C5AD,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_asin,
+                a: 0x3F7F_FFEF,
+                result: 0x3FC8_E134,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_acos,
+                a: 0x3F00_0000,
+                result: 0x3F86_0A92,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_acos,
+                a: 0xBF7F_FFEF,
+                result: 0x4048_F888,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_acos,
+                a: 0x3A83_126F,
+                result: 0x3FC8_EF16,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_acos,
+                a: 0x3E80_0000,
+                result: 0x3FA8_B807,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_atan,
+                a: 0x4060_0000,
+                result: 0x3FA5_7088,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_atan,
+                a: 0xD015_02F9,
+                result: 0xBFC9_0FDA,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_atan,
+                a: 0x3A83_126F,
+                result: 0x3A83_126C,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_atan,
+                a: 0x3F40_0000,
+                result: 0x3F24_BC7D,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(float32_t { v: c.a }, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f64_atan2() {
+        struct TestCase {
+            f: fn(float64_t, float64_t, u8, u8) -> (float64_t, u8),
+            a: u64,
+            b: u64,
+            result: u64,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f64_atan2,
+                a: 0x3FF0_0000_0000_0000,
+                b: 0x4000_0000_0000_0000,
+                result: 0x3FDD_AC67_0561_BB4F,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_atan2pi,
+                a: 0x3FF0_0000_0000_0000,
+                b: 0x4000_0000_0000_0000,
+                result: 0x3FC2_E405_1D9D_F308,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_atan2,
+                a: 0xC008_0000_0000_0000,
+                b: 0xBFE0_0000_0000_0000,
+                result: 0xBFFB_C66E_44CB_C074,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_atan2pi,
+                a: 0xC008_0000_0000_0000,
+                b: 0xBFE0_0000_0000_0000,
+                result: 0xBFE1_AEA4_0B4C_0866,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_atan2,
+                a: 0x01A5_6E1F_C2F8_F359,
+                b: 0x4202_A05F_2000_0000,
+                result: 0x0000_1268_8B70_E62B,
+                flags: 3,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_atan2pi,
+                a: 0x01A5_6E1F_C2F8_F359,
+                b: 0x4202_A05F_2000_0000,
+                result: 0x0000_05DC_0CB0_402F,
+                flags: 3,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_atan2,
+                a: 0x3FE0_0000_0000_0000,
+                b: 0xC01C_0000_0000_0000,
+                result: 0x4008_8FF1_AE5A_77DD,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_atan2pi,
+                a: 0x3FE0_0000_0000_0000,
+                b: 0xC01C_0000_0000_0000,
+                result: 0x3FEF_460F_1D4B_BC9A,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_atan2,
+                a: 0x0000_0000_0000_0000,
+                b: 0x8000_0000_0000_0000,
+                result: 0x4009_21FB_5444_2D18,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_atan2,
+                a: 0x8000_0000_0000_0000,
+                b: 0x0000_0000_0000_0000,
+                result: 0x8000_0000_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_atan2pi,
+                a: 0xFFF0_0000_0000_0000,
+                b: 0xFFF0_0000_0000_0000,
+                result: 0xBFE8_0000_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_atan2pi,
+                a: 0x4000_0000_0000_0000,
+                b: 0xC000_0000_0000_0000,
+                result: 0x3FE8_0000_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(
+                float64_t { v: c.a },
+                float64_t { v: c.b },
+                c.roundingMode,
+                c.detectTininess,
+            );
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+}
@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Correctly rounded mathematical functions.
+//!
+//! Every function here returns the exact result rounded once under the
+//! requested rounding mode, together with the IEEE exception flags that
+//! rounding raises, matching the conventions of [`crate::softfloat`]. Results
+//! are computed as midpoint-radius enclosures in multi-precision arithmetic
+//! and refined until the rounding is decided; all of it is
+//! `const`, allocation-free and panic-free.
+#![allow(
+    non_snake_case,
+    clippy::large_types_passed_by_value,
+    clippy::many_single_char_names,
+    clippy::module_name_repetitions,
+    clippy::similar_names,
+    clippy::too_many_lines
+)]
+
+/// Defines `f32_*` and `f64_*` entry points for a one-operand function
+/// written against [`format::Format`].
+macro_rules! unary {
+    ($(#[$doc:meta])* $name32:ident, $name64:ident, $imp:path) => {
+        $(#[$doc])*
+        #[must_use]
+        #[allow(clippy::cast_possible_truncation)]
+        pub const fn $name32(
+            a: $crate::softfloat::float32_t,
+            roundingMode: u8,
+            detectTininess: u8,
+        ) -> ($crate::softfloat::float32_t, u8) {
+            let (v, flags) = $imp(
+                $crate::math::format::Format::F32,
+                a.v as u64,
+                roundingMode,
+                detectTininess,
+            );
+            ($crate::softfloat::float32_t { v: v as u32 }, flags)
+        }
+
+        $(#[$doc])*
+        #[must_use]
+        pub const fn $name64(
+            a: $crate::softfloat::float64_t,
+            roundingMode: u8,
+            detectTininess: u8,
+        ) -> ($crate::softfloat::float64_t, u8) {
+            let (v, flags) = $imp(
+                $crate::math::format::Format::F64,
+                a.v,
+                roundingMode,
+                detectTininess,
+            );
+            ($crate::softfloat::float64_t { v }, flags)
+        }
+    };
+}
+
+/// Defines `f32_*` and `f64_*` entry points for a two-operand function
+/// written against [`format::Format`].
+macro_rules! binary {
+    ($(#[$doc:meta])* $name32:ident, $name64:ident, $imp:path) => {
+        $(#[$doc])*
+        #[must_use]
+        #[allow(clippy::cast_possible_truncation)]
+        pub const fn $name32(
+            a: $crate::softfloat::float32_t,
+            b: $crate::softfloat::float32_t,
+            roundingMode: u8,
+            detectTininess: u8,
+        ) -> ($crate::softfloat::float32_t, u8) {
+            let (v, flags) = $imp(
+                $crate::math::format::Format::F32,
+                a.v as u64,
+                b.v as u64,
+                roundingMode,
+                detectTininess,
+            );
+            ($crate::softfloat::float32_t { v: v as u32 }, flags)
+        }
+
+        $(#[$doc])*
+        #[must_use]
+        pub const fn $name64(
+            a: $crate::softfloat::float64_t,
+            b: $crate::softfloat::float64_t,
+            roundingMode: u8,
+            detectTininess: u8,
+        ) -> ($crate::softfloat::float64_t, u8) {
+            let (v, flags) = $imp(
+                $crate::math::format::Format::F64,
+                a.v,
+                b.v,
+                roundingMode,
+                detectTininess,
+            );
+            ($crate::softfloat::float64_t { v }, flags)
+        }
+    };
+}
+
+/// Defines `f32_*` and `f64_*` entry points for a function of one operand
+/// and an integer, written against [`format::Format`].
+macro_rules! unary_n {
+    ($(#[$doc:meta])* $name32:ident, $name64:ident, $imp:path) => {
+        $(#[$doc])*
+        #[must_use]
+        #[allow(clippy::cast_possible_truncation)]
+        pub const fn $name32(
+            a: $crate::softfloat::float32_t,
+            n: i64,
+            roundingMode: u8,
+            detectTininess: u8,
+        ) -> ($crate::softfloat::float32_t, u8) {
+            let (v, flags) = $imp(
+                $crate::math::format::Format::F32,
+                a.v as u64,
+                n,
+                roundingMode,
+                detectTininess,
+            );
+            ($crate::softfloat::float32_t { v: v as u32 }, flags)
+        }
+
+        $(#[$doc])*
+        #[must_use]
+        pub const fn $name64(
+            a: $crate::softfloat::float64_t,
+            n: i64,
+            roundingMode: u8,
+            detectTininess: u8,
+        ) -> ($crate::softfloat::float64_t, u8) {
+            let (v, flags) = $imp(
+                $crate::math::format::Format::F64,
+                a.v,
+                n,
+                roundingMode,
+                detectTininess,
+            );
+            ($crate::softfloat::float64_t { v }, flags)
+        }
+    };
+}
+
+mod accumulator;
+mod ball;
+mod compose;
+mod constant;
+mod consts;
+mod elementary;
+mod format;
+mod mp;
+mod ziv;
+
+mod erf;
+mod exponential;
+mod gamma;
+mod hyperbolic;
+mod inverse_trig;
+mod narrow;
+mod payload;
+mod power;
+mod precision;
+mod reduce;
+mod root;
+mod stochastic;
+mod trig;
+
+pub use accumulator::Accumulator;
+pub use compose::*;
+pub use constant::*;
+pub use erf::*;
+pub use exponential::*;
+pub use gamma::*;
+pub use hyperbolic::*;
+pub use inverse_trig::*;
+pub use narrow::*;
+pub use payload::*;
+pub use power::*;
+pub use precision::*;
+pub use reduce::*;
+pub use root::*;
+pub use stochastic::*;
+pub use trig::*;
@@ -0,0 +1,805 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Fixed-capacity multi-precision binary floating point.
+//!
+//! A value is `(-1)^neg × 0.d₀d₁…dₙ₋₁ × 2^exp` where `d` holds 64-bit limbs,
+//! most significant first, and `d[0]` has its top bit set unless the value is
+//! zero. Operations take the working precision `n` in limbs, expect their
+//! operands to have no set limbs at or beyond `n`, truncate their result
+//! towards zero and report whether it is inexact.
+
+pub const MAX_LIMBS: usize = 40;
+
+/// Scratch width for intermediate results, with room for guard limbs.
+const W: usize = MAX_LIMBS + 2;
+
+#[derive(Copy, Clone, Debug)]
+pub struct Mp {
+    pub neg: bool,
+    pub exp: i64,
+    pub d: [u64; MAX_LIMBS],
+}
+
+impl Mp {
+    pub const ZERO: Self = Self {
+        neg: false,
+        exp: 0,
+        d: [0; MAX_LIMBS],
+    };
+
+    pub const ONE: Self = Self::from_u64(1);
+
+    #[inline]
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.d[0] == 0
+    }
+
+    #[must_use]
+    pub const fn from_u64(v: u64) -> Self {
+        let mut r = Self::ZERO;
+        if v != 0 {
+            let s = v.leading_zeros();
+            r.d[0] = v << s;
+            r.exp = 64 - s as i64;
+        }
+        r
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn from_u128(v: u128) -> Self {
+        if (v >> 64) == 0 {
+            return Self::from_u64(v as u64);
+        }
+        let s = v.leading_zeros();
+        let w = v << s;
+        let mut r = Self::ZERO;
+        r.d[0] = (w >> 64) as u64;
+        r.d[1] = w as u64;
+        r.exp = 128 - s as i64;
+        r
+    }
+
+    /// `(-1)^neg × sig × 2^exp`
+    #[must_use]
+    pub const fn from_parts(neg: bool, sig: u64, exp: i64) -> Self {
+        let mut r = Self::from_u64(sig);
+        if sig != 0 {
+            r.neg = neg;
+            r.exp = r.exp.saturating_add(exp);
+        }
+        r
+    }
+
+    /// Exact value of a finite binary32 bit pattern.
+    #[must_use]
+    pub const fn from_f32(bits: u32) -> Self {
+        let exp = ((bits >> 23) & 0xFF) as i64;
+        let frac = (bits & 0x007F_FFFF) as u64;
+        let neg = (bits >> 31) != 0;
+        if exp == 0 {
+            Self::from_parts(neg, frac, -149)
+        } else {
+            Self::from_parts(neg, frac | 0x0080_0000, exp - 150)
+        }
+    }
+
+    /// Exact value of a finite binary64 bit pattern.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn from_f64(bits: u64) -> Self {
+        let exp = ((bits >> 52) & 0x7FF) as i64;
+        let frac = bits & 0x000F_FFFF_FFFF_FFFF;
+        let neg = (bits >> 63) != 0;
+        if exp == 0 {
+            Self::from_parts(neg, frac, -1074)
+        } else {
+            Self::from_parts(neg, frac | 0x0010_0000_0000_0000, exp - 1075)
+        }
+    }
+
+    /// Copy of the first `n` limbs of a constant table, as `0.d × 2^exp`.
+    #[must_use]
+    pub const fn from_limbs(limbs: &[u64; MAX_LIMBS], exp: i64, n: usize) -> Self {
+        let mut r = Self::ZERO;
+        let mut i = 0;
+        while i < n {
+            r.d[i] = limbs[i];
+            i += 1;
+        }
+        r.exp = exp;
+        r
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn neg(mut self) -> Self {
+        if !self.is_zero() {
+            self.neg = !self.neg;
+        }
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn abs(mut self) -> Self {
+        self.neg = false;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn mul_2exp(mut self, k: i64) -> Self {
+        if !self.is_zero() {
+            self.exp = self.exp.saturating_add(k);
+        }
+        self
+    }
+
+    /// Number of limbs up to and including the last non-zero one.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        let mut i = MAX_LIMBS;
+        while i > 0 && self.d[i - 1] == 0 {
+            i -= 1;
+        }
+        i
+    }
+
+    /// Truncates to `n` limbs, returning whether any set bits were dropped.
+    #[must_use]
+    pub const fn trunc(mut self, n: usize) -> (Self, bool) {
+        let mut inexact = false;
+        let mut i = n;
+        while i < MAX_LIMBS {
+            inexact |= self.d[i] != 0;
+            self.d[i] = 0;
+            i += 1;
+        }
+        (self, inexact)
+    }
+
+    /// Truncates the significand to `bits` bits, returning whether any set
+    /// bits were dropped.
+    #[must_use]
+    pub const fn trunc_bits(mut self, bits: u64) -> (Self, bool) {
+        let mut inexact = false;
+        let mut i = 0;
+        while i < MAX_LIMBS {
+            let lo = (i as u64) * 64;
+            if lo >= bits {
+                inexact |= self.d[i] != 0;
+                self.d[i] = 0;
+            } else if bits - lo < 64 {
+                let mask = u64::MAX >> (bits - lo);
+                inexact |= (self.d[i] & mask) != 0;
+                self.d[i] &= !mask;
+            }
+            i += 1;
+        }
+        (self, inexact)
+    }
+
+    /// Value of bit `i` of the significand, counting from the top.
+    #[inline]
+    #[must_use]
+    pub const fn bit(&self, i: u64) -> bool {
+        let limb = i / 64;
+        if limb >= MAX_LIMBS as u64 {
+            return false;
+        }
+        (self.d[limb as usize] >> (63 - (i % 64))) & 1 != 0
+    }
+
+    /// Whether any significand bit at or below position `i` (from the top) is set.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn any_from(&self, i: u64) -> bool {
+        let mut limb = i / 64;
+        if limb >= MAX_LIMBS as u64 {
+            return false;
+        }
+        if (self.d[limb as usize] & (u64::MAX >> (i % 64))) != 0 {
+            return true;
+        }
+        limb += 1;
+        while limb < MAX_LIMBS as u64 {
+            if self.d[limb as usize] != 0 {
+                return true;
+            }
+            limb += 1;
+        }
+        false
+    }
+
+    /// Compares magnitudes, returning -1, 0 or 1.
+    #[must_use]
+    pub const fn cmp_abs(a: &Self, b: &Self) -> i32 {
+        match (a.is_zero(), b.is_zero()) {
+            (true, true) => return 0,
+            (true, false) => return -1,
+            (false, true) => return 1,
+            (false, false) => {}
+        }
+        if a.exp != b.exp {
+            return if a.exp < b.exp { -1 } else { 1 };
+        }
+        let mut i = 0;
+        while i < MAX_LIMBS {
+            if a.d[i] != b.d[i] {
+                return if a.d[i] < b.d[i] { -1 } else { 1 };
+            }
+            i += 1;
+        }
+        0
+    }
+
+    /// Compares signed values, returning -1, 0 or 1.
+    #[must_use]
+    pub const fn cmp(a: &Self, b: &Self) -> i32 {
+        let sa = if a.is_zero() { false } else { a.neg };
+        let sb = if b.is_zero() { false } else { b.neg };
+        if sa != sb {
+            return if sa { -1 } else { 1 };
+        }
+        let c = Self::cmp_abs(a, b);
+        if sa {
+            -c
+        } else {
+            c
+        }
+    }
+
+    /// Whether the value is an integer.
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    pub const fn is_integer(&self) -> bool {
+        if self.is_zero() {
+            return true;
+        }
+        if self.exp <= 0 {
+            return false;
+        }
+        !self.any_from(self.exp as u64)
+    }
+
+    /// Nearest integer (ties away from zero), saturating at `±2^62`.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn nearest_i64(&self) -> i64 {
+        if self.is_zero() || self.exp < 0 {
+            return 0;
+        }
+        if self.exp > 62 {
+            return if self.neg { -(1 << 62) } else { 1 << 62 };
+        }
+        let v = if self.exp == 0 {
+            self.d[0] >> 63
+        } else {
+            let int = self.d[0] >> (64 - self.exp);
+            let half = (self.d[0] >> (63 - self.exp)) & 1;
+            int + half
+        } as i64;
+        if self.neg {
+            -v
+        } else {
+            v
+        }
+    }
+
+    /// Integer part (towards zero) of a value below `2^64`.
+    #[must_use]
+    pub const fn trunc_u64(&self) -> u64 {
+        if self.is_zero() || self.exp <= 0 {
+            return 0;
+        }
+        if self.exp >= 64 {
+            return u64::MAX;
+        }
+        self.d[0] >> (64 - self.exp)
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub const fn add(a: &Self, b: &Self, n: usize) -> (Self, bool) {
+        if b.is_zero() {
+            return a.trunc(n);
+        }
+        if a.is_zero() {
+            return b.trunc(n);
+        }
+        let (big, small) = if Self::cmp_abs(a, b) >= 0 {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        let len = n + 1;
+        let mut x = [0u64; W];
+        let mut i = 0;
+        while i < n {
+            x[i] = big.d[i];
+            i += 1;
+        }
+        let mut y = [0u64; W];
+        let shift = big.exp.wrapping_sub(small.exp) as u64;
+        let sticky = shr_into(&small.d, n, shift, &mut y, len);
+        let mut exp = big.exp;
+        if big.neg == small.neg {
+            if add_buf(&mut x, &y, len) {
+                let lost = (x[len - 1] & 1) != 0;
+                shr1_buf(&mut x, len);
+                x[0] |= 1 << 63;
+                exp = exp.saturating_add(1);
+                if lost {
+                    x[len - 1] |= 1;
+                }
+            }
+        } else {
+            sub_buf(&mut x, &y, len);
+            if sticky {
+                let mut one = [0u64; W];
+                one[len - 1] = 1;
+                sub_buf(&mut x, &one, len);
+            }
+            let lz = leading_zeros_buf(&x, len);
+            if lz >= 64 * len as u64 {
+                return (Self::ZERO, false);
+            }
+            shl_buf(&mut x, len, lz);
+            exp = exp.saturating_sub(lz as i64);
+        }
+        let mut r = Self::ZERO;
+        r.neg = big.neg;
+        r.exp = exp;
+        i = 0;
+        while i < n {
+            r.d[i] = x[i];
+            i += 1;
+        }
+        (r, x[n] != 0 || sticky)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn sub(a: &Self, b: &Self, n: usize) -> (Self, bool) {
+        Self::add(a, &b.neg(), n)
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn mul(a: &Self, b: &Self, n: usize) -> (Self, bool) {
+        if a.is_zero() || b.is_zero() {
+            return (Self::ZERO, false);
+        }
+        let na = min(a.len(), n);
+        let nb = min(b.len(), n);
+        let mut p = [0u64; 2 * MAX_LIMBS];
+        let mut i = na;
+        while i > 0 {
+            i -= 1;
+            if a.d[i] == 0 {
+                continue;
+            }
+            let mut carry = 0u64;
+            let mut j = nb;
+            while j > 0 {
+                j -= 1;
+                let t =
+                    (a.d[i] as u128) * (b.d[j] as u128) + (p[i + j + 1] as u128) + (carry as u128);
+                p[i + j + 1] = t as u64;
+                carry = (t >> 64) as u64;
+            }
+            p[i] = carry;
+        }
+        let mut exp = a.exp.saturating_add(b.exp);
+        let plen = na + nb;
+        if (p[0] >> 63) == 0 {
+            let mut k = 0;
+            while k < plen {
+                p[k] = (p[k] << 1) | if k + 1 < plen { p[k + 1] >> 63 } else { 0 };
+                k += 1;
+            }
+            exp = exp.saturating_sub(1);
+        }
+        let mut r = Self::ZERO;
+        r.neg = a.neg != b.neg;
+        r.exp = exp;
+        let mut inexact = false;
+        let mut k = 0;
+        while k < plen {
+            if k < n {
+                r.d[k] = p[k];
+            } else {
+                inexact |= p[k] != 0;
+            }
+            k += 1;
+        }
+        (r, inexact)
+    }
+
+    /// Quotient `a / b`; `b` must be non-zero.
+    #[must_use]
+    pub const fn div(a: &Self, b: &Self, n: usize) -> (Self, bool) {
+        if a.is_zero() || b.is_zero() {
+            return (Self::ZERO, !b.is_zero() || !a.is_zero());
+        }
+        if b.len() == 1 {
+            let (mut q, inexact) = Self::div_u64(a, b.d[0], n);
+            q.exp = q.exp.saturating_sub(b.exp).saturating_add(64);
+            q.neg = a.neg != b.neg;
+            return (q, inexact);
+        }
+        // Restoring division with an integer limb in front of each operand.
+        let len = n + 2;
+        let mut rem = [0u64; W];
+        let mut div = [0u64; W];
+        let mut i = 0;
+        while i < n {
+            rem[i + 1] = a.d[i];
+            div[i + 1] = b.d[i];
+            i += 1;
+        }
+        let mut q = [0u64; W];
+        let bits = 64 * (n as u64 + 1);
+        let mut k = 0;
+        while k < bits {
+            if cmp_buf(&rem, &div, len) >= 0 {
+                sub_buf(&mut rem, &div, len);
+                q[(k / 64) as usize] |= 1 << (63 - (k % 64));
+            }
+            shl_buf(&mut rem, len, 1);
+            k += 1;
+        }
+        let mut exp = a.exp.saturating_sub(b.exp).saturating_add(1);
+        let mut inexact = !is_zero_buf(&rem, len);
+        if (q[0] >> 63) == 0 {
+            shl_buf(&mut q, n + 1, 1);
+            exp = exp.saturating_sub(1);
+        }
+        inexact |= q[n] != 0;
+        let mut r = Self::ZERO;
+        r.neg = a.neg != b.neg;
+        r.exp = exp;
+        i = 0;
+        while i < n {
+            r.d[i] = q[i];
+            i += 1;
+        }
+        (r, inexact)
+    }
+
+    /// Quotient `a / k` for a non-zero integer `k`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub const fn div_u64(a: &Self, k: u64, n: usize) -> (Self, bool) {
+        if a.is_zero() || k == 0 {
+            return (Self::ZERO, k == 0);
+        }
+        let len = n + 2;
+        let mut q = [0u64; W];
+        let mut rem: u128 = 0;
+        let mut i = 0;
+        while i < len {
+            let limb = if i < n { a.d[i] } else { 0 };
+            let cur = (rem << 64) | (limb as u128);
+            q[i] = (cur / (k as u128)) as u64;
+            rem = cur % (k as u128);
+            i += 1;
+        }
+        let lz = leading_zeros_buf(&q, len);
+        shl_buf(&mut q, len, lz);
+        let mut r = Self::ZERO;
+        r.neg = a.neg;
+        r.exp = a.exp.saturating_sub(lz as i64);
+        let mut inexact = rem != 0;
+        i = 0;
+        while i < len {
+            if i < n {
+                r.d[i] = q[i];
+            } else {
+                inexact |= q[i] != 0;
+            }
+            i += 1;
+        }
+        (r, inexact)
+    }
+
+    /// Product `a × k`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub const fn mul_u64(a: &Self, k: u64, n: usize) -> (Self, bool) {
+        if a.is_zero() || k == 0 {
+            return (Self::ZERO, false);
+        }
+        let len = n + 1;
+        let mut p = [0u64; W];
+        let mut carry = 0u64;
+        let mut i = n;
+        while i > 0 {
+            i -= 1;
+            let t = (a.d[i] as u128) * (k as u128) + (carry as u128);
+            p[i + 1] = t as u64;
+            carry = (t >> 64) as u64;
+        }
+        p[0] = carry;
+        let lz = leading_zeros_buf(&p, len);
+        shl_buf(&mut p, len, lz);
+        let mut r = Self::ZERO;
+        r.neg = a.neg;
+        r.exp = a.exp.saturating_add(64 - lz as i64);
+        i = 0;
+        while i < n {
+            r.d[i] = p[i];
+            i += 1;
+        }
+        (r, p[n] != 0)
+    }
+
+    /// Square root of a non-negative value.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn sqrt(a: &Self, n: usize) -> (Self, bool) {
+        if a.is_zero() {
+            return (Self::ZERO, false);
+        }
+        // Make the exponent even so that the radicand is 0.m or 0.0m.
+        let odd = (a.exp & 1) != 0;
+        let pre: u64 = if odd { 1 } else { 0 };
+        let mut rem = [0u64; W];
+        let mut root = [0u64; W];
+        let len = n + 2;
+        let bits = 64 * n as u64;
+        let mut k = 0;
+        while k < bits {
+            // rem = rem * 4 + next two radicand bits
+            shl_buf(&mut rem, len, 2);
+            let hi = 2 * k;
+            let b1 = if hi >= pre && a.bit(hi - pre) { 2 } else { 0 };
+            let b0 = if hi + 1 >= pre && a.bit(hi + 1 - pre) {
+                1
+            } else {
+                0
+            };
+            rem[len - 1] |= b1 | b0;
+            // trial = root * 4 + 1
+            let mut trial = root;
+            shl_buf(&mut trial, len, 2);
+            trial[len - 1] |= 1;
+            shl_buf(&mut root, len, 1);
+            if cmp_buf(&rem, &trial, len) >= 0 {
+                sub_buf(&mut rem, &trial, len);
+                root[len - 1] |= 1;
+            }
+            k += 1;
+        }
+        // root holds 64n bits right-aligned in `len` limbs.
+        let mut r = Self::ZERO;
+        r.exp = (a.exp + pre as i64) >> 1;
+        let mut i = 0;
+        while i < n {
+            r.d[i] = root[i + 2];
+            i += 1;
+        }
+        let inexact = !is_zero_buf(&rem, len) || a.any_from(2 * bits - pre);
+        (r, inexact)
+    }
+
+    /// Rounds the magnitude to `bits` significant bits, ties away from zero.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn round_bits(&self, bits: u64) -> Self {
+        if self.is_zero() {
+            return *self;
+        }
+        let half = Self::from_parts(self.neg, 1, self.exp - bits as i64 - 1);
+        let (sum, _) = Self::add(self, &half, MAX_LIMBS);
+        let (r, _) = sum.trunc_bits(bits);
+        r
+    }
+
+    /// Number of significant bits, up to and including the lowest set one.
+    #[must_use]
+    pub const fn bits(&self) -> u64 {
+        let len = self.len();
+        if len == 0 {
+            return 0;
+        }
+        64 * len as u64 - self.d[len - 1].trailing_zeros() as u64
+    }
+
+    /// `a^k` computed exactly, or `None` if it does not fit in `MAX_LIMBS`
+    /// limbs or far exceeds the range of any format.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn pow_u64(a: &Self, k: u64) -> Option<Self> {
+        if k == 0 {
+            return Some(Self::ONE);
+        }
+        if a.is_zero() {
+            return Some(Self::ZERO);
+        }
+        let neg = a.neg && (k & 1) != 0;
+        if a.bits() == 1 {
+            // A power of two only moves the exponent.
+            let e = (a.exp as i128 - 1) * k as i128;
+            if e > (1 << 40) || e < -(1 << 40) {
+                return None;
+            }
+            return Some(Self::from_parts(neg, 1, e as i64));
+        }
+        // Every factor adds at least one bit to the odd part.
+        if k > 64 * MAX_LIMBS as u64 {
+            return None;
+        }
+        let mut base = a.abs();
+        let mut r = Self::ONE;
+        let mut k = k;
+        loop {
+            if (k & 1) != 0 {
+                let (p, inexact) = Self::mul(&r, &base, MAX_LIMBS);
+                if inexact {
+                    return None;
+                }
+                r = p;
+            }
+            k >>= 1;
+            if k == 0 {
+                break;
+            }
+            let (sq, inexact) = Self::mul(&base, &base, MAX_LIMBS);
+            if inexact {
+                return None;
+            }
+            base = sq;
+        }
+        r.neg = neg;
+        Some(r)
+    }
+
+    /// Whether `a^i = b^j`, or with `recip` whether `a^i b^j = 1`, exactly.
+    #[must_use]
+    pub const fn pow_eq(a: &Self, i: u64, b: &Self, j: u64, recip: bool) -> bool {
+        let (Some(l), Some(r)) = (Self::pow_u64(a, i), Self::pow_u64(b, j)) else {
+            return false;
+        };
+        if !recip {
+            return Self::cmp(&l, &r) == 0;
+        }
+        let (p, inexact) = Self::mul(&l, &r, MAX_LIMBS);
+        !inexact && Self::cmp(&p, &Self::ONE) == 0
+    }
+}
+
+#[inline]
+const fn min(a: usize, b: usize) -> usize {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Shifts `src[0..n]` right by `shift` bits into `dst[0..len]`, returning
+/// whether any set bits fell off the end.
+#[allow(clippy::cast_possible_truncation)]
+const fn shr_into(
+    src: &[u64; MAX_LIMBS],
+    n: usize,
+    shift: u64,
+    dst: &mut [u64; W],
+    len: usize,
+) -> bool {
+    let ws = shift / 64;
+    let bs = shift % 64;
+    let mut sticky = false;
+    let mut j = 0;
+    while j < n {
+        let v = src[j];
+        if v != 0 {
+            let k = (j as u64).saturating_add(ws);
+            let hi = v >> bs;
+            let lo = if bs == 0 { 0 } else { v << (64 - bs) };
+            if k < len as u64 {
+                dst[k as usize] |= hi;
+            } else {
+                sticky |= hi != 0;
+            }
+            if k + 1 < len as u64 {
+                dst[(k + 1) as usize] |= lo;
+            } else {
+                sticky |= lo != 0;
+            }
+        }
+        j += 1;
+    }
+    sticky
+}
+
+const fn add_buf(x: &mut [u64; W], y: &[u64; W], len: usize) -> bool {
+    let mut carry = false;
+    let mut i = len;
+    while i > 0 {
+        i -= 1;
+        let (s1, c1) = x[i].overflowing_add(y[i]);
+        let (s2, c2) = s1.overflowing_add(carry as u64);
+        x[i] = s2;
+        carry = c1 || c2;
+    }
+    carry
+}
+
+const fn sub_buf(x: &mut [u64; W], y: &[u64; W], len: usize) -> bool {
+    let mut borrow = false;
+    let mut i = len;
+    while i > 0 {
+        i -= 1;
+        let (s1, b1) = x[i].overflowing_sub(y[i]);
+        let (s2, b2) = s1.overflowing_sub(borrow as u64);
+        x[i] = s2;
+        borrow = b1 || b2;
+    }
+    borrow
+}
+
+const fn cmp_buf(x: &[u64; W], y: &[u64; W], len: usize) -> i32 {
+    let mut i = 0;
+    while i < len {
+        if x[i] != y[i] {
+            return if x[i] < y[i] { -1 } else { 1 };
+        }
+        i += 1;
+    }
+    0
+}
+
+const fn is_zero_buf(x: &[u64; W], len: usize) -> bool {
+    let mut i = 0;
+    while i < len {
+        if x[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn leading_zeros_buf(x: &[u64; W], len: usize) -> u64 {
+    let mut i = 0;
+    while i < len {
+        if x[i] != 0 {
+            return 64 * i as u64 + x[i].leading_zeros() as u64;
+        }
+        i += 1;
+    }
+    64 * len as u64
+}
+
+const fn shl_buf(x: &mut [u64; W], len: usize, shift: u64) {
+    if shift == 0 {
+        return;
+    }
+    let ws = (shift / 64) as usize;
+    let bs = (shift % 64) as u32;
+    let mut i = 0;
+    while i < len {
+        let src = i + ws;
+        let hi = if src < len { x[src] } else { 0 };
+        let lo = if src + 1 < len { x[src + 1] } else { 0 };
+        x[i] = if bs == 0 {
+            hi
+        } else {
+            (hi << bs) | (lo >> (64 - bs))
+        };
+        i += 1;
+    }
+}
+
+const fn shr1_buf(x: &mut [u64; W], len: usize) {
+    let mut i = len;
+    while i > 0 {
+        i -= 1;
+        let above = if i > 0 { x[i - 1] << 63 } else { 0 };
+        x[i] = (x[i] >> 1) | above;
+    }
+}
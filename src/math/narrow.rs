@@ -0,0 +1,333 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Arithmetic on `float64_t` operands rounded once to `float32_t` (IEEE 754
+//! formatOf operations, C23 `fadd`, `fsub`, `fmul`, `fdiv`, `fsqrt` and
+//! `ffma`).
+//!
+//! The operation is first evaluated in binary64 rounded to odd, which keeps
+//! more than two extra bits and a sticky bit, so the following conversion to
+//! binary32 rounds as if from the exact result. Only the invalid and
+//! divide-by-zero flags of the first step are kept; overflow, underflow and
+//! inexact come from the conversion. An exact zero is recomputed in the
+//! requested rounding mode, which decides its sign.
+
+use crate::softfloat::{
+    f64_add, f64_div, f64_mul, f64_mulAdd, f64_sqrt, f64_sub, f64_to_f32, float32_t, float64_t,
+    softfloat_flag_infinite, softfloat_flag_invalid, softfloat_round_odd,
+};
+
+const fn narrow(z: float64_t, flags: u8, roundingMode: u8, detectTininess: u8) -> (float32_t, u8) {
+    let (r, narrowed) = f64_to_f32(z, roundingMode, detectTininess);
+    (
+        r,
+        (flags & (softfloat_flag_invalid | softfloat_flag_infinite)) | narrowed,
+    )
+}
+
+const fn is_zero(z: float64_t) -> bool {
+    (z.v << 1) == 0
+}
+
+/// `a + b` rounded once to `float32_t`.
+#[must_use]
+pub const fn f32_addF64(
+    a: float64_t,
+    b: float64_t,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (mut z, mut flags) = f64_add(a, b, softfloat_round_odd, detectTininess);
+    if is_zero(z) {
+        (z, flags) = f64_add(a, b, roundingMode, detectTininess);
+    }
+    narrow(z, flags, roundingMode, detectTininess)
+}
+
+/// `a - b` rounded once to `float32_t`.
+#[must_use]
+pub const fn f32_subF64(
+    a: float64_t,
+    b: float64_t,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (mut z, mut flags) = f64_sub(a, b, softfloat_round_odd, detectTininess);
+    if is_zero(z) {
+        (z, flags) = f64_sub(a, b, roundingMode, detectTininess);
+    }
+    narrow(z, flags, roundingMode, detectTininess)
+}
+
+/// `a × b` rounded once to `float32_t`.
+#[must_use]
+pub const fn f32_mulF64(
+    a: float64_t,
+    b: float64_t,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (z, flags) = f64_mul(a, b, softfloat_round_odd, detectTininess);
+    narrow(z, flags, roundingMode, detectTininess)
+}
+
+/// `a / b` rounded once to `float32_t`.
+#[must_use]
+pub const fn f32_divF64(
+    a: float64_t,
+    b: float64_t,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (z, flags) = f64_div(a, b, softfloat_round_odd, detectTininess);
+    narrow(z, flags, roundingMode, detectTininess)
+}
+
+/// `√a` rounded once to `float32_t`.
+#[must_use]
+pub const fn f32_sqrtF64(a: float64_t, roundingMode: u8, detectTininess: u8) -> (float32_t, u8) {
+    let (z, flags) = f64_sqrt(a, softfloat_round_odd, detectTininess);
+    narrow(z, flags, roundingMode, detectTininess)
+}
+
+/// `a × b + c` rounded once to `float32_t`.
+#[must_use]
+pub const fn f32_mulAddF64(
+    a: float64_t,
+    b: float64_t,
+    c: float64_t,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (mut z, mut flags) = f64_mulAdd(a, b, c, softfloat_round_odd, detectTininess);
+    if is_zero(z) {
+        (z, flags) = f64_mulAdd(a, b, c, roundingMode, detectTininess);
+    }
+    narrow(z, flags, roundingMode, detectTininess)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Op = fn(float64_t, float64_t, float64_t, u8, u8) -> (float32_t, u8);
+
+    #[test]
+    fn test_f32_narrowing() {
+        struct TestCase {
+            f: Op,
+            a: u64,
+            b: u64,
+            c: u64,
+            result: u32,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        // The first twelve are rounded wrongly by computing in binary64 and
+        // converting.
+        let cases = [
+            TestCase {
+                f: |a, b, _, rm, tin| f32_mulF64(a, b, rm, tin),
+                a: 0xC330_4C05_0BA2_E8BA,
+                b: 0x4066_0000_0000_0000,
+                c: 0x0,
+                result: 0xDD33_4437,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, b, _, rm, tin| f32_mulF64(a, b, rm, tin),
+                a: 0x43EF_62B3_0F0F_0F0F,
+                b: 0x3FF9_8000_0000_0000,
+                c: 0x0,
+                result: 0x5FC8_1535,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_mulAddF64,
+                a: 0x3B17_98CF_B7E3_27A9,
+                b: 0x4051_C000_0000_0000,
+                c: 0x3820_7FFF_FFFF_FFFE,
+                result: 0x1BD1_6C33,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_mulAddF64,
+                a: 0x39A9_0AF8_FCAF_D20F,
+                b: 0x4092_1C00_0000_0000,
+                c: 0xB6EE_9000_0000_0001,
+                result: 0x1262_C15A,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, _, rm, tin| f32_sqrtF64(a, rm, tin),
+                a: 0x3A77_50D2_BF3D_058F,
+                b: 0x0,
+                c: 0x0,
+                result: 0x299A_842D,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, _, rm, tin| f32_sqrtF64(a, rm, tin),
+                a: 0x3719_0C3D_D743_C60F,
+                b: 0x0,
+                c: 0x0,
+                result: 0x1C20_2727,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, b, _, rm, tin| f32_addF64(a, b, rm, tin),
+                a: 0xC3BF_EC1D_B000_0000,
+                b: 0x3F9E_6BEE_FD76_AB00,
+                c: 0x0,
+                result: 0xDDFF_60ED,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, b, _, rm, tin| f32_addF64(a, b, rm, tin),
+                a: 0xB849_A452_3000_0000,
+                b: 0x33D4_291C_4BAB_E700,
+                c: 0x0,
+                result: 0x824D_2291,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, b, _, rm, tin| f32_subF64(a, b, rm, tin),
+                a: 0x38D5_C1CF_1000_0000,
+                b: 0xB564_ACA9_4561_3000,
+                c: 0x0,
+                result: 0x6AE_0E79,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: |a, b, _, rm, tin| f32_subF64(a, b, rm, tin),
+                a: 0x3E8D_A06A_D000_0000,
+                b: 0x3998_8993_78E2_4100,
+                c: 0x0,
+                result: 0x346D_0356,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, b, _, rm, tin| f32_divF64(a, b, rm, tin),
+                a: 0x3FF8_6063_2556_F2B7,
+                b: 0x3FF2_69E0_F2A7_4DE4,
+                c: 0x0,
+                result: 0x3FA9_735A,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, b, _, rm, tin| f32_divF64(a, b, rm, tin),
+                a: 0x3FFB_B55A_9567_71E7,
+                b: 0x3FF9_5319_5D9D_C9F8,
+                c: 0x0,
+                result: 0x3F8C_0C75,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, b, _, rm, tin| f32_addF64(a, b, rm, tin),
+                a: 0x3FF0_0000_0000_0000,
+                b: 0xBFF0_0000_0000_0000,
+                c: 0x0,
+                result: 0x8000_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, b, _, rm, tin| f32_subF64(a, b, rm, tin),
+                a: 0x3FF0_0000_0000_0000,
+                b: 0x3FF0_0000_0000_0000,
+                c: 0x0,
+                result: 0x0,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_mulAddF64,
+                a: 0x3FF0_0000_0000_0000,
+                b: 0x3FF0_0000_0000_0000,
+                c: 0xBFF0_0000_0000_0000,
+                result: 0x8000_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, b, _, rm, tin| f32_divF64(a, b, rm, tin),
+                a: 0x3FF0_0000_0000_0000,
+                b: 0x0,
+                c: 0x0,
+                result: 0x7F80_0000,
+                flags: 8,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, _, rm, tin| f32_sqrtF64(a, rm, tin),
+                a: 0xBFF0_0000_0000_0000,
+                b: 0x0,
+                c: 0x0,
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, b, _, rm, tin| f32_mulF64(a, b, rm, tin),
+                a: 0x7FEF_FFFF_FFFF_FFFF,
+                b: 0x4000_0000_0000_0000,
+                c: 0x0,
+                result: 0x7F80_0000,
+                flags: 5,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, b, _, rm, tin| f32_mulF64(a, b, rm, tin),
+                a: 0x3730_0000_0000_0000,
+                b: 0x3FF8_0000_0000_0001,
+                c: 0x0,
+                result: 0x300,
+                flags: 3,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(
+                float64_t { v: c.a },
+                float64_t { v: c.b },
+                float64_t { v: c.c },
+                c.roundingMode,
+                c.detectTininess,
+            );
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+}
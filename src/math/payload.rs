@@ -0,0 +1,329 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! NaN payloads (IEEE 754 §9.7, C23 `getpayload`, `setpayload` and
+//! `setpayloadsig`) and the canonical encoding operations of §5.5.1 and
+//! §5.7.2.
+//!
+//! The payload of a NaN is its fraction without the most significant bit,
+//! whose meaning follows `softfloat_quietBitSet` of the active
+//! specialization. Payloads are read and written as floating-point integers,
+//! from 0 up to `2^22 - 1` for `float32_t` and `2^51 - 1` for `float64_t`.
+//! None of these operations raise exceptions except `canonicalize` of a
+//! signaling NaN.
+
+use super::format::Format;
+use crate::softfloat::{float32_t, float64_t, softfloat_quietBitSet};
+
+/// NaN of the requested kind carrying `payload`, if it is encodable: a
+/// NaN needs a non-zero fraction, so one of the two kinds has no zero
+/// payload.
+const fn make_nan(fmt: Format, signaling: bool, payload: u64) -> Option<u64> {
+    if payload >= fmt.quiet_bit() {
+        return None;
+    }
+    let q = if signaling == softfloat_quietBitSet {
+        0
+    } else {
+        fmt.quiet_bit()
+    };
+    if q | payload == 0 {
+        return None;
+    }
+    Some(fmt.inf(false) | q | payload)
+}
+
+const fn get_payload(fmt: Format, a: u64) -> u64 {
+    if !fmt.is_nan(a) {
+        return fmt.one(true);
+    }
+    let p = a & (fmt.quiet_bit() - 1);
+    if p == 0 {
+        return 0;
+    }
+    // Payloads are narrower than the significand, so this is exact.
+    let n = 63 - p.leading_zeros() as u64;
+    let bias = fmt.max_biased_exp() >> 1;
+    let frac = (p << (fmt.precision() - 1 - n)) & (fmt.quiet_bit() * 2 - 1);
+    ((bias + n) << (fmt.precision() - 1)) | frac
+}
+
+const fn set_payload(fmt: Format, a: u64, signaling: bool) -> u64 {
+    let zero = fmt.zero(false);
+    if fmt.sign(a) {
+        return zero;
+    }
+    let p = if fmt.is_zero(a) {
+        0
+    } else {
+        let e = a >> (fmt.precision() - 1);
+        let bias = fmt.max_biased_exp() >> 1;
+        // Subnormals and values below one are not integers, and values from
+        // `2^(precision - 2)` on, infinities and NaNs are too large.
+        if e < bias || e - bias >= fmt.precision() - 2 {
+            return zero;
+        }
+        let k = fmt.precision() - 1 - (e - bias);
+        let m = (a & (fmt.quiet_bit() * 2 - 1)) | (fmt.quiet_bit() * 2);
+        if (m & ((1 << k) - 1)) != 0 {
+            return zero;
+        }
+        m >> k
+    };
+    match make_nan(fmt, signaling, p) {
+        Some(v) => v,
+        None => zero,
+    }
+}
+
+const fn canonicalize(fmt: Format, a: u64) -> (u64, u8) {
+    if fmt.is_signaling_nan(a) {
+        fmt.propagate_nan(a, a)
+    } else {
+        (a, 0)
+    }
+}
+
+/// The payload of `a` as a non-negative integer, or `-1` if `a` is not a
+/// NaN (getPayload).
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_getPayload(a: float32_t) -> float32_t {
+    float32_t {
+        v: get_payload(Format::F32, a.v as u64) as u32,
+    }
+}
+
+/// A quiet NaN with payload `a`, or `+0` if `a` is not an admissible
+/// payload (setPayload).
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_setPayload(a: float32_t) -> float32_t {
+    float32_t {
+        v: set_payload(Format::F32, a.v as u64, false) as u32,
+    }
+}
+
+/// A signaling NaN with payload `a`, or `+0` if `a` is not an admissible
+/// payload (setPayloadSignaling).
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_setPayloadSignaling(a: float32_t) -> float32_t {
+    float32_t {
+        v: set_payload(Format::F32, a.v as u64, true) as u32,
+    }
+}
+
+/// The canonical encoding of `a`, which is `a` itself for every binary
+/// encoding; a signaling NaN is quieted as by any operation, raising
+/// invalid.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_canonicalize(a: float32_t) -> (float32_t, u8) {
+    let (v, flags) = canonicalize(Format::F32, a.v as u64);
+    (float32_t { v: v as u32 }, flags)
+}
+
+/// Whether `a` is a canonical encoding, which every binary32 encoding is
+/// (isCanonical).
+#[must_use]
+pub const fn f32_isCanonical(_a: float32_t) -> bool {
+    true
+}
+
+/// A quiet NaN with the given raw payload, or `None` if it does not fit or
+/// the kind has no zero payload.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_quietNaN(payload: u32) -> Option<float32_t> {
+    match make_nan(Format::F32, false, payload as u64) {
+        Some(v) => Some(float32_t { v: v as u32 }),
+        None => None,
+    }
+}
+
+/// A signaling NaN with the given raw payload, or `None` if it does not fit
+/// or the kind has no zero payload.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_signalingNaN(payload: u32) -> Option<float32_t> {
+    match make_nan(Format::F32, true, payload as u64) {
+        Some(v) => Some(float32_t { v: v as u32 }),
+        None => None,
+    }
+}
+
+/// The payload of `a` as a non-negative integer, or `-1` if `a` is not a
+/// NaN (getPayload).
+#[must_use]
+pub const fn f64_getPayload(a: float64_t) -> float64_t {
+    float64_t {
+        v: get_payload(Format::F64, a.v),
+    }
+}
+
+/// A quiet NaN with payload `a`, or `+0` if `a` is not an admissible
+/// payload (setPayload).
+#[must_use]
+pub const fn f64_setPayload(a: float64_t) -> float64_t {
+    float64_t {
+        v: set_payload(Format::F64, a.v, false),
+    }
+}
+
+/// A signaling NaN with payload `a`, or `+0` if `a` is not an admissible
+/// payload (setPayloadSignaling).
+#[must_use]
+pub const fn f64_setPayloadSignaling(a: float64_t) -> float64_t {
+    float64_t {
+        v: set_payload(Format::F64, a.v, true),
+    }
+}
+
+/// The canonical encoding of `a`, which is `a` itself for every binary
+/// encoding; a signaling NaN is quieted as by any operation, raising
+/// invalid.
+#[must_use]
+pub const fn f64_canonicalize(a: float64_t) -> (float64_t, u8) {
+    let (v, flags) = canonicalize(Format::F64, a.v);
+    (float64_t { v }, flags)
+}
+
+/// Whether `a` is a canonical encoding, which every binary64 encoding is
+/// (isCanonical).
+#[must_use]
+pub const fn f64_isCanonical(_a: float64_t) -> bool {
+    true
+}
+
+/// A quiet NaN with the given raw payload, or `None` if it does not fit or
+/// the kind has no zero payload.
+#[must_use]
+pub const fn f64_quietNaN(payload: u64) -> Option<float64_t> {
+    match make_nan(Format::F64, false, payload) {
+        Some(v) => Some(float64_t { v }),
+        None => None,
+    }
+}
+
+/// A signaling NaN with the given raw payload, or `None` if it does not fit
+/// or the kind has no zero payload.
+#[must_use]
+pub const fn f64_signalingNaN(payload: u64) -> Option<float64_t> {
+    match make_nan(Format::F64, true, payload) {
+        Some(v) => Some(float64_t { v }),
+        None => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::softfloat::softfloat_flag_invalid;
+
+    #[test]
+    fn test_f32_payload() {
+        struct TestCase {
+            f: fn(float32_t) -> float32_t,
+            a: u32,
+            result: u32,
+        }
+
+        let cases = [
+            TestCase {
+                f: f32_getPayload,
+                a: 0x7FC0_0000,
+                result: 0x0,
+            },
+            TestCase {
+                f: f32_getPayload,
+                a: 0xFFC0_0005,
+                result: 0x40A0_0000,
+            },
+            TestCase {
+                f: f32_getPayload,
+                a: 0x7FBF_FFFF,
+                result: 0x4A7F_FFFC,
+            },
+            TestCase {
+                f: f32_getPayload,
+                a: 0x7F80_0000,
+                result: 0xBF80_0000,
+            },
+            TestCase {
+                f: f32_setPayload,
+                a: 0x40A0_0000,
+                result: 0x7FC0_0005,
+            },
+            TestCase {
+                f: f32_setPayload,
+                a: 0x0,
+                result: 0x7FC0_0000,
+            },
+            TestCase {
+                f: f32_setPayload,
+                a: 0x4A7F_FFFC,
+                result: 0x7FFF_FFFF,
+            },
+            TestCase {
+                f: f32_setPayload,
+                a: 0x4A80_0000,
+                result: 0x0,
+            },
+            TestCase {
+                f: f32_setPayload,
+                a: 0x3FC0_0000,
+                result: 0x0,
+            },
+            TestCase {
+                f: f32_setPayload,
+                a: 0xC0A0_0000,
+                result: 0x0,
+            },
+            TestCase {
+                f: f32_setPayload,
+                a: 0x7FC0_0005,
+                result: 0x0,
+            },
+            TestCase {
+                f: f32_setPayloadSignaling,
+                a: 0x40A0_0000,
+                result: 0x7F80_0005,
+            },
+            TestCase {
+                f: f32_setPayloadSignaling,
+                a: 0x0,
+                result: 0x0,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let res = (c.f)(float32_t { v: c.a });
+            assert_eq!((i, res.v), (i, c.result));
+        }
+    }
+
+    #[test]
+    fn test_f64_payload() {
+        let payload = float64_t {
+            v: 0x431F_FFFF_FFFF_FFFC,
+        };
+        let nan = f64_setPayloadSignaling(payload);
+        assert_eq!(nan.v, 0x7FF7_FFFF_FFFF_FFFF);
+        assert_eq!(f64_getPayload(nan), payload);
+        assert_eq!(f64_getPayload(f64_setPayload(payload)), payload);
+        assert_eq!(
+            f64_canonicalize(nan),
+            (
+                float64_t {
+                    v: 0x7FF8_0000_0000_0000
+                },
+                softfloat_flag_invalid
+            )
+        );
+        let quiet = f64_quietNaN(1).unwrap();
+        assert_eq!(f64_canonicalize(quiet), (quiet, 0));
+        assert!(f64_isCanonical(quiet));
+        assert_eq!(f64_signalingNaN(0), None);
+        assert_eq!(f64_quietNaN(1 << 51), None);
+    }
+}
@@ -0,0 +1,1046 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Powers: `pow`, `pown`, `powr` and `compound`.
+
+use super::ball::Ball;
+use super::elementary::{exp, log, log1p};
+use super::format::Format;
+use super::mp::{Mp, MAX_LIMBS};
+use super::ziv::{round_exp_near_one, ziv};
+
+/// Whether `y` is an odd integer.
+#[allow(clippy::cast_possible_wrap)]
+const fn is_odd_integer(y: &Mp) -> bool {
+    !y.is_zero() && y.bits() as i64 == y.exp
+}
+
+const fn signed(b: Ball, neg: bool) -> Ball {
+    if neg {
+        b.neg()
+    } else {
+        b
+    }
+}
+
+/// Whether `c = x^y` exactly, for positive `c` and `x`.
+#[allow(clippy::cast_possible_wrap)]
+const fn is_pow(c: &Mp, x: &Mp, y: &Mp) -> bool {
+    // y = q / 2^k with q odd, and c = x^y exactly when c^(2^k) = x^q.
+    let k = y.bits() as i64 - y.exp;
+    if k <= 0 {
+        if y.exp > 63 {
+            return false;
+        }
+        return Mp::pow_eq(c, 1, x, y.trunc_u64(), y.neg);
+    }
+    if k >= 63 {
+        return false;
+    }
+    let q = y.abs().mul_2exp(k).trunc_u64();
+    Mp::pow_eq(c, 1 << k, x, q, y.neg)
+}
+
+/// `x^y` for positive finite `x`, as `e^(y ln x)`.
+const fn pow_ball(x: &Mp, y: &Mp, n: usize) -> Ball {
+    let w = n + 1;
+    let l = log(&Ball::exact(*x), w);
+    exp(&Ball::mul(&l, &Ball::exact(*y), w), n)
+}
+
+/// `±x^y` for positive finite `x` and finite `y`.
+const fn power(fmt: Format, x: &Mp, y: &Mp, neg: bool, rnd: u8, tin: u8) -> (u64, u8) {
+    let z = Ball::mul(&log(&Ball::exact(*x), 2), &Ball::exact(*y), 2);
+    if let Some(r) = round_exp_near_one(&z, neg, fmt, rnd, tin) {
+        return r;
+    }
+    ziv!(fmt, rnd, tin, |n| signed(pow_ball(x, y, n), neg), |c| {
+        is_pow(&c.abs(), x, y)
+    })
+}
+
+const fn pow(fmt: Format, a: u64, b: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_signaling_nan(a) || fmt.is_signaling_nan(b) {
+        return fmt.propagate_nan(a, b);
+    }
+    if fmt.is_zero(b) || a == fmt.one(false) {
+        return (fmt.one(false), 0);
+    }
+    if fmt.is_nan(a) || fmt.is_nan(b) {
+        return fmt.propagate_nan(a, b);
+    }
+    if fmt.is_inf(b) {
+        let mag = fmt.abs(a);
+        if mag == fmt.one(false) {
+            return (fmt.one(false), 0);
+        }
+        return if (mag > fmt.one(false)) == fmt.sign(b) {
+            (fmt.zero(false), 0)
+        } else {
+            (fmt.inf(false), 0)
+        };
+    }
+    let y = fmt.to_mp(b);
+    let neg = fmt.sign(a) && is_odd_integer(&y);
+    if fmt.is_zero(a) {
+        if y.neg {
+            return fmt.pole(neg);
+        }
+        return (fmt.zero(neg), 0);
+    }
+    if fmt.is_inf(a) {
+        return if y.neg {
+            (fmt.zero(neg), 0)
+        } else {
+            (fmt.inf(neg), 0)
+        };
+    }
+    let x = fmt.to_mp(a);
+    if x.neg && !y.is_integer() {
+        return fmt.invalid();
+    }
+    power(fmt, &x.abs(), &y, neg, rnd, tin)
+}
+
+const fn pown(fmt: Format, a: u64, n: i64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_signaling_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if n == 0 {
+        return (fmt.one(false), 0);
+    }
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    let neg = fmt.sign(a) && (n & 1) != 0;
+    if fmt.is_zero(a) {
+        if n < 0 {
+            return fmt.pole(neg);
+        }
+        return (fmt.zero(neg), 0);
+    }
+    if fmt.is_inf(a) {
+        return if n < 0 {
+            (fmt.zero(neg), 0)
+        } else {
+            (fmt.inf(neg), 0)
+        };
+    }
+    let x = fmt.to_mp(a).abs();
+    let y = Mp::from_parts(n < 0, n.unsigned_abs(), 0);
+    power(fmt, &x, &y, neg, rnd, tin)
+}
+
+const fn powr(fmt: Format, a: u64, b: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) || fmt.is_nan(b) {
+        return fmt.propagate_nan(a, b);
+    }
+    if fmt.is_zero(a) {
+        if fmt.is_zero(b) {
+            return fmt.invalid();
+        }
+        if !fmt.sign(b) {
+            return (fmt.zero(false), 0);
+        }
+        if fmt.is_inf(b) {
+            return (fmt.inf(false), 0);
+        }
+        return fmt.pole(false);
+    }
+    if fmt.sign(a) {
+        return fmt.invalid();
+    }
+    if fmt.is_inf(a) {
+        if fmt.is_zero(b) {
+            return fmt.invalid();
+        }
+        return if fmt.sign(b) {
+            (fmt.zero(false), 0)
+        } else {
+            (fmt.inf(false), 0)
+        };
+    }
+    if a == fmt.one(false) {
+        if fmt.is_inf(b) {
+            return fmt.invalid();
+        }
+        return (fmt.one(false), 0);
+    }
+    if fmt.is_zero(b) {
+        return (fmt.one(false), 0);
+    }
+    if fmt.is_inf(b) {
+        return if (a > fmt.one(false)) == fmt.sign(b) {
+            (fmt.zero(false), 0)
+        } else {
+            (fmt.inf(false), 0)
+        };
+    }
+    power(fmt, &fmt.to_mp(a), &fmt.to_mp(b), false, rnd, tin)
+}
+
+/// `(1 + x)^n` as `e^(n ln(1 + x))`.
+const fn compound_ball(x: &Mp, n: i64, k: usize) -> Ball {
+    let w = k + 1;
+    let l = log1p(&Ball::exact(*x), w);
+    exp(&Ball::mul_i64(&l, n, w), k)
+}
+
+const fn compound(fmt: Format, a: u64, n: i64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_signaling_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_nan(a) {
+        if n == 0 {
+            return (fmt.one(false), 0);
+        }
+        return fmt.propagate_nan(a, 0);
+    }
+    let minus_one = fmt.one(true);
+    if fmt.sign(a) && fmt.abs(a) > fmt.abs(minus_one) {
+        return fmt.invalid();
+    }
+    if n == 0 || fmt.is_zero(a) {
+        return (fmt.one(false), 0);
+    }
+    if a == minus_one {
+        if n < 0 {
+            return fmt.pole(false);
+        }
+        return (fmt.zero(false), 0);
+    }
+    if fmt.is_inf(a) {
+        return if n < 0 {
+            (fmt.zero(false), 0)
+        } else {
+            (fmt.inf(false), 0)
+        };
+    }
+    let x = fmt.to_mp(a);
+    let (base, _) = Mp::add(&Mp::ONE, &x, MAX_LIMBS);
+    let z = Ball::mul_i64(&log1p(&Ball::exact(x), 2), n, 2);
+    if let Some(r) = round_exp_near_one(&z, false, fmt, rnd, tin) {
+        return r;
+    }
+    let m = n.unsigned_abs();
+    ziv!(fmt, rnd, tin, |k| compound_ball(&x, n, k), |c| {
+        Mp::pow_eq(&c, 1, &base, m, n < 0)
+    })
+}
+
+binary!(
+    /// `a^b`, with `pow(x, ±0) = 1` and `pow(+1, y) = 1` even for a quiet
+    /// NaN, and integral `b` allowing a negative `a`.
+    f32_pow,
+    f64_pow,
+    pow
+);
+
+unary_n!(
+    /// `a^n` for an integer `n`, with `pown(x, 0) = 1` even for a quiet NaN.
+    f32_pown,
+    f64_pown,
+    pown
+);
+
+binary!(
+    /// `a^b` defined as `e^(b ln a)`, so only for `a >= 0`.
+    f32_powr,
+    f64_powr,
+    powr
+);
+
+unary_n!(
+    /// `(1 + a)^n` for an integer `n`, defined for `a >= -1`.
+    f32_compound,
+    f64_compound,
+    compound
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::softfloat::{float32_t, float64_t};
+
+    #[test]
+    fn test_f64_pow() {
+        struct TestCase {
+            f: fn(float64_t, float64_t, u8, u8) -> (float64_t, u8),
+            a: u64,
+            b: u64,
+            result: u64,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f64_pow,
+                a: 0x3FF8_0000_0000_0000,
+                b: 0x4002_0000_0000_0000,
+                result: 0x4003_EB97_1CFB_5F72,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_pow,
+                a: 0xC000_0000_0000_0000,
+                b: 0xC008_0000_0000_0000,
+                result: 0xBFC0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_pow,
+                a: 0x4024_0000_0000_0000,
+                b: 0xBFE0_0000_0000_0000,
+                result: 0x3FD4_3D13_6248_490F,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_pow,
+                a: 0x4022_0000_0000_0000,
+                b: 0x3FF8_0000_0000_0000,
+                result: 0x403B_0000_0000_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_pow,
+                a: 0x3FB9_9999_9999_999A,
+                b: 0x4079_0000_0000_0000,
+                result: 0x0000_0000_0000_0000,
+                flags: 3,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_pow,
+                a: 0x4202_A05F_2000_0000,
+                b: 0x4202_A05F_2000_0000,
+                result: 0x7FF0_0000_0000_0000,
+                flags: 5,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_pow,
+                a: 0x3FF8_0000_0000_0000,
+                b: 0x39B4_484B_FEEB_C2A0,
+                result: 0x3FF0_0000_0000_0000,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_pow,
+                a: 0xBFF0_0000_0000_0000,
+                b: 0xFFF0_0000_0000_0000,
+                result: 0x3FF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_pow,
+                a: 0x7FF8_0000_0000_0000,
+                b: 0x8000_0000_0000_0000,
+                result: 0x3FF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_pow,
+                a: 0x3FF0_0000_0000_0000,
+                b: 0x7FF8_0000_0000_0000,
+                result: 0x3FF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_pow,
+                a: 0x8000_0000_0000_0000,
+                b: 0xC008_0000_0000_0000,
+                result: 0xFFF0_0000_0000_0000,
+                flags: 8,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_pow,
+                a: 0x8000_0000_0000_0000,
+                b: 0xC004_0000_0000_0000,
+                result: 0x7FF0_0000_0000_0000,
+                flags: 8,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_pow,
+                a: 0xC020_0000_0000_0000,
+                b: 0x3FD5_5555_5555_5555,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_pow,
+                a: 0xFFF0_0000_0000_0000,
+                b: 0x4008_0000_0000_0000,
+                result: 0xFFF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_pow,
+                a: 0x3FE0_0000_0000_0000,
+                b: 0xFFF0_0000_0000_0000,
+                result: 0x7FF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_powr,
+                a: 0x4004_0000_0000_0000,
+                b: 0xC00E_0000_0000_0000,
+                result: 0x3FA0_7B3F_176B_0A79,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_powr,
+                a: 0x3FE8_0000_0000_0000,
+                b: 0x4000_0000_0000_0000,
+                result: 0x3FE2_0000_0000_0000,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_powr,
+                a: 0xC000_0000_0000_0000,
+                b: 0x4000_0000_0000_0000,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_powr,
+                a: 0x3FF0_0000_0000_0000,
+                b: 0x7FF0_0000_0000_0000,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_powr,
+                a: 0x0000_0000_0000_0000,
+                b: 0x0000_0000_0000_0000,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_powr,
+                a: 0x0000_0000_0000_0000,
+                b: 0xBFF0_0000_0000_0000,
+                result: 0x7FF0_0000_0000_0000,
+                flags: 8,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_powr,
+                a: 0x7FF0_0000_0000_0000,
+                b: 0xC000_0000_0000_0000,
+                result: 0x0000_0000_0000_0000,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(
+                float64_t { v: c.a },
+                float64_t { v: c.b },
+                c.roundingMode,
+                c.detectTininess,
+            );
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f32_pow() {
+        struct TestCase {
+            f: fn(float32_t, float32_t, u8, u8) -> (float32_t, u8),
+            a: u32,
+            b: u32,
+            result: u32,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f32_pow,
+                a: 0x3FC0_0000,
+                b: 0x4010_0000,
+                result: 0x401F_5CB8,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_pow,
+                a: 0xC000_0000,
+                b: 0xC040_0000,
+                result: 0xBE00_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_pow,
+                a: 0x4120_0000,
+                b: 0xBF00_0000,
+                result: 0x3EA1_E89B,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_pow,
+                a: 0x4110_0000,
+                b: 0x3FC0_0000,
+                result: 0x41D8_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_pow,
+                a: 0x3DCC_CCCD,
+                b: 0x43C8_0000,
+                result: 0x0000_0001,
+                flags: 3,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_pow,
+                a: 0x5015_02F9,
+                b: 0x5015_02F9,
+                result: 0x7F80_0000,
+                flags: 5,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_pow,
+                a: 0x3FC0_0000,
+                b: 0x0DA2_4260,
+                result: 0x3F80_0000,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_pow,
+                a: 0xBF80_0000,
+                b: 0xFF80_0000,
+                result: 0x3F80_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_pow,
+                a: 0x7FC0_0000,
+                b: 0x8000_0000,
+                result: 0x3F80_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_pow,
+                a: 0x3F80_0000,
+                b: 0x7FC0_0000,
+                result: 0x3F80_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_pow,
+                a: 0x8000_0000,
+                b: 0xC040_0000,
+                result: 0xFF80_0000,
+                flags: 8,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_pow,
+                a: 0x8000_0000,
+                b: 0xC020_0000,
+                result: 0x7F80_0000,
+                flags: 8,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_pow,
+                a: 0xC100_0000,
+                b: 0x3EAA_AAAB,
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_pow,
+                a: 0xFF80_0000,
+                b: 0x4040_0000,
+                result: 0xFF80_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_pow,
+                a: 0x3F00_0000,
+                b: 0xFF80_0000,
+                result: 0x7F80_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_powr,
+                a: 0x4020_0000,
+                b: 0xC070_0000,
+                result: 0x3D03_D9F8,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_powr,
+                a: 0x3F40_0000,
+                b: 0x4000_0000,
+                result: 0x3F10_0000,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_powr,
+                a: 0xC000_0000,
+                b: 0x4000_0000,
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_powr,
+                a: 0x3F80_0000,
+                b: 0x7F80_0000,
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_powr,
+                a: 0x0000_0000,
+                b: 0x0000_0000,
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_powr,
+                a: 0x0000_0000,
+                b: 0xBF80_0000,
+                result: 0x7F80_0000,
+                flags: 8,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_powr,
+                a: 0x7F80_0000,
+                b: 0xC000_0000,
+                result: 0x0000_0000,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(
+                float32_t { v: c.a },
+                float32_t { v: c.b },
+                c.roundingMode,
+                c.detectTininess,
+            );
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f64_pown() {
+        struct TestCase {
+            f: fn(float64_t, i64, u8, u8) -> (float64_t, u8),
+            a: u64,
+            n: i64,
+            result: u64,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f64_pown,
+                a: 0x3FF1_9999_9999_999A,
+                n: 17,
+                result: 0x4014_37C7_0EF2_9810,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_pown,
+                a: 0xC008_0000_0000_0000,
+                n: 5,
+                result: 0xC06E_6000_0000_0000,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_pown,
+                a: 0xC008_0000_0000_0000,
+                n: -5,
+                result: 0xBF70_DB20_A88F_4695,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_pown,
+                a: 0x4000_0000_0000_0000,
+                n: -1074,
+                result: 0x0000_0000_0000_0001,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_pown,
+                a: 0x3FF0_0000_1AD7_F29B,
+                n: 1 << 40,
+                result: 0x7FF0_0000_0000_0000,
+                flags: 5,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_pown,
+                a: 0x7FF8_0000_0000_0000,
+                n: 0,
+                result: 0x3FF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_pown,
+                a: 0x8000_0000_0000_0000,
+                n: -3,
+                result: 0xFFF0_0000_0000_0000,
+                flags: 8,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_pown,
+                a: 0xFFF0_0000_0000_0000,
+                n: -2,
+                result: 0x0000_0000_0000_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_compound,
+                a: 0x3FA9_9999_9999_999A,
+                n: 30,
+                result: 0x4011_49AB_4311_DFE7,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_compound,
+                a: 0xBFD0_0000_0000_0000,
+                n: -7,
+                result: 0x401D_F756_80FE_B660,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_compound,
+                a: 0x3BC7_9CA1_0C92_4223,
+                n: 3,
+                result: 0x3FF0_0000_0000_0000,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_compound,
+                a: 0x3FE0_0000_0000_0000,
+                n: 2,
+                result: 0x4002_0000_0000_0000,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_compound,
+                a: 0xBFF0_0000_0000_0000,
+                n: -2,
+                result: 0x7FF0_0000_0000_0000,
+                flags: 8,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_compound,
+                a: 0xBFF8_0000_0000_0000,
+                n: 0,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_compound,
+                a: 0x7FF8_0000_0000_0000,
+                n: 0,
+                result: 0x3FF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_compound,
+                a: 0x7FF0_0000_0000_0001,
+                n: 0,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(float64_t { v: c.a }, c.n, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f32_pown() {
+        struct TestCase {
+            f: fn(float32_t, i64, u8, u8) -> (float32_t, u8),
+            a: u32,
+            n: i64,
+            result: u32,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f32_pown,
+                a: 0x3F8C_CCCD,
+                n: 17,
+                result: 0x40A1_BE3C,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_pown,
+                a: 0xC040_0000,
+                n: 5,
+                result: 0xC373_0000,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_pown,
+                a: 0xC040_0000,
+                n: -5,
+                result: 0xBB86_D905,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_pown,
+                a: 0x4000_0000,
+                n: -1074,
+                result: 0x0000_0000,
+                flags: 3,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_pown,
+                a: 0x3F80_0001,
+                n: 1 << 40,
+                result: 0x7F80_0000,
+                flags: 5,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_pown,
+                a: 0x7FC0_0000,
+                n: 0,
+                result: 0x3F80_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_pown,
+                a: 0x8000_0000,
+                n: -3,
+                result: 0xFF80_0000,
+                flags: 8,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_pown,
+                a: 0xFF80_0000,
+                n: -2,
+                result: 0x0000_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_compound,
+                a: 0x3D4C_CCCD,
+                n: 30,
+                result: 0x408A_4D5A,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_compound,
+                a: 0xBE80_0000,
+                n: -7,
+                result: 0x40EF_BAB4,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_compound,
+                a: 0x1E3C_E508,
+                n: 3,
+                result: 0x3F80_0000,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_compound,
+                a: 0x3F00_0000,
+                n: 2,
+                result: 0x4010_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_compound,
+                a: 0xBF80_0000,
+                n: -2,
+                result: 0x7F80_0000,
+                flags: 8,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_compound,
+                a: 0xBFC0_0000,
+                n: 0,
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_compound,
+                a: 0x7FC0_0000,
+                n: 0,
+                result: 0x3F80_0000,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_compound,
+                a: 0x7F80_0001,
+                n: 0,
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(
+                float32_t { v: c.a },
+                c.n,
+                c.roundingMode,
+                c.detectTininess,
+            );
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+}
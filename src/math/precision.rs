@@ -0,0 +1,523 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Rounding to a narrower floating-point format emulated inside `float32_t`
+//! or `float64_t`, like MPFR's `mpfr_subnormalize` or the chop and cpfloat
+//! libraries.
+//!
+//! The target format has `sigBits` bits of precision, including the hidden
+//! bit, and normal numbers `1.f × 2^e` with `emin <= e <= emax`. Below
+//! `2^emin` it has subnormals down to `2^(emin - sigBits + 1)`, so binary16
+//! is `(11, -14, 15)` and bfloat16 is `(8, -126, 127)`. Every value of the
+//! target format must be representable in the host format, so the result is
+//! exact once rounded, and the flags are those of rounding directly to the
+//! target format. Target formats that do not fit give the default NaN and
+//! the invalid flag.
+
+use super::format::Format;
+use super::stochastic::stochastic_mode;
+use crate::softfloat::{
+    float32_t, float64_t, softfloat_flag_inexact, softfloat_flag_invalid, softfloat_flag_overflow,
+    softfloat_flag_underflow, softfloat_round_max, softfloat_round_min, softfloat_round_minMag,
+    softfloat_round_near_even, softfloat_round_near_maxMag, softfloat_round_odd,
+    softfloat_tininess_beforeRounding,
+};
+
+/// `m` shifted right by `s` bits and rounded as a magnitude of sign `neg`,
+/// with whether bits were lost.
+const fn round_shift(m: u64, s: u32, neg: bool, roundingMode: u8) -> (u64, bool) {
+    if s == 0 {
+        return (m, false);
+    }
+    let (n, rest, half) = if s >= 64 {
+        // `m` is below `2^53`, so far less than half of `2^s`.
+        (0, m, u64::MAX)
+    } else {
+        (m >> s, m & ((1 << s) - 1), 1 << (s - 1))
+    };
+    if rest == 0 {
+        return (n, false);
+    }
+    let up = if roundingMode == softfloat_round_near_even {
+        rest > half || (rest == half && (n & 1) != 0)
+    } else if roundingMode == softfloat_round_near_maxMag {
+        rest >= half
+    } else if roundingMode == softfloat_round_min {
+        neg
+    } else if roundingMode == softfloat_round_max {
+        !neg
+    } else if roundingMode == softfloat_round_odd {
+        (n & 1) == 0
+    } else {
+        false
+    };
+    (if up { n + 1 } else { n }, true)
+}
+
+/// Whether the target format is embedded in `fmt`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+const fn fits(fmt: Format, sigBits: u32, emin: i32, emax: i32) -> bool {
+    let p = fmt.precision() as i32;
+    let host_emax = (fmt.max_biased_exp() >> 1) as i32;
+    sigBits >= 1
+        && sigBits as i32 <= p
+        && emin <= emax
+        && emax <= host_emax
+        && emin - sigBits as i32 + 1 >= fmt.min_exp() as i32
+}
+
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss
+)]
+const fn round_to_precision(
+    fmt: Format,
+    a: u64,
+    sigBits: u32,
+    emin: i32,
+    emax: i32,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (u64, u8) {
+    if !fits(fmt, sigBits, emin, emax) {
+        return (fmt.default_nan(), softfloat_flag_invalid);
+    }
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, a);
+    }
+    if fmt.is_inf(a) || fmt.is_zero(a) {
+        return (a, 0);
+    }
+    let neg = fmt.sign(a);
+    let p = fmt.precision() as i32;
+    let x = fmt.to_mp(a);
+    // `a` is `m × 2^e`, with `m` normalized to `p` bits.
+    let m = x.d[0] >> (64 - p);
+    let e = x.exp as i32 - p;
+    let top = e + p - 1;
+
+    let sig = sigBits as i32;
+    let q = if top > emin { top } else { emin } - (sig - 1);
+    let (mut n, inexact) = if q > e {
+        round_shift(m, (q - e) as u32, neg, roundingMode)
+    } else {
+        (m, false)
+    };
+    let mut q = q;
+
+    let mut flags = 0;
+    let n_top = q + 63 - n.leading_zeros() as i32;
+    if n != 0 && n_top > emax {
+        let ovf = softfloat_flag_overflow | softfloat_flag_inexact;
+        let to_max = roundingMode == softfloat_round_minMag
+            || roundingMode == softfloat_round_odd
+            || (roundingMode == softfloat_round_min && !neg)
+            || (roundingMode == softfloat_round_max && neg);
+        if !to_max {
+            return (fmt.inf(neg), ovf);
+        }
+        // The largest finite value, `(2^sigBits - 1) × 2^(emax - sigBits + 1)`.
+        n = (1 << sigBits) - 1;
+        q = emax - (sig - 1);
+        flags = ovf;
+    } else if inexact {
+        flags = softfloat_flag_inexact;
+        let tiny = if top >= emin {
+            false
+        } else if detectTininess == softfloat_tininess_beforeRounding || top < emin - 1 {
+            true
+        } else {
+            // Rounded with an unbounded exponent, the value just below
+            // `2^emin` may round up to it.
+            let (r, _) = round_shift(m, (p - sig) as u32, neg, roundingMode);
+            r >> sig == 0
+        };
+        if tiny {
+            flags |= softfloat_flag_underflow;
+        }
+    }
+
+    if n == 0 {
+        return (fmt.zero(neg), flags);
+    }
+    // Rebuild `n × 2^q`, which is exact in `fmt`, from `n` normalized to
+    // `p` bits; a carry may have left it one bit longer.
+    let shift = n.leading_zeros() as i32 - (64 - p);
+    let n = if shift >= 0 { n << shift } else { n >> -shift };
+    let q = q - shift;
+    let bias = (fmt.max_biased_exp() >> 1) as i32;
+    let biased = q + p - 1 + bias;
+    let v = if biased > 0 {
+        ((biased as u64) << (p - 1)) | (n & (fmt.quiet_bit() * 2 - 1))
+    } else {
+        n >> (1 - biased)
+    };
+    (v | fmt.zero(neg), flags)
+}
+
+const fn round_to_precision_stochastic(
+    fmt: Format,
+    a: u64,
+    sigBits: u32,
+    emin: i32,
+    emax: i32,
+    random: u64,
+    detectTininess: u8,
+) -> (u64, u8) {
+    let mode =
+        if !fits(fmt, sigBits, emin, emax) || fmt.is_nan(a) || fmt.is_inf(a) || fmt.is_zero(a) {
+            softfloat_round_minMag
+        } else {
+            stochastic_mode(&fmt.to_mp(a), false, sigBits, emin, emax, random)
+        };
+    round_to_precision(fmt, a, sigBits, emin, emax, mode, detectTininess)
+}
+
+/// Whether the target format fits in `float32_t`: `sigBits` is from 1 to
+/// 24, `emin <= emax <= 127` and `emin - sigBits + 1 >= -149`.
+#[must_use]
+pub const fn f32_fitsPrecision(sigBits: u32, emin: i32, emax: i32) -> bool {
+    fits(Format::F32, sigBits, emin, emax)
+}
+
+/// Whether the target format fits in `float64_t`: `sigBits` is from 1 to
+/// 53, `emin <= emax <= 1023` and `emin - sigBits + 1 >= -1074`.
+#[must_use]
+pub const fn f64_fitsPrecision(sigBits: u32, emin: i32, emax: i32) -> bool {
+    fits(Format::F64, sigBits, emin, emax)
+}
+
+/// `a` rounded to `sigBits` bits of precision and the exponent range
+/// `emin..=emax`, with subnormals.
+///
+/// If the target format does not fit in `float32_t` (see
+/// [`f32_fitsPrecision`]), the result is the default NaN with the invalid
+/// flag.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_roundToPrecision(
+    a: float32_t,
+    sigBits: u32,
+    emin: i32,
+    emax: i32,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (v, flags) = round_to_precision(
+        Format::F32,
+        a.v as u64,
+        sigBits,
+        emin,
+        emax,
+        roundingMode,
+        detectTininess,
+    );
+    (float32_t { v: v as u32 }, flags)
+}
+
+/// `a` rounded to `sigBits` bits of precision and the exponent range
+/// `emin..=emax`, with subnormals.
+///
+/// If the target format does not fit in `float64_t` (see
+/// [`f64_fitsPrecision`]), the result is the default NaN with the invalid
+/// flag.
+#[must_use]
+pub const fn f64_roundToPrecision(
+    a: float64_t,
+    sigBits: u32,
+    emin: i32,
+    emax: i32,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float64_t, u8) {
+    let (v, flags) = round_to_precision(
+        Format::F64,
+        a.v,
+        sigBits,
+        emin,
+        emax,
+        roundingMode,
+        detectTininess,
+    );
+    (float64_t { v }, flags)
+}
+
+/// `a` rounded stochastically to `sigBits` bits of precision and the
+/// exponent range `emin..=emax`, with subnormals. See
+/// [`f32_addStochastic`](super::f32_addStochastic) for the rounding.
+///
+/// Target formats that do not fit in `float32_t` give the default NaN with
+/// the invalid flag, as for [`f32_roundToPrecision`].
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_roundToPrecisionStochastic(
+    a: float32_t,
+    sigBits: u32,
+    emin: i32,
+    emax: i32,
+    random: u64,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (v, flags) = round_to_precision_stochastic(
+        Format::F32,
+        a.v as u64,
+        sigBits,
+        emin,
+        emax,
+        random,
+        detectTininess,
+    );
+    (float32_t { v: v as u32 }, flags)
+}
+
+/// `a` rounded stochastically to `sigBits` bits of precision and the
+/// exponent range `emin..=emax`, with subnormals. See
+/// [`f64_addStochastic`](super::f64_addStochastic) for the rounding.
+///
+/// Target formats that do not fit in `float64_t` give the default NaN with
+/// the invalid flag, as for [`f64_roundToPrecision`].
+#[must_use]
+pub const fn f64_roundToPrecisionStochastic(
+    a: float64_t,
+    sigBits: u32,
+    emin: i32,
+    emax: i32,
+    random: u64,
+    detectTininess: u8,
+) -> (float64_t, u8) {
+    let (v, flags) = round_to_precision_stochastic(
+        Format::F64,
+        a.v,
+        sigBits,
+        emin,
+        emax,
+        random,
+        detectTininess,
+    );
+    (float64_t { v }, flags)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f32_roundToPrecision() {
+        struct TestCase {
+            a: u32,
+            sigBits: u32,
+            emin: i32,
+            emax: i32,
+            result: u32,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        // binary16, then bfloat16.
+        let cases = [
+            TestCase {
+                a: 0x3F80_0001,
+                sigBits: 11,
+                emin: -14,
+                emax: 15,
+                result: 0x3F80_2000,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                a: 0x387F_FFFF,
+                sigBits: 11,
+                emin: -14,
+                emax: 15,
+                result: 0x3880_0000,
+                flags: 3,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                a: 0x387F_FFFF,
+                sigBits: 11,
+                emin: -14,
+                emax: 15,
+                result: 0x3880_0000,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                a: 0x477F_F000,
+                sigBits: 11,
+                emin: -14,
+                emax: 15,
+                result: 0x7F80_0000,
+                flags: 5,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                a: 0x477F_F000,
+                sigBits: 11,
+                emin: -14,
+                emax: 15,
+                result: 0x477F_E000,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                a: 0xD436_16F8,
+                sigBits: 11,
+                emin: -14,
+                emax: 15,
+                result: 0xFF80_0000,
+                flags: 5,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                a: 0xC82D_099C,
+                sigBits: 11,
+                emin: -14,
+                emax: 15,
+                result: 0xC77F_E000,
+                flags: 5,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                a: 0x0244_A1CD,
+                sigBits: 11,
+                emin: -14,
+                emax: 15,
+                result: 0x0,
+                flags: 3,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                a: 0x08D8_7695,
+                sigBits: 11,
+                emin: -14,
+                emax: 15,
+                result: 0x3380_0000,
+                flags: 3,
+                roundingMode: 6,
+                detectTininess: 0,
+            },
+            TestCase {
+                a: 0x3E67_A000,
+                sigBits: 11,
+                emin: -14,
+                emax: 15,
+                result: 0x3E67_A000,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                a: 0x8006_200A,
+                sigBits: 8,
+                emin: -126,
+                emax: 127,
+                result: 0x8006_0000,
+                flags: 3,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                a: 0x6C7F_6326,
+                sigBits: 8,
+                emin: -126,
+                emax: 127,
+                result: 0x6C7F_0000,
+                flags: 1,
+                roundingMode: 6,
+                detectTininess: 0,
+            },
+            TestCase {
+                a: 0x7F80_0001,
+                sigBits: 8,
+                emin: -126,
+                emax: 127,
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                a: 0x8000_0000,
+                sigBits: 11,
+                emin: -14,
+                emax: 15,
+                result: 0x8000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = f32_roundToPrecision(
+                float32_t { v: c.a },
+                c.sigBits,
+                c.emin,
+                c.emax,
+                c.roundingMode,
+                c.detectTininess,
+            );
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f64_roundToPrecision() {
+        // binary32 inside binary64 agrees with the conversion.
+        let a = float64_t {
+            v: 0x36A0_0000_0000_0001,
+        };
+        assert_eq!(
+            f64_roundToPrecision(a, 24, -126, 127, 0, 1),
+            (
+                float64_t {
+                    v: 0x36A0_0000_0000_0000
+                },
+                3
+            )
+        );
+        assert_eq!(
+            f64_roundToPrecision(a, 24, -126, 127, 3, 1),
+            (
+                float64_t {
+                    v: 0x36B0_0000_0000_0000
+                },
+                3
+            )
+        );
+    }
+
+    #[test]
+    fn test_roundToPrecision_invalid() {
+        let a = float32_t { v: 0x3F80_0000 };
+        for (sigBits, emin, emax) in [(0, -14, 15), (25, -14, 15), (11, 15, -14), (11, -14, 128)] {
+            assert!(!f32_fitsPrecision(sigBits, emin, emax));
+            assert_eq!(
+                f32_roundToPrecision(a, sigBits, emin, emax, 0, 1),
+                (float32_t { v: 0x7FC0_0000 }, softfloat_flag_invalid)
+            );
+            assert_eq!(
+                f32_roundToPrecisionStochastic(a, sigBits, emin, emax, 0, 1),
+                (float32_t { v: 0x7FC0_0000 }, softfloat_flag_invalid)
+            );
+        }
+        assert!(f32_fitsPrecision(24, -126, 127));
+        assert!(!f32_fitsPrecision(24, -127, 127));
+        assert!(f64_fitsPrecision(53, -1022, 1023));
+        let (v, flags) = f64_roundToPrecision(float64_t { v: 0 }, 54, -1022, 1023, 0, 1);
+        assert_eq!(
+            (v.v, flags),
+            (0x7FF8_0000_0000_0000, softfloat_flag_invalid)
+        );
+    }
+}
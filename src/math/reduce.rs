@@ -0,0 +1,846 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Reduction operations (IEEE 754 §9.4).
+//!
+//! `sum`, `dot`, `sumSquare` and `sumAbs` accumulate exactly in an
+//! [`Accumulator`] and round once, so their results do not depend on the
+//! order of the elements and overflow or underflow only when the final
+//! result does. The scaled products return `pr × 2^sf` with `1 <= |pr| < 2`,
+//! so they never overflow or underflow; their running product is kept to
+//! [`MAX_LIMBS`] limbs, which is exact for up to 48 full-precision `f64`
+//! factors and within a relative error of `2^-2400` beyond, before the final
+//! rounding.
+
+use super::accumulator::Accumulator;
+use super::format::Format;
+use super::mp::{Mp, MAX_LIMBS};
+use crate::softfloat::{float32_t, float64_t, softfloat_flag_invalid, softfloat_round_min};
+
+/// Sum of all elements of `a`, rounded once; `+0` if `a` is empty.
+#[must_use]
+pub const fn f32_sum(a: &[float32_t], roundingMode: u8, detectTininess: u8) -> (float32_t, u8) {
+    let mut acc = Accumulator::new();
+    let mut i = 0;
+    while i < a.len() {
+        acc.add_f32(a[i]);
+        i += 1;
+    }
+    acc.to_f32(roundingMode, detectTininess)
+}
+
+/// Sum of all elements of `a`, rounded once; `+0` if `a` is empty.
+#[must_use]
+pub const fn f64_sum(a: &[float64_t], roundingMode: u8, detectTininess: u8) -> (float64_t, u8) {
+    let mut acc = Accumulator::new();
+    let mut i = 0;
+    while i < a.len() {
+        acc.add_f64(a[i]);
+        i += 1;
+    }
+    acc.to_f64(roundingMode, detectTininess)
+}
+
+/// Dot product `Σ a[i] × b[i]` over the common length of `a` and `b`,
+/// rounded once.
+#[must_use]
+pub const fn f32_dot(
+    a: &[float32_t],
+    b: &[float32_t],
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let mut acc = Accumulator::new();
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        acc.add_product_f32(a[i], b[i]);
+        i += 1;
+    }
+    acc.to_f32(roundingMode, detectTininess)
+}
+
+/// Dot product `Σ a[i] × b[i]` over the common length of `a` and `b`,
+/// rounded once.
+#[must_use]
+pub const fn f64_dot(
+    a: &[float64_t],
+    b: &[float64_t],
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float64_t, u8) {
+    let mut acc = Accumulator::new();
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        acc.add_product_f64(a[i], b[i]);
+        i += 1;
+    }
+    acc.to_f64(roundingMode, detectTininess)
+}
+
+/// Sum of the squares of the elements of `a`, rounded once.
+#[must_use]
+pub const fn f32_sumSquare(
+    a: &[float32_t],
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let mut acc = Accumulator::new();
+    let mut i = 0;
+    while i < a.len() {
+        acc.add_product_f32(a[i], a[i]);
+        i += 1;
+    }
+    acc.to_f32(roundingMode, detectTininess)
+}
+
+/// Sum of the squares of the elements of `a`, rounded once.
+#[must_use]
+pub const fn f64_sumSquare(
+    a: &[float64_t],
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float64_t, u8) {
+    let mut acc = Accumulator::new();
+    let mut i = 0;
+    while i < a.len() {
+        acc.add_product_f64(a[i], a[i]);
+        i += 1;
+    }
+    acc.to_f64(roundingMode, detectTininess)
+}
+
+/// Sum of the magnitudes of the elements of `a`, rounded once.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_sumAbs(a: &[float32_t], roundingMode: u8, detectTininess: u8) -> (float32_t, u8) {
+    let mut acc = Accumulator::new();
+    let mut i = 0;
+    while i < a.len() {
+        acc.add_f32(float32_t {
+            v: Format::F32.abs(a[i].v as u64) as u32,
+        });
+        i += 1;
+    }
+    acc.to_f32(roundingMode, detectTininess)
+}
+
+/// Sum of the magnitudes of the elements of `a`, rounded once.
+#[must_use]
+pub const fn f64_sumAbs(a: &[float64_t], roundingMode: u8, detectTininess: u8) -> (float64_t, u8) {
+    let mut acc = Accumulator::new();
+    let mut i = 0;
+    while i < a.len() {
+        acc.add_f64(float64_t {
+            v: Format::F64.abs(a[i].v),
+        });
+        i += 1;
+    }
+    acc.to_f64(roundingMode, detectTininess)
+}
+
+/// A running product of factors, with its sign, special values and
+/// flags kept apart from the magnitude.
+struct Product {
+    x: Mp,
+    inexact: bool,
+    flags: u8,
+    neg: bool,
+    seen: u8,
+}
+
+// Kinds of factor seen so far, as bits of `Product::seen`.
+const NAN: u8 = 1;
+const INF: u8 = 2;
+const ZERO: u8 = 4;
+
+impl Product {
+    const fn new() -> Self {
+        Self {
+            x: Mp::ONE,
+            inexact: false,
+            flags: 0,
+            neg: false,
+            seen: 0,
+        }
+    }
+
+    const fn note_nan(&mut self, fmt: Format, a: u64) {
+        if fmt.is_signaling_nan(a) {
+            self.flags |= softfloat_flag_invalid;
+        }
+        self.seen |= NAN;
+    }
+
+    /// Multiplies by the finite value `v`.
+    const fn mul_mp(&mut self, v: &Mp) {
+        if v.is_zero() {
+            self.seen |= ZERO;
+            return;
+        }
+        self.neg ^= v.neg;
+        let (x, inexact) = Mp::mul(&self.x, &v.abs(), MAX_LIMBS);
+        self.x = x;
+        self.inexact |= inexact;
+    }
+
+    /// Multiplies by the factor `a`.
+    const fn mul(&mut self, fmt: Format, a: u64) {
+        if fmt.is_nan(a) {
+            self.note_nan(fmt, a);
+        } else if fmt.is_inf(a) {
+            self.seen |= INF;
+            self.neg ^= fmt.sign(a);
+        } else if fmt.is_zero(a) {
+            self.seen |= ZERO;
+            self.neg ^= fmt.sign(a);
+        } else {
+            self.mul_mp(&fmt.to_mp(a));
+        }
+    }
+
+    /// Multiplies by the factor `a + b`, which is computed exactly and takes
+    /// the sign of an exact zero from `roundingMode` as addition does.
+    const fn mul_sum(&mut self, fmt: Format, a: u64, b: u64, roundingMode: u8) {
+        let a_nan = fmt.is_nan(a);
+        let b_nan = fmt.is_nan(b);
+        if a_nan || b_nan {
+            if a_nan {
+                self.note_nan(fmt, a);
+            }
+            if b_nan {
+                self.note_nan(fmt, b);
+            }
+            return;
+        }
+        let (sa, sb) = (fmt.sign(a), fmt.sign(b));
+        let (a_inf, b_inf) = (fmt.is_inf(a), fmt.is_inf(b));
+        if a_inf && b_inf && sa != sb {
+            self.flags |= softfloat_flag_invalid;
+            self.seen |= NAN;
+        } else if a_inf || b_inf {
+            self.seen |= INF;
+            self.neg ^= if a_inf { sa } else { sb };
+        } else {
+            // Two finite values span fewer than 2100 bits, so this is exact.
+            let (v, _) = Mp::add(&fmt.to_mp(a), &fmt.to_mp(b), MAX_LIMBS);
+            if v.is_zero() {
+                self.seen |= ZERO;
+                self.neg ^= if sa == sb {
+                    sa
+                } else {
+                    roundingMode == softfloat_round_min
+                };
+            } else {
+                self.mul_mp(&v);
+            }
+        }
+    }
+
+    /// The product as `pr × 2^sf`, with `pr` rounded to `fmt`.
+    const fn finish(&self, fmt: Format, roundingMode: u8, detectTininess: u8) -> (u64, i64, u8) {
+        let invalid = (self.seen & (INF | ZERO)) == INF | ZERO;
+        if invalid || (self.seen & NAN) != 0 {
+            let flags = if invalid {
+                self.flags | softfloat_flag_invalid
+            } else {
+                self.flags
+            };
+            return (fmt.default_nan(), 0, flags);
+        }
+        if (self.seen & INF) != 0 {
+            return (fmt.inf(self.neg), 0, self.flags);
+        }
+        if (self.seen & ZERO) != 0 {
+            return (fmt.zero(self.neg), 0, self.flags);
+        }
+        let mut x = self.x;
+        let mut sf = x.exp - 1;
+        x.exp = 1;
+        x.neg = self.neg;
+        // Truncation only ever loses magnitude.
+        let dir = if !self.inexact {
+            0
+        } else if self.neg {
+            -1
+        } else {
+            1
+        };
+        let (mut pr, flags) = fmt.round(&x, dir, roundingMode, detectTininess);
+        let two = fmt.one(false) + (1 << (fmt.precision() - 1));
+        if fmt.abs(pr) == two {
+            pr = fmt.one(self.neg);
+            sf += 1;
+        }
+        (pr, sf, self.flags | flags)
+    }
+}
+
+/// Defines `f32_*` and `f64_*` scaled products of `a[i] op b[i]` over the
+/// common length of `a` and `b`.
+macro_rules! scaled_pairs {
+    ($(#[$doc:meta])* $name32:ident, $name64:ident, $neg:expr) => {
+        $(#[$doc])*
+        #[must_use]
+        #[allow(clippy::cast_possible_truncation)]
+        pub const fn $name32(
+            a: &[float32_t],
+            b: &[float32_t],
+            roundingMode: u8,
+            detectTininess: u8,
+        ) -> (float32_t, i64, u8) {
+            let fmt = Format::F32;
+            let mut p = Product::new();
+            let mut i = 0;
+            while i < a.len() && i < b.len() {
+                let y = if $neg {
+                    fmt.negate(b[i].v as u64)
+                } else {
+                    b[i].v as u64
+                };
+                p.mul_sum(fmt, a[i].v as u64, y, roundingMode);
+                i += 1;
+            }
+            let (pr, sf, flags) = p.finish(fmt, roundingMode, detectTininess);
+            (float32_t { v: pr as u32 }, sf, flags)
+        }
+
+        $(#[$doc])*
+        #[must_use]
+        pub const fn $name64(
+            a: &[float64_t],
+            b: &[float64_t],
+            roundingMode: u8,
+            detectTininess: u8,
+        ) -> (float64_t, i64, u8) {
+            let fmt = Format::F64;
+            let mut p = Product::new();
+            let mut i = 0;
+            while i < a.len() && i < b.len() {
+                let y = if $neg { fmt.negate(b[i].v) } else { b[i].v };
+                p.mul_sum(fmt, a[i].v, y, roundingMode);
+                i += 1;
+            }
+            let (pr, sf, flags) = p.finish(fmt, roundingMode, detectTininess);
+            (float64_t { v: pr }, sf, flags)
+        }
+    };
+}
+
+/// Product of the elements of `a` as `(pr, sf, flags)` with
+/// `pr × 2^sf` the product and `1 <= |pr| < 2`; `(1, 0)` if `a` is empty.
+/// Zero, infinite and NaN products have `sf = 0`.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_scaledProd(
+    a: &[float32_t],
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float32_t, i64, u8) {
+    let mut p = Product::new();
+    let mut i = 0;
+    while i < a.len() {
+        p.mul(Format::F32, a[i].v as u64);
+        i += 1;
+    }
+    let (pr, sf, flags) = p.finish(Format::F32, roundingMode, detectTininess);
+    (float32_t { v: pr as u32 }, sf, flags)
+}
+
+/// Product of the elements of `a` as `(pr, sf, flags)` with
+/// `pr × 2^sf` the product and `1 <= |pr| < 2`; `(1, 0)` if `a` is empty.
+/// Zero, infinite and NaN products have `sf = 0`.
+#[must_use]
+pub const fn f64_scaledProd(
+    a: &[float64_t],
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float64_t, i64, u8) {
+    let mut p = Product::new();
+    let mut i = 0;
+    while i < a.len() {
+        p.mul(Format::F64, a[i].v);
+        i += 1;
+    }
+    let (pr, sf, flags) = p.finish(Format::F64, roundingMode, detectTininess);
+    (float64_t { v: pr }, sf, flags)
+}
+
+scaled_pairs!(
+    /// Product `Π (a[i] + b[i])`, scaled like [`f64_scaledProd`]. Each sum
+    /// is exact, so only the final product is rounded.
+    f32_scaledProdSum,
+    f64_scaledProdSum,
+    false
+);
+
+scaled_pairs!(
+    /// Product `Π (a[i] - b[i])`, scaled like [`f64_scaledProd`]. Each
+    /// difference is exact, so only the final product is rounded.
+    f32_scaledProdDiff,
+    f64_scaledProdDiff,
+    true
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Reduction<F> = fn(&[F], &[F], u8, u8) -> (F, u8);
+    type Scaled<F> = fn(&[F], &[F], u8, u8) -> (F, i64, u8);
+
+    #[test]
+    fn test_f64_reductions() {
+        struct TestCase {
+            f: Reduction<float64_t>,
+            a: &'static [u64],
+            b: &'static [u64],
+            result: u64,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: |a, _, rm, tin| f64_sum(a, rm, tin),
+                a: &[
+                    0xCA1C_6EB0_0000_0000,
+                    0xBB55_3C22_0F6B_E8E2,
+                    0x3436_11AF_DAFF_76D6,
+                ],
+                b: &[],
+                result: 0xCA1C_6EB0_0000_0000,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_dot,
+                a: &[0xCDDF_600E_7A95_ED23],
+                b: &[0xB81C_1CE4_C87E_9069],
+                result: 0x460B_9061_08EA_0E68,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_dot,
+                a: &[0x0010_0000_0000_0000],
+                b: &[0x1],
+                result: 0x1,
+                flags: 3,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_dot,
+                a: &[0x3FF0_0000_0000_0000, 0x0010_0000_0000_0000],
+                b: &[0x3FF0_0000_0000_0000, 0x1],
+                result: 0x3FF0_0000_0000_0000,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f64_sumAbs(a, rm, tin),
+                a: &[0x2E6A_3195_D99E_2827, 0xBDE5_77D5_3E56_3EFC],
+                b: &[],
+                result: 0x3DE5_77D5_3E56_3EFC,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_dot,
+                a: &[0x5_8FFC_C619_FDBB],
+                b: &[0xBF59_D012_BF17_25F4],
+                result: 0x8000_023E_5454_00CC,
+                flags: 3,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f64_sumSquare(a, rm, tin),
+                a: &[0x319B_0000_0000_0000, 0xC8A7_F000_0000_0000],
+                b: &[],
+                result: 0x5161_E808_0000_0000,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_dot,
+                a: &[0x49E0_0000_0000_0000],
+                b: &[0x42DF_013C_24E2_B385],
+                result: 0x4CCF_013C_24E2_B385,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f64_sumSquare(a, rm, tin),
+                a: &[0x17_AD2C_4587_B293],
+                b: &[],
+                result: 0x1,
+                flags: 3,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f64_sum(a, rm, tin),
+                a: &[],
+                b: &[],
+                result: 0x0,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f64_sum(a, rm, tin),
+                a: &[0x8000_0000_0000_0000, 0x8000_0000_0000_0000],
+                b: &[],
+                result: 0x8000_0000_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f64_sum(a, rm, tin),
+                a: &[0x3FF0_0000_0000_0000, 0xBFF0_0000_0000_0000],
+                b: &[],
+                result: 0x8000_0000_0000_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f64_sum(a, rm, tin),
+                a: &[0x3FF0_0000_0000_0000, 0xBFF0_0000_0000_0000],
+                b: &[],
+                result: 0x0,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f64_sum(a, rm, tin),
+                a: &[0x7FF0_0000_0000_0000, 0xFFF0_0000_0000_0000],
+                b: &[],
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_dot,
+                a: &[0x0],
+                b: &[0x7FF0_0000_0000_0000],
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f64_sum(a, rm, tin),
+                a: &[
+                    0x7FEF_FFFF_FFFF_FFFF,
+                    0x7FEF_FFFF_FFFF_FFFF,
+                    0xFFEF_FFFF_FFFF_FFFF,
+                ],
+                b: &[],
+                result: 0x7FEF_FFFF_FFFF_FFFF,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f64_sum(a, rm, tin),
+                a: &[0x7FEF_FFFF_FFFF_FFFF, 0x7FEF_FFFF_FFFF_FFFF],
+                b: &[],
+                result: 0x7FF0_0000_0000_0000,
+                flags: 5,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let a: Vec<float64_t> = c.a.iter().map(|&v| float64_t { v }).collect();
+            let b: Vec<float64_t> = c.b.iter().map(|&v| float64_t { v }).collect();
+            let (res, flags) = (c.f)(&a, &b, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f32_reductions() {
+        struct TestCase {
+            f: Reduction<float32_t>,
+            a: &'static [u32],
+            b: &'static [u32],
+            result: u32,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: |a, _, rm, tin| f32_sum(a, rm, tin),
+                a: &[0x3E38_E54C, 0x29F1_FD60, 0x7E18_0000],
+                b: &[],
+                result: 0x7E18_0000,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_dot,
+                a: &[0x4423_6A00, 0x3880_0000, 0xC556_6494],
+                b: &[0x3EE6_7A97, 0x3A5B_BCF6, 0xBB95_3ECD],
+                result: 0x439A_EF4F,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f32_sumSquare(a, rm, tin),
+                a: &[0xFF34_CC0C, 0x80F3_8000],
+                b: &[],
+                result: 0x7F7F_FFFF,
+                flags: 5,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f32_sum(a, rm, tin),
+                a: &[0x3B9F_CC00, 0xB806_0208, 0x7F80_0001],
+                b: &[],
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 6,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f32_sumAbs(a, rm, tin),
+                a: &[0x3FEC_E241, 0x43C2_F800, 0xC2D9_8A89],
+                b: &[],
+                result: 0x43FA_4784,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f32_sum(a, rm, tin),
+                a: &[0xC66D_9B46, 0x466D_9B46],
+                b: &[],
+                result: 0x0,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let a: Vec<float32_t> = c.a.iter().map(|&v| float32_t { v }).collect();
+            let b: Vec<float32_t> = c.b.iter().map(|&v| float32_t { v }).collect();
+            let (res, flags) = (c.f)(&a, &b, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f64_scaled() {
+        struct TestCase {
+            f: Scaled<float64_t>,
+            a: &'static [u64],
+            b: &'static [u64],
+            result: u64,
+            sf: i64,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f64_scaledProdDiff,
+                a: &[0x3F98_C295_DAAC_08ED],
+                b: &[0x40A7_A131_5B4B_B0B3],
+                result: 0xBFF7_A124_FA00_C35D,
+                sf: 11,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f64_scaledProd(a, rm, tin),
+                a: &[0x36_BDF0_0000_0000, 0xBFB5_41E6_F357_1130],
+                b: &[],
+                result: 0xBFFE_36F6_E33A_6E37,
+                sf: -1024,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f64_scaledProd(a, rm, tin),
+                a: &[0x3D5C_1E8B_0000_0000],
+                b: &[],
+                result: 0x3FFC_1E8B_0000_0000,
+                sf: -42,
+                flags: 0,
+                roundingMode: 6,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_scaledProdSum,
+                a: &[
+                    0xFFBB_C292_8340_0000,
+                    0xBFEA_C666_CFEB_4000,
+                    0xC02D_8F1D_C000_0000,
+                ],
+                b: &[
+                    0xC14C_641C_5800_0000,
+                    0xC031_BEC0_0000_0000,
+                    0x3F6F_5994_7398_94FA,
+                ],
+                result: 0xBFFD_C5C9_81D2_AA73,
+                sf: 1028,
+                flags: 1,
+                roundingMode: 6,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_scaledProdSum,
+                a: &[0x7FF0_0000_0000_0001],
+                b: &[0xBD6A_44E4_76F2_8B4D],
+                result: 0x7FF8_0000_0000_0000,
+                sf: 0,
+                flags: 16,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f64_scaledProd(a, rm, tin),
+                a: &[],
+                b: &[],
+                result: 0x3FF0_0000_0000_0000,
+                sf: 0,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f64_scaledProd(a, rm, tin),
+                a: &[0x7FEF_FFFF_FFFF_FFFF, 0x7FEF_FFFF_FFFF_FFFF],
+                b: &[],
+                result: 0x3FFF_FFFF_FFFF_FFFE,
+                sf: 2047,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f64_scaledProd(a, rm, tin),
+                a: &[0x0, 0x7FF0_0000_0000_0000],
+                b: &[],
+                result: 0x7FF8_0000_0000_0000,
+                sf: 0,
+                flags: 16,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_scaledProdDiff,
+                a: &[0x3FF0_0000_0000_0000],
+                b: &[0x3FF0_0000_0000_0000],
+                result: 0x8000_0000_0000_0000,
+                sf: 0,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let a: Vec<float64_t> = c.a.iter().map(|&v| float64_t { v }).collect();
+            let b: Vec<float64_t> = c.b.iter().map(|&v| float64_t { v }).collect();
+            let (res, sf, flags) = (c.f)(&a, &b, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, sf, flags), (i, c.result, c.sf, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f32_scaled() {
+        struct TestCase {
+            f: Scaled<float32_t>,
+            a: &'static [u32],
+            b: &'static [u32],
+            result: u32,
+            sf: i64,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f32_scaledProdDiff,
+                a: &[0xBE86_B761],
+                b: &[0x3E80_0000],
+                result: 0xBF83_5BB1,
+                sf: -1,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f32_scaledProd(a, rm, tin),
+                a: &[0xC5D0_C394, 0xC1A7_7740],
+                b: &[],
+                result: 0x3F88_90D5,
+                sf: 17,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_scaledProdSum,
+                a: &[0xFF7F_F820, 0x809C_7C86, 0xAA_BE25],
+                b: &[0xBE_DB59, 0xCB_8280, 0x9EB0_0000],
+                result: 0x3F81_4C76,
+                sf: -65,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_scaledProdDiff,
+                a: &[0x3A20_0000],
+                b: &[0x422F_6C8D],
+                result: 0xBFAF_6BED,
+                sf: 5,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: |a, _, rm, tin| f32_scaledProd(a, rm, tin),
+                a: &[0x7F80_0001, 0x430C_1011, 0x3AA5_FF87],
+                b: &[],
+                result: 0x7FC0_0000,
+                sf: 0,
+                flags: 16,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let a: Vec<float32_t> = c.a.iter().map(|&v| float32_t { v }).collect();
+            let b: Vec<float32_t> = c.b.iter().map(|&v| float32_t { v }).collect();
+            let (res, sf, flags) = (c.f)(&a, &b, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, sf, flags), (i, c.result, c.sf, c.flags));
+        }
+    }
+}
@@ -0,0 +1,828 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Roots: `rootn`, `cbrt`, `hypot` and `rSqrt`.
+
+use super::ball::Ball;
+use super::elementary::{exp, log};
+use super::format::Format;
+use super::mp::{Mp, MAX_LIMBS};
+use super::ziv::{round_exact, round_exp_near_one, round_nudged, ziv};
+use crate::softfloat::softfloat_approxRecipSqrt32_1;
+
+/// `x^(1/n)` for positive finite `x`, as `e^(ln x / n)`.
+const fn root_ball(x: &Mp, n: i64, k: usize) -> Ball {
+    let w = k + 1;
+    let l = Ball::div_u64(&log(&Ball::exact(*x), w), n.unsigned_abs(), w);
+    exp(&if n < 0 { l.neg() } else { l }, k)
+}
+
+const fn rootn(fmt: Format, a: u64, n: i64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if n == 0 {
+        return fmt.invalid();
+    }
+    let odd = (n & 1) != 0;
+    let neg = fmt.sign(a);
+    if fmt.is_zero(a) {
+        if n < 0 {
+            return fmt.pole(neg && odd);
+        }
+        return (fmt.zero(neg && odd), 0);
+    }
+    if neg && !odd {
+        return fmt.invalid();
+    }
+    if fmt.is_inf(a) {
+        return if n < 0 {
+            (fmt.zero(neg), 0)
+        } else {
+            (fmt.inf(neg), 0)
+        };
+    }
+    let x = fmt.to_mp(a).abs();
+    let l = Ball::div_u64(&log(&Ball::exact(x), 2), n.unsigned_abs(), 2);
+    let z = if n < 0 { l.neg() } else { l };
+    if let Some(r) = round_exp_near_one(&z, neg, fmt, rnd, tin) {
+        return r;
+    }
+    ziv!(
+        fmt,
+        rnd,
+        tin,
+        |k| {
+            let r = root_ball(&x, n, k);
+            if neg {
+                r.neg()
+            } else {
+                r
+            }
+        },
+        |c| Mp::pow_eq(&c.abs(), n.unsigned_abs(), &x, 1, n < 0)
+    )
+}
+
+const fn cbrt(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    rootn(fmt, a, 3, rnd, tin)
+}
+
+#[allow(clippy::cast_possible_wrap)]
+const fn hypot(fmt: Format, a: u64, b: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_signaling_nan(a) || fmt.is_signaling_nan(b) {
+        return fmt.propagate_nan(a, b);
+    }
+    if fmt.is_inf(a) || fmt.is_inf(b) {
+        return (fmt.inf(false), 0);
+    }
+    if fmt.is_nan(a) || fmt.is_nan(b) {
+        return fmt.propagate_nan(a, b);
+    }
+    let (hi, lo) = if fmt.abs(a) < fmt.abs(b) {
+        (fmt.abs(b), fmt.abs(a))
+    } else {
+        (fmt.abs(a), fmt.abs(b))
+    };
+    if lo == 0 {
+        return (hi, 0);
+    }
+    let x = fmt.to_mp(hi);
+    let y = fmt.to_mp(lo);
+    // hypot = x √(1 + (y/x)²) with (y/x)² below a quarter ulp.
+    if x.exp - y.exp > fmt.precision() as i64 + 2 {
+        return round_nudged(&x, true, fmt, rnd, tin);
+    }
+    let (x2, _) = Mp::mul(&x, &x, 4);
+    let (y2, _) = Mp::mul(&y, &y, 4);
+    let (s, _) = Mp::add(&x2, &y2, 8);
+    ziv!(fmt, rnd, tin, |n| Ball::sqrt(&Ball::exact(s), n), |c| {
+        Mp::pow_eq(&c, 2, &s, 1, false)
+    })
+}
+
+/// `1 / √a` for `a` in `[1, 4)` to well beyond binary64 precision, from
+/// `softfloat_approxRecipSqrt32_1` and two Newton steps.
+const fn recip_sqrt(a: &Mp) -> Mp {
+    let odd = a.exp == 1;
+    let sig = (a.d[0] >> 32) as u32;
+    let r0 = softfloat_approxRecipSqrt32_1(odd as u32, sig);
+    let mut y = Mp::from_parts(false, r0 as u64, -32);
+    let mut i = 0;
+    while i < 2 {
+        // y += y (1 - a y²) / 2
+        let (y2, _) = Mp::mul(&y, &y, 3);
+        let (ay2, _) = Mp::mul(a, &y2, 3);
+        let (e, _) = Mp::sub(&Mp::ONE, &ay2, 3);
+        let (d, _) = Mp::mul(&y, &e, 3);
+        (y, _) = Mp::add(&y, &d.mul_2exp(-1), 3);
+        i += 1;
+    }
+    y
+}
+
+const fn rsqrt(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) {
+        return fmt.pole(fmt.sign(a));
+    }
+    if fmt.sign(a) {
+        return fmt.invalid();
+    }
+    if fmt.is_inf(a) {
+        return (fmt.zero(false), 0);
+    }
+    // a = m 4^k with m in [1, 4), so 1/√a = 2^-k / √m.
+    let x = fmt.to_mp(a);
+    let k = (x.exp - 1) >> 1;
+    let m = x.mul_2exp(-2 * k);
+    let y = recip_sqrt(&m);
+    // The estimate is far closer to 1/√m than breakpoints are to each
+    // other, so the nearest breakpoint and the sign of t² m - 1 decide.
+    let t = y.round_bits(fmt.precision() + 1);
+    let (t2, _) = Mp::mul(&t, &t, MAX_LIMBS);
+    let (s, _) = Mp::mul(&t2, &m, MAX_LIMBS);
+    let z = t.mul_2exp(-k);
+    match Mp::cmp(&s, &Mp::ONE) {
+        0 => round_exact(&z, fmt, rnd, tin),
+        c => fmt.round(&z, -c, rnd, tin),
+    }
+}
+
+unary_n!(
+    /// The `n`th root of `a`, for a non-zero integer `n`; negative `a` only
+    /// has a root for odd `n`.
+    f32_rootn,
+    f64_rootn,
+    rootn
+);
+
+unary!(
+    /// Cube root.
+    f32_cbrt,
+    f64_cbrt,
+    cbrt
+);
+
+binary!(
+    /// `√(a² + b²)` without intermediate overflow or underflow, and
+    /// `+inf` when either operand is infinite, even with a quiet NaN.
+    f32_hypot,
+    f64_hypot,
+    hypot
+);
+
+unary!(
+    /// Reciprocal square root `1 / √a`.
+    f32_rSqrt,
+    f64_rSqrt,
+    rsqrt
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::softfloat::{float32_t, float64_t};
+
+    #[test]
+    fn test_f64_rootn() {
+        struct TestCase {
+            f: fn(float64_t, i64, u8, u8) -> (float64_t, u8),
+            a: u64,
+            n: i64,
+            result: u64,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f64_rootn,
+                a: 0x4024_0000_0000_0000,
+                n: 3,
+                result: 0x4001_3C48_4138_704F,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_rootn,
+                a: 0xC024_0000_0000_0000,
+                n: -5,
+                result: 0xBFE4_30CD_74F6_D478,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_rootn,
+                a: 0x4090_0000_0000_0000,
+                n: 10,
+                result: 0x4000_0000_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_rootn,
+                a: 0x3F90_0000_0000_0000,
+                n: -6,
+                result: 0x4000_0000_0000_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_rootn,
+                a: 0x3FF8_0000_0000_0000,
+                n: 1 << 62,
+                result: 0x3FF0_0000_0000_0000,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_rootn,
+                a: 0xC010_0000_0000_0000,
+                n: 2,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_rootn,
+                a: 0x4000_0000_0000_0000,
+                n: 0,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_rootn,
+                a: 0x8000_0000_0000_0000,
+                n: -3,
+                result: 0xFFF0_0000_0000_0000,
+                flags: 8,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_rootn,
+                a: 0xFFF0_0000_0000_0000,
+                n: 3,
+                result: 0xFFF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(float64_t { v: c.a }, c.n, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f32_rootn() {
+        struct TestCase {
+            f: fn(float32_t, i64, u8, u8) -> (float32_t, u8),
+            a: u32,
+            n: i64,
+            result: u32,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f32_rootn,
+                a: 0x4120_0000,
+                n: 3,
+                result: 0x4009_E242,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_rootn,
+                a: 0xC120_0000,
+                n: -5,
+                result: 0xBF21_866C,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_rootn,
+                a: 0x4480_0000,
+                n: 10,
+                result: 0x4000_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_rootn,
+                a: 0x3C80_0000,
+                n: -6,
+                result: 0x4000_0000,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_rootn,
+                a: 0x3FC0_0000,
+                n: 1 << 62,
+                result: 0x3F80_0000,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_rootn,
+                a: 0xC080_0000,
+                n: 2,
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_rootn,
+                a: 0x4000_0000,
+                n: 0,
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_rootn,
+                a: 0x8000_0000,
+                n: -3,
+                result: 0xFF80_0000,
+                flags: 8,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_rootn,
+                a: 0xFF80_0000,
+                n: 3,
+                result: 0xFF80_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(
+                float32_t { v: c.a },
+                c.n,
+                c.roundingMode,
+                c.detectTininess,
+            );
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f64_roots() {
+        struct TestCase {
+            f: fn(float64_t, u8, u8) -> (float64_t, u8),
+            a: u64,
+            result: u64,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f64_cbrt,
+                a: 0x4000_0000_0000_0000,
+                result: 0x3FF4_28A2_F98D_728B,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_cbrt,
+                a: 0xBF50_624D_D2F1_A9FC,
+                result: 0xBFB9_9999_9999_9999,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_cbrt,
+                a: 0xC0AA_5E00_0000_0000,
+                result: 0xC02E_0000_0000_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_cbrt,
+                a: 0x0000_0000_0000_0001,
+                result: 0x2990_0000_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_cbrt,
+                a: 0x8000_0000_0000_0000,
+                result: 0x8000_0000_0000_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_rSqrt,
+                a: 0x4000_0000_0000_0000,
+                result: 0x3FE6_A09E_667F_3BCD,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_rSqrt,
+                a: 0x3FB0_0000_0000_0000,
+                result: 0x4010_0000_0000_0000,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_rSqrt,
+                a: 0x4629_3E59_39A0_8CEA,
+                result: 0x3CD2_03AF_9EE7_5616,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_rSqrt,
+                a: 0x0000_0000_0000_0003,
+                result: 0x6172_79A7_4590_331C,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_rSqrt,
+                a: 0x8000_0000_0000_0000,
+                result: 0xFFF0_0000_0000_0000,
+                flags: 8,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_rSqrt,
+                a: 0xBFF0_0000_0000_0000,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_rSqrt,
+                a: 0x7FF0_0000_0000_0000,
+                result: 0x0000_0000_0000_0000,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(float64_t { v: c.a }, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f32_roots() {
+        struct TestCase {
+            f: fn(float32_t, u8, u8) -> (float32_t, u8),
+            a: u32,
+            result: u32,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f32_cbrt,
+                a: 0x4000_0000,
+                result: 0x3FA1_4517,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_cbrt,
+                a: 0xBA83_126F,
+                result: 0xBDCC_CCCE,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_cbrt,
+                a: 0xC552_F000,
+                result: 0xC170_0000,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_cbrt,
+                a: 0x0000_0001,
+                result: 0x26A1_4517,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_cbrt,
+                a: 0x8000_0000,
+                result: 0x8000_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_rSqrt,
+                a: 0x4000_0000,
+                result: 0x3F35_04F3,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_rSqrt,
+                a: 0x3D80_0000,
+                result: 0x4080_0000,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_rSqrt,
+                a: 0x7149_F2CA,
+                result: 0x2690_1D7C,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_rSqrt,
+                a: 0x0000_0003,
+                result: 0x6451_05EC,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_rSqrt,
+                a: 0x8000_0000,
+                result: 0xFF80_0000,
+                flags: 8,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_rSqrt,
+                a: 0xBF80_0000,
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_rSqrt,
+                a: 0x7F80_0000,
+                result: 0x0000_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(
+                float32_t { v: c.a },
+                c.roundingMode,
+                c.detectTininess,
+            );
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f64_hypot() {
+        struct TestCase {
+            f: fn(float64_t, float64_t, u8, u8) -> (float64_t, u8),
+            a: u64,
+            b: u64,
+            result: u64,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f64_hypot,
+                a: 0x4008_0000_0000_0000,
+                b: 0xC010_0000_0000_0000,
+                result: 0x4014_0000_0000_0000,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_hypot,
+                a: 0x3FF0_0000_0000_0000,
+                b: 0x3FF0_0000_0000_0000,
+                result: 0x3FF6_A09E_667F_3BCD,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_hypot,
+                a: 0x47EC_363C_BF21_F28A,
+                b: 0x47EC_363C_BF21_F28A,
+                result: 0x47F3_F2E4_994B_FDCD,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_hypot,
+                a: 0x0000_0000_0000_0003,
+                b: 0x0000_0000_0000_0005,
+                result: 0x0000_0000_0000_0006,
+                flags: 3,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_hypot,
+                a: 0x4202_A05F_2000_0000,
+                b: 0x3DDB_7CDF_D9D7_BDBB,
+                result: 0x4202_A05F_2000_0000,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_hypot,
+                a: 0x8000_0000_0000_0000,
+                b: 0xC004_0000_0000_0000,
+                result: 0x4004_0000_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_hypot,
+                a: 0xFFF0_0000_0000_0000,
+                b: 0x7FF8_0000_0000_0000,
+                result: 0x7FF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_hypot,
+                a: 0x7FF8_0000_0000_0000,
+                b: 0x3FF0_0000_0000_0000,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(
+                float64_t { v: c.a },
+                float64_t { v: c.b },
+                c.roundingMode,
+                c.detectTininess,
+            );
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f32_hypot() {
+        struct TestCase {
+            f: fn(float32_t, float32_t, u8, u8) -> (float32_t, u8),
+            a: u32,
+            b: u32,
+            result: u32,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f32_hypot,
+                a: 0x4040_0000,
+                b: 0xC080_0000,
+                result: 0x40A0_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_hypot,
+                a: 0x3F80_0000,
+                b: 0x3F80_0000,
+                result: 0x3FB5_04F4,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_hypot,
+                a: 0x7F61_B1E6,
+                b: 0x7F61_B1E6,
+                result: 0x7F80_0000,
+                flags: 5,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_hypot,
+                a: 0x0000_0003,
+                b: 0x0000_0005,
+                result: 0x0000_0005,
+                flags: 3,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_hypot,
+                a: 0x5015_02F9,
+                b: 0x2EDB_E6FF,
+                result: 0x5015_02F9,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_hypot,
+                a: 0x8000_0000,
+                b: 0xC020_0000,
+                result: 0x4020_0000,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_hypot,
+                a: 0xFF80_0000,
+                b: 0x7FC0_0000,
+                result: 0x7F80_0000,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_hypot,
+                a: 0x7FC0_0000,
+                b: 0x3F80_0000,
+                result: 0x7FC0_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(
+                float32_t { v: c.a },
+                float32_t { v: c.b },
+                c.roundingMode,
+                c.detectTininess,
+            );
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+}
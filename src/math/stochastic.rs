@@ -0,0 +1,821 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Stochastic rounding of the basic arithmetic operations and of narrowing
+//! conversions.
+//!
+//! A result that is not representable rounds away from zero with probability
+//! equal to its distance from the neighbour towards zero, in units of the
+//! gap between the two neighbours, and towards zero otherwise, so the
+//! rounding is unbiased on average. That distance is compared with the
+//! caller's `random` word as a 64-bit binary fraction, rounded up, so every
+//! inexact result has a chance of at least `2^-64` of rounding away.
+//!
+//! Given the direction, the result and the flags are those of rounding the
+//! exact value towards zero or away from it: inexact whenever the result is
+//! not exact, overflow when it is rounded away past the largest finite value
+//! and underflow following `detectTininess` as for the directed modes. Values
+//! at or beyond `2^(emax + 1)` always round to infinity. Special operands and
+//! invalid or divide-by-zero cases behave as in the other modes.
+
+use super::format::Format;
+use super::mp::Mp;
+use crate::softfloat::{
+    f32_add, f32_div, f32_mul, f32_mulAdd, f32_sqrt, f32_sub, f64_add, f64_div, f64_mul,
+    f64_mulAdd, f64_sqrt, f64_sub, f64_to_f32, float32_t, float64_t, softfloat_flag_infinite,
+    softfloat_flag_invalid, softfloat_round_max, softfloat_round_min, softfloat_round_minMag,
+    softfloat_round_near_even,
+};
+
+/// Working precision in limbs: sums keep every bit down to 64 past the last
+/// one of a `float64_t` result, and the rest only as the inexact bit.
+const N: usize = 4;
+
+#[derive(Copy, Clone)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Sqrt,
+    MulAdd,
+}
+
+/// The directed rounding mode that rounds the non-zero `x`, or the value just
+/// above its magnitude if `sticky`, stochastically to `sigBits` bits of
+/// precision and the normal exponent range `emin..=emax`, drawing on
+/// `random`.
+pub(super) const fn stochastic_mode(
+    x: &Mp,
+    sticky: bool,
+    sigBits: u32,
+    emin: i32,
+    emax: i32,
+    random: u64,
+) -> u8 {
+    let away = if x.neg {
+        softfloat_round_min
+    } else {
+        softfloat_round_max
+    };
+    // `|x|` is in `[2^k, 2^(k + 1))`.
+    let k = x.exp - 1;
+    if k > emax as i64 {
+        return away;
+    }
+    let u = if k > emin as i64 { k } else { emin as i64 } - (sigBits as i64 - 1);
+    // `|x| / 2^u` is below `2^sigBits`; its fractional part is the distance
+    // to the neighbour towards zero.
+    let y = x.abs().mul_2exp(-u);
+    let (f, _) = Mp::sub(&y, &Mp::from_u64(y.trunc_u64()), N);
+    // Its top 64 bits, and whether any are left below them.
+    let frac = if f.is_zero() || f.exp <= -64 {
+        0
+    } else {
+        f.d[0] >> -f.exp
+    };
+    let sticky = sticky || !f.mul_2exp(64).is_integer();
+    if random < frac || (random == frac && sticky) {
+        away
+    } else {
+        softfloat_round_minMag
+    }
+}
+
+/// `x` truncated towards zero, with whether it is inexact, rounded
+/// stochastically to `fmt`.
+#[allow(clippy::cast_possible_truncation)]
+const fn round(fmt: Format, x: &Mp, sticky: bool, random: u64, detectTininess: u8) -> (u64, u8) {
+    let bias = (fmt.max_biased_exp() >> 1) as i32;
+    let mode = stochastic_mode(x, sticky, fmt.precision() as u32, 1 - bias, bias, random);
+    let dir = if !sticky {
+        0
+    } else if x.neg {
+        -1
+    } else {
+        1
+    };
+    fmt.round(x, dir, mode, detectTininess)
+}
+
+/// The operation rounded by softfloat, for special cases.
+#[allow(clippy::cast_possible_truncation)]
+const fn softfloat_op(
+    op: Op,
+    fmt: Format,
+    a: u64,
+    b: u64,
+    c: u64,
+    detectTininess: u8,
+) -> (u64, u8) {
+    let rm = softfloat_round_near_even;
+    match fmt {
+        Format::F32 => {
+            let (a, b, c) = (
+                float32_t { v: a as u32 },
+                float32_t { v: b as u32 },
+                float32_t { v: c as u32 },
+            );
+            let (z, flags) = match op {
+                Op::Add => f32_add(a, b, rm, detectTininess),
+                Op::Sub => f32_sub(a, b, rm, detectTininess),
+                Op::Mul => f32_mul(a, b, rm, detectTininess),
+                Op::Div => f32_div(a, b, rm, detectTininess),
+                Op::Sqrt => f32_sqrt(a, rm, detectTininess),
+                Op::MulAdd => f32_mulAdd(a, b, c, rm, detectTininess),
+            };
+            (z.v as u64, flags)
+        }
+        Format::F64 => {
+            let (a, b, c) = (float64_t { v: a }, float64_t { v: b }, float64_t { v: c });
+            let (z, flags) = match op {
+                Op::Add => f64_add(a, b, rm, detectTininess),
+                Op::Sub => f64_sub(a, b, rm, detectTininess),
+                Op::Mul => f64_mul(a, b, rm, detectTininess),
+                Op::Div => f64_div(a, b, rm, detectTininess),
+                Op::Sqrt => f64_sqrt(a, rm, detectTininess),
+                Op::MulAdd => f64_mulAdd(a, b, c, rm, detectTininess),
+            };
+            (z.v, flags)
+        }
+    }
+}
+
+/// Whether every operand of `op` is finite.
+const fn finite(op: Op, fmt: Format, a: u64, b: u64, c: u64) -> bool {
+    let max = fmt.inf(false);
+    let (a, b, c) = (fmt.abs(a) < max, fmt.abs(b) < max, fmt.abs(c) < max);
+    match op {
+        Op::Sqrt => a,
+        Op::MulAdd => a && b && c,
+        _ => a && b,
+    }
+}
+
+/// `op` on operands of format `fin`, rounded stochastically to `fout`.
+const fn arith(
+    op: Op,
+    fin: Format,
+    fout: Format,
+    [a, b, c]: [u64; 3],
+    random: u64,
+    detectTininess: u8,
+) -> (u64, u8) {
+    let (z, flags) = softfloat_op(op, fin, a, b, c, detectTininess);
+    if !finite(op, fin, a, b, c)
+        || (flags & (softfloat_flag_invalid | softfloat_flag_infinite)) != 0
+    {
+        return match (fin, fout) {
+            (Format::F64, Format::F32) => {
+                let (r, narrowed) = f64_to_f32(
+                    float64_t { v: z },
+                    softfloat_round_near_even,
+                    detectTininess,
+                );
+                (r.v as u64, flags | narrowed)
+            }
+            _ => (z, flags),
+        };
+    }
+    let (xa, xb, xc) = (fin.to_mp(a), fin.to_mp(b), fin.to_mp(c));
+    let (x, sticky) = match op {
+        Op::Add => Mp::add(&xa, &xb, N),
+        Op::Sub => Mp::sub(&xa, &xb, N),
+        Op::Mul => Mp::mul(&xa, &xb, 2),
+        Op::Div => Mp::div(&xa, &xb, 3),
+        Op::Sqrt => Mp::sqrt(&xa, 3),
+        Op::MulAdd => Mp::add(&Mp::mul(&xa, &xb, 2).0, &xc, N),
+    };
+    if x.is_zero() {
+        // An exact zero, whose sign follows the usual rules.
+        return (fout.zero(fin.sign(z)), 0);
+    }
+    round(fout, &x, sticky, random, detectTininess)
+}
+
+const fn from_parts(
+    fmt: Format,
+    sign: bool,
+    significand: u128,
+    exponent: i32,
+    random: u64,
+    detectTininess: u8,
+) -> (u64, u8) {
+    if significand == 0 {
+        return (fmt.zero(sign), 0);
+    }
+    let mut x = Mp::from_u128(significand).mul_2exp(exponent as i64);
+    x.neg = sign;
+    round(fmt, &x, false, random, detectTininess)
+}
+
+/// `a + b` rounded stochastically.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_addStochastic(
+    a: float32_t,
+    b: float32_t,
+    random: u64,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (v, flags) = arith(
+        Op::Add,
+        Format::F32,
+        Format::F32,
+        [a.v as u64, b.v as u64, 0],
+        random,
+        detectTininess,
+    );
+    (float32_t { v: v as u32 }, flags)
+}
+
+/// `a - b` rounded stochastically.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_subStochastic(
+    a: float32_t,
+    b: float32_t,
+    random: u64,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (v, flags) = arith(
+        Op::Sub,
+        Format::F32,
+        Format::F32,
+        [a.v as u64, b.v as u64, 0],
+        random,
+        detectTininess,
+    );
+    (float32_t { v: v as u32 }, flags)
+}
+
+/// `a × b` rounded stochastically.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_mulStochastic(
+    a: float32_t,
+    b: float32_t,
+    random: u64,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (v, flags) = arith(
+        Op::Mul,
+        Format::F32,
+        Format::F32,
+        [a.v as u64, b.v as u64, 0],
+        random,
+        detectTininess,
+    );
+    (float32_t { v: v as u32 }, flags)
+}
+
+/// `a / b` rounded stochastically.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_divStochastic(
+    a: float32_t,
+    b: float32_t,
+    random: u64,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (v, flags) = arith(
+        Op::Div,
+        Format::F32,
+        Format::F32,
+        [a.v as u64, b.v as u64, 0],
+        random,
+        detectTininess,
+    );
+    (float32_t { v: v as u32 }, flags)
+}
+
+/// `√a` rounded stochastically.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_sqrtStochastic(a: float32_t, random: u64, detectTininess: u8) -> (float32_t, u8) {
+    let (v, flags) = arith(
+        Op::Sqrt,
+        Format::F32,
+        Format::F32,
+        [a.v as u64, 0, 0],
+        random,
+        detectTininess,
+    );
+    (float32_t { v: v as u32 }, flags)
+}
+
+/// `a × b + c` rounded stochastically.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_mulAddStochastic(
+    a: float32_t,
+    b: float32_t,
+    c: float32_t,
+    random: u64,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (v, flags) = arith(
+        Op::MulAdd,
+        Format::F32,
+        Format::F32,
+        [a.v as u64, b.v as u64, c.v as u64],
+        random,
+        detectTininess,
+    );
+    (float32_t { v: v as u32 }, flags)
+}
+
+/// `a + b` rounded stochastically.
+#[must_use]
+pub const fn f64_addStochastic(
+    a: float64_t,
+    b: float64_t,
+    random: u64,
+    detectTininess: u8,
+) -> (float64_t, u8) {
+    let (v, flags) = arith(
+        Op::Add,
+        Format::F64,
+        Format::F64,
+        [a.v, b.v, 0],
+        random,
+        detectTininess,
+    );
+    (float64_t { v }, flags)
+}
+
+/// `a - b` rounded stochastically.
+#[must_use]
+pub const fn f64_subStochastic(
+    a: float64_t,
+    b: float64_t,
+    random: u64,
+    detectTininess: u8,
+) -> (float64_t, u8) {
+    let (v, flags) = arith(
+        Op::Sub,
+        Format::F64,
+        Format::F64,
+        [a.v, b.v, 0],
+        random,
+        detectTininess,
+    );
+    (float64_t { v }, flags)
+}
+
+/// `a × b` rounded stochastically.
+#[must_use]
+pub const fn f64_mulStochastic(
+    a: float64_t,
+    b: float64_t,
+    random: u64,
+    detectTininess: u8,
+) -> (float64_t, u8) {
+    let (v, flags) = arith(
+        Op::Mul,
+        Format::F64,
+        Format::F64,
+        [a.v, b.v, 0],
+        random,
+        detectTininess,
+    );
+    (float64_t { v }, flags)
+}
+
+/// `a / b` rounded stochastically.
+#[must_use]
+pub const fn f64_divStochastic(
+    a: float64_t,
+    b: float64_t,
+    random: u64,
+    detectTininess: u8,
+) -> (float64_t, u8) {
+    let (v, flags) = arith(
+        Op::Div,
+        Format::F64,
+        Format::F64,
+        [a.v, b.v, 0],
+        random,
+        detectTininess,
+    );
+    (float64_t { v }, flags)
+}
+
+/// `√a` rounded stochastically.
+#[must_use]
+pub const fn f64_sqrtStochastic(a: float64_t, random: u64, detectTininess: u8) -> (float64_t, u8) {
+    let (v, flags) = arith(
+        Op::Sqrt,
+        Format::F64,
+        Format::F64,
+        [a.v, 0, 0],
+        random,
+        detectTininess,
+    );
+    (float64_t { v }, flags)
+}
+
+/// `a × b + c` rounded stochastically.
+#[must_use]
+pub const fn f64_mulAddStochastic(
+    a: float64_t,
+    b: float64_t,
+    c: float64_t,
+    random: u64,
+    detectTininess: u8,
+) -> (float64_t, u8) {
+    let (v, flags) = arith(
+        Op::MulAdd,
+        Format::F64,
+        Format::F64,
+        [a.v, b.v, c.v],
+        random,
+        detectTininess,
+    );
+    (float64_t { v }, flags)
+}
+
+/// `a + b` rounded stochastically to `float32_t`.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_addF64Stochastic(
+    a: float64_t,
+    b: float64_t,
+    random: u64,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (v, flags) = arith(
+        Op::Add,
+        Format::F64,
+        Format::F32,
+        [a.v, b.v, 0],
+        random,
+        detectTininess,
+    );
+    (float32_t { v: v as u32 }, flags)
+}
+
+/// `a - b` rounded stochastically to `float32_t`.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_subF64Stochastic(
+    a: float64_t,
+    b: float64_t,
+    random: u64,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (v, flags) = arith(
+        Op::Sub,
+        Format::F64,
+        Format::F32,
+        [a.v, b.v, 0],
+        random,
+        detectTininess,
+    );
+    (float32_t { v: v as u32 }, flags)
+}
+
+/// `a × b` rounded stochastically to `float32_t`.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_mulF64Stochastic(
+    a: float64_t,
+    b: float64_t,
+    random: u64,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (v, flags) = arith(
+        Op::Mul,
+        Format::F64,
+        Format::F32,
+        [a.v, b.v, 0],
+        random,
+        detectTininess,
+    );
+    (float32_t { v: v as u32 }, flags)
+}
+
+/// `a / b` rounded stochastically to `float32_t`.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_divF64Stochastic(
+    a: float64_t,
+    b: float64_t,
+    random: u64,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (v, flags) = arith(
+        Op::Div,
+        Format::F64,
+        Format::F32,
+        [a.v, b.v, 0],
+        random,
+        detectTininess,
+    );
+    (float32_t { v: v as u32 }, flags)
+}
+
+/// `√a` rounded stochastically to `float32_t`.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_sqrtF64Stochastic(
+    a: float64_t,
+    random: u64,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (v, flags) = arith(
+        Op::Sqrt,
+        Format::F64,
+        Format::F32,
+        [a.v, 0, 0],
+        random,
+        detectTininess,
+    );
+    (float32_t { v: v as u32 }, flags)
+}
+
+/// `a × b + c` rounded stochastically to `float32_t`.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_mulAddF64Stochastic(
+    a: float64_t,
+    b: float64_t,
+    c: float64_t,
+    random: u64,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (v, flags) = arith(
+        Op::MulAdd,
+        Format::F64,
+        Format::F32,
+        [a.v, b.v, c.v],
+        random,
+        detectTininess,
+    );
+    (float32_t { v: v as u32 }, flags)
+}
+
+/// `a` converted to `float32_t`, rounded stochastically.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f64_to_f32Stochastic(
+    a: float64_t,
+    random: u64,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let fmt = Format::F64;
+    if fmt.is_nan(a.v) || fmt.is_inf(a.v) || fmt.is_zero(a.v) {
+        return f64_to_f32(a, softfloat_round_near_even, detectTininess);
+    }
+    let (v, flags) = round(Format::F32, &fmt.to_mp(a.v), false, random, detectTininess);
+    (float32_t { v: v as u32 }, flags)
+}
+
+/// `(-1)^sign × significand × 2^exponent` rounded stochastically to
+/// `float32_t`, which covers conversions from integers and fixed point.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn f32_fromPartsStochastic(
+    sign: bool,
+    significand: u128,
+    exponent: i32,
+    random: u64,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (v, flags) = from_parts(
+        Format::F32,
+        sign,
+        significand,
+        exponent,
+        random,
+        detectTininess,
+    );
+    (float32_t { v: v as u32 }, flags)
+}
+
+/// `(-1)^sign × significand × 2^exponent` rounded stochastically to
+/// `float64_t`, which covers conversions from integers and fixed point.
+#[must_use]
+pub const fn f64_fromPartsStochastic(
+    sign: bool,
+    significand: u128,
+    exponent: i32,
+    random: u64,
+    detectTininess: u8,
+) -> (float64_t, u8) {
+    let (v, flags) = from_parts(
+        Format::F64,
+        sign,
+        significand,
+        exponent,
+        random,
+        detectTininess,
+    );
+    (float64_t { v }, flags)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::f32_roundToPrecisionStochastic;
+
+    type Op = fn(float32_t, float32_t, float32_t, u64, u8) -> (float32_t, u8);
+
+    #[test]
+    fn test_f32_stochastic() {
+        struct TestCase {
+            f: Op,
+            a: u32,
+            b: u32,
+            c: u32,
+            random: u64,
+            result: u32,
+            flags: u8,
+            detectTininess: u8,
+        }
+
+        let add: Op = |a, b, _, r, tin| f32_addStochastic(a, b, r, tin);
+        let mul: Op = |a, b, _, r, tin| f32_mulStochastic(a, b, r, tin);
+        let cases = [
+            // `1 + 2^-24` is halfway between its neighbours.
+            TestCase {
+                f: add,
+                a: 0x3F80_0000,
+                b: 0x3380_0000,
+                c: 0x0,
+                random: 0x7FFF_FFFF_FFFF_FFFF,
+                result: 0x3F80_0001,
+                flags: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: add,
+                a: 0x3F80_0000,
+                b: 0x3380_0000,
+                c: 0x0,
+                random: 0x8000_0000_0000_0000,
+                result: 0x3F80_0000,
+                flags: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: add,
+                a: 0xBF80_0000,
+                b: 0xB380_0000,
+                c: 0x0,
+                random: 0x0,
+                result: 0xBF80_0001,
+                flags: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, b, _, r, tin| f32_subStochastic(a, b, r, tin),
+                a: 0x3F80_0000,
+                b: 0x3F80_0000,
+                c: 0x0,
+                random: 0x0,
+                result: 0x0,
+                flags: 0,
+                detectTininess: 1,
+            },
+            // Far less than `2^-64` of an ulp still rounds away from zero
+            // for the smallest random word.
+            TestCase {
+                f: |a, b, c, r, tin| f32_mulAddStochastic(a, b, c, r, tin),
+                a: 0x3F80_0000,
+                b: 0x3F80_0000,
+                c: 0x0000_0001,
+                random: 0x0,
+                result: 0x3F80_0001,
+                flags: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, b, c, r, tin| f32_mulAddStochastic(a, b, c, r, tin),
+                a: 0x3F80_0000,
+                b: 0x3F80_0000,
+                c: 0x0000_0001,
+                random: 0x1,
+                result: 0x3F80_0000,
+                flags: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: mul,
+                a: 0x0000_0001,
+                b: 0x3F00_0000,
+                c: 0x0,
+                random: 0x0,
+                result: 0x0000_0001,
+                flags: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: mul,
+                a: 0x0000_0001,
+                b: 0x3F00_0000,
+                c: 0x0,
+                random: u64::MAX,
+                result: 0x0,
+                flags: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: mul,
+                a: 0x7F7F_FFFF,
+                b: 0x4000_0000,
+                c: 0x0,
+                random: u64::MAX,
+                result: 0x7F80_0000,
+                flags: 5,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, b, _, r, tin| f32_divStochastic(a, b, r, tin),
+                a: 0x3F80_0000,
+                b: 0x8000_0000,
+                c: 0x0,
+                random: 0x0,
+                result: 0xFF80_0000,
+                flags: 8,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: |a, _, _, r, tin| f32_sqrtStochastic(a, r, tin),
+                a: 0xBF80_0000,
+                b: 0x0,
+                c: 0x0,
+                random: 0x0,
+                result: 0x7FC0_0000,
+                flags: 16,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(
+                float32_t { v: c.a },
+                float32_t { v: c.b },
+                float32_t { v: c.c },
+                c.random,
+                c.detectTininess,
+            );
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_narrowing_stochastic() {
+        // `1 + 2^-25 + 2^-52` is a quarter of an ulp and a bit above 1 in
+        // binary32.
+        let a = float64_t {
+            v: 0x3FF0_0000_0800_0001,
+        };
+        let quarter = 0x4000_0000_0000_0000 + (1 << 35);
+        assert_eq!(
+            f64_to_f32Stochastic(a, quarter - 1, 1),
+            (float32_t { v: 0x3F80_0001 }, 1)
+        );
+        assert_eq!(
+            f64_to_f32Stochastic(a, quarter, 1),
+            (float32_t { v: 0x3F80_0000 }, 1)
+        );
+        assert_eq!(
+            f32_sqrtF64Stochastic(
+                float64_t {
+                    v: 0xFFF0_0000_0000_0000
+                },
+                0,
+                1
+            ),
+            (float32_t { v: 0x7FC0_0000 }, 16)
+        );
+        assert_eq!(
+            f64_fromPartsStochastic(true, u128::MAX, 0, 0, 1),
+            (
+                float64_t {
+                    v: 0xC7F0_0000_0000_0000
+                },
+                1
+            )
+        );
+    }
+
+    #[test]
+    fn test_stochastic_mean() {
+        // Evenly spread random words round `1/3` up to binary16 as often as
+        // its distance from the value below says.
+        let third = float32_t { v: 0x3EAA_AAAB };
+        let mut ups = 0;
+        for i in 0..1024u64 {
+            let (r, flags) = f32_roundToPrecisionStochastic(third, 11, -14, 15, i << 54, 1);
+            assert_eq!(flags, 1);
+            if r.v == 0x3EAA_C000 {
+                ups += 1;
+            } else {
+                assert_eq!(r.v, 0x3EAA_A000);
+            }
+        }
+        // The distance is `0xAAB / 2^13` of a binary16 ulp, just over a third.
+        assert_eq!(ups, 342);
+    }
+}
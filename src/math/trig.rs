@@ -0,0 +1,449 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Trigonometric functions.
+
+use super::ball::Ball;
+use super::elementary::sin_series;
+use super::format::Format;
+use super::mp::{Mp, MAX_LIMBS};
+use super::ziv::{never, round_nudged, ziv};
+
+/// `|x| = k π/2 + r` with `|r|` about `π/4` at most, as `r` and `k mod 4`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn reduce(x: &Mp, n: usize) -> (Ball, u64) {
+    let x = x.abs();
+    if x.exp <= 0 {
+        return (Ball::exact(x), 0);
+    }
+    // Enough bits of π for every integer bit of k, on top of the working
+    // precision and the cancellation in r, which stays below 2^-64 for
+    // any binary64 argument.
+    let m = n + 2 + x.exp as usize / 64;
+    let m = if m < MAX_LIMBS - 1 { m } else { MAX_LIMBS - 1 };
+    let half_pi = Ball::pi(m).mul_2exp(-1);
+    let (q, _) = Mp::div(&x, &half_pi.mid, m);
+    let (q, _) = Mp::add(&q, &Mp::from_parts(false, 1, -1), m);
+    if q.exp <= 0 {
+        return (Ball::exact(x), 0);
+    }
+    let (k, _) = q.trunc_bits(q.exp as u64);
+    // k mod 4, from the two lowest integer bits.
+    let e = k.exp as u64;
+    let mut quadrant = k.bit(e - 1) as u64;
+    if e >= 2 && k.bit(e - 2) {
+        quadrant |= 2;
+    }
+    let r = Ball::sub(&Ball::exact(x), &Ball::mul(&Ball::exact(k), &half_pi, m), m);
+    (r, quadrant)
+}
+
+/// `sin r` and `cos r = 1 - 2 sin²(r/2)` for `|r| <= 2`.
+const fn sin_cos(r: &Ball, n: usize) -> (Ball, Ball) {
+    let s = sin_series(r, n);
+    let h = sin_series(&r.mul_2exp(-1), n);
+    let c = Ball::sub(&Ball::ONE, &Ball::sqr(&h, n).mul_2exp(1), n);
+    (s, c)
+}
+
+const fn signed(b: Ball, neg: bool) -> Ball {
+    if neg {
+        b.neg()
+    } else {
+        b
+    }
+}
+
+const fn sin_ball(x: &Mp, n: usize) -> Ball {
+    let w = n + 1;
+    let (r, quadrant) = reduce(x, w);
+    let (s, c) = sin_cos(&r, w);
+    let v = match quadrant {
+        0 => s,
+        1 => c,
+        2 => s.neg(),
+        _ => c.neg(),
+    };
+    signed(v, x.neg)
+}
+
+const fn sin(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) {
+        return (a, 0);
+    }
+    if fmt.is_inf(a) {
+        return fmt.invalid();
+    }
+    let x = fmt.to_mp(a);
+    if x.exp <= fmt.tiny_exp() {
+        return round_nudged(&x, false, fmt, rnd, tin);
+    }
+    ziv!(fmt, rnd, tin, |n| sin_ball(&x, n), |c| never(&c))
+}
+
+const fn cos_ball(x: &Mp, n: usize) -> Ball {
+    let w = n + 1;
+    let (r, quadrant) = reduce(x, w);
+    let (s, c) = sin_cos(&r, w);
+    match quadrant {
+        0 => c,
+        1 => s.neg(),
+        2 => c.neg(),
+        _ => s,
+    }
+}
+
+const fn cos(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) {
+        return (fmt.one(false), 0);
+    }
+    if fmt.is_inf(a) {
+        return fmt.invalid();
+    }
+    let x = fmt.to_mp(a);
+    if x.exp <= fmt.tiny_exp() {
+        return round_nudged(&Mp::ONE, false, fmt, rnd, tin);
+    }
+    ziv!(fmt, rnd, tin, |n| cos_ball(&x, n), |c| never(&c))
+}
+
+const fn tan_ball(x: &Mp, n: usize) -> Ball {
+    let w = n + 1;
+    let (r, quadrant) = reduce(x, w);
+    let (s, c) = sin_cos(&r, w);
+    let v = if quadrant & 1 == 0 {
+        Ball::div(&s, &c, w)
+    } else {
+        Ball::div(&c, &s, w).neg()
+    };
+    signed(v, x.neg)
+}
+
+const fn tan(fmt: Format, a: u64, rnd: u8, tin: u8) -> (u64, u8) {
+    if fmt.is_nan(a) {
+        return fmt.propagate_nan(a, 0);
+    }
+    if fmt.is_zero(a) {
+        return (a, 0);
+    }
+    if fmt.is_inf(a) {
+        return fmt.invalid();
+    }
+    let x = fmt.to_mp(a);
+    if x.exp <= fmt.tiny_exp() {
+        return round_nudged(&x, true, fmt, rnd, tin);
+    }
+    ziv!(fmt, rnd, tin, |n| tan_ball(&x, n), |c| never(&c))
+}
+
+unary!(
+    /// Sine of `a` radians.
+    f32_sin,
+    f64_sin,
+    sin
+);
+
+unary!(
+    /// Cosine of `a` radians.
+    f32_cos,
+    f64_cos,
+    cos
+);
+
+unary!(
+    /// Tangent of `a` radians.
+    f32_tan,
+    f64_tan,
+    tan
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::softfloat::{float32_t, float64_t};
+
+    #[test]
+    fn test_f64_trig() {
+        struct TestCase {
+            f: fn(float64_t, u8, u8) -> (float64_t, u8),
+            a: u64,
+            result: u64,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f64_sin,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0x3FDE_AEE8_744B_05EF,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_sin,
+                a: 0xC00A_0000_0000_0000,
+                result: 0x3FBB_B2AD_2464_A48D,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_sin,
+                a: 0x4480_F0CF_064D_D592,
+                result: 0xBFEB_453A_B76B_F397,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_sin,
+                a: 0x7506_AC5B_262C_A1FF,
+                result: 0x3FEF_FFFF_FFFF_FFFF,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_sin,
+                a: 0x3DDB_7CDF_D9D7_BDBB,
+                result: 0x3DDB_7CDF_D9D7_BDBB,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_cos,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0x3FEC_1528_065B_7D50,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_cos,
+                a: 0xC00A_0000_0000_0000,
+                result: 0xBFEF_CFE9_09D7_F7F8,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_cos,
+                a: 0x4480_F0CF_064D_D592,
+                result: 0x3FE0_BE2C_EF01_C8F3,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_cos,
+                a: 0x7506_AC5B_262C_A1FF,
+                result: 0xBC21_4AE7_2E6B_A22F,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_cos,
+                a: 0x7FF0_0000_0000_0000,
+                result: 0x7FF8_0000_0000_0000,
+                flags: 16,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_tan,
+                a: 0x3FE0_0000_0000_0000,
+                result: 0x3FE1_7B4F_5BF3_474A,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_tan,
+                a: 0xC00A_0000_0000_0000,
+                result: 0xBFBB_DC8B_F445_936C,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_tan,
+                a: 0x4480_F0CF_064D_D592,
+                result: 0xBFFA_0F79_C1B6_B257,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f64_tan,
+                a: 0x3FF9_21FB_5444_2D18,
+                result: 0x434D_0296_7C31_CDB5,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f64_tan,
+                a: 0x3DDB_7CDF_D9D7_BDBB,
+                result: 0x3DDB_7CDF_D9D7_BDBB,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(float64_t { v: c.a }, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f32_trig() {
+        struct TestCase {
+            f: fn(float32_t, u8, u8) -> (float32_t, u8),
+            a: u32,
+            result: u32,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f32_sin,
+                a: 0x3F00_0000,
+                result: 0x3EF5_7744,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_sin,
+                a: 0xC050_0000,
+                result: 0x3DDD_9569,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_sin,
+                a: 0x6407_8678,
+                result: 0xBF3B_ECC4,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_sin,
+                a: 0x4049_0FDB,
+                result: 0xB3BB_BD2E,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_sin,
+                a: 0x3727_C5AC,
+                result: 0x3727_C5AC,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_cos,
+                a: 0x3F00_0000,
+                result: 0x3F60_A940,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_cos,
+                a: 0xC050_0000,
+                result: 0xBF7E_7F48,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_cos,
+                a: 0x6407_8678,
+                result: 0x3F2D_D6F6,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_cos,
+                a: 0x3FC9_0FDB,
+                result: 0xB33B_BD2E,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_cos,
+                a: 0xFF80_0000,
+                result: 0x7FC0_0000,
+                flags: 16,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_tan,
+                a: 0x3F00_0000,
+                result: 0x3F0B_DA7A,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_tan,
+                a: 0xC050_0000,
+                result: 0xBDDE_E460,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_tan,
+                a: 0x6407_8678,
+                result: 0xBF8A_5EFE,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                f: f32_tan,
+                a: 0x3FC9_0FDB,
+                result: 0xCBAE_8A4A,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                f: f32_tan,
+                a: 0x3727_C5AC,
+                result: 0x3727_C5AC,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(float32_t { v: c.a }, c.roundingMode, c.detectTininess);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Ziv's strategy for correct rounding.
+//!
+//! A function is evaluated as a [`Ball`] at increasing precision until both
+//! endpoints of the enclosure round to the same value and flags. Results that
+//! are exact, or exactly halfway between two floats, never separate from the
+//! rounding boundary, so [`ziv!`] also asks the caller whether the nearest
+//! candidate breakpoint is the exact result.
+
+use super::ball::{Ball, Mag};
+use super::format::Format;
+use super::mp::Mp;
+
+/// Working precisions, in limbs, tried in turn.
+pub const LEVELS: [usize; 5] = [2, 3, 5, 9, 17];
+
+/// Rounds `b` if every value it encloses rounds identically.
+#[must_use]
+pub const fn try_round(
+    b: &Ball,
+    fmt: Format,
+    roundingMode: u8,
+    detectTininess: u8,
+    n: usize,
+) -> Option<(u64, u8)> {
+    if b.is_indeterminate() || b.contains_zero() {
+        return None;
+    }
+    if b.is_exact() {
+        return Some(fmt.round(&b.mid, 0, roundingMode, detectTininess));
+    }
+    // A radius far below the working precision is widened so that the
+    // endpoints stay exactly representable in `n + 2` limbs.
+    let floor = Mag::ulp(&b.mid, n + 1);
+    let rad = if Mag::lt(b.rad, floor) { floor } else { b.rad };
+    let rad = rad.to_mp();
+    let (lo, lo_inexact) = Mp::sub(&b.mid, &rad, n + 2);
+    let (hi, hi_inexact) = Mp::add(&b.mid, &rad, n + 2);
+    if lo_inexact || hi_inexact {
+        return None;
+    }
+    // A float strictly inside the enclosure separates the truncations.
+    if fmt.round_trunc(&lo, -1) != fmt.round_trunc(&hi, 1) {
+        return None;
+    }
+    let (z_lo, f_lo) = fmt.round(&lo, -1, roundingMode, detectTininess);
+    let (z_hi, f_hi) = fmt.round(&hi, 1, roundingMode, detectTininess);
+    if z_lo != z_hi || f_lo != f_hi {
+        return None;
+    }
+    Some((z_lo, f_lo))
+}
+
+/// The candidate exact result nearest to the midpoint: a float or a
+/// midpoint between two floats, if it lies inside the enclosure.
+#[must_use]
+pub const fn breakpoint(b: &Ball, fmt: Format) -> Option<Mp> {
+    if b.is_indeterminate() {
+        return None;
+    }
+    let c = b.mid.round_bits(fmt.precision() + 1);
+    if b.contains(&c) {
+        Some(c)
+    } else {
+        None
+    }
+}
+
+/// Rounds `x` exactly.
+#[must_use]
+pub const fn round_exact(x: &Mp, fmt: Format, roundingMode: u8, detectTininess: u8) -> (u64, u8) {
+    fmt.round(x, 0, roundingMode, detectTininess)
+}
+
+/// Evaluates a correctly rounded result.
+///
+/// `|$n| $eval` yields the enclosing [`Ball`] at `$n` limbs and
+/// `|$c| $exact` decides whether the candidate `$c` is the exact value.
+/// Expands in place so that it can be used from `const fn`.
+macro_rules! ziv {
+    ($fmt:expr, $rnd:expr, $tin:expr, |$n:ident| $eval:expr, |$c:ident| $exact:expr $(,)?) => {{
+        let fmt: $crate::math::format::Format = $fmt;
+        let mut level = 0;
+        let mut result = (0, 0);
+        while level < $crate::math::ziv::LEVELS.len() {
+            let $n = $crate::math::ziv::LEVELS[level];
+            let b: $crate::math::ball::Ball = $eval;
+            if let Some(r) = $crate::math::ziv::try_round(&b, fmt, $rnd, $tin, $n) {
+                result = r;
+                break;
+            }
+            if let Some($c) = $crate::math::ziv::breakpoint(&b, fmt) {
+                if $exact {
+                    result = $crate::math::ziv::round_exact(&$c, fmt, $rnd, $tin);
+                    break;
+                }
+            }
+            level += 1;
+            if level == $crate::math::ziv::LEVELS.len() {
+                // Not reached for any known input; round the best midpoint.
+                result = $crate::math::ziv::round_exact(&b.mid, fmt, $rnd, $tin);
+            }
+        }
+        result
+    }};
+}
+
+pub(crate) use ziv;
+
+/// Rounds a result known to lie strictly between `x` and the next value
+/// away from (`away`) or towards zero, closer to `x` than any breakpoint.
+#[must_use]
+pub const fn round_nudged(
+    x: &Mp,
+    away: bool,
+    fmt: Format,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (u64, u8) {
+    let dir = if away == x.neg { -1 } else { 1 };
+    fmt.round(x, dir, roundingMode, detectTininess)
+}
+
+/// Candidate check for functions whose result is never a breakpoint.
+#[must_use]
+pub const fn never(_c: &Mp) -> bool {
+    false
+}
+
+/// Rounds `±e^z` when `z`, an enclosure at any precision, is too small
+/// for the result to separate from 1.
+#[must_use]
+#[allow(clippy::cast_possible_wrap)]
+pub const fn round_exp_near_one(
+    z: &Ball,
+    neg: bool,
+    fmt: Format,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> Option<(u64, u8)> {
+    if z.contains_zero() || z.upper().log2_upper() >= -(fmt.precision() as i64 + 3) {
+        return None;
+    }
+    let one = if neg { Mp::ONE.neg() } else { Mp::ONE };
+    Some(round_nudged(
+        &one,
+        z.is_positive(),
+        fmt,
+        roundingMode,
+        detectTininess,
+    ))
+}
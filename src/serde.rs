@@ -0,0 +1,538 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Serialization with [serde](https://serde.rs), behind the `serde` feature.
+//!
+//! `float32_t` and `float64_t` serialize as their raw bits. A field can pick
+//! another representation with `#[serde(with = "...")]` and one of the
+//! modules here, for any [`Float`]:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use softfloat_pure::{float32_t, float64_t};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Vector {
+//!     #[serde(with = "softfloat_pure::serde::hex")]
+//!     a: float64_t,
+//!     #[serde(with = "softfloat_pure::serde::decimal")]
+//!     b: float32_t,
+//! }
+//! ```
+//!
+//! Every representation reads back the exact bits that were written. In the
+//! textual ones a NaN is written `nan`, or with its sign and payload as in
+//! `-nan(0x5)`; signaling NaNs are written `snan(0x1)`. The payload is that
+//! of [`Float::nan_payload`], so it does not include the quiet bit whatever
+//! its meaning.
+//!
+//! `RoundingMode` and `TininessMode` serialize as unit variants,
+//! `ExceptionFlags` as its bits, and `FPU` as a struct of its flags, tininess
+//! mode, emulated precision and stochastic rounding source.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{self, Deserializer, Unexpected, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+
+use super::{
+    fpu::FPU,
+    wrapper::{
+        ExceptionFlags, Float, Precision, PrecisionError, RandomSource, RoundingMode, TininessMode,
+    },
+};
+
+/// The serialized form of [`FPU`], checked on the way in.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "FPU")]
+pub struct FpuState {
+    flags: ExceptionFlags,
+    tininess: TininessMode,
+    precision: Option<Precision>,
+    stochastic: Option<RandomSource>,
+}
+
+impl From<FPU> for FpuState {
+    fn from(fpu: FPU) -> Self {
+        Self {
+            flags: fpu.flags,
+            tininess: fpu.tininess(),
+            precision: fpu.precision(),
+            stochastic: fpu.stochastic_rounding(),
+        }
+    }
+}
+
+impl TryFrom<FpuState> for FPU {
+    type Error = PrecisionError;
+
+    /// Rejects precisions that [`FPU::set_precision`] rejects.
+    fn try_from(state: FpuState) -> Result<Self, Self::Error> {
+        let mut fpu = Self::new(state.tininess);
+        fpu.flags = state.flags;
+        fpu.set_precision(state.precision)?;
+        fpu.set_stochastic_rounding(state.stochastic);
+        Ok(fpu)
+    }
+}
+
+/// The serialized form of [`Precision`], checked on the way in.
+#[derive(Deserialize)]
+#[serde(rename = "Precision")]
+pub struct PrecisionState {
+    sig_bits: u32,
+    emin: i32,
+    emax: i32,
+}
+
+impl TryFrom<PrecisionState> for Precision {
+    type Error = PrecisionError;
+
+    fn try_from(state: PrecisionState) -> Result<Self, Self::Error> {
+        Self::new(state.sig_bits, state.emin, state.emax).ok_or(PrecisionError::INVALID)
+    }
+}
+
+/// A float as text: hexadecimal or shortest decimal, with NaN payloads.
+struct Text<'a, F> {
+    x: &'a F,
+    hex: bool,
+}
+
+impl<F: Float> fmt::Display for Text<'_, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let x = self.x;
+        if x.is_nan() {
+            let sign = if x.is_negative() { "-" } else { "" };
+            let kind = if x.is_signaling_nan() { "snan" } else { "nan" };
+            let (payload, _) = x.nan_payload().to_u64(RoundingMode::RneTiesToEven, true);
+            if payload == 0 {
+                write!(f, "{sign}{kind}")
+            } else {
+                write!(f, "{sign}{kind}({payload:#x})")
+            }
+        } else if self.hex {
+            fmt::Display::fmt(&x.to_hex(), f)
+        } else {
+            // Positional between 1e-7 and 1e21, as in JavaScript.
+            let d = x.to_shortest();
+            if (-7..21).contains(&d.exponent()) {
+                fmt::Display::fmt(&d, f)
+            } else {
+                fmt::LowerExp::fmt(&d, f)
+            }
+        }
+    }
+}
+
+/// Parses what [`Text`] writes, or any other literal the text parsers
+/// accept, rounding to nearest even.
+fn parse<F: Float>(s: &str, hex: bool) -> Option<F> {
+    let (neg, rest) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let nan = |prefix: &str| {
+        rest.get(..prefix.len())
+            .filter(|p| p.eq_ignore_ascii_case(prefix))
+            .map(|_| &rest[prefix.len()..])
+    };
+    let rnd = RoundingMode::RneTiesToEven;
+    let tininess = TininessMode::After;
+    if let Some((signaling, digits)) = nan("snan(")
+        .map(|d| (true, d))
+        .or_else(|| nan("nan(").map(|d| (false, d)))
+    {
+        let digits = digits.strip_suffix(')')?;
+        let digits = digits
+            .strip_prefix("0x")
+            .or_else(|| digits.strip_prefix("0X"))?;
+        let (payload, _) = F::from_u64(u64::from_str_radix(digits, 16).ok()?, rnd, tininess);
+        let x = if signaling {
+            F::from_nan_payload_signaling(payload)
+        } else {
+            F::from_nan_payload(payload)
+        };
+        // Payloads that are not admissible give `+0`.
+        if !x.is_nan() {
+            return None;
+        }
+        return Some(if neg { x.neg() } else { x });
+    }
+    let parsed = if hex {
+        F::parse_hex(s, rnd, tininess)
+    } else {
+        F::parse(s, rnd, tininess)
+    };
+    parsed.ok().map(|(x, _)| x)
+}
+
+struct TextVisitor<F> {
+    hex: bool,
+    format: PhantomData<F>,
+}
+
+impl<F: Float> Visitor<'_> for TextVisitor<F> {
+    type Value = F;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.hex {
+            "a hexadecimal floating-point literal"
+        } else {
+            "a decimal floating-point literal"
+        })
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<F, E> {
+        parse(v, self.hex).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+fn serialize_text<F: Float, S: Serializer>(x: &F, hex: bool, s: S) -> Result<S::Ok, S::Error> {
+    s.collect_str(&Text { x, hex })
+}
+
+fn deserialize_text<'de, F: Float, D: Deserializer<'de>>(hex: bool, d: D) -> Result<F, D::Error> {
+    d.deserialize_str(TextVisitor {
+        hex,
+        format: PhantomData,
+    })
+}
+
+/// Raw bits, the default for `float32_t` and `float64_t`.
+pub mod bits {
+    use super::{Deserialize, Deserializer, Float, Serialize, Serializer};
+
+    /// # Errors
+    ///
+    /// Returns the error of `serializer`.
+    pub fn serialize<F, S>(x: &F, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        F: Float,
+        F::Payload: Serialize,
+        S: Serializer,
+    {
+        x.to_bits().serialize(serializer)
+    }
+
+    /// # Errors
+    ///
+    /// Returns the error of `deserializer`.
+    pub fn deserialize<'de, F, D>(deserializer: D) -> Result<F, D::Error>
+    where
+        F: Float,
+        F::Payload: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        F::Payload::deserialize(deserializer).map(F::from_bits)
+    }
+}
+
+/// Hexadecimal scientific notation with as many digits as needed, such as
+/// `0x1.8p+1` or `-inf`.
+pub mod hex {
+    use super::{deserialize_text, serialize_text, Deserializer, Float, Serializer};
+
+    /// # Errors
+    ///
+    /// Returns the error of `serializer`.
+    pub fn serialize<F: Float, S: Serializer>(x: &F, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_text(x, true, serializer)
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a valid literal.
+    pub fn deserialize<'de, F: Float, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<F, D::Error> {
+        deserialize_text(true, deserializer)
+    }
+}
+
+/// The shortest decimal that parses back to the same float, such as `0.1`
+/// or `5e-324`.
+pub mod decimal {
+    use super::{deserialize_text, serialize_text, Deserializer, Float, Serializer};
+
+    /// # Errors
+    ///
+    /// Returns the error of `serializer`.
+    pub fn serialize<F: Float, S: Serializer>(x: &F, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_text(x, false, serializer)
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a valid literal.
+    pub fn deserialize<'de, F: Float, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<F, D::Error> {
+        deserialize_text(false, deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{float32_t, float64_t};
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Vector {
+        #[serde(with = "super::hex")]
+        a: float64_t,
+        #[serde(with = "super::decimal")]
+        b: float32_t,
+        #[serde(with = "super::bits")]
+        c: float64_t,
+        d: float32_t,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct Mode(RoundingMode);
+
+    impl PartialEq for Mode {
+        fn eq(&self, other: &Self) -> bool {
+            self.0.to_softfloat() == other.0.to_softfloat()
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct Fpu(FPU);
+
+    impl PartialEq for Fpu {
+        fn eq(&self, other: &Self) -> bool {
+            self.0.flags == other.0.flags
+                && self.0.tininess() == other.0.tininess()
+                && self.0.precision() == other.0.precision()
+                && self.0.stochastic_rounding() == other.0.stochastic_rounding()
+        }
+    }
+
+    fn read<F: Float>(s: &str, hex: bool) -> Option<F::Payload> {
+        parse::<F>(s, hex).as_ref().map(Float::to_bits)
+    }
+
+    fn text<F: Float>(x: &F, hex: bool) -> String {
+        Text { x, hex }.to_string()
+    }
+
+    #[test]
+    fn test_serde_tokens() {
+        let v = Vector {
+            a: float64_t::from_bits(0xC004_0000_0000_0000),
+            b: float32_t::from_bits(0x3DCC_CCCD),
+            c: float64_t::from_bits(0x7FF0_0000_0000_0001),
+            d: float32_t::from_bits(0xFFC0_0005),
+        };
+        assert_tokens(
+            &v,
+            &[
+                Token::Struct {
+                    name: "Vector",
+                    len: 4,
+                },
+                Token::Str("a"),
+                Token::Str("-0x1.4p+1"),
+                Token::Str("b"),
+                Token::Str("0.1"),
+                Token::Str("c"),
+                Token::U64(0x7FF0_0000_0000_0001),
+                Token::Str("d"),
+                Token::U32(0xFFC0_0005),
+                Token::StructEnd,
+            ],
+        );
+        assert_tokens(
+            &Mode(RoundingMode::RupTowardPositive),
+            &[Token::UnitVariant {
+                name: "RoundingMode",
+                variant: "RupTowardPositive",
+            }],
+        );
+        assert_tokens(&ExceptionFlags::from_bits(0x11), &[Token::U8(0x11)]);
+        assert_tokens(
+            &TininessMode::Before,
+            &[Token::UnitVariant {
+                name: "TininessMode",
+                variant: "Before",
+            }],
+        );
+        assert_de_tokens_error::<Vector>(
+            &[
+                Token::Struct {
+                    name: "Vector",
+                    len: 4,
+                },
+                Token::Str("a"),
+                Token::Str("snan(0x0)"),
+            ],
+            "invalid value: string \"snan(0x0)\", expected a hexadecimal floating-point literal",
+        );
+    }
+
+    #[test]
+    fn test_serde_fpu() {
+        let mut fpu = FPU::new(TininessMode::Before);
+        fpu.flags = ExceptionFlags::from_bits(0x05);
+        fpu.set_precision(Some(Precision::BFLOAT16)).unwrap();
+        fpu.set_stochastic_rounding(Some(RandomSource::new(42)));
+        let tokens = [
+            Token::Struct {
+                name: "FPU",
+                len: 4,
+            },
+            Token::Str("flags"),
+            Token::U8(0x05),
+            Token::Str("tininess"),
+            Token::UnitVariant {
+                name: "TininessMode",
+                variant: "Before",
+            },
+            Token::Str("precision"),
+            Token::Some,
+            Token::Struct {
+                name: "Precision",
+                len: 3,
+            },
+            Token::Str("sig_bits"),
+            Token::U32(8),
+            Token::Str("emin"),
+            Token::I32(-126),
+            Token::Str("emax"),
+            Token::I32(127),
+            Token::StructEnd,
+            Token::Str("stochastic"),
+            Token::Some,
+            Token::Struct {
+                name: "RandomSource",
+                len: 1,
+            },
+            Token::Str("state"),
+            Token::U64(42),
+            Token::StructEnd,
+            Token::StructEnd,
+        ];
+        assert_tokens(&Fpu(fpu), &tokens);
+        assert_de_tokens_error::<Precision>(
+            &[
+                Token::Struct {
+                    name: "Precision",
+                    len: 3,
+                },
+                Token::Str("sig_bits"),
+                Token::U32(0),
+                Token::Str("emin"),
+                Token::I32(-14),
+                Token::Str("emax"),
+                Token::I32(15),
+                Token::StructEnd,
+            ],
+            "precision does not fit in the format",
+        );
+
+        // Fits in `float64_t` but not in `float32_t`.
+        assert_de_tokens_error::<Fpu>(
+            &[
+                Token::Struct {
+                    name: "FPU",
+                    len: 4,
+                },
+                Token::Str("flags"),
+                Token::U8(0),
+                Token::Str("tininess"),
+                Token::UnitVariant {
+                    name: "TininessMode",
+                    variant: "After",
+                },
+                Token::Str("precision"),
+                Token::Some,
+                Token::Struct {
+                    name: "Precision",
+                    len: 3,
+                },
+                Token::Str("sig_bits"),
+                Token::U32(30),
+                Token::Str("emin"),
+                Token::I32(-126),
+                Token::Str("emax"),
+                Token::I32(127),
+                Token::StructEnd,
+                Token::Str("stochastic"),
+                Token::None,
+                Token::StructEnd,
+            ],
+            "precision does not fit in the format",
+        );
+    }
+
+    #[test]
+    fn test_serde_text() {
+        let cases: &[(u64, &str, &str)] = &[
+            (0x0000_0000_0000_0000, "0x0p+0", "0"),
+            (0x8000_0000_0000_0000, "-0x0p+0", "-0"),
+            (0x3FB9_9999_9999_999A, "0x1.999999999999ap-4", "0.1"),
+            (0x0000_0000_0000_0001, "0x1p-1074", "5e-324"),
+            (
+                0x7FEF_FFFF_FFFF_FFFF,
+                "0x1.fffffffffffffp+1023",
+                "1.7976931348623157e308",
+            ),
+            (0x444B_1AE4_D6E2_EF50, "0x1.b1ae4d6e2ef5p+69", "1e21"),
+            (0x3E7A_D7F2_9ABC_AF48, "0x1.ad7f29abcaf48p-24", "0.0000001"),
+            (0xFFF0_0000_0000_0000, "-inf", "-inf"),
+            (0x7FF8_0000_0000_0000, "nan", "nan"),
+            (0xFFF8_0000_0000_0005, "-nan(0x5)", "-nan(0x5)"),
+            (0x7FF0_0000_0000_0001, "snan(0x1)", "snan(0x1)"),
+            (
+                0xFFF7_FFFF_FFFF_FFFF,
+                "-snan(0x7ffffffffffff)",
+                "-snan(0x7ffffffffffff)",
+            ),
+        ];
+        for &(bits, hex, decimal) in cases {
+            let x = float64_t::from_bits(bits);
+            assert_eq!(text(&x, true), hex, "{bits:#x}");
+            assert_eq!(text(&x, false), decimal, "{bits:#x}");
+            assert_eq!(read::<float64_t>(hex, true), Some(bits));
+            assert_eq!(read::<float64_t>(decimal, false), Some(bits));
+        }
+
+        let mut state = 1u64;
+        for _ in 0..500 {
+            let bits = RandomSource::new(state).next_u64();
+            state = bits;
+            let x = float64_t::from_bits(bits);
+            let small = u32::try_from(bits >> 32).unwrap();
+            let y = float32_t::from_bits(small);
+            for hex in [true, false] {
+                let back = read::<float64_t>(&text(&x, hex), hex);
+                assert_eq!(back, Some(bits), "{bits:#x}");
+                let back = read::<float32_t>(&text(&y, hex), hex);
+                assert_eq!(back, Some(small), "{bits:#x}");
+            }
+        }
+
+        for s in [
+            "snan(0x0)",
+            "snan(0x8000000000000)",
+            "snan(5)",
+            "snan(0x1",
+            "nan(",
+            "nan(0x8000000000000)",
+            "sna\u{20ac}(0x1)",
+            "",
+        ] {
+            assert_eq!(read::<float64_t>(s, true), None, "{s}");
+        }
+        assert_eq!(parse::<float32_t>("snan(0x400000)", false), None);
+        assert_eq!(
+            read::<float32_t>("-snan(0x3fffff)", false),
+            Some(0xFFBF_FFFF)
+        );
+    }
+}
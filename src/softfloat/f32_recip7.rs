@@ -0,0 +1,159 @@
+use super::{
+    defaultNaNF32UI, f32_classify, float32_t, softfloat_flag_infinite, softfloat_flag_invalid,
+    softfloat_recip7,
+};
+
+/// Reciprocal estimate to 7 bits, as computed by the RISC-V `vfrec7.v`
+/// instruction.
+///
+/// Subnormal inputs too small for the estimate to be finite overflow
+/// according to `roundingMode`.
+#[must_use]
+pub const fn f32_recip7(a: float32_t, roundingMode: u8) -> (float32_t, u8) {
+    let (uiZ, flags) = match f32_classify(a) {
+        // -inf
+        0x001 => (0x8000_0000, 0),
+        // +inf
+        0x080 => (0, 0),
+        // -0
+        0x008 => (0xFF80_0000, softfloat_flag_infinite),
+        // +0
+        0x010 => (0x7F80_0000, softfloat_flag_infinite),
+        // sNaN
+        0x100 => (defaultNaNF32UI, softfloat_flag_invalid),
+        // qNaN
+        0x200 => (defaultNaNF32UI, 0),
+        // normal or subnormal
+        class => {
+            let (uiZ, flags) =
+                softfloat_recip7(a.v as u64, 8, 23, roundingMode, (class & 0x024) != 0);
+            (uiZ as u32, flags)
+        }
+    };
+    return (float32_t { v: uiZ }, flags);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_f32_recip7() {
+        struct f32_recip7_TestCase {
+            a: u32,
+            roundingMode: u8,
+            ret: u32,
+            flags: u8,
+        }
+
+        let cases = [
+            f32_recip7_TestCase {
+                a: 0x3F80_0000,
+                roundingMode: 0,
+                ret: 0x3F7F_0000,
+                flags: 0,
+            },
+            f32_recip7_TestCase {
+                a: 0x4049_0FDB,
+                roundingMode: 1,
+                ret: 0x3EA3_0000,
+                flags: 0,
+            },
+            f32_recip7_TestCase {
+                a: 0xC2F6_E979,
+                roundingMode: 0,
+                ret: 0xBC05_0000,
+                flags: 0,
+            },
+            f32_recip7_TestCase {
+                a: 0x7F7F_FFFF,
+                roundingMode: 0,
+                ret: 0x0020_0000,
+                flags: 0,
+            },
+            f32_recip7_TestCase {
+                a: 0xFF00_0000,
+                roundingMode: 2,
+                ret: 0x803F_C000,
+                flags: 0,
+            },
+            f32_recip7_TestCase {
+                a: 0x7E80_0001,
+                roundingMode: 0,
+                ret: 0x007F_8000,
+                flags: 0,
+            },
+            f32_recip7_TestCase {
+                a: 0x0060_0000,
+                roundingMode: 0,
+                ret: 0x7EAA_0000,
+                flags: 0,
+            },
+            f32_recip7_TestCase {
+                a: 0x0020_0000,
+                roundingMode: 0,
+                ret: 0x7F7F_0000,
+                flags: 0,
+            },
+            f32_recip7_TestCase {
+                a: 0x001F_FFFF,
+                roundingMode: 0,
+                ret: 0x7F80_0000,
+                flags: 5,
+            },
+            f32_recip7_TestCase {
+                a: 0x001F_FFFF,
+                roundingMode: 1,
+                ret: 0x7F7F_FFFF,
+                flags: 5,
+            },
+            f32_recip7_TestCase {
+                a: 0x801F_FFFF,
+                roundingMode: 2,
+                ret: 0xFF80_0000,
+                flags: 5,
+            },
+            f32_recip7_TestCase {
+                a: 0x801F_FFFF,
+                roundingMode: 3,
+                ret: 0xFF7F_FFFF,
+                flags: 5,
+            },
+            f32_recip7_TestCase {
+                a: 0x0000_0001,
+                roundingMode: 2,
+                ret: 0x7F7F_FFFF,
+                flags: 5,
+            },
+            f32_recip7_TestCase {
+                a: 0x8000_0000,
+                roundingMode: 0,
+                ret: 0xFF80_0000,
+                flags: 8,
+            },
+            f32_recip7_TestCase {
+                a: 0xFF80_0000,
+                roundingMode: 0,
+                ret: 0x8000_0000,
+                flags: 0,
+            },
+            f32_recip7_TestCase {
+                a: 0x7F80_0001,
+                roundingMode: 0,
+                ret: 0x7FC0_0000,
+                flags: 16,
+            },
+            f32_recip7_TestCase {
+                a: 0x7FC0_0000,
+                roundingMode: 0,
+                ret: 0x7FC0_0000,
+                flags: 0,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = f32_recip7(float32_t { v: c.a }, c.roundingMode);
+            assert_eq!((i, res.v, flags), (i, c.ret, c.flags));
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use super::{
+    defaultNaNF32UI, f32_classify, float32_t, softfloat_flag_infinite, softfloat_flag_invalid,
+    softfloat_rsqrte7,
+};
+
+/// Reciprocal square root estimate to 7 bits, as computed by the RISC-V
+/// `vfrsqrt7.v` instruction.
+#[must_use]
+pub const fn f32_rsqrte7(a: float32_t) -> (float32_t, u8) {
+    let (uiZ, flags) = match f32_classify(a) {
+        // -inf, -normal, -subnormal, sNaN
+        0x001 | 0x002 | 0x004 | 0x100 => (defaultNaNF32UI, softfloat_flag_invalid),
+        // qNaN
+        0x200 => (defaultNaNF32UI, 0),
+        // -0
+        0x008 => (0xFF80_0000, softfloat_flag_infinite),
+        // +0
+        0x010 => (0x7F80_0000, softfloat_flag_infinite),
+        // +inf
+        0x080 => (0, 0),
+        // +normal or +subnormal
+        class => (
+            softfloat_rsqrte7(a.v as u64, 8, 23, class == 0x020) as u32,
+            0,
+        ),
+    };
+    return (float32_t { v: uiZ }, flags);
+}
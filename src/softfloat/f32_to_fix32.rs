@@ -0,0 +1,180 @@
+use super::{
+    expF32UI, float32_t, fracF32UI, i32_fromNaN, i32_fromNegOverflow, i32_fromPosOverflow,
+    signF32UI, softfloat_flag_invalid, softfloat_normSubnormalF32Sig, softfloat_roundToI32,
+    softfloat_shiftRightJam64,
+};
+
+/// Converts `a` to a signed 32-bit fixed-point number with `fbits` fractional
+/// bits, that is, rounds `a * 2^fbits` to an integer. Any `fbits` is allowed.
+#[must_use]
+pub const fn f32_to_fix32(a: float32_t, fbits: u8, roundingMode: u8, exact: bool) -> (i32, u8) {
+    let mut sign = signF32UI(a.v);
+    let mut exp = expF32UI(a.v);
+    let mut sig = fracF32UI(a.v);
+    if exp == 0 && sig != 0 {
+        // A large `fbits` can bring a subnormal into range.
+        let normExpSig = softfloat_normSubnormalF32Sig(sig);
+        exp = normExpSig.exp;
+        sig = normExpSig.sig;
+    }
+
+    if (i32_fromNaN != i32_fromPosOverflow || i32_fromNaN != i32_fromNegOverflow)
+        && exp == 0xFF
+        && sig != 0
+    {
+        if i32_fromNaN == i32_fromPosOverflow {
+            sign = false;
+        } else if i32_fromNaN == i32_fromNegOverflow {
+            sign = true;
+        } else {
+            return (i32_fromNaN, softfloat_flag_invalid);
+        }
+    }
+
+    if exp != 0 {
+        sig |= 0x0080_0000;
+    }
+
+    let mut sig64 = (sig as u64) << 32;
+    let shiftDist = (0xAA as i16).wrapping_sub(exp).wrapping_sub(fbits as i16);
+    if 0 < shiftDist {
+        sig64 = softfloat_shiftRightJam64(sig64, shiftDist as u16 as u32);
+    }
+    return softfloat_roundToI32(sign, sig64, roundingMode, exact);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_f32_to_fix32() {
+        struct f32_to_fix32_TestCase {
+            a: u32,
+            fbits: u8,
+            result: i32,
+            flags: u8,
+            roundingMode: u8,
+        }
+
+        let cases = [
+            f32_to_fix32_TestCase {
+                a: 0xCFD2_5180,
+                fbits: 0,
+                result: -2_147_483_648,
+                flags: 16,
+                roundingMode: 6,
+            },
+            f32_to_fix32_TestCase {
+                a: 0xAE14_7A79,
+                fbits: 31,
+                result: -1,
+                flags: 1,
+                roundingMode: 2,
+            },
+            f32_to_fix32_TestCase {
+                a: 0xB3F7_706D,
+                fbits: 32,
+                result: -495,
+                flags: 1,
+                roundingMode: 0,
+            },
+            f32_to_fix32_TestCase {
+                a: 0x76_4C86,
+                fbits: 0,
+                result: 0,
+                flags: 1,
+                roundingMode: 1,
+            },
+            f32_to_fix32_TestCase {
+                a: 0x4729_C884,
+                fbits: 18,
+                result: 2_147_483_647,
+                flags: 16,
+                roundingMode: 2,
+            },
+            f32_to_fix32_TestCase {
+                a: 0xC3B6_757F,
+                fbits: 0,
+                result: -365,
+                flags: 1,
+                roundingMode: 6,
+            },
+            f32_to_fix32_TestCase {
+                a: 0xBCBD_DA56,
+                fbits: 32,
+                result: -99_537_584,
+                flags: 0,
+                roundingMode: 6,
+            },
+            f32_to_fix32_TestCase {
+                a: 0x4893_89B6,
+                fbits: 0,
+                result: 302_158,
+                flags: 1,
+                roundingMode: 4,
+            },
+            f32_to_fix32_TestCase {
+                a: 0xC2A0_8500,
+                fbits: 0,
+                result: -80,
+                flags: 1,
+                roundingMode: 3,
+            },
+            f32_to_fix32_TestCase {
+                a: 0xBC73_4C71,
+                fbits: 31,
+                result: -31_889_634,
+                flags: 0,
+                roundingMode: 1,
+            },
+            f32_to_fix32_TestCase {
+                a: 0x3B19_2890,
+                fbits: 32,
+                result: 10_037_392,
+                flags: 0,
+                roundingMode: 3,
+            },
+            f32_to_fix32_TestCase {
+                a: 0x45AB_2D3F,
+                fbits: 13,
+                result: 44_872_956,
+                flags: 0,
+                roundingMode: 2,
+            },
+            f32_to_fix32_TestCase {
+                a: 0xBBA7_1E9B,
+                fbits: 31,
+                result: -10_952_347,
+                flags: 0,
+                roundingMode: 0,
+            },
+            f32_to_fix32_TestCase {
+                a: 0x3B3C_AB00,
+                fbits: 31,
+                result: 6_182_272,
+                flags: 0,
+                roundingMode: 4,
+            },
+            f32_to_fix32_TestCase {
+                a: 0x0000_0001,
+                fbits: 170,
+                result: 2_097_152,
+                flags: 0,
+                roundingMode: 0,
+            },
+            f32_to_fix32_TestCase {
+                a: 0x0000_0001,
+                fbits: 200,
+                result: 2_147_483_647,
+                flags: 16,
+                roundingMode: 0,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = f32_to_fix32(float32_t { v: c.a }, c.fbits, c.roundingMode, true);
+            assert_eq!((i, res, flags), (i, c.result, c.flags));
+        }
+    }
+}
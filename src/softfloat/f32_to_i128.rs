@@ -0,0 +1,35 @@
+use super::{
+    f32_to_limbs, float32_t, i128_fromNegOverflow, i128_fromPosOverflow, softfloat_flag_invalid,
+};
+
+#[must_use]
+pub const fn f32_to_i128(a: float32_t, roundingMode: u8, exact: bool) -> (i128, u8) {
+    let mut z = [0; 2];
+    let (sign, flags) = f32_to_limbs(a, &mut z, roundingMode, exact);
+    let absZ = ((z[1] as u128) << 64) | z[0] as u128;
+
+    if flags & softfloat_flag_invalid != 0
+        || (if sign {
+            (i128::MIN as u128) < absZ
+        } else {
+            (i128::MIN as u128) <= absZ
+        })
+    {
+        return (
+            if sign {
+                i128_fromNegOverflow
+            } else {
+                i128_fromPosOverflow
+            },
+            softfloat_flag_invalid,
+        );
+    }
+    return (
+        if sign {
+            (absZ as i128).wrapping_neg()
+        } else {
+            absZ as i128
+        },
+        flags,
+    );
+}
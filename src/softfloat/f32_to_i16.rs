@@ -0,0 +1,15 @@
+use super::{
+    f32_to_i32, float32_t, i16_fromNegOverflow, i16_fromPosOverflow, softfloat_flag_invalid,
+};
+
+#[must_use]
+pub const fn f32_to_i16(a: float32_t, roundingMode: u8, exact: bool) -> (i16, u8) {
+    let (z, flags) = f32_to_i32(a, roundingMode, exact);
+    if (i16::MAX as i32) < z {
+        return (i16_fromPosOverflow, softfloat_flag_invalid);
+    }
+    if z < (i16::MIN as i32) {
+        return (i16_fromNegOverflow, softfloat_flag_invalid);
+    }
+    return (z as i16, flags);
+}
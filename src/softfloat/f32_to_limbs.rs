@@ -0,0 +1,169 @@
+use super::{
+    expF32UI, float32_t, fracF32UI, signF32UI, softfloat_flag_invalid, softfloat_roundToLimbs,
+};
+
+/// Converts `a` to an integer of arbitrary length, stored in `z` as
+/// little-endian 64-bit limbs in sign-magnitude form. Returns the sign.
+///
+/// Infinities and magnitudes that do not fit saturate `z` and raise the
+/// invalid exception; a NaN does the same with a positive sign.
+#[must_use]
+pub const fn f32_to_limbs(
+    a: float32_t,
+    z: &mut [u64],
+    roundingMode: u8,
+    exact: bool,
+) -> (bool, u8) {
+    let mut sign = signF32UI(a.v);
+    let exp = expF32UI(a.v);
+    let mut sig = fracF32UI(a.v) as u64;
+
+    if exp == 0xFF {
+        if sig != 0 {
+            sign = false;
+        }
+        let mut i = 0;
+        while i < z.len() {
+            z[i] = u64::MAX;
+            i += 1;
+        }
+        return (sign, softfloat_flag_invalid);
+    }
+
+    if exp != 0 {
+        sig |= 0x0080_0000;
+    }
+    let shiftDist = (if exp != 0 { exp } else { 1 }) - 0x96;
+
+    return (
+        sign,
+        softfloat_roundToLimbs(sign, sig, shiftDist, z, roundingMode, exact),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_f32_to_limbs() {
+        struct f32_to_limbs_TestCase {
+            a: u32,
+            limbs: usize,
+            sign: bool,
+            result: [u64; 3],
+            flags: u8,
+            roundingMode: u8,
+        }
+
+        let cases = [
+            f32_to_limbs_TestCase {
+                a: 0x4911_0154,
+                limbs: 1,
+                sign: false,
+                result: [0x9_1015, 0x0, 0x0],
+                flags: 1,
+                roundingMode: 6,
+            },
+            f32_to_limbs_TestCase {
+                a: 0xCF24_3BB1,
+                limbs: 1,
+                sign: true,
+                result: [0xA43B_B100, 0x0, 0x0],
+                flags: 0,
+                roundingMode: 2,
+            },
+            f32_to_limbs_TestCase {
+                a: 0xCDAE_EC0A,
+                limbs: 1,
+                sign: true,
+                result: [0x15DD_8140, 0x0, 0x0],
+                flags: 0,
+                roundingMode: 3,
+            },
+            f32_to_limbs_TestCase {
+                a: 0xD3CE_D4D2,
+                limbs: 3,
+                sign: true,
+                result: [0x19D_A9A4_0000, 0x0, 0x0],
+                flags: 0,
+                roundingMode: 3,
+            },
+            f32_to_limbs_TestCase {
+                a: 0xCD2A_D77F,
+                limbs: 3,
+                sign: true,
+                result: [0xAAD_77F0, 0x0, 0x0],
+                flags: 0,
+                roundingMode: 6,
+            },
+            f32_to_limbs_TestCase {
+                a: 0x6C62_E6AF,
+                limbs: 2,
+                sign: false,
+                result: [0x0, 0x38B_9ABC, 0x0],
+                flags: 0,
+                roundingMode: 4,
+            },
+            f32_to_limbs_TestCase {
+                a: 0x6CFD_EDC1,
+                limbs: 3,
+                sign: false,
+                result: [0x0, 0x7EF_6E08, 0x0],
+                flags: 0,
+                roundingMode: 4,
+            },
+            f32_to_limbs_TestCase {
+                a: 0x4773_2881,
+                limbs: 2,
+                sign: false,
+                result: [0xF329, 0x0, 0x0],
+                flags: 1,
+                roundingMode: 0,
+            },
+            f32_to_limbs_TestCase {
+                a: 0x4329_903E,
+                limbs: 3,
+                sign: false,
+                result: [0xAA, 0x0, 0x0],
+                flags: 1,
+                roundingMode: 3,
+            },
+            f32_to_limbs_TestCase {
+                a: 0x3EEE_B431,
+                limbs: 2,
+                sign: false,
+                result: [0x0, 0x0, 0x0],
+                flags: 1,
+                roundingMode: 4,
+            },
+            f32_to_limbs_TestCase {
+                a: 0xFF0E_AF67,
+                limbs: 2,
+                sign: true,
+                result: [0x0, 0x8EAF_6700_0000_0000, 0x0],
+                flags: 0,
+                roundingMode: 1,
+            },
+            f32_to_limbs_TestCase {
+                a: 0xFF6D_9E8D,
+                limbs: 1,
+                sign: true,
+                result: [0xFFFF_FFFF_FFFF_FFFF, 0x0, 0x0],
+                flags: 16,
+                roundingMode: 6,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let mut z = [0; 3];
+            let (sign, flags) = f32_to_limbs(
+                float32_t { v: c.a },
+                &mut z[..c.limbs],
+                c.roundingMode,
+                true,
+            );
+            assert_eq!((i, sign, z, flags), (i, c.sign, c.result, c.flags));
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use super::{f32_to_ufix32, float32_t, softfloat_flag_invalid, ui16_fromPosOverflow};
+
+/// Converts `a` to a unsigned 16-bit fixed-point number with `fbits` fractional
+/// bits, that is, rounds `a * 2^fbits` to an integer. Any `fbits` is allowed.
+#[must_use]
+pub const fn f32_to_ufix16(a: float32_t, fbits: u8, roundingMode: u8, exact: bool) -> (u16, u8) {
+    let (z, flags) = f32_to_ufix32(a, fbits, roundingMode, exact);
+    if (u16::MAX as u32) < z {
+        return (ui16_fromPosOverflow, softfloat_flag_invalid);
+    }
+    return (z as u16, flags);
+}
@@ -0,0 +1,49 @@
+use super::{
+    expF32UI, float32_t, fracF32UI, signF32UI, softfloat_flag_invalid,
+    softfloat_normSubnormalF32Sig, softfloat_roundToUI64, softfloat_shiftRightJam64Extra,
+    ui64_fromNaN, ui64_fromNegOverflow, ui64_fromPosOverflow,
+};
+
+/// Converts `a` to a unsigned 64-bit fixed-point number with `fbits` fractional
+/// bits, that is, rounds `a * 2^fbits` to an integer. Any `fbits` is allowed.
+#[must_use]
+pub const fn f32_to_ufix64(a: float32_t, fbits: u8, roundingMode: u8, exact: bool) -> (u64, u8) {
+    let sign = signF32UI(a.v);
+    let mut exp = expF32UI(a.v);
+    let mut sig = fracF32UI(a.v);
+    if exp == 0 && sig != 0 {
+        // A large `fbits` can bring a subnormal into range.
+        let normExpSig = softfloat_normSubnormalF32Sig(sig);
+        exp = normExpSig.exp;
+        sig = normExpSig.sig;
+    }
+
+    let shiftDist: i16 = (0xBE as i16).wrapping_sub(exp).wrapping_sub(fbits as i16);
+    if shiftDist < 0 {
+        return (
+            if exp == 0xFF && sig != 0 {
+                ui64_fromNaN
+            } else if sign {
+                ui64_fromNegOverflow
+            } else {
+                ui64_fromPosOverflow
+            },
+            softfloat_flag_invalid,
+        );
+    }
+
+    if exp != 0 {
+        sig |= 0x0080_0000;
+    }
+
+    let mut sig64 = (sig as u64) << 40;
+    let mut extra: u64 = 0;
+
+    if shiftDist != 0 {
+        let sig64Extra = softfloat_shiftRightJam64Extra(sig64, 0, shiftDist as u16 as u32);
+        sig64 = sig64Extra.v;
+        extra = sig64Extra.extra;
+    }
+
+    return softfloat_roundToUI64(sign, sig64, extra, roundingMode, exact);
+}
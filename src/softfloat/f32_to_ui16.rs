@@ -0,0 +1,10 @@
+use super::{f32_to_ui32, float32_t, softfloat_flag_invalid, ui16_fromPosOverflow};
+
+#[must_use]
+pub const fn f32_to_ui16(a: float32_t, roundingMode: u8, exact: bool) -> (u16, u8) {
+    let (z, flags) = f32_to_ui32(a, roundingMode, exact);
+    if (u16::MAX as u32) < z {
+        return (ui16_fromPosOverflow, softfloat_flag_invalid);
+    }
+    return (z as u16, flags);
+}
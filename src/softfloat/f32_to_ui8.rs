@@ -0,0 +1,129 @@
+use super::{f32_to_ui32, float32_t, softfloat_flag_invalid, ui8_fromPosOverflow};
+
+#[must_use]
+pub const fn f32_to_ui8(a: float32_t, roundingMode: u8, exact: bool) -> (u8, u8) {
+    let (z, flags) = f32_to_ui32(a, roundingMode, exact);
+    if (u8::MAX as u32) < z {
+        return (ui8_fromPosOverflow, softfloat_flag_invalid);
+    }
+    return (z as u8, flags);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_f32_to_ui8() {
+        struct f32_to_ui8_TestCase {
+            a: u32,
+            result: u8,
+            flags: u8,
+            roundingMode: u8,
+        }
+
+        let cases = [
+            f32_to_ui8_TestCase {
+                a: 0x3F00_0000,
+                result: 0,
+                flags: 1,
+                roundingMode: 0,
+            },
+            f32_to_ui8_TestCase {
+                a: 0x3FC0_0000,
+                result: 2,
+                flags: 1,
+                roundingMode: 0,
+            },
+            f32_to_ui8_TestCase {
+                a: 0x4020_0000,
+                result: 2,
+                flags: 1,
+                roundingMode: 0,
+            },
+            f32_to_ui8_TestCase {
+                a: 0x4020_0000,
+                result: 3,
+                flags: 1,
+                roundingMode: 4,
+            },
+            f32_to_ui8_TestCase {
+                a: 0x437F_4000,
+                result: 255,
+                flags: 1,
+                roundingMode: 0,
+            },
+            f32_to_ui8_TestCase {
+                a: 0x437F_8000,
+                result: 255,
+                flags: 16,
+                roundingMode: 0,
+            },
+            f32_to_ui8_TestCase {
+                a: 0x4380_0000,
+                result: 255,
+                flags: 16,
+                roundingMode: 1,
+            },
+            f32_to_ui8_TestCase {
+                a: 0xBEC0_0000,
+                result: 0,
+                flags: 1,
+                roundingMode: 1,
+            },
+            f32_to_ui8_TestCase {
+                a: 0xBEC0_0000,
+                result: 0,
+                flags: 16,
+                roundingMode: 2,
+            },
+            f32_to_ui8_TestCase {
+                a: 0xBF80_0000,
+                result: 0,
+                flags: 16,
+                roundingMode: 0,
+            },
+            f32_to_ui8_TestCase {
+                a: 0x4348_C000,
+                result: 200,
+                flags: 1,
+                roundingMode: 2,
+            },
+            f32_to_ui8_TestCase {
+                a: 0x4348_C000,
+                result: 201,
+                flags: 1,
+                roundingMode: 3,
+            },
+            f32_to_ui8_TestCase {
+                a: 0x4010_0000,
+                result: 3,
+                flags: 1,
+                roundingMode: 6,
+            },
+            f32_to_ui8_TestCase {
+                a: 0x4050_0000,
+                result: 3,
+                flags: 1,
+                roundingMode: 6,
+            },
+            f32_to_ui8_TestCase {
+                a: 0x7FC0_0000,
+                result: 255,
+                flags: 16,
+                roundingMode: 0,
+            },
+            f32_to_ui8_TestCase {
+                a: 0xFF80_0000,
+                result: 0,
+                flags: 16,
+                roundingMode: 0,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = f32_to_ui8(float32_t { v: c.a }, c.roundingMode, true);
+            assert_eq!((i, res, flags), (i, c.result, c.flags));
+        }
+    }
+}
@@ -2,7 +2,7 @@ use super::{expF64UI, float64_t, fracF64UI, isNaNF64UI, signF64UI, softfloat_isS
 
 #[must_use]
 pub const fn f64_classify(a: float64_t) -> u16 {
-    let infOrNaN = expF64UI(a.v) == 0xFF;
+    let infOrNaN = expF64UI(a.v) == 0x7FF;
     let subnormalOrZero = expF64UI(a.v) == 0;
     let sign = signF64UI(a.v);
     let fracZero = fracF64UI(a.v) == 0;
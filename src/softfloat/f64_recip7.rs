@@ -0,0 +1,33 @@
+use super::{
+    defaultNaNF64UI, f64_classify, float64_t, softfloat_flag_infinite, softfloat_flag_invalid,
+    softfloat_recip7,
+};
+
+/// Reciprocal estimate to 7 bits, as computed by the RISC-V `vfrec7.v`
+/// instruction.
+///
+/// Subnormal inputs too small for the estimate to be finite overflow
+/// according to `roundingMode`.
+#[must_use]
+pub const fn f64_recip7(a: float64_t, roundingMode: u8) -> (float64_t, u8) {
+    let (uiZ, flags) = match f64_classify(a) {
+        // -inf
+        0x001 => (0x8000_0000_0000_0000, 0),
+        // +inf
+        0x080 => (0, 0),
+        // -0
+        0x008 => (0xFFF0_0000_0000_0000, softfloat_flag_infinite),
+        // +0
+        0x010 => (0x7FF0_0000_0000_0000, softfloat_flag_infinite),
+        // sNaN
+        0x100 => (defaultNaNF64UI, softfloat_flag_invalid),
+        // qNaN
+        0x200 => (defaultNaNF64UI, 0),
+        // normal or subnormal
+        class => {
+            let (uiZ, flags) = softfloat_recip7(a.v, 11, 52, roundingMode, (class & 0x024) != 0);
+            (uiZ, flags)
+        }
+    };
+    return (float64_t { v: uiZ }, flags);
+}
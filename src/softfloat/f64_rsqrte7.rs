@@ -0,0 +1,112 @@
+use super::{
+    defaultNaNF64UI, f64_classify, float64_t, softfloat_flag_infinite, softfloat_flag_invalid,
+    softfloat_rsqrte7,
+};
+
+/// Reciprocal square root estimate to 7 bits, as computed by the RISC-V
+/// `vfrsqrt7.v` instruction.
+#[must_use]
+pub const fn f64_rsqrte7(a: float64_t) -> (float64_t, u8) {
+    let (uiZ, flags) = match f64_classify(a) {
+        // -inf, -normal, -subnormal, sNaN
+        0x001 | 0x002 | 0x004 | 0x100 => (defaultNaNF64UI, softfloat_flag_invalid),
+        // qNaN
+        0x200 => (defaultNaNF64UI, 0),
+        // -0
+        0x008 => (0xFFF0_0000_0000_0000, softfloat_flag_infinite),
+        // +0
+        0x010 => (0x7FF0_0000_0000_0000, softfloat_flag_infinite),
+        // +inf
+        0x080 => (0, 0),
+        // +normal or +subnormal
+        class => (softfloat_rsqrte7(a.v, 11, 52, class == 0x020), 0),
+    };
+    return (float64_t { v: uiZ }, flags);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_f64_rsqrte7() {
+        struct f64_rsqrte7_TestCase {
+            a: u64,
+            ret: u64,
+            flags: u8,
+        }
+
+        let cases = [
+            f64_rsqrte7_TestCase {
+                a: 0x3FF0_0000_0000_0000,
+                ret: 0x3FEF_E000_0000_0000,
+                flags: 0,
+            },
+            f64_rsqrte7_TestCase {
+                a: 0x4000_0000_0000_0000,
+                ret: 0x3FE6_8000_0000_0000,
+                flags: 0,
+            },
+            f64_rsqrte7_TestCase {
+                a: 0x4010_0000_0000_0000,
+                ret: 0x3FDF_E000_0000_0000,
+                flags: 0,
+            },
+            f64_rsqrte7_TestCase {
+                a: 0x7FEF_FFFF_FFFF_FFFF,
+                ret: 0x1FF0_0000_0000_0000,
+                flags: 0,
+            },
+            f64_rsqrte7_TestCase {
+                a: 0x0000_0000_0000_0001,
+                ret: 0x617F_E000_0000_0000,
+                flags: 0,
+            },
+            f64_rsqrte7_TestCase {
+                a: 0x000F_FFFF_FFFF_FFFF,
+                ret: 0x5FE0_0000_0000_0000,
+                flags: 0,
+            },
+            f64_rsqrte7_TestCase {
+                a: 0x3F50_624D_D2F1_A9FC,
+                ret: 0x403F_A000_0000_0000,
+                flags: 0,
+            },
+            f64_rsqrte7_TestCase {
+                a: 0x0000_0000_0000_0000,
+                ret: 0x7FF0_0000_0000_0000,
+                flags: 8,
+            },
+            f64_rsqrte7_TestCase {
+                a: 0x8000_0000_0000_0000,
+                ret: 0xFFF0_0000_0000_0000,
+                flags: 8,
+            },
+            f64_rsqrte7_TestCase {
+                a: 0x7FF0_0000_0000_0000,
+                ret: 0,
+                flags: 0,
+            },
+            f64_rsqrte7_TestCase {
+                a: 0xBFF0_0000_0000_0000,
+                ret: 0x7FF8_0000_0000_0000,
+                flags: 16,
+            },
+            f64_rsqrte7_TestCase {
+                a: 0x8000_0000_0000_0001,
+                ret: 0x7FF8_0000_0000_0000,
+                flags: 16,
+            },
+            f64_rsqrte7_TestCase {
+                a: 0x7FF8_0000_0000_0000,
+                ret: 0x7FF8_0000_0000_0000,
+                flags: 0,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = f64_rsqrte7(float64_t { v: c.a });
+            assert_eq!((i, res.v, flags), (i, c.ret, c.flags));
+        }
+    }
+}
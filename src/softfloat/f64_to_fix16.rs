@@ -0,0 +1,17 @@
+use super::{
+    f64_to_fix32, float64_t, i16_fromNegOverflow, i16_fromPosOverflow, softfloat_flag_invalid,
+};
+
+/// Converts `a` to a signed 16-bit fixed-point number with `fbits` fractional
+/// bits, that is, rounds `a * 2^fbits` to an integer. Any `fbits` is allowed.
+#[must_use]
+pub const fn f64_to_fix16(a: float64_t, fbits: u8, roundingMode: u8, exact: bool) -> (i16, u8) {
+    let (z, flags) = f64_to_fix32(a, fbits, roundingMode, exact);
+    if (i16::MAX as i32) < z {
+        return (i16_fromPosOverflow, softfloat_flag_invalid);
+    }
+    if z < (i16::MIN as i32) {
+        return (i16_fromNegOverflow, softfloat_flag_invalid);
+    }
+    return (z as i16, flags);
+}
@@ -0,0 +1,142 @@
+use super::{
+    f64_to_limbs, float64_t, i128_fromNegOverflow, i128_fromPosOverflow, softfloat_flag_invalid,
+};
+
+#[must_use]
+pub const fn f64_to_i128(a: float64_t, roundingMode: u8, exact: bool) -> (i128, u8) {
+    let mut z = [0; 2];
+    let (sign, flags) = f64_to_limbs(a, &mut z, roundingMode, exact);
+    let absZ = ((z[1] as u128) << 64) | z[0] as u128;
+
+    if flags & softfloat_flag_invalid != 0
+        || (if sign {
+            (i128::MIN as u128) < absZ
+        } else {
+            (i128::MIN as u128) <= absZ
+        })
+    {
+        return (
+            if sign {
+                i128_fromNegOverflow
+            } else {
+                i128_fromPosOverflow
+            },
+            softfloat_flag_invalid,
+        );
+    }
+    return (
+        if sign {
+            (absZ as i128).wrapping_neg()
+        } else {
+            absZ as i128
+        },
+        flags,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_f64_to_i128() {
+        struct f64_to_i128_TestCase {
+            a: u64,
+            result: i128,
+            flags: u8,
+            roundingMode: u8,
+        }
+
+        let cases = [
+            f64_to_i128_TestCase {
+                a: 0xC25D_B4D9_B89D_A780,
+                result: -510_352_876_151,
+                flags: 1,
+                roundingMode: 6,
+            },
+            f64_to_i128_TestCase {
+                a: 0xC783_5667_BA85_5D81,
+                result: -3_212_996_664_456_168_266_148_220_079_196_602_368,
+                flags: 0,
+                roundingMode: 4,
+            },
+            f64_to_i128_TestCase {
+                a: 0xC60F_C0B9_3DDE_7C55,
+                result: -314_464_762_909_126_641_429_742_878_720,
+                flags: 0,
+                roundingMode: 4,
+            },
+            f64_to_i128_TestCase {
+                a: 0xC15C_AF75_31B6_1C49,
+                result: -7_519_701,
+                flags: 1,
+                roundingMode: 2,
+            },
+            f64_to_i128_TestCase {
+                a: 0xC39D_E824_FB6A_288C,
+                result: -538_753_270_983_303_936,
+                flags: 0,
+                roundingMode: 0,
+            },
+            f64_to_i128_TestCase {
+                a: 0x443E_AB62_6AF6_0C8F,
+                result: 565_751_872_944_495_067_136,
+                flags: 0,
+                roundingMode: 2,
+            },
+            f64_to_i128_TestCase {
+                a: 0x404B_817E_9AF6_24AA,
+                result: 55,
+                flags: 1,
+                roundingMode: 6,
+            },
+            f64_to_i128_TestCase {
+                a: 0x4257_FAB2_C401_25E3,
+                result: 411_961_069_573,
+                flags: 1,
+                roundingMode: 4,
+            },
+            f64_to_i128_TestCase {
+                a: 0x46BC_E7FD_3EBF_0482,
+                result: 586_287_550_005_982_602_955_649_894_580_224,
+                flags: 0,
+                roundingMode: 1,
+            },
+            f64_to_i128_TestCase {
+                a: 0xC6C8_4106_BE48_CB4D,
+                result: -983_859_496_001_585_102_433_176_690_622_464,
+                flags: 0,
+                roundingMode: 1,
+            },
+            f64_to_i128_TestCase {
+                a: 0x47AF_592F_7278_2433,
+                result: 20_834_572_316_092_926_661_179_902_737_990_549_504,
+                flags: 0,
+                roundingMode: 3,
+            },
+            f64_to_i128_TestCase {
+                a: 0xC7F6_7F79_0A5C_7080,
+                result: -170_141_183_460_469_231_731_687_303_715_884_105_728,
+                flags: 16,
+                roundingMode: 6,
+            },
+            f64_to_i128_TestCase {
+                a: 0xC1C9_60B2_DD40_EEFE,
+                result: -851_535_291,
+                flags: 1,
+                roundingMode: 4,
+            },
+            f64_to_i128_TestCase {
+                a: 0xC727_E76A_EF77_AFD7,
+                result: -62_058_268_330_790_491_682_004_239_378_284_544,
+                flags: 0,
+                roundingMode: 6,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = f64_to_i128(float64_t { v: c.a }, c.roundingMode, true);
+            assert_eq!((i, res, flags), (i, c.result, c.flags));
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use super::{
+    f64_to_i32, float64_t, i8_fromNegOverflow, i8_fromPosOverflow, softfloat_flag_invalid,
+};
+
+#[must_use]
+pub const fn f64_to_i8(a: float64_t, roundingMode: u8, exact: bool) -> (i8, u8) {
+    let (z, flags) = f64_to_i32(a, roundingMode, exact);
+    if (i8::MAX as i32) < z {
+        return (i8_fromPosOverflow, softfloat_flag_invalid);
+    }
+    if z < (i8::MIN as i32) {
+        return (i8_fromNegOverflow, softfloat_flag_invalid);
+    }
+    return (z as i8, flags);
+}
@@ -0,0 +1,42 @@
+use super::{
+    expF64UI, float64_t, fracF64UI, signF64UI, softfloat_flag_invalid, softfloat_roundToLimbs,
+};
+
+/// Converts `a` to an integer of arbitrary length, stored in `z` as
+/// little-endian 64-bit limbs in sign-magnitude form. Returns the sign.
+///
+/// Infinities and magnitudes that do not fit saturate `z` and raise the
+/// invalid exception; a NaN does the same with a positive sign.
+#[must_use]
+pub const fn f64_to_limbs(
+    a: float64_t,
+    z: &mut [u64],
+    roundingMode: u8,
+    exact: bool,
+) -> (bool, u8) {
+    let mut sign = signF64UI(a.v);
+    let exp = expF64UI(a.v);
+    let mut sig = fracF64UI(a.v) as u64;
+
+    if exp == 0x7FF {
+        if sig != 0 {
+            sign = false;
+        }
+        let mut i = 0;
+        while i < z.len() {
+            z[i] = u64::MAX;
+            i += 1;
+        }
+        return (sign, softfloat_flag_invalid);
+    }
+
+    if exp != 0 {
+        sig |= 0x0010_0000_0000_0000;
+    }
+    let shiftDist = (if exp != 0 { exp } else { 1 }) - 0x433;
+
+    return (
+        sign,
+        softfloat_roundToLimbs(sign, sig, shiftDist, z, roundingMode, exact),
+    );
+}
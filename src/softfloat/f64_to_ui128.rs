@@ -0,0 +1,18 @@
+use super::{
+    f64_to_limbs, float64_t, softfloat_flag_invalid, ui128_fromNegOverflow, ui128_fromPosOverflow,
+};
+
+#[must_use]
+pub const fn f64_to_ui128(a: float64_t, roundingMode: u8, exact: bool) -> (u128, u8) {
+    let mut z = [0; 2];
+    let (sign, flags) = f64_to_limbs(a, &mut z, roundingMode, exact);
+    let absZ = ((z[1] as u128) << 64) | z[0] as u128;
+
+    if sign && (absZ != 0 || flags & softfloat_flag_invalid != 0) {
+        return (ui128_fromNegOverflow, softfloat_flag_invalid);
+    }
+    if flags & softfloat_flag_invalid != 0 {
+        return (ui128_fromPosOverflow, softfloat_flag_invalid);
+    }
+    return (absZ, flags);
+}
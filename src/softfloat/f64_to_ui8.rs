@@ -0,0 +1,10 @@
+use super::{f64_to_ui32, float64_t, softfloat_flag_invalid, ui8_fromPosOverflow};
+
+#[must_use]
+pub const fn f64_to_ui8(a: float64_t, roundingMode: u8, exact: bool) -> (u8, u8) {
+    let (z, flags) = f64_to_ui32(a, roundingMode, exact);
+    if (u8::MAX as u32) < z {
+        return (ui8_fromPosOverflow, softfloat_flag_invalid);
+    }
+    return (z as u8, flags);
+}
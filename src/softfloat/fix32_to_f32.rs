@@ -0,0 +1,40 @@
+use super::{float32_t, softfloat_normRoundPackToF32, softfloat_roundPackToF32};
+
+/// Converts the signed 32-bit fixed-point number `a` with `fbits` fractional
+/// bits, that is, `a * 2^-fbits`. Any `fbits` is allowed.
+#[must_use]
+pub const fn fix32_to_f32(
+    a: i32,
+    fbits: u8,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let sign = a < 0;
+
+    if a == 0 {
+        return (float32_t { v: 0 }, 0);
+    }
+    if a == i32::MIN {
+        // `-2^31` does not fit the normalizing shift, and a large `fbits`
+        // makes it subnormal.
+        return softfloat_roundPackToF32(
+            true,
+            (0x9D as i16).wrapping_sub(fbits as i16),
+            0x4000_0000,
+            roundingMode,
+            detectTininess,
+        );
+    }
+    let absA = if sign {
+        (a as u32).wrapping_neg()
+    } else {
+        a as u32
+    };
+    return softfloat_normRoundPackToF32(
+        sign,
+        (0x9C as i16).wrapping_sub(fbits as i16),
+        absA,
+        roundingMode,
+        detectTininess,
+    );
+}
@@ -0,0 +1,13 @@
+use super::{fix64_to_f64, float64_t};
+
+/// Converts the signed 32-bit fixed-point number `a` with `fbits` fractional
+/// bits, that is, `a * 2^-fbits`. Any `fbits` is allowed.
+#[must_use]
+pub const fn fix32_to_f64(
+    a: i32,
+    fbits: u8,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float64_t, u8) {
+    return fix64_to_f64(a as i64, fbits, roundingMode, detectTininess);
+}
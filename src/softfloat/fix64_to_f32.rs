@@ -0,0 +1,118 @@
+use super::{
+    float32_t, packToF32UI, softfloat_countLeadingZeros64, softfloat_roundPackToF32,
+    softfloat_shortShiftRightJam64,
+};
+
+/// Converts the signed 64-bit fixed-point number `a` with `fbits` fractional
+/// bits, that is, `a * 2^-fbits`. Any `fbits` is allowed.
+#[must_use]
+pub const fn fix64_to_f32(
+    a: i64,
+    fbits: u8,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let sign = a < 0;
+    let absA = if sign {
+        (a as u64).wrapping_neg()
+    } else {
+        a as u64
+    };
+    if a == 0 {
+        return (float32_t { v: 0 }, 0);
+    }
+    let mut shiftDist = softfloat_countLeadingZeros64(absA).wrapping_sub(40) as i8;
+    let exp = (0x95 as i16)
+        .wrapping_sub(shiftDist as i16)
+        .wrapping_sub(fbits as i16);
+    // A large `fbits` gives a subnormal or zero, which needs rounding.
+    if 0 <= shiftDist && 0 <= exp {
+        return (
+            float32_t {
+                v: packToF32UI(sign, exp, (absA << shiftDist) as u32),
+            },
+            0,
+        );
+    }
+    shiftDist = shiftDist.wrapping_add(7);
+    let sig: u32 = if shiftDist < 0 {
+        softfloat_shortShiftRightJam64(absA, shiftDist.wrapping_neg() as u8) as u32
+    } else {
+        (absA << shiftDist) as u32
+    };
+    return softfloat_roundPackToF32(
+        sign,
+        (0x9C as i16)
+            .wrapping_sub(shiftDist as i16)
+            .wrapping_sub(fbits as i16),
+        sig,
+        roundingMode,
+        detectTininess,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fix64_to_f32() {
+        struct fix64_to_f32_TestCase {
+            a: i64,
+            fbits: u8,
+            result: u32,
+            flags: u8,
+            roundingMode: u8,
+        }
+
+        let cases = [
+            fix64_to_f32_TestCase {
+                a: 3,
+                fbits: 1,
+                result: 0x3FC0_0000,
+                flags: 0,
+                roundingMode: 0,
+            },
+            fix64_to_f32_TestCase {
+                a: -9_223_372_036_854_775_807,
+                fbits: 63,
+                result: 0xBF80_0000,
+                flags: 1,
+                roundingMode: 0,
+            },
+            fix64_to_f32_TestCase {
+                a: 1,
+                fbits: 149,
+                result: 0x0000_0001,
+                flags: 0,
+                roundingMode: 0,
+            },
+            fix64_to_f32_TestCase {
+                a: 3,
+                fbits: 150,
+                result: 0x0000_0002,
+                flags: 3,
+                roundingMode: 0,
+            },
+            fix64_to_f32_TestCase {
+                a: -1,
+                fbits: 255,
+                result: 0x8000_0000,
+                flags: 3,
+                roundingMode: 0,
+            },
+            fix64_to_f32_TestCase {
+                a: -1,
+                fbits: 255,
+                result: 0x8000_0001,
+                flags: 3,
+                roundingMode: 2,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = fix64_to_f32(c.a, c.fbits, c.roundingMode, 1);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+}
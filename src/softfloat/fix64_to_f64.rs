@@ -0,0 +1,143 @@
+use super::{float64_t, packToF64, softfloat_normRoundPackToF64};
+
+/// Converts the signed 64-bit fixed-point number `a` with `fbits` fractional
+/// bits, that is, `a * 2^-fbits`. Any `fbits` is allowed.
+#[must_use]
+pub const fn fix64_to_f64(
+    a: i64,
+    fbits: u8,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float64_t, u8) {
+    let sign = a < 0;
+    if (a as u64).trailing_zeros() >= 63 {
+        return (
+            if sign {
+                packToF64(true, (0x43E as i16).wrapping_sub(fbits as i16), 0)
+            } else {
+                float64_t { v: 0 }
+            },
+            0,
+        );
+    }
+    let absA = if sign {
+        (a as u64).wrapping_neg()
+    } else {
+        a as u64
+    };
+    return softfloat_normRoundPackToF64(
+        sign,
+        (0x43C as i16).wrapping_sub(fbits as i16),
+        absA,
+        roundingMode,
+        detectTininess,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fix64_to_f64() {
+        struct fix64_to_f64_TestCase {
+            a: i64,
+            fbits: u8,
+            result: u64,
+            flags: u8,
+            roundingMode: u8,
+        }
+
+        let cases = [
+            fix64_to_f64_TestCase {
+                a: 103_929_166_649_660_881,
+                fbits: 0,
+                result: 0x4377_13B0_6C3F_495D,
+                flags: 1,
+                roundingMode: 2,
+            },
+            fix64_to_f64_TestCase {
+                a: -9_223_372_036_854_775_807,
+                fbits: 0,
+                result: 0xC3E0_0000_0000_0000,
+                flags: 1,
+                roundingMode: 4,
+            },
+            fix64_to_f64_TestCase {
+                a: 3_199_516,
+                fbits: 36,
+                result: 0x3F08_690E_0000_0000,
+                flags: 0,
+                roundingMode: 2,
+            },
+            fix64_to_f64_TestCase {
+                a: -9_169_918_384_766_275_012,
+                fbits: 56,
+                result: 0xC05F_D086_0BF1_D453,
+                flags: 1,
+                roundingMode: 6,
+            },
+            fix64_to_f64_TestCase {
+                a: 1_776_984,
+                fbits: 64,
+                result: 0x3D3B_1D58_0000_0000,
+                flags: 0,
+                roundingMode: 3,
+            },
+            fix64_to_f64_TestCase {
+                a: 58,
+                fbits: 0,
+                result: 0x404D_0000_0000_0000,
+                flags: 0,
+                roundingMode: 4,
+            },
+            fix64_to_f64_TestCase {
+                a: 1_974_646,
+                fbits: 22,
+                result: 0x3FDE_2176_0000_0000,
+                flags: 0,
+                roundingMode: 1,
+            },
+            fix64_to_f64_TestCase {
+                a: -9_193_440_335_498_603_074,
+                fbits: 0,
+                result: 0xC3DF_E56A_51AD_491A,
+                flags: 1,
+                roundingMode: 3,
+            },
+            fix64_to_f64_TestCase {
+                a: 77_258_466_709_329_216,
+                fbits: 64,
+                result: 0x3F71_27A2_A881_9D54,
+                flags: 0,
+                roundingMode: 6,
+            },
+            fix64_to_f64_TestCase {
+                a: -9_223_372_036_854_710_160,
+                fbits: 19,
+                result: 0xC2AF_FFFF_FFFF_FFBF,
+                flags: 1,
+                roundingMode: 1,
+            },
+            fix64_to_f64_TestCase {
+                a: -9_223_372_030_783_880_873,
+                fbits: 64,
+                result: 0xBFDF_FFFF_FFA5_8960,
+                flags: 1,
+                roundingMode: 0,
+            },
+            fix64_to_f64_TestCase {
+                a: 48,
+                fbits: 64,
+                result: 0x3C48_0000_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = fix64_to_f64(c.a, c.fbits, c.roundingMode, 1);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use super::{float32_t, limbs_to_f32};
+
+#[must_use]
+pub const fn i128_to_f32(a: i128, roundingMode: u8, detectTininess: u8) -> (float32_t, u8) {
+    let absA = a.unsigned_abs();
+    return limbs_to_f32(
+        a < 0,
+        &[absA as u64, (absA >> 64) as u64],
+        roundingMode,
+        detectTininess,
+    );
+}
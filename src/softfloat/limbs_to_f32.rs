@@ -0,0 +1,26 @@
+use super::{
+    float32_t, softfloat_normLimbs, softfloat_roundPackToF32, softfloat_shortShiftRightJam64,
+};
+
+/// Converts the integer `(-1)^sign * a`, whose magnitude is given as
+/// little-endian 64-bit limbs, to `float32_t`. A zero magnitude converts to
+/// positive zero.
+#[must_use]
+pub const fn limbs_to_f32(
+    sign: bool,
+    a: &[u64],
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let (sig, exp) = softfloat_normLimbs(a);
+    if sig == 0 {
+        return (float32_t { v: 0 }, 0);
+    }
+    return softfloat_roundPackToF32(
+        sign,
+        0x9C - 30 + exp,
+        softfloat_shortShiftRightJam64(sig, 33) as u32,
+        roundingMode,
+        detectTininess,
+    );
+}
@@ -0,0 +1,153 @@
+use super::{
+    float64_t, softfloat_normLimbs, softfloat_roundPackToF64, softfloat_shortShiftRightJam64,
+};
+
+/// Converts the integer `(-1)^sign * a`, whose magnitude is given as
+/// little-endian 64-bit limbs, to `float64_t`. A zero magnitude converts to
+/// positive zero.
+#[must_use]
+pub const fn limbs_to_f64(
+    sign: bool,
+    a: &[u64],
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float64_t, u8) {
+    let (sig, exp) = softfloat_normLimbs(a);
+    if sig == 0 {
+        return (float64_t { v: 0 }, 0);
+    }
+    return softfloat_roundPackToF64(
+        sign,
+        0x43C - 62 + exp,
+        softfloat_shortShiftRightJam64(sig, 1),
+        roundingMode,
+        detectTininess,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_limbs_to_f64() {
+        struct limbs_to_f64_TestCase {
+            sign: bool,
+            a: &'static [u64],
+            result: u64,
+            flags: u8,
+            roundingMode: u8,
+        }
+
+        let cases = [
+            limbs_to_f64_TestCase {
+                sign: true,
+                a: &[0x27EE_5CD6_2966_FA3D, 0xB7, 0x0],
+                result: 0xC466_E4FD_CB9A_C52D,
+                flags: 1,
+                roundingMode: 2,
+            },
+            limbs_to_f64_TestCase {
+                sign: false,
+                a: &[0x26F0_5B09_A4B3_26C9, 0x15B5, 0x0, 0x0],
+                result: 0x44B5_B526_F05B_09A4,
+                flags: 1,
+                roundingMode: 1,
+            },
+            limbs_to_f64_TestCase {
+                sign: true,
+                a: &[0x2AE7_F8E4_843C_DE25, 0x12_A044, 0x0],
+                result: 0xC532_A044_2AE7_F8E4,
+                flags: 1,
+                roundingMode: 3,
+            },
+            limbs_to_f64_TestCase {
+                sign: true,
+                a: &[0x1BC1_7957, 0x0],
+                result: 0xC1BB_C179_5700_0000,
+                flags: 0,
+                roundingMode: 0,
+            },
+            limbs_to_f64_TestCase {
+                sign: true,
+                a: &[0x4_EA5D, 0x0],
+                result: 0xC113_A974_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+            },
+            limbs_to_f64_TestCase {
+                sign: true,
+                a: &[0x7607],
+                result: 0xC0DD_81C0_0000_0000,
+                flags: 0,
+                roundingMode: 4,
+            },
+            limbs_to_f64_TestCase {
+                sign: false,
+                a: &[0x14_3751_4C58_0000],
+                result: 0x4334_3751_4C58_0000,
+                flags: 0,
+                roundingMode: 6,
+            },
+            limbs_to_f64_TestCase {
+                sign: true,
+                a: &[0x9BA6_A5AB_1244_8CEC, 0xA, 0x0],
+                result: 0xC425_374D_4B56_2489,
+                flags: 1,
+                roundingMode: 4,
+            },
+            limbs_to_f64_TestCase {
+                sign: true,
+                a: &[0xC4E7_B3FB_3D93_79DB, 0x3, 0x0, 0x0],
+                result: 0xC40E_273D_9FD9_EC9B,
+                flags: 1,
+                roundingMode: 1,
+            },
+            limbs_to_f64_TestCase {
+                sign: false,
+                a: &[0x38_DA64_7494],
+                result: 0x424C_6D32_3A4A_0000,
+                flags: 0,
+                roundingMode: 3,
+            },
+            limbs_to_f64_TestCase {
+                sign: false,
+                a: &[
+                    0x6E43_970E_811F_8A22,
+                    0x7405_7227_CD42_20A3,
+                    0xEFE1_54B8_CFBC_105F,
+                    0x4671_F8D1,
+                ],
+                result: 0x4DD1_9C7E_347B_F855,
+                flags: 1,
+                roundingMode: 4,
+            },
+            limbs_to_f64_TestCase {
+                sign: false,
+                a: &[0x857_2B2F_43C1_4012, 0x5406, 0x0, 0x0],
+                result: 0x44D5_0182_15CA_CBD1,
+                flags: 1,
+                roundingMode: 6,
+            },
+            limbs_to_f64_TestCase {
+                sign: true,
+                a: &[0x2400_0000, 0x0, 0x0],
+                result: 0xC1C2_0000_0000_0000,
+                flags: 0,
+                roundingMode: 6,
+            },
+            limbs_to_f64_TestCase {
+                sign: true,
+                a: &[0x7BBD_B900_0000_0000, 0xC, 0x0, 0x0],
+                result: 0xC428_F77B_7200_0000,
+                flags: 0,
+                roundingMode: 2,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = limbs_to_f64(c.sign, c.a, c.roundingMode, 1);
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+}
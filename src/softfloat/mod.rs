@@ -31,15 +31,30 @@ mod f32_lt;
 mod f32_lt_quiet;
 mod f32_mul;
 mod f32_mulAdd;
+mod f32_recip7;
 mod f32_rem;
 mod f32_roundToInt;
+mod f32_rsqrte7;
 mod f32_sqrt;
 mod f32_sub;
 mod f32_to_f64;
+mod f32_to_fix16;
+mod f32_to_fix32;
+mod f32_to_fix64;
+mod f32_to_i128;
+mod f32_to_i16;
 mod f32_to_i32;
 mod f32_to_i64;
+mod f32_to_i8;
+mod f32_to_limbs;
+mod f32_to_ufix16;
+mod f32_to_ufix32;
+mod f32_to_ufix64;
+mod f32_to_ui128;
+mod f32_to_ui16;
 mod f32_to_ui32;
 mod f32_to_ui64;
+mod f32_to_ui8;
 mod f64_add;
 mod f64_classify;
 mod f64_div;
@@ -52,19 +67,42 @@ mod f64_lt;
 mod f64_lt_quiet;
 mod f64_mul;
 mod f64_mulAdd;
+mod f64_recip7;
 mod f64_rem;
 mod f64_roundToInt;
+mod f64_rsqrte7;
 mod f64_sqrt;
 mod f64_sub;
 mod f64_to_f32;
+mod f64_to_fix16;
+mod f64_to_fix32;
+mod f64_to_fix64;
+mod f64_to_i128;
+mod f64_to_i16;
 mod f64_to_i32;
 mod f64_to_i64;
+mod f64_to_i8;
+mod f64_to_limbs;
+mod f64_to_ufix16;
+mod f64_to_ufix32;
+mod f64_to_ufix64;
+mod f64_to_ui128;
+mod f64_to_ui16;
 mod f64_to_ui32;
 mod f64_to_ui64;
+mod f64_to_ui8;
+mod fix32_to_f32;
+mod fix32_to_f64;
+mod fix64_to_f32;
+mod fix64_to_f64;
+mod i128_to_f32;
+mod i128_to_f64;
 mod i32_to_f32;
 mod i32_to_f64;
 mod i64_to_f32;
 mod i64_to_f64;
+mod limbs_to_f32;
+mod limbs_to_f64;
 mod s_add128;
 mod s_addMagsF32;
 mod s_addMagsF64;
@@ -72,21 +110,27 @@ mod s_approxRecip32_1;
 mod s_approxRecipSqrt32_1;
 mod s_approxRecipSqrt_1Ks;
 mod s_approxRecip_1Ks;
+mod s_approxRecip_7s;
+mod s_approxRecipSqrt_7s;
 mod s_countLeadingZeros32;
 mod s_countLeadingZeros64;
 mod s_mul64To128;
 mod s_mulAddF32;
 mod s_mulAddF64;
+mod s_normLimbs;
 mod s_normRoundPackToF32;
 mod s_normRoundPackToF64;
 mod s_normSubnormalF32Sig;
 mod s_normSubnormalF64Sig;
+mod s_recip7;
 mod s_roundPackToF32;
 mod s_roundPackToF64;
 mod s_roundToI32;
 mod s_roundToI64;
+mod s_roundToLimbs;
 mod s_roundToUI32;
 mod s_roundToUI64;
+mod s_rsqrte7;
 mod s_shiftRightJam128;
 mod s_shiftRightJam32;
 mod s_shiftRightJam64;
@@ -97,6 +141,12 @@ mod s_shortShiftRightJam64;
 mod s_sub128;
 mod s_subMagsF32;
 mod s_subMagsF64;
+mod ufix32_to_f32;
+mod ufix32_to_f64;
+mod ufix64_to_f32;
+mod ufix64_to_f64;
+mod ui128_to_f32;
+mod ui128_to_f64;
 mod ui32_to_f32;
 mod ui32_to_f64;
 mod ui64_to_f32;
@@ -139,15 +189,30 @@ pub use f32_lt::f32_lt;
 pub use f32_lt_quiet::f32_lt_quiet;
 pub use f32_mul::f32_mul;
 pub use f32_mulAdd::f32_mulAdd;
+pub use f32_recip7::f32_recip7;
 pub use f32_rem::f32_rem;
 pub use f32_roundToInt::f32_roundToInt;
+pub use f32_rsqrte7::f32_rsqrte7;
 pub use f32_sqrt::f32_sqrt;
 pub use f32_sub::f32_sub;
 pub use f32_to_f64::f32_to_f64;
+pub use f32_to_fix16::f32_to_fix16;
+pub use f32_to_fix32::f32_to_fix32;
+pub use f32_to_fix64::f32_to_fix64;
+pub use f32_to_i128::f32_to_i128;
+pub use f32_to_i16::f32_to_i16;
 pub use f32_to_i32::f32_to_i32;
 pub use f32_to_i64::f32_to_i64;
+pub use f32_to_i8::f32_to_i8;
+pub use f32_to_limbs::f32_to_limbs;
+pub use f32_to_ufix16::f32_to_ufix16;
+pub use f32_to_ufix32::f32_to_ufix32;
+pub use f32_to_ufix64::f32_to_ufix64;
+pub use f32_to_ui128::f32_to_ui128;
+pub use f32_to_ui16::f32_to_ui16;
 pub use f32_to_ui32::f32_to_ui32;
 pub use f32_to_ui64::f32_to_ui64;
+pub use f32_to_ui8::f32_to_ui8;
 pub use f64_add::f64_add;
 pub use f64_classify::f64_classify;
 pub use f64_div::f64_div;
@@ -160,31 +225,64 @@ pub use f64_lt::f64_lt;
 pub use f64_lt_quiet::f64_lt_quiet;
 pub use f64_mul::f64_mul;
 pub use f64_mulAdd::f64_mulAdd;
+pub use f64_recip7::f64_recip7;
 pub use f64_rem::f64_rem;
 pub use f64_roundToInt::f64_roundToInt;
+pub use f64_rsqrte7::f64_rsqrte7;
 pub use f64_sqrt::f64_sqrt;
 pub use f64_sub::f64_sub;
 pub use f64_to_f32::f64_to_f32;
+pub use f64_to_fix16::f64_to_fix16;
+pub use f64_to_fix32::f64_to_fix32;
+pub use f64_to_fix64::f64_to_fix64;
+pub use f64_to_i128::f64_to_i128;
+pub use f64_to_i16::f64_to_i16;
 pub use f64_to_i32::f64_to_i32;
 pub use f64_to_i64::f64_to_i64;
+pub use f64_to_i8::f64_to_i8;
+pub use f64_to_limbs::f64_to_limbs;
+pub use f64_to_ufix16::f64_to_ufix16;
+pub use f64_to_ufix32::f64_to_ufix32;
+pub use f64_to_ufix64::f64_to_ufix64;
+pub use f64_to_ui128::f64_to_ui128;
+pub use f64_to_ui16::f64_to_ui16;
 pub use f64_to_ui32::f64_to_ui32;
 pub use f64_to_ui64::f64_to_ui64;
+pub use f64_to_ui8::f64_to_ui8;
+pub use fix32_to_f32::fix32_to_f32;
+pub use fix32_to_f64::fix32_to_f64;
+pub use fix64_to_f32::fix64_to_f32;
+pub use fix64_to_f64::fix64_to_f64;
+pub use i128_to_f32::i128_to_f32;
+pub use i128_to_f64::i128_to_f64;
 pub use i32_to_f32::i32_to_f32;
 pub use i32_to_f64::i32_to_f64;
 pub use i64_to_f32::i64_to_f32;
 pub use i64_to_f64::i64_to_f64;
+pub use limbs_to_f32::limbs_to_f32;
+pub use limbs_to_f64::limbs_to_f64;
 pub use s_addMagsF32::softfloat_addMagsF32;
 pub use s_addMagsF64::softfloat_addMagsF64;
 pub use s_approxRecip32_1::softfloat_approxRecip32_1;
 pub use s_approxRecipSqrt32_1::softfloat_approxRecipSqrt32_1;
 pub use s_approxRecipSqrt_1Ks::softfloat_approxRecipSqrt_1k0s;
 pub use s_approxRecipSqrt_1Ks::softfloat_approxRecipSqrt_1k1s;
+pub use s_approxRecipSqrt_7s::softfloat_approxRecipSqrt_7s;
 pub use s_countLeadingZeros32::softfloat_countLeadingZeros32;
 pub use s_countLeadingZeros64::softfloat_countLeadingZeros64;
+pub use s_normLimbs::softfloat_normLimbs;
+pub use s_roundToLimbs::softfloat_roundToLimbs;
+pub use ufix32_to_f32::ufix32_to_f32;
+pub use ufix32_to_f64::ufix32_to_f64;
+pub use ufix64_to_f32::ufix64_to_f32;
+pub use ufix64_to_f64::ufix64_to_f64;
+pub use ui128_to_f32::ui128_to_f32;
+pub use ui128_to_f64::ui128_to_f64;
 
 pub use s_add128::softfloat_add128;
 pub use s_approxRecip_1Ks::softfloat_approxRecip_1k0s;
 pub use s_approxRecip_1Ks::softfloat_approxRecip_1k1s;
+pub use s_approxRecip_7s::softfloat_approxRecip_7s;
 pub use s_mul64To128::softfloat_mul64To128;
 pub use s_mulAddF32::softfloat_mulAddF32;
 pub use s_mulAddF64::softfloat_mulAddF64;
@@ -192,12 +290,14 @@ pub use s_normRoundPackToF32::softfloat_normRoundPackToF32;
 pub use s_normRoundPackToF64::softfloat_normRoundPackToF64;
 pub use s_normSubnormalF32Sig::softfloat_normSubnormalF32Sig;
 pub use s_normSubnormalF64Sig::softfloat_normSubnormalF64Sig;
+pub use s_recip7::softfloat_recip7;
 pub use s_roundPackToF32::*;
 pub use s_roundPackToF64::softfloat_roundPackToF64;
 pub use s_roundToI32::softfloat_roundToI32;
 pub use s_roundToI64::softfloat_roundToI64;
 pub use s_roundToUI32::softfloat_roundToUI32;
 pub use s_roundToUI64::softfloat_roundToUI64;
+pub use s_rsqrte7::softfloat_rsqrte7;
 pub use s_shiftRightJam128::softfloat_shiftRightJam128;
 pub use s_shiftRightJam32::softfloat_shiftRightJam32;
 pub use s_shiftRightJam64::softfloat_shiftRightJam64;
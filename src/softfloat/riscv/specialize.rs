@@ -40,6 +40,23 @@ use super::super::softfloat_tininess_afterRounding;
 *----------------------------------------------------------------------------*/
 pub const init_detectTininess: u8 = softfloat_tininess_afterRounding;
 
+/*----------------------------------------------------------------------------
+| The values to return on conversions to 8-bit and 16-bit integer formats that
+| raise an invalid exception.
+*----------------------------------------------------------------------------*/
+pub const ui8_fromPosOverflow: u8 = 0xFF;
+pub const ui8_fromNegOverflow: u8 = 0;
+pub const ui8_fromNaN: u8 = 0xFF;
+pub const i8_fromPosOverflow: i8 = 0x7F;
+pub const i8_fromNegOverflow: i8 = -0x7F - 1;
+pub const i8_fromNaN: i8 = 0x7F;
+pub const ui16_fromPosOverflow: u16 = 0xFFFF;
+pub const ui16_fromNegOverflow: u16 = 0;
+pub const ui16_fromNaN: u16 = 0xFFFF;
+pub const i16_fromPosOverflow: i16 = 0x7FFF;
+pub const i16_fromNegOverflow: i16 = -0x7FFF - 1;
+pub const i16_fromNaN: i16 = 0x7FFF;
+
 /*----------------------------------------------------------------------------
 | The values to return on conversions to 32-bit integer formats that raise an
 | invalid exception.
@@ -62,6 +79,24 @@ pub const i64_fromPosOverflow: i64 = 0x7FFF_FFFF_FFFF_FFFF;
 pub const i64_fromNegOverflow: i64 = -0x7FFF_FFFF_FFFF_FFFF - 1;
 pub const i64_fromNaN: i64 = 0x7FFF_FFFF_FFFF_FFFF;
 
+/*----------------------------------------------------------------------------
+| The values to return on conversions to 128-bit integer formats that raise an
+| invalid exception.
+*----------------------------------------------------------------------------*/
+pub const ui128_fromPosOverflow: u128 = u128::MAX;
+pub const ui128_fromNegOverflow: u128 = 0;
+pub const ui128_fromNaN: u128 = u128::MAX;
+pub const i128_fromPosOverflow: i128 = i128::MAX;
+pub const i128_fromNegOverflow: i128 = i128::MIN;
+pub const i128_fromNaN: i128 = i128::MAX;
+
+/*----------------------------------------------------------------------------
+| True when a NaN is quiet if the most significant bit of its fraction is set,
+| as IEEE 754-2008 recommends; false for targets where that bit being set
+| marks a signaling NaN instead.
+*----------------------------------------------------------------------------*/
+pub const softfloat_quietBitSet: bool = true;
+
 /*----------------------------------------------------------------------------
 | "Common NaN" structure, used to transfer NaN representations from one format
 | to another.
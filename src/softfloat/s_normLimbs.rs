@@ -0,0 +1,42 @@
+use super::softfloat_countLeadingZeros64;
+
+/// Returns the leading 64 bits of the little-endian magnitude `a` and the
+/// position of its leading bit.
+///
+/// The significand is normalized so that bit 63 is set, with any lower
+/// nonzero bits jammed into bit 0. A zero magnitude returns a zero
+/// significand. Positions beyond any finite format are clamped.
+#[must_use]
+pub const fn softfloat_normLimbs(a: &[u64]) -> (u64, i16) {
+    let mut index = a.len();
+    while index > 0 && a[index - 1] == 0 {
+        index -= 1;
+    }
+    if index == 0 {
+        return (0, 0);
+    }
+    index -= 1;
+
+    let shiftDist = softfloat_countLeadingZeros64(a[index]) as u32;
+    let mut sig = a[index] << shiftDist;
+    let mut sticky = false;
+    if index > 0 {
+        let next = a[index - 1];
+        if shiftDist != 0 {
+            sig |= next >> (64 - shiftDist);
+            sticky = next << shiftDist != 0;
+        } else {
+            sticky = next != 0;
+        }
+        let mut i = 0;
+        while !sticky && i + 1 < index {
+            sticky = a[i] != 0;
+            i += 1;
+        }
+    }
+    sig |= sticky as u64;
+
+    let top = index * 64 + 63 - shiftDist as usize;
+    let exp = if top < 0x1000 { top as i16 } else { 0x1000 };
+    return (sig, exp);
+}
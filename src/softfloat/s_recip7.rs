@@ -0,0 +1,53 @@
+use super::{
+    softfloat_approxRecip_7s, softfloat_flag_inexact, softfloat_flag_overflow, softfloat_round_max,
+    softfloat_round_min, softfloat_round_minMag, softfloat_round_odd,
+};
+
+/// The `vfrec7.v` estimate of a finite non-zero value with `expBits` exponent
+/// bits and `sigBits` fraction bits, as raw bits and flags.
+#[must_use]
+pub const fn softfloat_recip7(
+    a: u64,
+    expBits: u32,
+    sigBits: u32,
+    roundingMode: u8,
+    subnormal: bool,
+) -> (u64, u8) {
+    let expMask = (1_u64 << expBits) - 1;
+    let sigMask = (1_u64 << sigBits) - 1;
+    let sign = (a >> (sigBits + expBits)) & 1;
+    let mut exp = (a >> sigBits) & expMask;
+    let mut sig = a & sigMask;
+    // ------------------------------------------------------------------------
+    if subnormal {
+        while (sig >> (sigBits - 1)) & 1 == 0 {
+            exp = exp.wrapping_sub(1);
+            sig <<= 1;
+        }
+        sig = (sig << 1) & sigMask;
+        if exp != 0 && exp != u64::MAX {
+            // The reciprocal is beyond the largest finite value.
+            let uiZ = (sign << (sigBits + expBits)) | (expMask << sigBits);
+            let towardZero = roundingMode == softfloat_round_minMag
+                || roundingMode == softfloat_round_odd
+                || (roundingMode == softfloat_round_min && sign == 0)
+                || (roundingMode == softfloat_round_max && sign != 0);
+            return (
+                if towardZero { uiZ - 1 } else { uiZ },
+                softfloat_flag_overflow | softfloat_flag_inexact,
+            );
+        }
+    }
+    // ------------------------------------------------------------------------
+    let index = (sig >> (sigBits - 7)) as usize;
+    let mut sigZ = (softfloat_approxRecip_7s[index] as u64) << (sigBits - 7);
+    let mut expZ = (2 * (expMask >> 1)).wrapping_add(!exp);
+    if expZ == 0 || expZ == u64::MAX {
+        sigZ = (sigZ >> 1) | (1 << (sigBits - 1));
+        if expZ == u64::MAX {
+            sigZ >>= 1;
+            expZ = 0;
+        }
+    }
+    return ((sign << (sigBits + expBits)) | (expZ << sigBits) | sigZ, 0);
+}
@@ -0,0 +1,89 @@
+use super::{
+    softfloat_countLeadingZeros64, softfloat_flag_inexact, softfloat_flag_invalid,
+    softfloat_round_max, softfloat_round_min, softfloat_round_near_even,
+    softfloat_round_near_maxMag, softfloat_round_odd, softfloat_shiftRightJam64Extra,
+};
+
+/// Rounds the magnitude `sig * 2^exp` to an integer and stores it in `z` as
+/// little-endian 64-bit limbs. A magnitude that does not fit saturates `z`
+/// and raises the invalid exception.
+#[must_use]
+pub const fn softfloat_roundToLimbs(
+    sign: bool,
+    sig: u64,
+    exp: i16,
+    z: &mut [u64],
+    roundingMode: u8,
+    exact: bool,
+) -> u8 {
+    let mut i = 0;
+    while i < z.len() {
+        z[i] = 0;
+        i += 1;
+    }
+    if sig == 0 {
+        return 0;
+    }
+
+    if 0 <= exp {
+        let bits = 64 - softfloat_countLeadingZeros64(sig) as usize;
+        if z.len() * 64 < exp as usize + bits {
+            return softfloat_saturateLimbs(z);
+        }
+        let index = exp as usize / 64;
+        let dist = exp as u32 % 64;
+        z[index] = sig << dist;
+        if dist != 0 && index + 1 < z.len() {
+            z[index + 1] = sig >> (64 - dist);
+        }
+        return 0;
+    }
+
+    let sigExtra = softfloat_shiftRightJam64Extra(sig, 0, exp.unsigned_abs() as u32);
+    let mut v = sigExtra.v;
+    let extra = sigExtra.extra;
+    if roundingMode == softfloat_round_near_maxMag || roundingMode == softfloat_round_near_even {
+        if 0x8000_0000_0000_0000 <= extra {
+            v += 1;
+            if extra == 0x8000_0000_0000_0000 && roundingMode == softfloat_round_near_even {
+                v &= !(1 as u64);
+            }
+        }
+    } else if extra != 0
+        && (if sign {
+            roundingMode == softfloat_round_min
+        } else {
+            roundingMode == softfloat_round_max
+        })
+    {
+        v += 1;
+    }
+
+    let mut flags: u8 = 0;
+    if extra != 0 {
+        if roundingMode == softfloat_round_odd {
+            v |= 1;
+        }
+        if exact {
+            flags |= softfloat_flag_inexact;
+        }
+    }
+    if z.is_empty() {
+        return if v != 0 {
+            softfloat_saturateLimbs(z)
+        } else {
+            flags
+        };
+    }
+    z[0] = v;
+    return flags;
+}
+
+const fn softfloat_saturateLimbs(z: &mut [u64]) -> u8 {
+    let mut i = 0;
+    while i < z.len() {
+        z[i] = u64::MAX;
+        i += 1;
+    }
+    return softfloat_flag_invalid;
+}
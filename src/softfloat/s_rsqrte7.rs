@@ -0,0 +1,25 @@
+use super::softfloat_approxRecipSqrt_7s;
+
+/// The `vfrsqrt7.v` estimate of a finite positive value with `expBits`
+/// exponent bits and `sigBits` fraction bits, as raw bits.
+#[must_use]
+pub const fn softfloat_rsqrte7(a: u64, expBits: u32, sigBits: u32, subnormal: bool) -> u64 {
+    let expMask = (1_u64 << expBits) - 1;
+    let sigMask = (1_u64 << sigBits) - 1;
+    let sign = (a >> (sigBits + expBits)) & 1;
+    let mut exp = (a >> sigBits) & expMask;
+    let mut sig = a & sigMask;
+    // ------------------------------------------------------------------------
+    if subnormal {
+        while (sig >> (sigBits - 1)) & 1 == 0 {
+            exp = exp.wrapping_sub(1);
+            sig <<= 1;
+        }
+        sig = (sig << 1) & sigMask;
+    }
+    // ------------------------------------------------------------------------
+    let index = (((exp & 1) << 6) | (sig >> (sigBits - 6))) as usize;
+    let sigZ = (softfloat_approxRecipSqrt_7s[index] as u64) << (sigBits - 7);
+    let expZ = (3 * (expMask >> 1)).wrapping_add(!exp) / 2;
+    return (sign << (sigBits + expBits)) | (expZ << sigBits) | sigZ;
+}
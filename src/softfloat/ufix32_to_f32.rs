@@ -0,0 +1,13 @@
+use super::{float32_t, ufix64_to_f32};
+
+/// Converts the unsigned 32-bit fixed-point number `a` with `fbits` fractional
+/// bits, that is, `a * 2^-fbits`. Any `fbits` is allowed.
+#[must_use]
+pub const fn ufix32_to_f32(
+    a: u32,
+    fbits: u8,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    return ufix64_to_f32(a as u64, fbits, roundingMode, detectTininess);
+}
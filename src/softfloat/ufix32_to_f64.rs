@@ -0,0 +1,13 @@
+use super::{float64_t, ufix64_to_f64};
+
+/// Converts the unsigned 32-bit fixed-point number `a` with `fbits` fractional
+/// bits, that is, `a * 2^-fbits`. Any `fbits` is allowed.
+#[must_use]
+pub const fn ufix32_to_f64(
+    a: u32,
+    fbits: u8,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float64_t, u8) {
+    return ufix64_to_f64(a as u64, fbits, roundingMode, detectTininess);
+}
@@ -0,0 +1,48 @@
+use super::{
+    float32_t, packToF32UI, softfloat_countLeadingZeros64, softfloat_roundPackToF32,
+    softfloat_shortShiftRightJam64,
+};
+
+/// Converts the unsigned 64-bit fixed-point number `a` with `fbits` fractional
+/// bits, that is, `a * 2^-fbits`. Any `fbits` is allowed.
+#[must_use]
+pub const fn ufix64_to_f32(
+    a: u64,
+    fbits: u8,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    let sign = false;
+    let absA = a;
+    if a == 0 {
+        return (float32_t { v: 0 }, 0);
+    }
+    let mut shiftDist = softfloat_countLeadingZeros64(absA).wrapping_sub(40) as i8;
+    let exp = (0x95 as i16)
+        .wrapping_sub(shiftDist as i16)
+        .wrapping_sub(fbits as i16);
+    // A large `fbits` gives a subnormal or zero, which needs rounding.
+    if 0 <= shiftDist && 0 <= exp {
+        return (
+            float32_t {
+                v: packToF32UI(sign, exp, (absA << shiftDist) as u32),
+            },
+            0,
+        );
+    }
+    shiftDist = shiftDist.wrapping_add(7);
+    let sig: u32 = if shiftDist < 0 {
+        softfloat_shortShiftRightJam64(absA, shiftDist.wrapping_neg() as u8) as u32
+    } else {
+        (absA << shiftDist) as u32
+    };
+    return softfloat_roundPackToF32(
+        sign,
+        (0x9C as i16)
+            .wrapping_sub(shiftDist as i16)
+            .wrapping_sub(fbits as i16),
+        sig,
+        roundingMode,
+        detectTininess,
+    );
+}
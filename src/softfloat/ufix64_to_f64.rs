@@ -0,0 +1,34 @@
+use super::{
+    float64_t, softfloat_normRoundPackToF64, softfloat_roundPackToF64,
+    softfloat_shortShiftRightJam64,
+};
+
+/// Converts the unsigned 64-bit fixed-point number `a` with `fbits` fractional
+/// bits, that is, `a * 2^-fbits`. Any `fbits` is allowed.
+#[must_use]
+pub const fn ufix64_to_f64(
+    a: u64,
+    fbits: u8,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float64_t, u8) {
+    if a == 0 {
+        return (float64_t { v: 0 }, 0);
+    }
+    if (a & 0x8000_0000_0000_0000) != 0 {
+        return softfloat_roundPackToF64(
+            false,
+            (0x43D as i16).wrapping_sub(fbits as i16),
+            softfloat_shortShiftRightJam64(a, 1),
+            roundingMode,
+            detectTininess,
+        );
+    }
+    return softfloat_normRoundPackToF64(
+        false,
+        (0x43C as i16).wrapping_sub(fbits as i16),
+        a,
+        roundingMode,
+        detectTininess,
+    );
+}
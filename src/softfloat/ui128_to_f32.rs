@@ -0,0 +1,11 @@
+use super::{float32_t, limbs_to_f32};
+
+#[must_use]
+pub const fn ui128_to_f32(a: u128, roundingMode: u8, detectTininess: u8) -> (float32_t, u8) {
+    return limbs_to_f32(
+        false,
+        &[a as u64, (a >> 64) as u64],
+        roundingMode,
+        detectTininess,
+    );
+}
@@ -0,0 +1,11 @@
+use super::{float64_t, limbs_to_f64};
+
+#[must_use]
+pub const fn ui128_to_f64(a: u128, roundingMode: u8, detectTininess: u8) -> (float64_t, u8) {
+    return limbs_to_f64(
+        false,
+        &[a as u64, (a >> 64) as u64],
+        roundingMode,
+        detectTininess,
+    );
+}
@@ -0,0 +1,239 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Fixed-capacity unsigned big integers for exact decimal conversion.
+//!
+//! Limbs are stored least significant first. Operations are exact as long as
+//! results fit in [`LIMBS`] limbs, which callers ensure by bounding the
+//! decimal exponents they work with.
+
+pub const LIMBS: usize = 56;
+
+#[derive(Copy, Clone, Debug)]
+pub struct Big {
+    pub d: [u64; LIMBS],
+}
+
+impl Big {
+    pub const ZERO: Self = Self { d: [0; LIMBS] };
+
+    #[must_use]
+    pub const fn from_u64(v: u64) -> Self {
+        let mut r = Self::ZERO;
+        r.d[0] = v;
+        r
+    }
+
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        let mut i = 0;
+        while i < LIMBS {
+            if self.d[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Number of significant bits.
+    #[must_use]
+    pub const fn bit_len(&self) -> usize {
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+            if self.d[i] != 0 {
+                return i * 64 + 64 - self.d[i].leading_zeros() as usize;
+            }
+        }
+        0
+    }
+
+    /// `self * m + a`.
+    #[must_use]
+    pub const fn mul_add_small(&self, m: u64, a: u64) -> Self {
+        let mut r = Self::ZERO;
+        let mut carry = a as u128;
+        let mut i = 0;
+        while i < LIMBS {
+            let t = self.d[i] as u128 * m as u128 + carry;
+            r.d[i] = t as u64;
+            carry = t >> 64;
+            i += 1;
+        }
+        r
+    }
+
+    /// `self * 10^n`.
+    #[must_use]
+    pub const fn mul_pow10(&self, n: u32) -> Self {
+        self.mul_pow(10, n)
+    }
+
+    /// `self * 5^n`.
+    #[must_use]
+    pub const fn mul_pow5(&self, n: u32) -> Self {
+        self.mul_pow(5, n)
+    }
+
+    const fn mul_pow(&self, base: u64, mut n: u32) -> Self {
+        // The largest powers of 5 and 10 that fit in a limb.
+        let (step, chunk) = if base == 5 {
+            (27, 7_450_580_596_923_828_125)
+        } else {
+            (19, 10_000_000_000_000_000_000)
+        };
+        let mut r = *self;
+        while n >= step {
+            r = r.mul_add_small(chunk, 0);
+            n -= step;
+        }
+        r.mul_add_small(base.pow(n), 0)
+    }
+
+    /// `self << n`.
+    #[must_use]
+    pub const fn shl(&self, n: usize) -> Self {
+        let mut r = Self::ZERO;
+        let limbs = n / 64;
+        let bits = (n % 64) as u32;
+        let mut i = LIMBS;
+        while i > limbs {
+            i -= 1;
+            let lo = self.d[i - limbs];
+            let mut v = lo << bits;
+            if bits != 0 && i > limbs {
+                v |= self.d[i - limbs - 1] >> (64 - bits);
+            }
+            r.d[i] = v;
+        }
+        r
+    }
+
+    /// `self >> n`.
+    #[must_use]
+    pub const fn shr(&self, n: usize) -> Self {
+        let mut r = Self::ZERO;
+        let limbs = n / 64;
+        let bits = (n % 64) as u32;
+        let mut i = 0;
+        while i + limbs < LIMBS {
+            let mut v = self.d[i + limbs] >> bits;
+            if bits != 0 && i + limbs + 1 < LIMBS {
+                v |= self.d[i + limbs + 1] << (64 - bits);
+            }
+            r.d[i] = v;
+            i += 1;
+        }
+        r
+    }
+
+    /// `self >> 1`.
+    #[must_use]
+    pub const fn shr1(&self) -> Self {
+        let mut r = Self::ZERO;
+        let mut i = 0;
+        while i < LIMBS {
+            r.d[i] = self.d[i] >> 1;
+            if i + 1 < LIMBS {
+                r.d[i] |= self.d[i + 1] << 63;
+            }
+            i += 1;
+        }
+        r
+    }
+
+    #[must_use]
+    pub const fn ge(&self, b: &Self) -> bool {
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+            if self.d[i] != b.d[i] {
+                return self.d[i] > b.d[i];
+            }
+        }
+        true
+    }
+
+    /// `self - b`, for `self >= b`.
+    #[must_use]
+    pub const fn sub(&self, b: &Self) -> Self {
+        let mut r = Self::ZERO;
+        let mut borrow = false;
+        let mut i = 0;
+        while i < LIMBS {
+            let (t, b1) = self.d[i].overflowing_sub(b.d[i]);
+            let (t, b2) = t.overflowing_sub(borrow as u64);
+            r.d[i] = t;
+            borrow = b1 || b2;
+            i += 1;
+        }
+        r
+    }
+
+    /// `self + a`.
+    #[must_use]
+    pub const fn add_small(&self, a: u64) -> Self {
+        self.mul_add_small(1, a)
+    }
+
+    /// `(self / d, self % d)` for a nonzero `d`.
+    #[must_use]
+    pub const fn div_small(&self, d: u64) -> (Self, u64) {
+        let mut q = Self::ZERO;
+        let mut rem: u128 = 0;
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+            let t = (rem << 64) | self.d[i] as u128;
+            q.d[i] = (t / d as u128) as u64;
+            rem = t % d as u128;
+        }
+        (q, rem as u64)
+    }
+
+    /// `(self / d, self % d)` for a nonzero `d`, by restoring division.
+    #[must_use]
+    pub const fn div_rem(&self, d: &Self) -> (Self, Self) {
+        let len = self.bit_len();
+        let dlen = d.bit_len();
+        if len < dlen {
+            return (Self::ZERO, *self);
+        }
+        let mut q = Self::ZERO;
+        let mut r = *self;
+        let mut j = len - dlen + 1;
+        let mut t = d.shl(j - 1);
+        while j > 0 {
+            j -= 1;
+            if r.ge(&t) {
+                r = r.sub(&t);
+                q.d[j / 64] |= 1 << (j % 64);
+            }
+            t = t.shr1();
+        }
+        (q, r)
+    }
+
+    /// The 64 bits starting at bit `lsb`, and whether any bit below them is
+    /// set.
+    #[must_use]
+    pub const fn window(&self, lsb: usize) -> (u64, bool) {
+        let limb = lsb / 64;
+        let bits = (lsb % 64) as u32;
+        let mut v = if limb < LIMBS {
+            self.d[limb] >> bits
+        } else {
+            0
+        };
+        if bits != 0 && limb + 1 < LIMBS {
+            v |= self.d[limb + 1] << (64 - bits);
+        }
+        let mut sticky = bits != 0 && limb < LIMBS && self.d[limb] << (64 - bits) != 0;
+        let mut i = 0;
+        while !sticky && i < limb && i < LIMBS {
+            sticky = self.d[i] != 0;
+            i += 1;
+        }
+        (v, sticky)
+    }
+}
@@ -0,0 +1,778 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Correctly rounded decimal parsing.
+//!
+//! The significant digits are read into a [`Big`] integer `D` with a decimal
+//! exponent `e`. For `e >= 0` the product `D × 10^e` is exact; otherwise the
+//! leading 64 bits of `D / 10^e` come from a restoring division and the
+//! remainder becomes a sticky bit. Either way the result is rounded once by
+//! the format's `roundPack`.
+
+use super::big::Big;
+use super::ParseFloatError;
+use crate::softfloat::{
+    defaultNaNF32UI, defaultNaNF64UI, float32_t, float64_t, packToF32UI, packToF64UI,
+    softfloat_roundPackToF32, softfloat_roundPackToF64, softfloat_shortShiftRightJam64,
+};
+
+/// Significant digits kept; later digits only matter through whether they
+/// are all zero. Every `f64` value and every midpoint between two of them
+/// has at most 767 significant digits, so truncating after more than that
+/// never moves a value across a rounding boundary.
+const MAX_DIGITS: usize = 800;
+
+/// Decimal magnitude beyond which every format overflows or underflows.
+const MAX_DECIMAL_EXP: i64 = 400;
+
+/// Binary exponent standing in for values too large or too small for any
+/// format, small enough to keep `roundPack` exponents in range.
+pub const OUT_OF_RANGE_EXP: i32 = 4000;
+
+/// A parsed literal before rounding.
+#[derive(Copy, Clone, Debug)]
+pub enum Literal {
+    Zero,
+    Inf,
+    /// A NaN with an optional payload.
+    NaN(u64),
+    /// `sig × 2^exp` with bit 63 of `sig` set and any bits below the
+    /// significant ones jammed into bit 0.
+    Finite {
+        sig: u64,
+        exp: i32,
+    },
+}
+
+pub const fn is_digit(c: u8) -> bool {
+    c.is_ascii_digit()
+}
+
+/// Whether `s[i..]` starts with `word`, ignoring ASCII case.
+pub const fn has_prefix(s: &[u8], i: usize, word: &[u8]) -> bool {
+    if s.len() - i < word.len() {
+        return false;
+    }
+    let mut j = 0;
+    while j < word.len() {
+        if s[i + j].to_ascii_lowercase() != word[j] {
+            return false;
+        }
+        j += 1;
+    }
+    true
+}
+
+/// Whether `s[i..]` is exactly `word`, ignoring ASCII case.
+const fn is_word(s: &[u8], i: usize, word: &[u8]) -> bool {
+    s.len() - i == word.len() && has_prefix(s, i, word)
+}
+
+/// Parses `nan`, `inf` and `infinity`, ignoring case, and `nan(payload)`
+/// with a decimal or `0x`-prefixed hexadecimal payload.
+pub const fn parse_special(s: &[u8], i: usize) -> Option<Literal> {
+    if is_word(s, i, b"inf") || is_word(s, i, b"infinity") {
+        return Some(Literal::Inf);
+    }
+    if is_word(s, i, b"nan") {
+        return Some(Literal::NaN(0));
+    }
+    let n = s.len();
+    if n - i < 6 || s[n - 1] != b')' || !has_prefix(s, i, b"nan(") {
+        return None;
+    }
+    let mut j = i + 4;
+    let end = n - 1;
+    let mut radix = 10;
+    if end - j > 2 && s[j] == b'0' && (s[j + 1] == b'x' || s[j + 1] == b'X') {
+        radix = 16;
+        j += 2;
+    }
+    if j == end {
+        return None;
+    }
+    let mut payload: u64 = 0;
+    while j < end {
+        let c = s[j].to_ascii_lowercase();
+        let v = if is_digit(c) {
+            c - b'0'
+        } else if radix == 16 && c >= b'a' && c <= b'f' {
+            c - b'a' + 10
+        } else {
+            return None;
+        };
+        payload = payload.wrapping_mul(radix).wrapping_add(v as u64);
+        j += 1;
+    }
+    Some(Literal::NaN(payload))
+}
+
+/// Parses an optionally signed decimal literal, `inf`, `infinity`, `nan` or
+/// `nan(payload)`.
+pub const fn parse_decimal(s: &[u8]) -> Result<(bool, Literal), ParseFloatError> {
+    let n = s.len();
+    if n == 0 {
+        return Err(ParseFloatError::EMPTY);
+    }
+    let mut i = 0;
+    let sign = s[0] == b'-';
+    if s[0] == b'-' || s[0] == b'+' {
+        i += 1;
+    }
+    if i == n {
+        return Err(ParseFloatError::INVALID);
+    }
+    if !is_digit(s[i]) && s[i] != b'.' {
+        return match parse_special(s, i) {
+            Some(lit) => Ok((sign, lit)),
+            None => Err(ParseFloatError::INVALID),
+        };
+    }
+
+    let mut d = Big::ZERO;
+    let mut chunk: u64 = 0;
+    let mut chunk_len: u32 = 0;
+    let mut kept = 0;
+    let mut sticky = false;
+    let mut scale: i64 = 0;
+    let mut any_digit = false;
+    let mut point = false;
+    while i < n && (is_digit(s[i]) || (s[i] == b'.' && !point)) {
+        if s[i] == b'.' {
+            point = true;
+            i += 1;
+            continue;
+        }
+        any_digit = true;
+        let v = s[i] - b'0';
+        if kept == 0 && v == 0 {
+            if point {
+                scale -= 1;
+            }
+        } else if kept < MAX_DIGITS {
+            chunk = chunk * 10 + v as u64;
+            chunk_len += 1;
+            kept += 1;
+            if chunk_len == 19 {
+                d = d.mul_pow10(19).mul_add_small(1, chunk);
+                chunk = 0;
+                chunk_len = 0;
+            }
+            if point {
+                scale -= 1;
+            }
+        } else {
+            sticky |= v != 0;
+            if !point {
+                scale += 1;
+            }
+        }
+        i += 1;
+    }
+    if !any_digit {
+        return Err(ParseFloatError::INVALID);
+    }
+    d = d.mul_pow10(chunk_len).mul_add_small(1, chunk);
+
+    let mut exp: i64 = 0;
+    if i < n && (s[i] == b'e' || s[i] == b'E') {
+        i += 1;
+        let neg = i < n && s[i] == b'-';
+        if i < n && (s[i] == b'-' || s[i] == b'+') {
+            i += 1;
+        }
+        if i == n {
+            return Err(ParseFloatError::INVALID);
+        }
+        while i < n && is_digit(s[i]) {
+            if exp < 1_000_000_000 {
+                exp = exp * 10 + (s[i] - b'0') as i64;
+            }
+            i += 1;
+        }
+        if neg {
+            exp = -exp;
+        }
+    }
+    if i != n {
+        return Err(ParseFloatError::INVALID);
+    }
+    if kept == 0 {
+        return Ok((sign, Literal::Zero));
+    }
+    Ok((
+        sign,
+        decimal_to_binary(&d, kept as i64, exp + scale, sticky),
+    ))
+}
+
+/// Converts `(D + δ) × 10^e`, where `D` has `digits` significant digits and
+/// `0 < δ < 1` when `sticky`, into a jammed 64-bit significand.
+pub const fn decimal_to_binary(d: &Big, digits: i64, e: i64, sticky: bool) -> Literal {
+    let magnitude = digits + e;
+    if magnitude > MAX_DECIMAL_EXP {
+        return Literal::Finite {
+            sig: 1 << 63,
+            exp: OUT_OF_RANGE_EXP,
+        };
+    }
+    if magnitude < -MAX_DECIMAL_EXP {
+        return Literal::Finite {
+            sig: 1 << 63,
+            exp: -OUT_OF_RANGE_EXP,
+        };
+    }
+
+    if e >= 0 {
+        let v = d.mul_pow10(e as u32);
+        let len = v.bit_len();
+        if len <= 64 {
+            return Literal::Finite {
+                sig: (v.d[0] << (64 - len)) | sticky as u64,
+                exp: len as i32 - 64,
+            };
+        }
+        let (sig, below) = v.window(len - 64);
+        return Literal::Finite {
+            sig: sig | (below || sticky) as u64,
+            exp: len as i32 - 64,
+        };
+    }
+
+    // D / 10^k = (D × 2^s / 5^k) × 2^(-s-k), with s chosen so that the
+    // quotient lies in (2^63, 2^65).
+    let k = -e;
+    let m = Big::from_u64(1).mul_pow5(k as u32);
+    let s = 64 - (d.bit_len() as i64 - m.bit_len() as i64);
+    let mut r = if s > 0 { d.shl(s as usize) } else { *d };
+    let m = if s < 0 { m.shl(-s as usize) } else { m };
+    let mut t = m.shl(65);
+    let mut q: u128 = 0;
+    let mut j = 66;
+    while j > 0 {
+        j -= 1;
+        if r.ge(&t) {
+            r = r.sub(&t);
+            q |= 1 << j;
+        }
+        t = t.shr1();
+    }
+    let mut exp = -(s + k) as i32;
+    let mut below = sticky || !r.is_zero();
+    if q >> 64 != 0 {
+        below |= q & 1 != 0;
+        q >>= 1;
+        exp += 1;
+    }
+    Literal::Finite {
+        sig: q as u64 | below as u64,
+        exp,
+    }
+}
+
+/// Parses a decimal literal into a correctly rounded `float32_t`.
+///
+/// Accepts an optional sign followed by digits with an optional decimal
+/// point and exponent, `inf`, `infinity`, `nan` or `nan(payload)`, ignoring
+/// case. The payload is decimal or `0x`-prefixed hexadecimal and fills the
+/// low bits of a quiet NaN.
+///
+/// # Errors
+///
+/// Returns [`ParseFloatError`] if `s` is not a valid literal.
+pub const fn f32_fromDecimal(
+    s: &str,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> Result<(float32_t, u8), ParseFloatError> {
+    match parse_decimal(s.as_bytes()) {
+        Ok((sign, lit)) => Ok(f32_fromLiteral(sign, lit, roundingMode, detectTininess)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Parses a decimal literal into a correctly rounded `float64_t`.
+///
+/// Accepts the same syntax as [`f32_fromDecimal`].
+///
+/// # Errors
+///
+/// Returns [`ParseFloatError`] if `s` is not a valid literal.
+pub const fn f64_fromDecimal(
+    s: &str,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> Result<(float64_t, u8), ParseFloatError> {
+    match parse_decimal(s.as_bytes()) {
+        Ok((sign, lit)) => Ok(f64_fromLiteral(sign, lit, roundingMode, detectTininess)),
+        Err(e) => Err(e),
+    }
+}
+
+pub const fn f32_fromLiteral(
+    sign: bool,
+    lit: Literal,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float32_t, u8) {
+    match lit {
+        Literal::Zero => (
+            float32_t {
+                v: packToF32UI(sign, 0, 0),
+            },
+            0,
+        ),
+        Literal::Inf => (
+            float32_t {
+                v: packToF32UI(sign, 0xFF, 0),
+            },
+            0,
+        ),
+        Literal::NaN(payload) => (
+            float32_t {
+                v: defaultNaNF32UI | ((sign as u32) << 31) | (payload as u32 & 0x003F_FFFF),
+            },
+            0,
+        ),
+        Literal::Finite { sig, exp } => softfloat_roundPackToF32(
+            sign,
+            (0x9C + 33 + exp) as i16,
+            softfloat_shortShiftRightJam64(sig, 33) as u32,
+            roundingMode,
+            detectTininess,
+        ),
+    }
+}
+
+pub const fn f64_fromLiteral(
+    sign: bool,
+    lit: Literal,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> (float64_t, u8) {
+    match lit {
+        Literal::Zero => (
+            float64_t {
+                v: packToF64UI(sign, 0, 0),
+            },
+            0,
+        ),
+        Literal::Inf => (
+            float64_t {
+                v: packToF64UI(sign, 0x7FF, 0),
+            },
+            0,
+        ),
+        Literal::NaN(payload) => (
+            float64_t {
+                v: defaultNaNF64UI | ((sign as u64) << 63) | (payload & 0x0007_FFFF_FFFF_FFFF),
+            },
+            0,
+        ),
+        Literal::Finite { sig, exp } => softfloat_roundPackToF64(
+            sign,
+            (0x43C + 1 + exp) as i16,
+            softfloat_shortShiftRightJam64(sig, 1),
+            roundingMode,
+            detectTininess,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f64_fromDecimal() {
+        struct TestCase {
+            s: &'static str,
+            result: u64,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                s: "9007199254740993",
+                result: 0x4340_0000_0000_0000,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "9007199254740993",
+                result: 0x4340_0000_0000_0001,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "1.7976931348623157e308",
+                result: 0x7FEF_FFFF_FFFF_FFFF,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "1e309",
+                result: 0x7FF0_0000_0000_0000,
+                flags: 5,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "1e309",
+                result: 0x7FEF_FFFF_FFFF_FFFF,
+                flags: 5,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "4.9406564584124654e-324",
+                result: 0x0000_0000_0000_0001,
+                flags: 3,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "1e-400",
+                result: 0x0000_0000_0000_0000,
+                flags: 3,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "1e-400",
+                result: 0x0000_0000_0000_0001,
+                flags: 3,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "-0",
+                result: 0x8000_0000_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "-inf",
+                result: 0xFFF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "Infinity",
+                result: 0x7FF0_0000_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "nan",
+                result: 0x7FF8_0000_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "-nan(0x5)",
+                result: 0xFFF8_0000_0000_0005,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "0.1",
+                result: 0x3FB9_9999_9999_999A,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "0.1",
+                result: 0x3FB9_9999_9999_9999,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "9.6060e151",
+                result: 0x5F7D_5881_056F_84BB,
+                flags: 1,
+                roundingMode: 6,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "2.607789670228296e+56",
+                result: 0x4BA5_4551_A3F0_5368,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "-1.2878278975119187e-61",
+                result: 0xB34A_7D34_9258_EEBD,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "2.029589048316193e-219",
+                result: 0x1286_ED25_5A5E_3223,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "1.60949563043415926075211222361642675253e188",
+                result: 0x6702_7ECF_1768_0795,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "-1.4827555286345797135853e306",
+                result: 0xFF80_E461_6E98_0845,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "-0.00409197896777997727202347277852823026478290557861328125",
+                result: 0xBF70_C2C0_3D7B_05D0,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "-71808412167064.59375",
+                result: 0xC2D0_53CC_B98A_E626,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "-7.058724301095368670419939272798037997320477651e313",
+                result: 0xFFEF_FFFF_FFFF_FFFF,
+                flags: 5,
+                roundingMode: 6,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "-3.00416739426921848980011399367658560717e-332",
+                result: 0x8000_0000_0000_0001,
+                flags: 3,
+                roundingMode: 2,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "-2.8503615228485154155415123582201050516264275139e323",
+                result: 0xFFF0_0000_0000_0000,
+                flags: 5,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "10117021201434679296",
+                result: 0x43E1_8CDC_25C6_1383,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "7.09886241059493894312026895931921899318695068359375",
+                result: 0x401C_653C_3011_3C67,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "5.206352308578836e-309",
+                result: 0x0003_BE67_ADE8_FBCB,
+                flags: 3,
+                roundingMode: 6,
+                detectTininess: 0,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = f64_fromDecimal(c.s, c.roundingMode, c.detectTininess).unwrap();
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f32_fromDecimal() {
+        struct TestCase {
+            s: &'static str,
+            result: u32,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                s: "16777217",
+                result: 0x4B80_0000,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "16777219",
+                result: 0x4B80_0002,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "16777217",
+                result: 0x4B80_0001,
+                flags: 1,
+                roundingMode: 6,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "3.4028236e38",
+                result: 0x7F80_0000,
+                flags: 5,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "1e-46",
+                result: 0x0000_0000,
+                flags: 3,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "1.1754942e-38",
+                result: 0x007F_FFFF,
+                flags: 3,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "1.1754942e-38",
+                result: 0x007F_FFFE,
+                flags: 3,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "+1.5E+0",
+                result: 0x3FC0_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "nan(12)",
+                result: 0x7FC0_000C,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "0.1",
+                result: 0x3DCC_CCCD,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "-.0643973648548126220703125",
+                result: 0xBD83_E2C4,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "-7.5095227602e-48",
+                result: 0x8000_0001,
+                flags: 3,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "11617827",
+                result: 0x4B31_4623,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "8.18151468404049e-24",
+                result: 0x191E_40E6,
+                flags: 1,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "-3683660184679031105060005308530688",
+                result: 0xF735_9E54,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "16.05617523193359375",
+                result: 0x4180_730C,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "-1.3617590921569007e+32",
+                result: 0xF4D6_D902,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "-5673962135172939776",
+                result: 0xDE9D_7BED,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "-9.9884155259962e+18",
+                result: 0xDF0A_9DFB,
+                flags: 1,
+                roundingMode: 6,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "1434640401461468132794171392",
+                result: 0x6C94_569D,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = f32_fromDecimal(c.s, c.roundingMode, c.detectTininess).unwrap();
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = [
+            "", "+", "1e", ".", "1.2.3", "0x1p0", "infx", "nan(", " 1", "1_000",
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let empty = c.is_empty();
+            let err = f64_fromDecimal(c, 0, 1).unwrap_err();
+            assert_eq!((i, err == ParseFloatError::EMPTY), (i, empty));
+        }
+    }
+}
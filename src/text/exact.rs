@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Exact values of floating-point numbers.
+//!
+//! Every finite binary float is a dyadic rational `m × 2^e`, and so has a
+//! terminating decimal expansion: at most 767 significant digits for
+//! `f64`. Both forms are available here without rounding.
+
+use core::fmt;
+
+use super::format::{unpack_f32, unpack_f64, Decimal, Kind, Unpacked};
+use crate::softfloat::{float32_t, float64_t};
+
+/// The exact value of a finite float as `(-1)^sign × mantissa × 2^exponent`.
+///
+/// The decomposition is normalized: `mantissa` is odd, or zero with a zero
+/// `exponent`. For negative exponents this is the fraction
+/// `mantissa / 2^-exponent` in lowest terms.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    pub sign: bool,
+    pub mantissa: u64,
+    pub exponent: i32,
+}
+
+impl Rational {
+    const fn from_unpacked(x: &Unpacked) -> Option<Self> {
+        if x.kind as u8 != Kind::Finite as u8 {
+            return None;
+        }
+        if x.m == 0 {
+            return Some(Self {
+                sign: x.sign,
+                mantissa: 0,
+                exponent: 0,
+            });
+        }
+        let tz = x.m.trailing_zeros();
+        Some(Self {
+            sign: x.sign,
+            mantissa: x.m >> tz,
+            exponent: x.e + tz as i32,
+        })
+    }
+}
+
+impl fmt::Display for Rational {
+    /// Writes `m`, `m*2^e` or `m/2^e`, with a leading `-` if negative.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.sign { "-" } else { "" };
+        match self.exponent {
+            0 => write!(f, "{sign}{}", self.mantissa),
+            e if e > 0 => write!(f, "{sign}{}*2^{e}", self.mantissa),
+            e => write!(f, "{sign}{}/2^{}", self.mantissa, e.unsigned_abs()),
+        }
+    }
+}
+
+/// The exact value of `a` as a normalized dyadic rational, or `None` for
+/// infinities and NaNs.
+#[must_use]
+pub const fn f32_toRational(a: float32_t) -> Option<Rational> {
+    Rational::from_unpacked(&unpack_f32(a))
+}
+
+/// The exact value of `a` as a normalized dyadic rational, or `None` for
+/// infinities and NaNs.
+#[must_use]
+pub const fn f64_toRational(a: float64_t) -> Option<Rational> {
+    Rational::from_unpacked(&unpack_f64(a))
+}
+
+/// The exact decimal expansion of `a`, such as
+/// `0.100000001490116119384765625` for the `float32_t` nearest to 0.1.
+#[must_use]
+pub const fn f32_toExact(a: float32_t) -> Decimal {
+    Decimal::exact(&unpack_f32(a))
+}
+
+/// The exact decimal expansion of `a`.
+#[must_use]
+pub const fn f64_toExact(a: float64_t) -> Decimal {
+    Decimal::exact(&unpack_f64(a))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toExact_toRational() {
+        struct TestCase {
+            a: u64,
+            double: bool,
+            exact: &'static str,
+            rational: &'static str,
+        }
+
+        let cases = [
+            TestCase {
+                a: 0x3DCC_CCCD,
+                double: false,
+                exact: "0.100000001490116119384765625",
+                rational: "13421773/2^27",
+            },
+            TestCase {
+                a: 0x3FB9_9999_9999_999A,
+                double: true,
+                exact: "0.1000000000000000055511151231257827021181583404541015625",
+                rational: "3602879701896397/2^55",
+            },
+            TestCase {
+                a: 0x4450_0000_0000_0000,
+                double: true,
+                exact: "1180591620717411303424",
+                rational: "1*2^70",
+            },
+            TestCase {
+                a: 0xC00C_0000_0000_0000,
+                double: true,
+                exact: "-3.5",
+                rational: "-7/2^1",
+            },
+            TestCase {
+                a: 0x8000_0000_0000_0000,
+                double: true,
+                exact: "-0",
+                rational: "-0",
+            },
+            TestCase {
+                a: 0x4B80_0001,
+                double: false,
+                exact: "16777218",
+                rational: "8388609*2^1",
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (exact, rational) = if c.double {
+                let a = float64_t { v: c.a };
+                (f64_toExact(a), f64_toRational(a).unwrap())
+            } else {
+                let a = float32_t { v: c.a as u32 };
+                (f32_toExact(a), f32_toRational(a).unwrap())
+            };
+            assert_eq!(
+                (i, format!("{exact}"), format!("{rational}")),
+                (i, c.exact.into(), c.rational.into())
+            );
+        }
+    }
+
+    #[test]
+    fn test_exact_digits() {
+        let max = f64_toExact(float64_t {
+            v: 0x7FEF_FFFF_FFFF_FFFF,
+        });
+        assert_eq!(max.exponent(), 308);
+        assert_eq!(max.digits().count(), 309);
+        let min = f64_toExact(float64_t { v: 1 });
+        assert_eq!(min.exponent(), -324);
+        assert_eq!(min.digits().count(), 751);
+        assert_eq!(min.digits().next(), Some(4));
+        assert_eq!(f32_toRational(float32_t { v: 0x7FC0_0000 }), None);
+        assert_eq!(
+            f64_toRational(float64_t { v: 0x3FF0_0000_0000_0000 }),
+            Some(Rational {
+                sign: false,
+                mantissa: 1,
+                exponent: 0,
+            })
+        );
+    }
+}
@@ -0,0 +1,933 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Decimal formatting.
+//!
+//! A finite value `m × 2^e` is scaled by a power of ten with exact
+//! arithmetic, giving `⌊m × 2^e × 10^s⌋` and where the discarded fraction
+//! lies relative to one half. Fixed and exponent formatting round that
+//! quotient once under the requested rounding mode. Shortest formatting
+//! tries one significant digit more at a time and keeps the first of the
+//! two neighbouring candidates that parses back to the same value,
+//! preferring the nearer one.
+
+use core::fmt::{self, Write};
+
+use super::big::Big;
+use super::decimal::{decimal_to_binary, f32_fromLiteral, f64_fromLiteral};
+use crate::softfloat::{
+    float32_t, float64_t, softfloat_flag_inexact, softfloat_round_max, softfloat_round_min,
+    softfloat_round_near_even, softfloat_round_near_maxMag, softfloat_round_odd,
+    softfloat_tininess_afterRounding,
+};
+
+/// Digits held by a [`Decimal`]: enough for the exact expansion of any
+/// `f64`, which has at most 767 significant digits.
+const MAX_DIGITS: usize = 800;
+
+/// Scientific digits beyond which every expansion only continues with
+/// zeros.
+const MAX_EXPONENT_DIGITS: usize = 2000;
+
+/// The largest power of ten that fits in a limb.
+const TEN_POW_19: u64 = 10_000_000_000_000_000_000;
+
+/// The discarded fraction is zero.
+pub const EXACT: u8 = 0;
+/// The discarded fraction is below one half.
+pub const BELOW: u8 = 1;
+/// The discarded fraction is exactly one half.
+pub const HALF: u8 = 2;
+/// The discarded fraction is above one half.
+pub const ABOVE: u8 = 3;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Kind {
+    Finite,
+    Inf,
+    NaN,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Style {
+    /// Positional, with as many digits as needed.
+    Shortest,
+    /// Positional, with this many digits after the point.
+    Fixed(usize),
+    /// Scientific, with this many digits after the point.
+    Exponent(usize),
+}
+
+/// A floating-point value rounded to decimal, ready to be written.
+///
+/// [`fmt::Display`] writes the notation the value was rounded for, while
+/// [`fmt::LowerExp`] and [`fmt::UpperExp`] always write scientific notation.
+/// The formatter's width, fill, alignment, `+` and `0` flags are honoured;
+/// its precision is not, as the digits are already rounded.
+#[derive(Copy, Clone)]
+pub struct Decimal {
+    sign: bool,
+    kind: Kind,
+    /// ASCII significant digits without trailing zeros.
+    digits: [u8; MAX_DIGITS],
+    len: usize,
+    /// The power of ten of the first digit.
+    exp: i32,
+    style: Style,
+}
+
+/// A floating-point value `m × 2^e`.
+#[derive(Copy, Clone)]
+pub struct Unpacked {
+    pub sign: bool,
+    pub kind: Kind,
+    pub m: u64,
+    pub e: i32,
+    /// The encoding with the sign cleared.
+    pub bits: u64,
+    pub double: bool,
+}
+
+pub const fn unpack_f32(a: float32_t) -> Unpacked {
+    let exp = ((a.v >> 23) & 0xFF) as i32;
+    let frac = (a.v & 0x007F_FFFF) as u64;
+    let (kind, m, e) = if exp == 0xFF {
+        (if frac != 0 { Kind::NaN } else { Kind::Inf }, 0, 0)
+    } else if exp == 0 {
+        (Kind::Finite, frac, -149)
+    } else {
+        (Kind::Finite, frac | 1 << 23, exp - 150)
+    };
+    Unpacked {
+        sign: a.v >> 31 != 0,
+        kind,
+        m,
+        e,
+        bits: (a.v & 0x7FFF_FFFF) as u64,
+        double: false,
+    }
+}
+
+pub const fn unpack_f64(a: float64_t) -> Unpacked {
+    let exp = ((a.v >> 52) & 0x7FF) as i32;
+    let frac = a.v & 0x000F_FFFF_FFFF_FFFF;
+    let (kind, m, e) = if exp == 0x7FF {
+        (if frac != 0 { Kind::NaN } else { Kind::Inf }, 0, 0)
+    } else if exp == 0 {
+        (Kind::Finite, frac, -1074)
+    } else {
+        (Kind::Finite, frac | 1 << 52, exp - 1075)
+    };
+    Unpacked {
+        sign: a.v >> 63 != 0,
+        kind,
+        m,
+        e,
+        bits: a.v & 0x7FFF_FFFF_FFFF_FFFF,
+        double: true,
+    }
+}
+
+pub const fn digit_count(mut v: u64) -> u32 {
+    let mut n = 1;
+    while v >= 10 {
+        v /= 10;
+        n += 1;
+    }
+    n
+}
+
+/// `⌊m × 2^e × 10^s⌋`, and where the discarded fraction lies.
+const fn scale(m: u64, e: i32, s: i64) -> (Big, u8) {
+    let t = e as i64 + s;
+    let mut num = Big::from_u64(m);
+    if s > 0 {
+        num = num.mul_pow5(s as u32);
+    }
+    if t > 0 {
+        num = num.shl(t as usize);
+    }
+    if s >= 0 {
+        if t >= 0 {
+            return (num, EXACT);
+        }
+        let n = -t as usize;
+        let (w, sticky) = num.window(n - 1);
+        let class = match (w & 1 != 0, sticky) {
+            (false, false) => EXACT,
+            (false, true) => BELOW,
+            (true, false) => HALF,
+            (true, true) => ABOVE,
+        };
+        return (num.shr(n), class);
+    }
+    let mut den = Big::from_u64(1).mul_pow5(-s as u32);
+    if t < 0 {
+        den = den.shl(-t as usize);
+    }
+    let (q, r) = num.div_rem(&den);
+    if r.is_zero() {
+        return (q, EXACT);
+    }
+    let r2 = r.shl(1);
+    let class = if !r2.ge(&den) {
+        BELOW
+    } else if den.ge(&r2) {
+        HALF
+    } else {
+        ABOVE
+    };
+    (q, class)
+}
+
+/// `⌊log10(m × 2^e)⌋` for a nonzero `m`.
+const fn floor_log10(m: u64, e: i32) -> i64 {
+    let b = 63 - m.leading_zeros() as i64 + e as i64;
+    // ⌊b × log10(2)⌋, off by at most one from the answer.
+    let est = (b * 78913) >> 18;
+    if scale(m, e, -(est + 1)).0.is_zero() {
+        est
+    } else {
+        est + 1
+    }
+}
+
+/// Whether a value with sign `sign` and an `odd` truncated last digit
+/// rounds away from zero, given where its discarded fraction lies.
+pub const fn round_up(class: u8, odd: bool, sign: bool, roundingMode: u8) -> bool {
+    if class == EXACT {
+        false
+    } else if roundingMode == softfloat_round_near_even {
+        class == ABOVE || (class == HALF && odd)
+    } else if roundingMode == softfloat_round_near_maxMag {
+        class != BELOW
+    } else if roundingMode == softfloat_round_min {
+        sign
+    } else if roundingMode == softfloat_round_max {
+        !sign
+    } else if roundingMode == softfloat_round_odd {
+        !odd
+    } else {
+        false
+    }
+}
+
+/// Rounds the quotient `q` of a value with sign `sign`, given where its
+/// discarded fraction lies.
+const fn round_quotient(q: &Big, class: u8, sign: bool, roundingMode: u8) -> Big {
+    if round_up(class, q.d[0] & 1 != 0, sign, roundingMode) {
+        q.add_small(1)
+    } else {
+        *q
+    }
+}
+
+/// Whether `c × 10^-s` parses back to `x`.
+const fn round_trips(c: u64, s: i64, x: &Unpacked) -> bool {
+    let lit = decimal_to_binary(&Big::from_u64(c), digit_count(c) as i64, -s, false);
+    let bits = if x.double {
+        f64_fromLiteral(
+            false,
+            lit,
+            softfloat_round_near_even,
+            softfloat_tininess_afterRounding,
+        )
+        .0
+        .v
+    } else {
+        f32_fromLiteral(
+            false,
+            lit,
+            softfloat_round_near_even,
+            softfloat_tininess_afterRounding,
+        )
+        .0
+        .v as u64
+    };
+    bits == x.bits
+}
+
+impl Decimal {
+    const fn empty(sign: bool, kind: Kind, style: Style) -> Self {
+        Self {
+            sign,
+            kind,
+            digits: [b'0'; MAX_DIGITS],
+            len: 0,
+            exp: 0,
+            style,
+        }
+    }
+
+    /// `q × 10^-s`.
+    const fn from_quotient(sign: bool, q: &Big, s: i64, style: Style) -> Self {
+        let mut r = Self::empty(sign, Kind::Finite, style);
+        let mut groups = [0u64; MAX_DIGITS / 19 + 1];
+        let mut n = 0;
+        let mut q = *q;
+        while !q.is_zero() {
+            let (t, g) = q.div_small(TEN_POW_19);
+            groups[n] = g;
+            n += 1;
+            q = t;
+        }
+        let mut len = 0;
+        let mut i = n;
+        while i > 0 {
+            i -= 1;
+            let g = groups[i];
+            let mut width = if i == n - 1 { digit_count(g) } else { 19 };
+            while width > 0 {
+                width -= 1;
+                r.digits[len] = b'0' + (g / 10u64.pow(width) % 10) as u8;
+                len += 1;
+            }
+        }
+        if len == 0 {
+            return r;
+        }
+        r.exp = (len as i64 - 1 - s) as i32;
+        while r.digits[len - 1] == b'0' {
+            len -= 1;
+        }
+        r.len = len;
+        r
+    }
+
+    const fn shortest(x: &Unpacked) -> Self {
+        if x.kind as u8 != Kind::Finite as u8 || x.m == 0 {
+            return Self::empty(x.sign, x.kind, Style::Shortest);
+        }
+        let max = if x.double { 17 } else { 9 };
+        let e10 = floor_log10(x.m, x.e);
+        let mut p = 1;
+        loop {
+            let s = p - 1 - e10;
+            let (q, class) = scale(x.m, x.e, s);
+            let c = q.d[0];
+            let lo = class == EXACT || round_trips(c, s, x);
+            let hi = class != EXACT && round_trips(c + 1, s, x);
+            if lo || hi || p == max {
+                // With both or neither, take the nearer.
+                let c = if lo != hi {
+                    if lo {
+                        c
+                    } else {
+                        c + 1
+                    }
+                } else if class == ABOVE || (class == HALF && c & 1 != 0) {
+                    c + 1
+                } else {
+                    c
+                };
+                return Self::from_quotient(x.sign, &Big::from_u64(c), s, Style::Shortest);
+            }
+            p += 1;
+        }
+    }
+
+    /// The exact value of `x`, written positionally.
+    #[must_use]
+    pub const fn exact(x: &Unpacked) -> Self {
+        if x.kind as u8 != Kind::Finite as u8 || x.m == 0 {
+            return Self::empty(x.sign, x.kind, Style::Shortest);
+        }
+        let s = if x.e < 0 { -x.e as i64 } else { 0 };
+        let (q, _) = scale(x.m, x.e, s);
+        Self::from_quotient(x.sign, &q, s, Style::Shortest)
+    }
+
+    /// The significant digits, most significant first, without trailing
+    /// zeros. Empty for zero, infinities and NaNs.
+    pub fn digits(&self) -> impl Iterator<Item = u8> + '_ {
+        self.digits[..self.len].iter().map(|d| d - b'0')
+    }
+
+    /// The power of ten of the first significant digit.
+    #[must_use]
+    pub const fn exponent(&self) -> i32 {
+        self.exp
+    }
+
+    #[must_use]
+    pub const fn is_sign_negative(&self) -> bool {
+        self.sign
+    }
+
+    #[must_use]
+    pub const fn is_finite(&self) -> bool {
+        self.kind as u8 == Kind::Finite as u8
+    }
+
+    const fn fixed(x: &Unpacked, digits: usize, roundingMode: u8) -> (Self, u8) {
+        let style = Style::Fixed(digits);
+        if x.kind as u8 != Kind::Finite as u8 || x.m == 0 {
+            return (Self::empty(x.sign, x.kind, style), 0);
+        }
+        // Digits past the last nonzero one of the exact expansion are zero.
+        let exact = if x.e < 0 { -x.e as usize } else { 0 };
+        let s = if digits < exact { digits } else { exact };
+        let (q, class) = scale(x.m, x.e, s as i64);
+        let q = round_quotient(&q, class, x.sign, roundingMode);
+        let flags = if class == EXACT {
+            0
+        } else {
+            softfloat_flag_inexact
+        };
+        (Self::from_quotient(x.sign, &q, s as i64, style), flags)
+    }
+
+    const fn scientific(x: &Unpacked, digits: usize, roundingMode: u8) -> (Self, u8) {
+        let style = Style::Exponent(digits);
+        if x.kind as u8 != Kind::Finite as u8 || x.m == 0 {
+            return (Self::empty(x.sign, x.kind, style), 0);
+        }
+        let exact = if x.e < 0 { -x.e as i64 } else { 0 };
+        let d = if digits < MAX_EXPONENT_DIGITS {
+            digits
+        } else {
+            MAX_EXPONENT_DIGITS
+        };
+        let mut s = d as i64 - floor_log10(x.m, x.e);
+        if s > exact {
+            s = exact;
+        }
+        let (q, class) = scale(x.m, x.e, s);
+        let q = round_quotient(&q, class, x.sign, roundingMode);
+        let flags = if class == EXACT {
+            0
+        } else {
+            softfloat_flag_inexact
+        };
+        (Self::from_quotient(x.sign, &q, s, style), flags)
+    }
+
+    /// The digit `i` places after the first.
+    const fn digit(&self, i: i64) -> u8 {
+        if i >= 0 && i < self.len as i64 {
+            self.digits[i as usize]
+        } else {
+            b'0'
+        }
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, scientific: bool, upper: bool) -> fmt::Result {
+        let last = self.len as i64 - 1;
+        let frac = match self.style {
+            Style::Fixed(n) if !scientific => n,
+            Style::Exponent(n) if scientific => n,
+            _ if scientific => last.max(0) as usize,
+            _ => (last - self.exp as i64).max(0) as usize,
+        };
+        let point = if frac > 0 { frac + 1 } else { 0 };
+        let len = match self.kind {
+            Kind::NaN | Kind::Inf => 3,
+            Kind::Finite if scientific => 1 + point + 1 + exponent_len(self.exp),
+            Kind::Finite => self.exp.max(0) as usize + 1 + point,
+        };
+
+        let sign = sign_str(f, self.sign, self.kind);
+        write_padded(f, sign, "", len, |f| match self.kind {
+            Kind::NaN => f.write_str("NaN"),
+            Kind::Inf => f.write_str("inf"),
+            Kind::Finite if scientific => {
+                f.write_char(self.digit(0) as char)?;
+                if frac > 0 {
+                    f.write_char('.')?;
+                    for i in 1..=frac {
+                        f.write_char(self.digit(i as i64) as char)?;
+                    }
+                }
+                f.write_char(if upper { 'E' } else { 'e' })?;
+                write!(f, "{}", self.exp)
+            }
+            Kind::Finite => {
+                let exp = self.exp as i64;
+                if exp < 0 {
+                    f.write_char('0')?;
+                }
+                for i in 0..=exp {
+                    f.write_char(self.digit(i) as char)?;
+                }
+                if frac > 0 {
+                    f.write_char('.')?;
+                    for j in 0..frac {
+                        f.write_char(self.digit(exp + 1 + j as i64) as char)?;
+                    }
+                }
+                Ok(())
+            }
+        })
+    }
+}
+
+/// The characters of a decimal exponent.
+pub const fn exponent_len(exp: i32) -> usize {
+    (exp < 0) as usize + digit_count(exp.unsigned_abs() as u64) as usize
+}
+
+/// The sign to write: none for NaN, and `+` only if asked for.
+pub fn sign_str(f: &fmt::Formatter<'_>, sign: bool, kind: Kind) -> &'static str {
+    if kind == Kind::NaN {
+        ""
+    } else if sign {
+        "-"
+    } else if f.sign_plus() {
+        "+"
+    } else {
+        ""
+    }
+}
+
+/// Writes `sign`, `prefix` and a `len`-character body, padded to the
+/// formatter's width. Zero padding goes between the prefix and the body.
+pub fn write_padded(
+    f: &mut fmt::Formatter<'_>,
+    sign: &str,
+    prefix: &str,
+    len: usize,
+    body: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    let len = sign.len() + prefix.len() + len;
+    let pad = f.width().map_or(0, |w| w.saturating_sub(len));
+    let (pre, post) = if f.sign_aware_zero_pad() {
+        (0, 0)
+    } else {
+        match f.align() {
+            Some(fmt::Alignment::Left) => (0, pad),
+            Some(fmt::Alignment::Center) => (pad / 2, pad - pad / 2),
+            _ => (pad, 0),
+        }
+    };
+    let fill = f.fill();
+    for _ in 0..pre {
+        f.write_char(fill)?;
+    }
+    f.write_str(sign)?;
+    f.write_str(prefix)?;
+    if f.sign_aware_zero_pad() {
+        for _ in 0..pad {
+            f.write_char('0')?;
+        }
+    }
+    body(f)?;
+    for _ in 0..post {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, matches!(self.style, Style::Exponent(_)), false)
+    }
+}
+
+impl fmt::Debug for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::LowerExp for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, true, false)
+    }
+}
+
+impl fmt::UpperExp for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, true, true)
+    }
+}
+
+/// The shortest decimal that parses back to `a` when rounding to nearest
+/// even; of two equally short candidates, the one nearer to `a`.
+#[must_use]
+pub const fn f32_toShortest(a: float32_t) -> Decimal {
+    Decimal::shortest(&unpack_f32(a))
+}
+
+/// The shortest decimal that parses back to `a` when rounding to nearest
+/// even; of two equally short candidates, the one nearer to `a`.
+#[must_use]
+pub const fn f64_toShortest(a: float64_t) -> Decimal {
+    Decimal::shortest(&unpack_f64(a))
+}
+
+/// Rounds `a` to `digits` digits after the decimal point, like C's `%.*f`.
+/// Raises inexact if any nonzero digit was dropped.
+#[must_use]
+pub const fn f32_toFixed(a: float32_t, digits: usize, roundingMode: u8) -> (Decimal, u8) {
+    Decimal::fixed(&unpack_f32(a), digits, roundingMode)
+}
+
+/// Rounds `a` to `digits` digits after the decimal point, like C's `%.*f`.
+/// Raises inexact if any nonzero digit was dropped.
+#[must_use]
+pub const fn f64_toFixed(a: float64_t, digits: usize, roundingMode: u8) -> (Decimal, u8) {
+    Decimal::fixed(&unpack_f64(a), digits, roundingMode)
+}
+
+/// Rounds `a` to scientific notation with `digits` digits after the
+/// decimal point, like C's `%.*e`. Raises inexact if any nonzero digit was
+/// dropped.
+#[must_use]
+pub const fn f32_toExponent(a: float32_t, digits: usize, roundingMode: u8) -> (Decimal, u8) {
+    Decimal::scientific(&unpack_f32(a), digits, roundingMode)
+}
+
+/// Rounds `a` to scientific notation with `digits` digits after the
+/// decimal point, like C's `%.*e`. Raises inexact if any nonzero digit was
+/// dropped.
+#[must_use]
+pub const fn f64_toExponent(a: float64_t, digits: usize, roundingMode: u8) -> (Decimal, u8) {
+    Decimal::scientific(&unpack_f64(a), digits, roundingMode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f64_toFixed_toExponent() {
+        struct TestCase {
+            f: fn(float64_t, usize, u8) -> (Decimal, u8),
+            a: u64,
+            digits: usize,
+            result: &'static str,
+            flags: u8,
+            roundingMode: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f64_toExponent,
+                a: 0x4270_0000_0000_0007,
+                digits: 11,
+                result: "1.09951162778e12",
+                flags: 1,
+                roundingMode: 0,
+            },
+            TestCase {
+                f: f64_toExponent,
+                a: 0xC0BD_8DDE_ED0A_EDD2,
+                digits: 1,
+                result: "-7.5e3",
+                flags: 1,
+                roundingMode: 1,
+            },
+            TestCase {
+                f: f64_toExponent,
+                a: 0xC020_0000_0000_0071,
+                digits: 9,
+                result: "-8.000000001e0",
+                flags: 1,
+                roundingMode: 2,
+            },
+            TestCase {
+                f: f64_toExponent,
+                a: 0x3FB0_0000_0000_00FC,
+                digits: 19,
+                result: "6.2500000000003497203e-2",
+                flags: 1,
+                roundingMode: 3,
+            },
+            TestCase {
+                f: f64_toExponent,
+                a: 0x3EE0_0000_0000_0005,
+                digits: 7,
+                result: "7.6293945e-6",
+                flags: 1,
+                roundingMode: 4,
+            },
+            TestCase {
+                f: f64_toExponent,
+                a: 0xC230_0000_0000_019C,
+                digits: 20,
+                result: "-6.87194767360062866211e10",
+                flags: 1,
+                roundingMode: 6,
+            },
+            TestCase {
+                f: f64_toFixed,
+                a: 0xC190_0000_0000_0005,
+                digits: 0,
+                result: "-67108864",
+                flags: 1,
+                roundingMode: 0,
+            },
+            TestCase {
+                f: f64_toFixed,
+                a: 0x3FB0_0000_0000_009C,
+                digits: 10,
+                result: "0.0625000000",
+                flags: 1,
+                roundingMode: 1,
+            },
+            TestCase {
+                f: f64_toFixed,
+                a: 0xC33A_1A91_B3BB_2D12,
+                digits: 2,
+                result: "-7347562482445586.00",
+                flags: 0,
+                roundingMode: 2,
+            },
+            TestCase {
+                f: f64_toFixed,
+                a: 0x3FC6_7808_123C_B81B,
+                digits: 18,
+                result: "0.175538071541667091",
+                flags: 1,
+                roundingMode: 3,
+            },
+            TestCase {
+                f: f64_toFixed,
+                a: 0xC1A0_0000_0000_0005,
+                digits: 11,
+                result: "-134217728.00000014901",
+                flags: 1,
+                roundingMode: 4,
+            },
+            TestCase {
+                f: f64_toFixed,
+                a: 0x3F92_4078_3292_6997,
+                digits: 6,
+                result: "0.017825",
+                flags: 1,
+                roundingMode: 6,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(float64_t { v: c.a }, c.digits, c.roundingMode);
+            assert_eq!((i, format!("{res}"), flags), (i, c.result.into(), c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f32_toFixed_toExponent() {
+        struct TestCase {
+            f: fn(float32_t, usize, u8) -> (Decimal, u8),
+            a: u32,
+            digits: usize,
+            result: &'static str,
+            flags: u8,
+            roundingMode: u8,
+        }
+
+        let cases = [
+            TestCase {
+                f: f32_toExponent,
+                a: 0xA0E2_5F7F,
+                digits: 7,
+                result: "-3.8349083e-19",
+                flags: 1,
+                roundingMode: 0,
+            },
+            TestCase {
+                f: f32_toExponent,
+                a: 0xC1D0_89DB,
+                digits: 16,
+                result: "-2.6067312240600585e1",
+                flags: 1,
+                roundingMode: 1,
+            },
+            TestCase {
+                f: f32_toExponent,
+                a: 0xA8D0_39B0,
+                digits: 5,
+                result: "-2.31177e-14",
+                flags: 1,
+                roundingMode: 2,
+            },
+            TestCase {
+                f: f32_toExponent,
+                a: 0x4D00_013F,
+                digits: 16,
+                result: "1.3422283200000000e8",
+                flags: 0,
+                roundingMode: 3,
+            },
+            TestCase {
+                f: f32_toExponent,
+                a: 0xCD80_0005,
+                digits: 0,
+                result: "-3e8",
+                flags: 1,
+                roundingMode: 4,
+            },
+            TestCase {
+                f: f32_toExponent,
+                a: 0x52F7_C4D2,
+                digits: 2,
+                result: "5.33e11",
+                flags: 1,
+                roundingMode: 6,
+            },
+            TestCase {
+                f: f32_toFixed,
+                a: 0xD980_0190,
+                digits: 8,
+                result: "-4503814375735296.00000000",
+                flags: 0,
+                roundingMode: 0,
+            },
+            TestCase {
+                f: f32_toFixed,
+                a: 0xD100_0003,
+                digits: 9,
+                result: "-34359750656.000000000",
+                flags: 0,
+                roundingMode: 1,
+            },
+            TestCase {
+                f: f32_toFixed,
+                a: 0x4800_0230,
+                digits: 18,
+                result: "131080.750000000000000000",
+                flags: 0,
+                roundingMode: 2,
+            },
+            TestCase {
+                f: f32_toFixed,
+                a: 0xC800_0146,
+                digits: 2,
+                result: "-131077.09",
+                flags: 1,
+                roundingMode: 3,
+            },
+            TestCase {
+                f: f32_toFixed,
+                a: 0xB100_0002,
+                digits: 12,
+                result: "-0.000000001863",
+                flags: 1,
+                roundingMode: 4,
+            },
+            TestCase {
+                f: f32_toFixed,
+                a: 0xCE51_F795,
+                digits: 18,
+                result: "-880665920.000000000000000000",
+                flags: 0,
+                roundingMode: 6,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = (c.f)(float32_t { v: c.a }, c.digits, c.roundingMode);
+            assert_eq!((i, format!("{res}"), flags), (i, c.result.into(), c.flags));
+        }
+    }
+
+    #[test]
+    fn test_toShortest() {
+        struct TestCase {
+            a: u64,
+            double: bool,
+            display: &'static str,
+            exp: &'static str,
+        }
+
+        let cases = [
+            TestCase {
+                a: 0x3FB9_9999_9999_999A,
+                double: true,
+                display: "0.1",
+                exp: "1e-1",
+            },
+            TestCase {
+                a: 0x44B5_2D02_C7E1_4AF6,
+                double: true,
+                display: "100000000000000000000000",
+                exp: "1e23",
+            },
+            TestCase {
+                a: 0x0000_0000_0000_0001,
+                double: true,
+                display: "0.000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000005",
+                exp: "5e-324",
+            },
+            TestCase {
+                a: 0x7FEF_FFFF_FFFF_FFFF,
+                double: true,
+                display: "179769313486231570000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+                exp: "1.7976931348623157e308",
+            },
+            TestCase {
+                a: 0xC00C_0000_0000_0000,
+                double: true,
+                display: "-3.5",
+                exp: "-3.5e0",
+            },
+            TestCase {
+                a: 0x8000_0000_0000_0000,
+                double: true,
+                display: "-0",
+                exp: "-0e0",
+            },
+            TestCase {
+                a: 0xFFF0_0000_0000_0000,
+                double: true,
+                display: "-inf",
+                exp: "-inf",
+            },
+            TestCase {
+                a: 0xFFF8_0000_0000_0000,
+                double: true,
+                display: "NaN",
+                exp: "NaN",
+            },
+            TestCase {
+                a: 0x3DCC_CCCD,
+                double: false,
+                display: "0.1",
+                exp: "1e-1",
+            },
+            TestCase {
+                a: 0x4B80_0000,
+                double: false,
+                display: "16777216",
+                exp: "1.6777216e7",
+            },
+            TestCase {
+                // Exactly halfway between two shortest candidates.
+                a: 0x48FB_3D44,
+                double: false,
+                display: "514538.12",
+                exp: "5.1453812e5",
+            },
+            TestCase {
+                a: 0x0000_0001,
+                double: false,
+                display: "0.000000000000000000000000000000000000000000001",
+                exp: "1e-45",
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let res = if c.double {
+                f64_toShortest(float64_t { v: c.a })
+            } else {
+                f32_toShortest(float32_t { v: c.a as u32 })
+            };
+            assert_eq!(
+                (i, format!("{res}"), format!("{res:e}")),
+                (i, c.display.into(), c.exp.into())
+            );
+        }
+    }
+
+    #[test]
+    fn test_formatter_flags() {
+        let a = float64_t::from_f64(-2.5);
+        let b = float32_t::from_bits(0x3FC0_0000);
+        assert_eq!(format!("{a:.0}"), "-2");
+        assert_eq!(format!("{a:>8.2}|{b:<6}|{b:^7}"), "   -2.50|1.5   |  1.5  ");
+        assert_eq!(
+            format!("{a:08.1}|{b:+}|{b:*>+6.1e}"),
+            "-00002.5|+1.5|+1.5e0"
+        );
+        assert_eq!(format!("{a:E}|{b:.3E}"), "-2.5E0|1.500E0");
+    }
+}
@@ -0,0 +1,739 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Hexadecimal floating-point literals, as read by C's `strtod` and
+//! written by `%a`.
+//!
+//! Parsing keeps the first 64 significant bits of the digits and jams the
+//! rest into a sticky bit, which is all the format's `roundPack` needs to
+//! round correctly. Formatting normalizes every nonzero value, subnormals
+//! included, to a leading `1`.
+
+use core::fmt::{self, Write};
+
+use super::decimal::{
+    f32_fromLiteral, f64_fromLiteral, has_prefix, is_digit, parse_special, Literal,
+    OUT_OF_RANGE_EXP,
+};
+use super::format::{
+    exponent_len, round_up, sign_str, unpack_f32, unpack_f64, write_padded, Kind, Unpacked, ABOVE,
+    BELOW, EXACT, HALF,
+};
+use super::ParseFloatError;
+use crate::softfloat::{float32_t, float64_t, softfloat_flag_inexact};
+
+const fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Parses an optionally signed hexadecimal literal, `inf`, `infinity`,
+/// `nan` or `nan(payload)`.
+pub const fn parse_hex(s: &[u8]) -> Result<(bool, Literal), ParseFloatError> {
+    let n = s.len();
+    if n == 0 {
+        return Err(ParseFloatError::EMPTY);
+    }
+    let mut i = 0;
+    let sign = s[0] == b'-';
+    if s[0] == b'-' || s[0] == b'+' {
+        i += 1;
+    }
+    if i == n {
+        return Err(ParseFloatError::INVALID);
+    }
+    if !has_prefix(s, i, b"0x") {
+        return match parse_special(s, i) {
+            Some(lit) => Ok((sign, lit)),
+            None => Err(ParseFloatError::INVALID),
+        };
+    }
+    i += 2;
+
+    let mut sig: u64 = 0;
+    let mut kept = 0;
+    let mut sticky = false;
+    let mut scale: i64 = 0;
+    let mut any_digit = false;
+    let mut point = false;
+    while i < n {
+        if s[i] == b'.' && !point {
+            point = true;
+            i += 1;
+            continue;
+        }
+        let Some(v) = hex_value(s[i]) else {
+            break;
+        };
+        any_digit = true;
+        if kept == 0 && v == 0 {
+            if point {
+                scale -= 4;
+            }
+        } else if kept < 16 {
+            sig = sig << 4 | v as u64;
+            kept += 1;
+            if point {
+                scale -= 4;
+            }
+        } else {
+            sticky |= v != 0;
+            if !point {
+                scale += 4;
+            }
+        }
+        i += 1;
+    }
+    if !any_digit {
+        return Err(ParseFloatError::INVALID);
+    }
+
+    let mut exp: i64 = 0;
+    if i < n && (s[i] == b'p' || s[i] == b'P') {
+        i += 1;
+        let neg = i < n && s[i] == b'-';
+        if i < n && (s[i] == b'-' || s[i] == b'+') {
+            i += 1;
+        }
+        if i == n {
+            return Err(ParseFloatError::INVALID);
+        }
+        while i < n && is_digit(s[i]) {
+            if exp < 1_000_000_000 {
+                exp = exp * 10 + (s[i] - b'0') as i64;
+            }
+            i += 1;
+        }
+        if neg {
+            exp = -exp;
+        }
+    }
+    if i != n {
+        return Err(ParseFloatError::INVALID);
+    }
+    if kept == 0 {
+        return Ok((sign, Literal::Zero));
+    }
+
+    let shift = sig.leading_zeros();
+    let mut e = scale + exp - shift as i64;
+    if e > OUT_OF_RANGE_EXP as i64 {
+        e = OUT_OF_RANGE_EXP as i64;
+    } else if e < -OUT_OF_RANGE_EXP as i64 {
+        e = -OUT_OF_RANGE_EXP as i64;
+    }
+    Ok((
+        sign,
+        Literal::Finite {
+            sig: sig << shift | sticky as u64,
+            exp: e as i32,
+        },
+    ))
+}
+
+/// Parses a hexadecimal literal such as `0x1.8p3` into a correctly rounded
+/// `float32_t`.
+///
+/// Accepts an optional sign, `0x` or `0X`, hexadecimal digits with an
+/// optional point and an optional binary exponent `p` with decimal digits,
+/// as well as the special values accepted by
+/// [`f32_fromDecimal`](super::f32_fromDecimal).
+///
+/// # Errors
+///
+/// Returns [`ParseFloatError`] if `s` is not a valid literal.
+pub const fn f32_fromHex(
+    s: &str,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> Result<(float32_t, u8), ParseFloatError> {
+    match parse_hex(s.as_bytes()) {
+        Ok((sign, lit)) => Ok(f32_fromLiteral(sign, lit, roundingMode, detectTininess)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Parses a hexadecimal literal such as `0x1.8p3` into a correctly rounded
+/// `float64_t`.
+///
+/// Accepts the same syntax as [`f32_fromHex`].
+///
+/// # Errors
+///
+/// Returns [`ParseFloatError`] if `s` is not a valid literal.
+pub const fn f64_fromHex(
+    s: &str,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> Result<(float64_t, u8), ParseFloatError> {
+    match parse_hex(s.as_bytes()) {
+        Ok((sign, lit)) => Ok(f64_fromLiteral(sign, lit, roundingMode, detectTininess)),
+        Err(e) => Err(e),
+    }
+}
+
+/// A floating-point value in hexadecimal scientific notation, ready to be
+/// written.
+///
+/// [`fmt::Display`] and [`fmt::LowerHex`] write C's `%a` form, such as
+/// `0x1.8p+3`, and [`fmt::UpperHex`] its `%A` form. The formatter's width,
+/// fill, alignment, `+` and `0` flags are honoured; its precision is not.
+#[derive(Copy, Clone, Debug)]
+pub struct Hex {
+    sign: bool,
+    kind: Kind,
+    /// The digit before the point: 1, or 0 for zero.
+    lead: u8,
+    /// Fraction digits after the leading `1`, from the top nibble down.
+    frac: u64,
+    /// Fraction digits to write.
+    digits: usize,
+    exp: i32,
+}
+
+impl Hex {
+    const fn empty(sign: bool, kind: Kind, digits: usize) -> Self {
+        Self {
+            sign,
+            kind,
+            lead: 0,
+            frac: 0,
+            digits,
+            exp: 0,
+        }
+    }
+
+    /// The significand's fraction bits below the leading `1`, and the
+    /// exponent of that `1`.
+    const fn normalize(x: &Unpacked) -> (u64, i32) {
+        let shift = x.m.leading_zeros();
+        ((x.m << shift) << 1, x.e + 63 - shift as i32)
+    }
+
+    const fn exact(x: &Unpacked) -> Self {
+        if x.kind as u8 != Kind::Finite as u8 || x.m == 0 {
+            return Self::empty(x.sign, x.kind, 0);
+        }
+        let (frac, exp) = Self::normalize(x);
+        let digits = if frac == 0 {
+            0
+        } else {
+            (64 - frac.trailing_zeros() as usize).div_ceil(4)
+        };
+        Self {
+            sign: x.sign,
+            kind: Kind::Finite,
+            lead: 1,
+            frac,
+            digits,
+            exp,
+        }
+    }
+
+    const fn rounded(x: &Unpacked, digits: usize, roundingMode: u8) -> (Self, u8) {
+        if x.kind as u8 != Kind::Finite as u8 || x.m == 0 || digits >= 16 {
+            return (Self::exact(x).with_digits(digits), 0);
+        }
+        let (frac, mut exp) = Self::normalize(x);
+        let kept = 4 * digits as u32;
+        let (mut q, rest) = if kept == 0 {
+            (0, frac)
+        } else {
+            (frac >> (64 - kept), frac << kept)
+        };
+        let class = match (rest >> 63 != 0, rest << 1 != 0) {
+            (false, false) => EXACT,
+            (false, true) => BELOW,
+            (true, false) => HALF,
+            (true, true) => ABOVE,
+        };
+        // The leading `1` is the last kept digit when no fraction is kept.
+        let odd = if kept == 0 { true } else { q & 1 != 0 };
+        if round_up(class, odd, x.sign, roundingMode) {
+            q += 1;
+            if q >> kept != 0 {
+                q = 0;
+                exp += 1;
+            }
+        }
+        let frac = if kept == 0 { 0 } else { q << (64 - kept) };
+        let flags = if class == EXACT {
+            0
+        } else {
+            softfloat_flag_inexact
+        };
+        (
+            Self {
+                sign: x.sign,
+                kind: Kind::Finite,
+                lead: 1,
+                frac,
+                digits,
+                exp,
+            },
+            flags,
+        )
+    }
+
+    const fn with_digits(mut self, digits: usize) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// The fraction digit `i` places after the point.
+    const fn nibble(&self, i: usize) -> u8 {
+        if i < 16 {
+            ((self.frac >> (60 - 4 * i)) & 0xF) as u8
+        } else {
+            0
+        }
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, upper: bool) -> fmt::Result {
+        let sign = sign_str(f, self.sign, self.kind);
+        if self.kind != Kind::Finite {
+            let s = if self.kind == Kind::NaN { "NaN" } else { "inf" };
+            return write_padded(f, sign, "", 3, |f| f.write_str(s));
+        }
+        let point = if self.digits > 0 { self.digits + 1 } else { 0 };
+        let len = 1 + point + 2 + exponent_len(self.exp.abs());
+        let prefix = if upper { "0X" } else { "0x" };
+        write_padded(f, sign, prefix, len, |f| {
+            f.write_char((b'0' + self.lead) as char)?;
+            if self.digits > 0 {
+                f.write_char('.')?;
+                for i in 0..self.digits {
+                    let c = char::from_digit(self.nibble(i) as u32, 16).unwrap_or('0');
+                    f.write_char(if upper { c.to_ascii_uppercase() } else { c })?;
+                }
+            }
+            f.write_char(if upper { 'P' } else { 'p' })?;
+            f.write_char(if self.exp < 0 { '-' } else { '+' })?;
+            write!(f, "{}", self.exp.unsigned_abs())
+        })
+    }
+}
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, false)
+    }
+}
+
+impl fmt::LowerHex for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, false)
+    }
+}
+
+impl fmt::UpperHex for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, true)
+    }
+}
+
+/// `a` in hexadecimal scientific notation with as many digits as needed,
+/// like C's `%a`.
+#[must_use]
+pub const fn f32_toHex(a: float32_t) -> Hex {
+    Hex::exact(&unpack_f32(a))
+}
+
+/// `a` in hexadecimal scientific notation with as many digits as needed,
+/// like C's `%a`.
+#[must_use]
+pub const fn f64_toHex(a: float64_t) -> Hex {
+    Hex::exact(&unpack_f64(a))
+}
+
+/// Rounds `a` to hexadecimal scientific notation with `digits` digits after
+/// the point, like C's `%.*a`. Raises inexact if any nonzero bit was
+/// dropped.
+#[must_use]
+pub const fn f32_toHexDigits(a: float32_t, digits: usize, roundingMode: u8) -> (Hex, u8) {
+    Hex::rounded(&unpack_f32(a), digits, roundingMode)
+}
+
+/// Rounds `a` to hexadecimal scientific notation with `digits` digits after
+/// the point, like C's `%.*a`. Raises inexact if any nonzero bit was
+/// dropped.
+#[must_use]
+pub const fn f64_toHexDigits(a: float64_t, digits: usize, roundingMode: u8) -> (Hex, u8) {
+    Hex::rounded(&unpack_f64(a), digits, roundingMode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f64_fromHex() {
+        struct TestCase {
+            s: &'static str,
+            result: u64,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                s: "-0X.4d8d632800000080",
+                result: 0xBFD3_6358_CA00_0000,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "-0xEB.de9c25610000800001P+33",
+                result: 0xC27D_7BD3_84AC_2000,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "-0x.fP+984",
+                result: 0xFD6E_0000_0000_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "-0XD4.5918fbb000000000800001P-1096",
+                result: 0x8000_0000_0000_0001,
+                flags: 3,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "-0XF.bd",
+                result: 0xC02F_7A00_0000_0000,
+                flags: 0,
+                roundingMode: 6,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "-0X7B.52686f7f8fb8e14a1379efP-40",
+                result: 0xBDDE_D49A_1BDF_E3EE,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "+0XB.8a0a3538854",
+                result: 0x4027_1414_6A71_0A80,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "0xd4.2a63p976",
+                result: 0x7D6A_854C_6000_0000,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "0X.8f59ffbf0000008p-254",
+                result: 0x3001_EB3F_F7E0_0001,
+                flags: 1,
+                roundingMode: 6,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "-0x6c7.d093P+1071",
+                result: 0xFFF0_0000_0000_0000,
+                flags: 5,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "-0XB6.74",
+                result: 0xC066_CE80_0000_0000,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "+0x.fab9deP539",
+                result: 0x619F_573B_C000_0000,
+                flags: 0,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = f64_fromHex(c.s, c.roundingMode, c.detectTininess).unwrap();
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f32_fromHex() {
+        struct TestCase {
+            s: &'static str,
+            result: u32,
+            flags: u8,
+            roundingMode: u8,
+            detectTininess: u8,
+        }
+
+        let cases = [
+            TestCase {
+                s: "-0x3.d391675b3eb5babb7e4be4P-129",
+                result: 0x803D_3916,
+                flags: 3,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "-0X.2144fc5a754cd0992275P-152",
+                result: 0x8000_0000,
+                flags: 3,
+                roundingMode: 1,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "0xa5.2ee4b",
+                result: 0x4325_2EE5,
+                flags: 1,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "-0X79b.bd465376a24ba8365fP69",
+                result: 0xE773_77A8,
+                flags: 1,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "-0x.189639110000000000800001p122",
+                result: 0xFAC4_B1C9,
+                flags: 1,
+                roundingMode: 0,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "-0xb3b.6cb9b5ba0000000000800001P+37",
+                result: 0xD7B3_B6CC,
+                flags: 1,
+                roundingMode: 2,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "-0xB.bdacp+69",
+                result: 0xE3BB_DAC0,
+                flags: 0,
+                roundingMode: 1,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "+0x.0dccfc72c6a1554P+2",
+                result: 0x3E5C_CFC7,
+                flags: 1,
+                roundingMode: 6,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "0X.d00000000080P-129",
+                result: 0x000D_0001,
+                flags: 3,
+                roundingMode: 6,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "-0xA8E.c81975d90000080P+122",
+                result: 0xFF7F_FFFF,
+                flags: 5,
+                roundingMode: 6,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "+0xf8.0P-12",
+                result: 0x3D78_0000,
+                flags: 0,
+                roundingMode: 0,
+                detectTininess: 1,
+            },
+            TestCase {
+                s: "+0xb4F.10000p-79",
+                result: 0x1DB4_F100,
+                flags: 0,
+                roundingMode: 4,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "-0XDB5.20000000000p-121",
+                result: 0x88DB_5200,
+                flags: 0,
+                roundingMode: 3,
+                detectTininess: 0,
+            },
+            TestCase {
+                s: "0XA23.fb06b6fd000000000P+135",
+                result: 0x7F80_0000,
+                flags: 5,
+                roundingMode: 4,
+                detectTininess: 1,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let (res, flags) = f32_fromHex(c.s, c.roundingMode, c.detectTininess).unwrap();
+            assert_eq!((i, res.v, flags), (i, c.result, c.flags));
+        }
+    }
+
+    #[test]
+    fn test_f64_toHexDigits() {
+        struct TestCase {
+            a: u64,
+            digits: Option<usize>,
+            result: &'static str,
+            flags: u8,
+            roundingMode: u8,
+        }
+
+        let cases = [
+            TestCase {
+                a: 0x8CFD_903F_F413_AB1B,
+                digits: Some(0),
+                result: "-0x1p-816",
+                flags: 1,
+                roundingMode: 6,
+            },
+            TestCase {
+                a: 0xBE87_C4D7_9C3F_376B,
+                digits: None,
+                result: "-0x1.7c4d79c3f376bp-23",
+                flags: 0,
+                roundingMode: 0,
+            },
+            TestCase {
+                a: 0xC990_2069_876E_38AF,
+                digits: Some(4),
+                result: "-0x1.0206p+154",
+                flags: 1,
+                roundingMode: 1,
+            },
+            TestCase {
+                a: 0x7F4D_3000_0000_0000,
+                digits: Some(7),
+                result: "0x1.d300000p+1013",
+                flags: 0,
+                roundingMode: 6,
+            },
+            TestCase {
+                a: 0x12C5_5F8F_4CE1_F41F,
+                digits: Some(16),
+                result: "0x1.55f8f4ce1f41f000p-723",
+                flags: 0,
+                roundingMode: 4,
+            },
+            TestCase {
+                a: 0x5479_A2BB_3C64_D855,
+                digits: Some(5),
+                result: "0x1.9a2bbp+328",
+                flags: 1,
+                roundingMode: 2,
+            },
+            TestCase {
+                a: 0x255D_3604_A50A_A1EF,
+                digits: Some(7),
+                result: "0x1.d3604a5p-426",
+                flags: 1,
+                roundingMode: 4,
+            },
+            TestCase {
+                a: 0xCB25_8000_0000_0000,
+                digits: Some(12),
+                result: "-0x1.580000000000p+179",
+                flags: 0,
+                roundingMode: 2,
+            },
+            TestCase {
+                a: 0x5988_FCE0_F96D_3ABA,
+                digits: Some(14),
+                result: "0x1.8fce0f96d3aba0p+409",
+                flags: 0,
+                roundingMode: 1,
+            },
+            TestCase {
+                a: 0xE000_0000_0000_0000,
+                digits: Some(15),
+                result: "-0x1.000000000000000p+513",
+                flags: 0,
+                roundingMode: 3,
+            },
+            TestCase {
+                a: 0xDAF7_D081_93DB_4B78,
+                digits: Some(14),
+                result: "-0x1.7d08193db4b780p+432",
+                flags: 0,
+                roundingMode: 0,
+            },
+            TestCase {
+                a: 0x9036_A5B3_A4FD_8000,
+                digits: Some(5),
+                result: "-0x1.6a5b4p-764",
+                flags: 1,
+                roundingMode: 0,
+            },
+            TestCase {
+                a: 0xCA41_F868_BFEE_ECDC,
+                digits: Some(4),
+                result: "-0x1.1f86p+165",
+                flags: 1,
+                roundingMode: 3,
+            },
+        ];
+
+        for (i, c) in cases.iter().enumerate() {
+            let a = float64_t { v: c.a };
+            let (res, flags) = c.digits.map_or_else(
+                || (f64_toHex(a), 0),
+                |n| f64_toHexDigits(a, n, c.roundingMode),
+            );
+            assert_eq!((i, format!("{res}"), flags), (i, c.result.into(), c.flags));
+        }
+    }
+
+    #[test]
+    fn test_hex_special_and_flags() {
+        let min = float64_t { v: 1 };
+        let one = float32_t { v: 0x3F80_0000 };
+        let half_up = float64_t {
+            v: 0x3FF8_0000_0000_0000,
+        };
+        assert_eq!(format!("{}", f64_toHex(min)), "0x1p-1074");
+        assert_eq!(format!("{:X}", f32_toHex(one)), "0X1P+0");
+        assert_eq!(format!("{}", f64_toHexDigits(half_up, 0, 0).0), "0x1p+1");
+        assert_eq!(format!("{}", f64_toHexDigits(half_up, 0, 1).0), "0x1p+0");
+        assert_eq!(
+            format!("{:+012}", f32_toHexDigits(one, 2, 0).0),
+            "+0x001.00p+0"
+        );
+        assert_eq!(
+            format!("{:>8}", f64_toHex(float64_t { v: 1 << 63 })),
+            " -0x0p+0"
+        );
+        assert_eq!(
+            format!("{}", f32_toHex(float32_t { v: 0xFF80_0000 })),
+            "-inf"
+        );
+        assert_eq!(
+            f64_fromHex("-nan(0x5)", 0, 1).unwrap().0.v,
+            0xFFF8_0000_0000_0005
+        );
+        for s in [
+            "", "0x", "0x.p1", "1.8p3", "0x1p", "0x1p+", "0x1.8q3", "0x1..8",
+        ] {
+            assert!(f64_fromHex(s, 0, 1).is_err(), "{s}");
+        }
+    }
+}
@@ -0,0 +1,201 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Literals in either notation and the compile-time literal macros.
+
+use super::decimal::has_prefix;
+use super::{f32_fromDecimal, f32_fromHex, f64_fromDecimal, f64_fromHex, ParseFloatError};
+use crate::softfloat::{float32_t, float64_t};
+
+/// Whether `s` is hexadecimal, that is `0x` or `0X` after an optional sign.
+const fn is_hex(s: &[u8]) -> bool {
+    let i = if !s.is_empty() && (s[0] == b'+' || s[0] == b'-') {
+        1
+    } else {
+        0
+    };
+    has_prefix(s, i, b"0x")
+}
+
+/// Parses a decimal or hexadecimal literal into a correctly rounded
+/// `float32_t`.
+///
+/// Literals prefixed with `0x` or `0X` after the optional sign are read by
+/// [`f32_fromHex`], everything else by [`f32_fromDecimal`].
+///
+/// # Errors
+///
+/// Returns [`ParseFloatError`] if `s` is not a valid literal.
+pub const fn f32_fromStr(
+    s: &str,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> Result<(float32_t, u8), ParseFloatError> {
+    if is_hex(s.as_bytes()) {
+        f32_fromHex(s, roundingMode, detectTininess)
+    } else {
+        f32_fromDecimal(s, roundingMode, detectTininess)
+    }
+}
+
+/// Parses a decimal or hexadecimal literal into a correctly rounded
+/// `float64_t`.
+///
+/// Accepts the same syntax as [`f32_fromStr`].
+///
+/// # Errors
+///
+/// Returns [`ParseFloatError`] if `s` is not a valid literal.
+pub const fn f64_fromStr(
+    s: &str,
+    roundingMode: u8,
+    detectTininess: u8,
+) -> Result<(float64_t, u8), ParseFloatError> {
+    if is_hex(s.as_bytes()) {
+        f64_fromHex(s, roundingMode, detectTininess)
+    } else {
+        f64_fromDecimal(s, roundingMode, detectTininess)
+    }
+}
+
+/// A correctly rounded `float32_t` literal, evaluated at compile time.
+///
+/// Takes a string literal in the syntax of
+/// [`f32_fromStr`](crate::text::f32_fromStr) and an optional
+/// [`RoundingMode`](crate::RoundingMode), rounding to nearest even by
+/// default.
+///
+/// ```
+/// use softfloat_pure::{f32_lit, RoundingMode};
+///
+/// assert_eq!(f32_lit!("0.1").v, 0x3DCC_CCCD);
+/// assert_eq!(f32_lit!("0.1", RoundingMode::RtzTowardZero).v, 0x3DCC_CCCC);
+/// ```
+///
+/// A malformed literal is a compile error:
+///
+/// ```compile_fail
+/// let x = softfloat_pure::f32_lit!("1e");
+/// ```
+///
+/// ```compile_fail
+/// let x = softfloat_pure::f32_lit!("0x1.p");
+/// ```
+#[macro_export]
+macro_rules! f32_lit {
+    ($s:literal) => {
+        $crate::f32_lit!($s, $crate::RoundingMode::RneTiesToEven)
+    };
+    ($s:literal, $rnd:expr) => {{
+        const VALUE: $crate::float32_t = match $crate::text::f32_fromStr(
+            $s,
+            $rnd.to_softfloat(),
+            $crate::softfloat::init_detectTininess,
+        ) {
+            Ok((v, _)) => v,
+            Err(_) => panic!("{}", concat!("invalid float literal ", stringify!($s))),
+        };
+        VALUE
+    }};
+}
+
+/// A correctly rounded `float64_t` literal, evaluated at compile time.
+///
+/// Takes the same arguments as [`f32_lit!`](crate::f32_lit).
+///
+/// ```
+/// use softfloat_pure::f64_lit;
+///
+/// assert_eq!(f64_lit!("0x1.8p-3").v, 0x3FC8_0000_0000_0000);
+/// ```
+///
+/// A malformed literal is a compile error:
+///
+/// ```compile_fail
+/// let x = softfloat_pure::f64_lit!("0x1.p");
+/// ```
+///
+/// ```compile_fail
+/// let x = softfloat_pure::f64_lit!("1e");
+/// ```
+#[macro_export]
+macro_rules! f64_lit {
+    ($s:literal) => {
+        $crate::f64_lit!($s, $crate::RoundingMode::RneTiesToEven)
+    };
+    ($s:literal, $rnd:expr) => {{
+        const VALUE: $crate::float64_t = match $crate::text::f64_fromStr(
+            $s,
+            $rnd.to_softfloat(),
+            $crate::softfloat::init_detectTininess,
+        ) {
+            Ok((v, _)) => v,
+            Err(_) => panic!("{}", concat!("invalid float literal ", stringify!($s))),
+        };
+        VALUE
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RoundingMode;
+
+    const TENTH: float32_t = crate::f32_lit!("0.1");
+
+    #[test]
+    fn test_fromStr() {
+        assert_eq!(
+            f32_fromStr("-0X1p-1", 0, 1),
+            Ok((float32_t { v: 0xBF00_0000 }, 0))
+        );
+        assert_eq!(
+            f32_fromStr("0.1", 0, 1),
+            Ok((float32_t { v: 0x3DCC_CCCD }, 1))
+        );
+        assert_eq!(
+            f64_fromStr("+0x1.8p-3", 0, 1),
+            Ok((
+                float64_t {
+                    v: 0x3FC8_0000_0000_0000
+                },
+                0
+            ))
+        );
+        assert_eq!(
+            f64_fromStr("inf", 0, 1),
+            Ok((
+                float64_t {
+                    v: 0x7FF0_0000_0000_0000
+                },
+                0
+            ))
+        );
+        assert!(f32_fromStr("", 0, 1).is_err());
+        assert!(f32_fromStr("-", 0, 1).is_err());
+        assert!(f64_fromStr("0x", 0, 1).is_err());
+        // What `f32_lit!` and `f64_lit!` refuse to compile.
+        for s in ["0x1.p", "1e", "0x1p", "1e+", ".", "0x.p1"] {
+            assert!(f32_fromStr(s, 0, 1).is_err(), "{s}");
+            assert!(f64_fromStr(s, 0, 1).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_lit() {
+        assert_eq!(TENTH.v, 0x3DCC_CCCD);
+        assert_eq!(
+            crate::f32_lit!("0.1", RoundingMode::RtzTowardZero).v,
+            0x3DCC_CCCC
+        );
+        assert_eq!(crate::f64_lit!("0.1").v, 0x3FB9_9999_9999_999A);
+        assert_eq!(
+            crate::f64_lit!("0.1", RoundingMode::RdnTowardNegative).v,
+            0x3FB9_9999_9999_9999
+        );
+        assert_eq!(
+            crate::f64_lit!("0x1.8p-3", RoundingMode::RdnTowardNegative).v,
+            0x3FC8_0000_0000_0000
+        );
+        assert_eq!(crate::f32_lit!("-1e60").v, 0xFF80_0000);
+    }
+}
@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Conversions between floating-point values and text.
+//!
+//! Parsing and formatting round once under the requested rounding mode and
+//! return the IEEE exception flags they raise, matching the conventions of
+//! [`crate::softfloat`]. Everything here is `const`, allocation-free and
+//! panic-free.
+#![allow(
+    non_snake_case,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss,
+    clippy::cast_lossless,
+    clippy::many_single_char_names,
+    clippy::module_name_repetitions,
+    clippy::too_many_lines
+)]
+
+use core::fmt;
+
+mod big;
+mod decimal;
+mod exact;
+mod format;
+mod hex;
+mod literal;
+
+pub use decimal::{f32_fromDecimal, f64_fromDecimal};
+pub use exact::{f32_toExact, f32_toRational, f64_toExact, f64_toRational, Rational};
+pub use format::{
+    f32_toExponent, f32_toFixed, f32_toShortest, f64_toExponent, f64_toFixed, f64_toShortest,
+    Decimal,
+};
+pub use hex::{
+    f32_fromHex, f32_toHex, f32_toHexDigits, f64_fromHex, f64_toHex, f64_toHexDigits, Hex,
+};
+pub use literal::{f32_fromStr, f64_fromStr};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ParseErrorKind {
+    Empty,
+    Invalid,
+}
+
+/// The error returned when a string is not a valid floating-point literal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseFloatError {
+    kind: ParseErrorKind,
+}
+
+impl ParseFloatError {
+    const EMPTY: Self = Self {
+        kind: ParseErrorKind::Empty,
+    };
+
+    pub(crate) const INVALID: Self = Self {
+        kind: ParseErrorKind::Invalid,
+    };
+}
+
+impl fmt::Display for ParseFloatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.kind {
+            ParseErrorKind::Empty => "cannot parse float from empty string",
+            ParseErrorKind::Invalid => "invalid float literal",
+        })
+    }
+}
@@ -2,7 +2,8 @@
 
 use core::borrow::Borrow;
 use core::cmp::Ordering;
-use core::fmt::{LowerHex, UpperHex};
+use core::fmt::{self, LowerHex, UpperHex};
+use core::ops::{BitAnd, BitOr};
 use num_traits::{
     identities::{One, Zero},
     PrimInt,
@@ -10,16 +11,20 @@ use num_traits::{
 
 use super::super::{
     float32_t, float64_t,
+    fpu::{FpuFormat, FPU},
+    math::{f64_fitsPrecision, Constant, Parts},
     softfloat::{
         softfloat_flag_inexact, softfloat_flag_infinite, softfloat_flag_invalid,
-        softfloat_flag_overflow, softfloat_flag_underflow, softfloat_round_max,
-        softfloat_round_min, softfloat_round_minMag, softfloat_round_near_even,
-        softfloat_round_near_maxMag, softfloat_round_odd, softfloat_tininess_afterRounding,
-        softfloat_tininess_beforeRounding,
+        softfloat_flag_overflow, softfloat_flag_underflow, softfloat_quietBitSet,
+        softfloat_round_max, softfloat_round_min, softfloat_round_minMag,
+        softfloat_round_near_even, softfloat_round_near_maxMag, softfloat_round_odd,
+        softfloat_tininess_afterRounding, softfloat_tininess_beforeRounding,
     },
+    text::{Decimal, Hex, ParseFloatError, Rational},
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TininessMode {
     Before,
     After,
@@ -34,10 +39,34 @@ impl TininessMode {
             Self::After => softfloat_tininess_afterRounding,
         }
     }
+
+    /// The tininess mode with the softfloat encoding `detect_tininess`, if
+    /// any.
+    #[inline]
+    #[must_use]
+    pub const fn from_softfloat(detect_tininess: u8) -> Option<Self> {
+        if detect_tininess == softfloat_tininess_beforeRounding {
+            Some(Self::Before)
+        } else if detect_tininess == softfloat_tininess_afterRounding {
+            Some(Self::After)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for TininessMode {
+    /// Tininess detected after rounding, as the RISC-V specialization of
+    /// softfloat does.
+    #[inline]
+    fn default() -> Self {
+        Self::After
+    }
 }
 
 /// floating-point rounding mode defined by standard
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RoundingMode {
     /// to nearest, ties to even
     RneTiesToEven,
@@ -66,9 +95,35 @@ impl RoundingMode {
             Self::Rodd => softfloat_round_odd,
         }
     }
+
+    /// The rounding mode with the softfloat encoding `rnd`, if any.
+    #[inline]
+    #[must_use]
+    pub const fn from_softfloat(rnd: u8) -> Option<Self> {
+        if rnd == softfloat_round_near_even {
+            Some(Self::RneTiesToEven)
+        } else if rnd == softfloat_round_minMag {
+            Some(Self::RtzTowardZero)
+        } else if rnd == softfloat_round_min {
+            Some(Self::RdnTowardNegative)
+        } else if rnd == softfloat_round_max {
+            Some(Self::RupTowardPositive)
+        } else if rnd == softfloat_round_near_maxMag {
+            Some(Self::RmmTiesToAway)
+        } else if rnd == softfloat_round_odd {
+            Some(Self::Rodd)
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct ExceptionFlags(u8);
 
 impl ExceptionFlags {
@@ -78,6 +133,25 @@ impl ExceptionFlags {
     const FLAG_INFINITE: u8 = softfloat_flag_infinite;
     const FLAG_INVALID: u8 = softfloat_flag_invalid;
 
+    /// Inexact result.
+    pub const INEXACT: Self = Self(Self::FLAG_INEXACT);
+    /// Tiny and inexact result.
+    pub const UNDERFLOW: Self = Self(Self::FLAG_UNDERFLOW);
+    /// Result too large for the format.
+    pub const OVERFLOW: Self = Self(Self::FLAG_OVERFLOW);
+    /// Exact infinite result from finite operands, such as division by zero.
+    pub const INFINITE: Self = Self(Self::FLAG_INFINITE);
+    /// Invalid operation.
+    pub const INVALID: Self = Self(Self::FLAG_INVALID);
+    /// Every flag, as a mask.
+    pub const ALL: Self = Self(
+        Self::FLAG_INEXACT
+            | Self::FLAG_UNDERFLOW
+            | Self::FLAG_OVERFLOW
+            | Self::FLAG_INFINITE
+            | Self::FLAG_INVALID,
+    );
+
     #[inline]
     #[must_use]
     pub const fn from_bits(x: u8) -> Self {
@@ -129,6 +203,31 @@ impl ExceptionFlags {
     pub const fn merge(&mut self, flags: u8) {
         self.0 |= flags;
     }
+
+    /// Whether any of the flags in `mask` is raised.
+    #[inline]
+    #[must_use]
+    pub const fn intersects(&self, mask: Self) -> bool {
+        self.0 & mask.0 != 0
+    }
+}
+
+impl BitOr for ExceptionFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for ExceptionFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
 }
 
 impl Default for ExceptionFlags {
@@ -138,7 +237,203 @@ impl Default for ExceptionFlags {
     }
 }
 
-pub trait Float: Sized {
+/// A narrower floating-point format to emulate, with `sig_bits` bits of
+/// precision including the hidden bit and normal exponents from `emin` to
+/// `emax`. See [`crate::math::f64_roundToPrecision`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "crate::serde::PrecisionState")
+)]
+pub struct Precision {
+    sig_bits: u32,
+    emin: i32,
+    emax: i32,
+}
+
+impl Precision {
+    /// IEEE 754 binary16.
+    pub const BINARY16: Self = Self {
+        sig_bits: 11,
+        emin: -14,
+        emax: 15,
+    };
+    /// bfloat16.
+    pub const BFLOAT16: Self = Self {
+        sig_bits: 8,
+        emin: -126,
+        emax: 127,
+    };
+    /// IEEE 754 binary32, to emulate it in `float64_t`.
+    pub const BINARY32: Self = Self {
+        sig_bits: 24,
+        emin: -126,
+        emax: 127,
+    };
+
+    /// The format, or `None` if it does not fit in `float64_t`. See
+    /// [`crate::math::f64_fitsPrecision`].
+    #[inline]
+    #[must_use]
+    pub const fn new(sig_bits: u32, emin: i32, emax: i32) -> Option<Self> {
+        if f64_fitsPrecision(sig_bits, emin, emax) {
+            Some(Self {
+                sig_bits,
+                emin,
+                emax,
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn sig_bits(&self) -> u32 {
+        self.sig_bits
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn emin(&self) -> i32 {
+        self.emin
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn emax(&self) -> i32 {
+        self.emax
+    }
+}
+
+/// The error returned when a [`Precision`] does not fit in a format.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PrecisionError(());
+
+impl PrecisionError {
+    pub(crate) const INVALID: Self = Self(());
+}
+
+impl fmt::Display for PrecisionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("precision does not fit in the format")
+    }
+}
+
+/// A seedable, deterministic source of random bits for stochastic rounding.
+///
+/// It is the splitmix64 generator over a 64-bit state, so a run is
+/// reproduced by starting again from the same seed. See
+/// [`crate::math::f64_addStochastic`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RandomSource {
+    state: u64,
+}
+
+impl RandomSource {
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// The current state, from which [`RandomSource::new`] resumes the same
+    /// sequence.
+    #[inline]
+    #[must_use]
+    pub const fn state(&self) -> u64 {
+        self.state
+    }
+
+    #[inline]
+    pub const fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// A primitive integer type, so that conversions can be generic over the
+/// integer width as well as the format, as in [`crate::FPU::from_int`].
+pub trait Int: Copy {
+    /// `self` converted to `F`, rounded under `rnd`.
+    fn to_float<F: Float>(self, rnd: RoundingMode, tininess: TininessMode) -> (F, u8);
+
+    /// `x` rounded to an integer under `rnd`. NaNs and values out of range
+    /// saturate and raise invalid; `exact` raises inexact if rounding
+    /// changed the value.
+    fn from_float<F: Float>(x: &F, rnd: RoundingMode, exact: bool) -> (Self, u8);
+
+    /// The sign and magnitude of `self`.
+    fn sign_magnitude(self) -> (bool, u128);
+
+    /// `self` converted to `F` by `fpu`, as in [`FPU::from_int`].
+    fn fpu_to_float<F: Float>(self, fpu: &mut FPU, rnd: RoundingMode) -> F;
+
+    /// `x` rounded to an integer by `fpu`, as in [`FPU::to_int`].
+    fn fpu_from_float<F: Float>(fpu: &mut FPU, x: F, rnd: RoundingMode, exact: bool) -> Self;
+}
+
+macro_rules! int {
+    ($(
+        $t:ident: $from:ident, $to:ident, $fpu_from:ident, $fpu_to:ident,
+        |$x:ident| $sign_magnitude:expr;
+    )*) => {
+        $(
+            impl Int for $t {
+                #[inline]
+                fn to_float<F: Float>(self, rnd: RoundingMode, tininess: TininessMode) -> (F, u8) {
+                    F::$from(self, rnd, tininess)
+                }
+
+                #[inline]
+                fn from_float<F: Float>(x: &F, rnd: RoundingMode, exact: bool) -> (Self, u8) {
+                    x.$to(rnd, exact)
+                }
+
+                #[inline]
+                fn sign_magnitude(self) -> (bool, u128) {
+                    let $x = self;
+                    $sign_magnitude
+                }
+
+                #[inline]
+                fn fpu_to_float<F: Float>(self, fpu: &mut FPU, rnd: RoundingMode) -> F {
+                    F::$fpu_from(fpu, self, rnd)
+                }
+
+                #[inline]
+                fn fpu_from_float<F: Float>(
+                    fpu: &mut FPU,
+                    x: F,
+                    rnd: RoundingMode,
+                    exact: bool,
+                ) -> Self {
+                    F::$fpu_to(fpu, x, rnd, exact)
+                }
+            }
+        )*
+    };
+}
+
+int! {
+    i8: from_i8, to_i8, fpu_from_i8, fpu_to_i8, |x| (x < 0, u128::from(x.unsigned_abs()));
+    i16: from_i16, to_i16, fpu_from_i16, fpu_to_i16, |x| (x < 0, u128::from(x.unsigned_abs()));
+    i32: from_i32, to_i32, fpu_from_i32, fpu_to_i32, |x| (x < 0, u128::from(x.unsigned_abs()));
+    i64: from_i64, to_i64, fpu_from_i64, fpu_to_i64, |x| (x < 0, u128::from(x.unsigned_abs()));
+    i128: from_i128, to_i128, fpu_from_i128, fpu_to_i128, |x| (x < 0, x.unsigned_abs());
+    u8: from_u8, to_u8, fpu_from_u8, fpu_to_u8, |x| (false, u128::from(x));
+    u16: from_u16, to_u16, fpu_from_u16, fpu_to_u16, |x| (false, u128::from(x));
+    u32: from_u32, to_u32, fpu_from_u32, fpu_to_u32, |x| (false, u128::from(x));
+    u64: from_u64, to_u64, fpu_from_u64, fpu_to_u64, |x| (false, u128::from(x));
+    u128: from_u128, to_u128, fpu_from_u128, fpu_to_u128, |x| (false, x);
+}
+
+pub trait Float: FpuFormat {
     type Payload: PrimInt + UpperHex + LowerHex;
 
     const EXPONENT_BIT: Self::Payload;
@@ -146,36 +441,51 @@ pub trait Float: Sized {
     const SIGN_POS: usize;
     const EXPONENT_POS: usize;
 
+    /// The largest finite value.
+    const MAX: Self;
+    /// The smallest positive normal value.
+    const MIN_POSITIVE: Self;
+    /// The gap between 1 and the next larger value.
+    const EPSILON: Self;
+    /// The smallest positive subnormal value.
+    const MIN_SUBNORMAL: Self;
+
     fn set_payload(&mut self, x: Self::Payload);
 
     fn from_bits(v: Self::Payload) -> Self;
 
     fn to_bits(&self) -> Self::Payload;
 
-    fn add<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8);
+    fn add<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
 
-    fn sub<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8);
+    fn sub<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
 
-    fn mul<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8);
+    fn mul<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
 
     fn fused_mul_add<T: Borrow<Self>>(
         &self,
         x: T,
         y: T,
         rnd: RoundingMode,
-        detect_tininess: u8,
+        tininess: TininessMode,
     ) -> (Self, u8);
 
-    fn div<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8);
+    fn div<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
 
-    fn rem<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8);
+    fn rem<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
 
-    fn sqrt(&self, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8);
+    fn sqrt(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
 
+    /// `self == x`; only signaling NaNs raise invalid (compareQuietEqual).
+    /// Unlike the bitwise `PartialEq` of `float32_t` and `float64_t`, zeros
+    /// of either sign are equal and NaNs are unequal to everything, so call
+    /// it as `Float::eq(&a, b)` where both are in scope.
     fn eq<T: Borrow<Self>>(&self, x: T) -> (bool, u8);
 
+    /// `self < x`; any NaN raises invalid (compareSignalingLess).
     fn lt<T: Borrow<Self>>(&self, x: T) -> (bool, u8);
 
+    /// `self <= x`; any NaN raises invalid (compareSignalingLessEqual).
     fn le<T: Borrow<Self>>(&self, x: T) -> (bool, u8);
 
     fn lt_quiet<T: Borrow<Self>>(&self, x: T) -> (bool, u8);
@@ -186,43 +496,543 @@ pub trait Float: Sized {
 
     fn is_signaling_nan(&self) -> bool;
 
-    fn from_u32(x: u32, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8);
+    fn from_u32(x: u32, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn from_u64(x: u64, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn from_i32(x: i32, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn from_i64(x: i64, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    #[inline]
+    #[must_use]
+    fn from_u8(x: u8, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        Self::from_u32(x.into(), rnd, tininess)
+    }
+
+    #[inline]
+    #[must_use]
+    fn from_u16(x: u16, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        Self::from_u32(x.into(), rnd, tininess)
+    }
+
+    #[inline]
+    #[must_use]
+    fn from_i8(x: i8, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        Self::from_i32(x.into(), rnd, tininess)
+    }
+
+    #[inline]
+    #[must_use]
+    fn from_i16(x: i16, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        Self::from_i32(x.into(), rnd, tininess)
+    }
+
+    fn from_u128(x: u128, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
 
-    fn from_u64(x: u64, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8);
+    fn from_i128(x: i128, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
 
-    fn from_i32(x: i32, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8);
+    fn from_limbs(sign: bool, x: &[u64], rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
 
-    fn from_i64(x: i64, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8);
+    fn to_u8(&self, rnd: RoundingMode, exact: bool) -> (u8, u8);
+
+    fn to_u16(&self, rnd: RoundingMode, exact: bool) -> (u16, u8);
 
     fn to_u32(&self, rnd: RoundingMode, exact: bool) -> (u32, u8);
 
     fn to_u64(&self, rnd: RoundingMode, exact: bool) -> (u64, u8);
 
+    fn to_u128(&self, rnd: RoundingMode, exact: bool) -> (u128, u8);
+
+    fn to_i8(&self, rnd: RoundingMode, exact: bool) -> (i8, u8);
+
+    fn to_i16(&self, rnd: RoundingMode, exact: bool) -> (i16, u8);
+
     fn to_i32(&self, rnd: RoundingMode, exact: bool) -> (i32, u8);
 
     fn to_i64(&self, rnd: RoundingMode, exact: bool) -> (i64, u8);
 
-    fn to_f32(&self, rnd: RoundingMode, detect_tininess: u8) -> (float32_t, u8);
+    fn to_i128(&self, rnd: RoundingMode, exact: bool) -> (i128, u8);
+
+    fn to_limbs(&self, z: &mut [u64], rnd: RoundingMode, exact: bool) -> (bool, u8);
+
+    /// `self` rounded to a narrower format, with subnormals, or the default
+    /// NaN and the invalid flag if `precision` does not fit in `Self`. See
+    /// [`crate::math::f64_roundToPrecision`].
+    fn round_to_precision(
+        &self,
+        precision: Precision,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, u8);
+
+    /// `(-1)^sign × significand × 2^exponent` rounded once. See
+    /// [`crate::math::f64_fromParts`].
+    fn from_parts(
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, u8);
+
+    /// Class, sign, exponent and integer significand. See [`Parts`].
+    fn to_parts(&self) -> Parts;
+
+    /// `c` correctly rounded under `rnd`. See [`crate::math::F64_CONSTANTS`].
+    fn constant(c: Constant, rnd: RoundingMode) -> Self;
+
+    /// `(-1)^sign × significand × 2^exponent` rounded stochastically with
+    /// the random word `random`. See [`crate::math::f64_fromPartsStochastic`].
+    fn from_parts_stochastic(
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8);
+
+    /// [`Float::round_to_precision`], rounding stochastically.
+    fn round_to_precision_stochastic(
+        &self,
+        precision: Precision,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8);
+
+    /// `self + x` rounded stochastically with the random word `random`. See
+    /// [`crate::math::f64_addStochastic`].
+    fn add_stochastic<T: Borrow<Self>>(
+        &self,
+        x: T,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8);
+
+    fn sub_stochastic<T: Borrow<Self>>(
+        &self,
+        x: T,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8);
+
+    fn mul_stochastic<T: Borrow<Self>>(
+        &self,
+        x: T,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8);
+
+    fn div_stochastic<T: Borrow<Self>>(
+        &self,
+        x: T,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8);
+
+    fn fused_mul_add_stochastic<T: Borrow<Self>>(
+        &self,
+        x: T,
+        y: T,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8);
+
+    fn sqrt_stochastic(&self, random: u64, tininess: TininessMode) -> (Self, u8);
+
+    /// Conversion to `float32_t` rounded stochastically, exact from
+    /// `float32_t` itself.
+    fn to_f32_stochastic(&self, random: u64, tininess: TininessMode) -> (float32_t, u8);
+
+    #[inline]
+    #[must_use]
+    fn from_fix16(x: i16, fbits: u8, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        Self::from_fix32(x.into(), fbits, rnd, tininess)
+    }
+
+    #[inline]
+    #[must_use]
+    fn from_ufix16(x: u16, fbits: u8, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        Self::from_ufix32(x.into(), fbits, rnd, tininess)
+    }
+
+    fn from_fix32(x: i32, fbits: u8, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn from_ufix32(x: u32, fbits: u8, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn from_fix64(x: i64, fbits: u8, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn from_ufix64(x: u64, fbits: u8, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn to_fix16(&self, fbits: u8, rnd: RoundingMode, exact: bool) -> (i16, u8);
+
+    fn to_ufix16(&self, fbits: u8, rnd: RoundingMode, exact: bool) -> (u16, u8);
+
+    fn to_fix32(&self, fbits: u8, rnd: RoundingMode, exact: bool) -> (i32, u8);
+
+    fn to_ufix32(&self, fbits: u8, rnd: RoundingMode, exact: bool) -> (u32, u8);
+
+    fn to_fix64(&self, fbits: u8, rnd: RoundingMode, exact: bool) -> (i64, u8);
+
+    fn to_ufix64(&self, fbits: u8, rnd: RoundingMode, exact: bool) -> (u64, u8);
+
+    /// Parses a decimal literal, correctly rounded under `rnd`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseFloatError`] if `s` is not a valid literal.
+    fn parse(
+        s: &str,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> Result<(Self, u8), ParseFloatError>;
+
+    /// The exact decimal expansion of `self`.
+    fn to_exact(&self) -> Decimal;
+
+    /// The exact value of `self` as a normalized dyadic rational, or `None`
+    /// for infinities and NaNs.
+    fn to_rational(&self) -> Option<Rational>;
+
+    /// The shortest decimal that parses back to `self`.
+    fn to_shortest(&self) -> Decimal;
+
+    /// Rounds to `digits` digits after the decimal point under `rnd`, like
+    /// C's `%.*f`.
+    fn to_fixed(&self, digits: usize, rnd: RoundingMode) -> (Decimal, u8);
+
+    /// Rounds to scientific notation with `digits` digits after the decimal
+    /// point under `rnd`, like C's `%.*e`.
+    fn to_exponent(&self, digits: usize, rnd: RoundingMode) -> (Decimal, u8);
+
+    /// Parses a hexadecimal literal such as `0x1.8p3`, correctly rounded
+    /// under `rnd`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseFloatError`] if `s` is not a valid literal.
+    fn parse_hex(
+        s: &str,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> Result<(Self, u8), ParseFloatError>;
+
+    /// Hexadecimal scientific notation with as many digits as needed, like
+    /// C's `%a`.
+    fn to_hex(&self) -> Hex;
 
-    fn to_f64(&self, rnd: RoundingMode, detect_tininess: u8) -> (float64_t, u8);
+    /// Rounds to hexadecimal scientific notation with `digits` digits after
+    /// the point under `rnd`, like C's `%.*a`.
+    fn to_hex_digits(&self, digits: usize, rnd: RoundingMode) -> (Hex, u8);
+
+    fn to_f32(&self, rnd: RoundingMode, tininess: TininessMode) -> (float32_t, u8);
+
+    fn to_f64(&self, rnd: RoundingMode, tininess: TininessMode) -> (float64_t, u8);
+
+    /// `x` converted from any format to `Self`, rounded under `rnd` if it
+    /// narrows.
+    fn from_float<G: Float>(x: &G, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    /// [`Float::from_float`], rounding stochastically with the random word
+    /// `random`.
+    fn from_float_stochastic<G: Float>(x: &G, random: u64, tininess: TininessMode) -> (Self, u8);
 
     fn round_to_integral(&self, rnd: RoundingMode, exact: bool) -> (Self, u8);
 
+    fn exp(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn exp2(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn expm1(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn log(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn log2(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn log10(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn log1p(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn sin(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn cos(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn tan(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn asin(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn acos(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn atan(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn atan2<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode)
+        -> (Self, u8);
+
+    fn atan2pi<T: Borrow<Self>>(
+        &self,
+        x: T,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, u8);
+
+    fn sinh(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn cosh(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn tanh(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn asinh(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn acosh(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn atanh(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn pow<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn pown(&self, n: i64, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn powr<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn rootn(&self, n: i64, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn compound(&self, n: i64, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn cbrt(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn hypot<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode)
+        -> (Self, u8);
+
+    fn rsqrt(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn erf(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn erfc(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn tgamma(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn lgamma(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    fn gamma_sign(&self) -> i8;
+
+    fn recip7(&self, rnd: RoundingMode) -> (Self, u8);
+
+    fn rsqrte7(&self) -> (Self, u8);
+
+    /// Sum of `a`, rounded once. See [`crate::math::f64_sum`].
+    fn sum(a: &[Self], rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    /// Dot product of `a` and `b`, rounded once. See [`crate::math::f64_dot`].
+    fn dot(a: &[Self], b: &[Self], rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    /// Sum of the squares of `a`, rounded once.
+    fn sum_square(a: &[Self], rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    /// Sum of the magnitudes of `a`, rounded once.
+    fn sum_abs(a: &[Self], rnd: RoundingMode, tininess: TininessMode) -> (Self, u8);
+
+    /// Product of `a` as `(pr, sf, flags)` with `pr × 2^sf` the product.
+    /// See [`crate::math::f64_scaledProd`].
+    fn scaled_prod(a: &[Self], rnd: RoundingMode, tininess: TininessMode) -> (Self, i64, u8);
+
+    /// Product of `a[i] + b[i]`, scaled like [`Float::scaled_prod`].
+    fn scaled_prod_sum(
+        a: &[Self],
+        b: &[Self],
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, i64, u8);
+
+    /// Product of `a[i] - b[i]`, scaled like [`Float::scaled_prod`].
+    fn scaled_prod_diff(
+        a: &[Self],
+        b: &[Self],
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, i64, u8);
+
+    /// The NaN payload of `self` as an integer, or `-1` if `self` is not a
+    /// NaN. See [`crate::math::f64_getPayload`].
+    #[must_use]
+    fn nan_payload(&self) -> Self;
+
+    /// A quiet NaN with payload `x`, or `+0` if `x` is not an admissible
+    /// payload (setPayload).
+    #[must_use]
+    fn from_nan_payload<T: Borrow<Self>>(x: T) -> Self;
+
+    /// A signaling NaN with payload `x`, or `+0` if `x` is not an admissible
+    /// payload (setPayloadSignaling).
+    #[must_use]
+    fn from_nan_payload_signaling<T: Borrow<Self>>(x: T) -> Self;
+
+    /// The canonical encoding of `self`; signaling NaNs are quieted, raising
+    /// invalid.
+    fn canonicalize(&self) -> (Self, u8);
+
+    /// Whether `self` is canonical, which every binary encoding is.
+    fn is_canonical(&self) -> bool;
+
     #[inline]
     fn compare<T: Borrow<Self>>(&self, x: T) -> Option<Ordering> {
-        let (eq, _) = self.eq(x.borrow());
-        let (lt, _) = self.lt(x.borrow());
+        self.compare_quiet(x).0
+    }
+
+    /// Orders `self` and `x`, or `None` if either is a NaN. Only signaling
+    /// NaNs raise invalid.
+    #[inline]
+    fn compare_quiet<T: Borrow<Self>>(&self, x: T) -> (Option<Ordering>, u8) {
+        let (eq, flags) = self.eq(x.borrow());
+        let (lt, _) = self.lt_quiet(x.borrow());
         if self.is_nan() || x.borrow().is_nan() {
-            None
+            (None, flags)
         } else if eq {
-            Some(Ordering::Equal)
+            (Some(Ordering::Equal), flags)
         } else if lt {
-            Some(Ordering::Less)
+            (Some(Ordering::Less), flags)
         } else {
-            Some(Ordering::Greater)
+            (Some(Ordering::Greater), flags)
         }
     }
 
+    /// Orders `self` and `x`, or `None` if either is a NaN. Every NaN raises
+    /// invalid.
+    #[inline]
+    fn compare_signaling<T: Borrow<Self>>(&self, x: T) -> (Option<Ordering>, u8) {
+        let (ord, _) = self.compare_quiet(x.borrow());
+        let (_, flags) = self.lt(x.borrow());
+        (ord, flags)
+    }
+
+    /// `self > x`; any NaN raises invalid (compareSignalingGreater).
+    #[inline]
+    fn gt_signaling<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
+        x.borrow().lt(self)
+    }
+
+    /// `self >= x`; any NaN raises invalid (compareSignalingGreaterEqual).
+    #[inline]
+    fn ge_signaling<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
+        x.borrow().le(self)
+    }
+
+    /// `self > x`; only signaling NaNs raise invalid (compareQuietGreater).
+    #[inline]
+    fn gt_quiet<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
+        x.borrow().lt_quiet(self)
+    }
+
+    /// `self >= x`; only signaling NaNs raise invalid
+    /// (compareQuietGreaterEqual).
+    #[inline]
+    fn ge_quiet<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
+        x.borrow().le_quiet(self)
+    }
+
+    /// `self != x`, true if either is a NaN; only signaling NaNs raise invalid
+    /// (compareQuietNotEqual).
+    #[inline]
+    fn ne_quiet<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
+        let (eq, flags) = self.eq(x);
+        (!eq, flags)
+    }
+
+    /// `self != x`, true if either is a NaN; any NaN raises invalid
+    /// (compareSignalingNotEqual).
+    #[inline]
+    fn ne_signaling<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
+        let (eq, flags) = self.eq_signaling(x);
+        (!eq, flags)
+    }
+
+    /// Whether either operand is a NaN; only signaling NaNs raise invalid
+    /// (compareQuietUnordered).
+    #[inline]
+    fn unordered<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
+        let (ord, flags) = self.compare_quiet(x);
+        (ord.is_none(), flags)
+    }
+
+    /// Whether either operand is a NaN, raising invalid if so.
+    #[inline]
+    fn unordered_signaling<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
+        let (ord, flags) = self.compare_signaling(x);
+        (ord.is_none(), flags)
+    }
+
+    /// Whether neither operand is a NaN; only signaling NaNs raise invalid
+    /// (compareQuietOrdered).
+    #[inline]
+    fn ordered<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
+        let (ord, flags) = self.compare_quiet(x);
+        (ord.is_some(), flags)
+    }
+
+    /// Whether neither operand is a NaN, raising invalid if one is.
+    #[inline]
+    fn ordered_signaling<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
+        let (ord, flags) = self.compare_signaling(x);
+        (ord.is_some(), flags)
+    }
+
+    /// `!(self > x)`; any NaN raises invalid (compareSignalingNotGreater).
+    #[inline]
+    fn not_gt<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
+        let (gt, flags) = self.gt_signaling(x);
+        (!gt, flags)
+    }
+
+    /// `!(self > x)`; only signaling NaNs raise invalid (compareQuietNotGreater).
+    #[inline]
+    fn not_gt_quiet<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
+        let (gt, flags) = self.gt_quiet(x);
+        (!gt, flags)
+    }
+
+    /// `!(self >= x)`; any NaN raises invalid (compareSignalingLessUnordered).
+    #[inline]
+    fn lt_unordered<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
+        let (ge, flags) = self.ge_signaling(x);
+        (!ge, flags)
+    }
+
+    /// `!(self >= x)`; only signaling NaNs raise invalid
+    /// (compareQuietLessUnordered).
+    #[inline]
+    fn lt_unordered_quiet<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
+        let (ge, flags) = self.ge_quiet(x);
+        (!ge, flags)
+    }
+
+    /// `!(self < x)`; any NaN raises invalid (compareSignalingNotLess).
+    #[inline]
+    fn not_lt<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
+        let (lt, flags) = self.lt(x);
+        (!lt, flags)
+    }
+
+    /// `!(self < x)`; only signaling NaNs raise invalid (compareQuietNotLess).
+    #[inline]
+    fn not_lt_quiet<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
+        let (lt, flags) = self.lt_quiet(x);
+        (!lt, flags)
+    }
+
+    /// `!(self <= x)`; any NaN raises invalid
+    /// (compareSignalingGreaterUnordered).
+    #[inline]
+    fn gt_unordered<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
+        let (le, flags) = self.le(x);
+        (!le, flags)
+    }
+
+    /// `!(self <= x)`; only signaling NaNs raise invalid
+    /// (compareQuietGreaterUnordered).
+    #[inline]
+    fn gt_unordered_quiet<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
+        let (le, flags) = self.le_quiet(x);
+        (!le, flags)
+    }
+
     #[inline]
     #[must_use]
     fn neg(&self) -> Self {
@@ -403,12 +1213,423 @@ pub trait Float: Sized {
         x
     }
 
+    /// A quiet NaN following the quiet bit convention of the softfloat
+    /// specialization: payload 0, or every payload bit set where the quiet
+    /// bit being clear marks a quiet NaN.
     #[inline]
     #[must_use]
     fn quiet_nan() -> Self {
+        let quiet = Self::Payload::one() << (Self::EXPONENT_POS - 1);
+        let mut x = Self::from_bits(Self::Payload::zero());
+        x.set_exponent(Self::EXPONENT_BIT);
+        x.set_fraction(if softfloat_quietBitSet {
+            quiet
+        } else {
+            quiet - Self::Payload::one()
+        });
+        x
+    }
+
+    /// A signaling NaN with payload 1, following the quiet bit convention
+    /// of the softfloat specialization.
+    #[inline]
+    #[must_use]
+    fn signaling_nan() -> Self {
+        let quiet = Self::Payload::one() << (Self::EXPONENT_POS - 1);
         let mut x = Self::from_bits(Self::Payload::zero());
         x.set_exponent(Self::EXPONENT_BIT);
-        x.set_fraction(Self::Payload::one() << (Self::EXPONENT_POS - 1));
+        x.set_fraction(if softfloat_quietBitSet {
+            Self::Payload::one()
+        } else {
+            quiet | Self::Payload::one()
+        });
         x
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_predicates() {
+        use Ordering::{Equal, Greater, Less};
+        type Predicate = fn(&float64_t, &float64_t) -> (bool, u8);
+        type Relation = fn(Option<Ordering>) -> bool;
+
+        // Each predicate, the orderings for which it holds and whether it
+        // signals on quiet NaNs.
+        let cases: [(Predicate, Relation, bool); 20] = [
+            (|a, b| Float::eq(a, b), |o| o == Some(Equal), false),
+            (|a, b| a.eq_signaling(b), |o| o == Some(Equal), true),
+            (|a, b| a.ne_quiet(b), |o| o != Some(Equal), false),
+            (|a, b| a.ne_signaling(b), |o| o != Some(Equal), true),
+            (|a, b| a.gt_signaling(b), |o| o == Some(Greater), true),
+            (|a, b| a.gt_quiet(b), |o| o == Some(Greater), false),
+            (
+                |a, b| a.ge_signaling(b),
+                |o| matches!(o, Some(Greater | Equal)),
+                true,
+            ),
+            (
+                |a, b| a.ge_quiet(b),
+                |o| matches!(o, Some(Greater | Equal)),
+                false,
+            ),
+            (|a, b| a.unordered(b), |o| o.is_none(), false),
+            (|a, b| a.unordered_signaling(b), |o| o.is_none(), true),
+            (|a, b| a.ordered(b), |o| o.is_some(), false),
+            (|a, b| a.ordered_signaling(b), |o| o.is_some(), true),
+            (|a, b| a.not_gt(b), |o| o != Some(Greater), true),
+            (|a, b| a.not_gt_quiet(b), |o| o != Some(Greater), false),
+            (
+                |a, b| a.lt_unordered(b),
+                |o| matches!(o, None | Some(Less)),
+                true,
+            ),
+            (
+                |a, b| a.lt_unordered_quiet(b),
+                |o| matches!(o, None | Some(Less)),
+                false,
+            ),
+            (|a, b| a.not_lt(b), |o| o != Some(Less), true),
+            (|a, b| a.not_lt_quiet(b), |o| o != Some(Less), false),
+            (
+                |a, b| a.gt_unordered(b),
+                |o| matches!(o, None | Some(Greater)),
+                true,
+            ),
+            (
+                |a, b| a.gt_unordered_quiet(b),
+                |o| matches!(o, None | Some(Greater)),
+                false,
+            ),
+        ];
+
+        let values = [
+            0x3FF0_0000_0000_0000,
+            0x4000_0000_0000_0000,
+            0x8000_0000_0000_0000,
+            0,
+            0xFFF0_0000_0000_0000,
+            0x7FF8_0000_0000_0000,
+            0x7FF0_0000_0000_0001,
+        ];
+
+        for (i, (f, relation, signaling)) in cases.iter().enumerate() {
+            for &a in &values {
+                for &b in &values {
+                    let (x, y) = (float64_t::from_bits(a), float64_t::from_bits(b));
+                    let ordering = f64::from_bits(a).partial_cmp(&f64::from_bits(b));
+                    let invalid = if *signaling {
+                        ordering.is_none()
+                    } else {
+                        x.is_signaling_nan() || y.is_signaling_nan()
+                    };
+                    let flags = if invalid { softfloat_flag_invalid } else { 0 };
+                    assert_eq!((i, a, b, f(&x, &y)), (i, a, b, (relation(ordering), flags)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_eq() {
+        // `==` compares encodings, `Float::eq` compares values.
+        let zero = float64_t::from_bits(0);
+        let minus_zero = float64_t::from_bits(0x8000_0000_0000_0000);
+        let nan = float64_t::from_bits(0x7FF8_0000_0000_0000);
+        assert!(zero != minus_zero);
+        assert_eq!(Float::eq(&zero, minus_zero), (true, 0));
+        assert!(nan == nan);
+        assert_eq!(Float::eq(&nan, nan), (false, 0));
+        assert_eq!(nan.ne_quiet(nan), (true, 0));
+    }
+
+    #[test]
+    fn test_compare() {
+        let one = float32_t::from_bits(0x3F80_0000);
+        let qnan = float32_t::from_bits(0x7FC0_0000);
+        let snan = float32_t::from_bits(0x7F80_0001);
+        assert_eq!(one.compare_quiet(one), (Some(Ordering::Equal), 0));
+        assert_eq!(one.compare_signaling(qnan), (None, softfloat_flag_invalid));
+        assert_eq!(one.compare_quiet(qnan), (None, 0));
+        assert_eq!(snan.compare_quiet(one), (None, softfloat_flag_invalid));
+        assert_eq!(
+            float32_t::from_bits(0xBF80_0000).compare_signaling(one),
+            (Some(Ordering::Less), 0)
+        );
+        assert_eq!(one.compare(qnan), None);
+    }
+
+    #[test]
+    fn test_nan_payload() {
+        let quiet = float32_t::quiet_nan();
+        let signaling = float64_t::signaling_nan();
+        assert!(quiet.is_nan() && !quiet.is_signaling_nan());
+        assert!(signaling.is_signaling_nan());
+        assert_eq!(quiet.nan_payload(), float32_t::positive_zero());
+
+        let payload = signaling.nan_payload();
+        assert_eq!(payload.to_bits(), 0x3FF0_0000_0000_0000);
+        assert_eq!(float64_t::from_nan_payload_signaling(payload), signaling);
+        assert_eq!(
+            signaling.canonicalize(),
+            (float64_t::quiet_nan(), softfloat_flag_invalid)
+        );
+        assert_eq!(
+            float64_t::from_nan_payload(payload).canonicalize(),
+            (float64_t::from_bits(0x7FF8_0000_0000_0001), 0)
+        );
+    }
+
+    #[test]
+    fn test_stochastic_rounding() {
+        let mut source = RandomSource::new(0);
+        assert_eq!(source.next_u64(), 0xE220_A839_7B1D_CDAF);
+
+        // `1 + 2^-25` rounds up a quarter of the time, and the same seed
+        // gives the same run.
+        let run = |seed| {
+            let mut fpu = crate::FPU::default();
+            fpu.set_stochastic_rounding(Some(RandomSource::new(seed)));
+            let one = float32_t::from_bits(0x3F80_0000);
+            let x = float64_t::from_bits(0x3FF0_0000_0800_0000);
+            let (mut ups, mut last) = (0, 0);
+            for i in 0..4096 {
+                let r = fpu.to_f32::<float64_t, _>(x, RoundingMode::RneTiesToEven);
+                assert!(r == one || r.to_bits() == 0x3F80_0001);
+                if r != one {
+                    (ups, last) = (ups + 1, i);
+                }
+            }
+            (ups, last, fpu.stochastic_rounding(), fpu.flags)
+        };
+        let (ups, last, source, flags) = run(7);
+        assert!((960..1088).contains(&ups));
+        assert_eq!(run(7), (ups, last, source, flags));
+        assert_ne!(run(8).0, ups);
+        assert_eq!(
+            source.map(|s| s.state()),
+            Some(0x9E37_79B9_7F4A_7C15_u64.wrapping_mul(4096) + 7)
+        );
+        assert!(flags.is_inexact());
+
+        // The generic methods draw exactly as the `const` ones do.
+        let mut generic = crate::FPU::default();
+        generic.set_stochastic_rounding(Some(RandomSource::new(7)));
+        assert!(generic.set_precision(Some(Precision::BINARY16)).is_ok());
+        let mut fixed = generic;
+        let x = float32_t::from_bits(0x3F80_0001);
+        let y = float32_t::from_bits(0x3380_0000);
+        for _ in 0..64 {
+            let r = generic.add::<float32_t, _>(x, y, RoundingMode::RneTiesToEven);
+            assert_eq!(r, fixed.f32_add(x, y, RoundingMode::RneTiesToEven));
+        }
+        assert_eq!(generic.flags, fixed.flags);
+    }
+
+    #[test]
+    fn test_precision() {
+        assert_eq!(Precision::new(11, -14, 15), Some(Precision::BINARY16));
+        assert_eq!(Precision::new(0, -14, 15), None);
+        assert_eq!(Precision::new(54, -1022, 1023), None);
+        assert_eq!(Precision::new(11, 15, -14), None);
+
+        // Every result is rounded to binary16, and precisions that do not
+        // fit in `float32_t` are rejected.
+        let mut fpu = crate::FPU::default();
+        assert!(fpu.set_precision(Some(Precision::BINARY16)).is_ok());
+        let wide = Precision::new(30, -126, 127);
+        assert!(wide.is_some());
+        assert!(fpu.set_precision(wide).is_err());
+        assert_eq!(fpu.precision(), Some(Precision::BINARY16));
+        let x = float32_t::from_bits(0x3F80_0001);
+        let y = float64_t::from_bits(0x3FF0_0000_0000_0001);
+        let r =
+            fpu.add::<float32_t, _>(x, float32_t::from_bits(0), RoundingMode::RupTowardPositive);
+        assert_eq!(r.to_bits(), 0x3F80_2000);
+        let r =
+            fpu.add::<float64_t, _>(y, float64_t::from_bits(0), RoundingMode::RupTowardPositive);
+        assert_eq!(r.to_bits(), 0x3FF0_0400_0000_0000);
+        assert!(fpu.flags.is_inexact());
+
+        let (r, flags) = x.round_to_precision(
+            wide.unwrap(),
+            RoundingMode::RneTiesToEven,
+            TininessMode::After,
+        );
+        assert!(r.is_nan());
+        assert_eq!(flags, softfloat_flag_invalid);
+    }
+
+    #[test]
+    fn test_const_fpu() {
+        const ONE: float64_t = float64_t {
+            v: 0x3FF0_0000_0000_0000,
+        };
+        const RNE: RoundingMode = RoundingMode::RneTiesToEven;
+        const RESULT: (float64_t, float32_t, bool, u8) = {
+            let mut fpu = crate::FPU::new(TininessMode::After);
+            let three = fpu.f64_from_u8(3, RNE);
+            let third = fpu.f64_div(ONE, three, RNE);
+            let narrow = fpu.f64_to_f32(third, RoundingMode::RtzTowardZero);
+            let less = fpu.f64_lt(third, ONE);
+            (third, narrow, less, fpu.flags.to_bits())
+        };
+
+        let mut fpu = crate::FPU::new(TininessMode::After);
+        let three = fpu.f64_from_u8(3, RNE);
+        let third = fpu.div::<float64_t, _>(ONE, three, RNE);
+        let narrow = fpu.to_f32::<float64_t, _>(third, RoundingMode::RtzTowardZero);
+        let less = fpu.lt::<float64_t, _>(third, ONE);
+        assert_eq!(RESULT, (third, narrow, less, fpu.flags.to_bits()));
+        assert_eq!(RESULT.0.to_bits(), 0x3FD5_5555_5555_5555);
+        assert_eq!(RESULT.3, softfloat_flag_inexact);
+    }
+
+    #[test]
+    fn test_generic_conversions() {
+        const RNE: RoundingMode = RoundingMode::RneTiesToEven;
+        const RTZ: RoundingMode = RoundingMode::RtzTowardZero;
+        let big = (1u64 << 60) + 1;
+        let mut fpu = crate::FPU::default();
+        let mut each = crate::FPU::default();
+        assert_eq!(
+            fpu.from_int::<float64_t, _>(-5i8, RNE),
+            each.f64_from_i8(-5, RNE)
+        );
+        assert_eq!(
+            fpu.from_int::<float32_t, _>(big, RTZ),
+            each.f32_from_u64(big, RTZ)
+        );
+        assert_eq!(
+            fpu.from_int::<float64_t, _>(i128::MIN, RNE),
+            each.f64_from_i128(i128::MIN, RNE)
+        );
+        assert_eq!(fpu.flags, each.flags);
+        assert!(fpu.flags.is_inexact());
+
+        let third = float64_t::from_bits(0x3FD5_5555_5555_5555);
+        let narrow = fpu.convert::<float64_t, float32_t>(third, RTZ);
+        assert_eq!(narrow, each.f64_to_f32(third, RTZ));
+        assert_eq!(
+            fpu.convert::<float32_t, float64_t>(narrow, RNE),
+            each.f32_to_f64(narrow, RNE)
+        );
+        assert_eq!(fpu.convert::<float64_t, float64_t>(third, RNE), third);
+        assert_eq!(
+            fpu.to_int::<i16, float64_t>(third, RNE, true),
+            each.f64_to_i16(third, RNE, true)
+        );
+        let minus_two = float64_t::from_bits(0xC000_0000_0000_0000);
+        assert_eq!(
+            fpu.to_int::<u128, _>(narrow, RNE, false),
+            each.f32_to_u128(narrow, RNE, false)
+        );
+        assert_eq!(
+            fpu.to_int::<u8, _>(minus_two, RNE, false),
+            each.f64_to_u8(minus_two, RNE, false)
+        );
+        assert_eq!(fpu.flags, each.flags);
+        assert!(fpu.flags.is_invalid());
+
+        // Stochastic rounding draws a word for integer conversions and
+        // narrowing, as the per-format operations do, but not for widening.
+        let mut fpu = crate::FPU::new(TininessMode::Before);
+        let mut each = crate::FPU::new(TininessMode::Before);
+        fpu.set_stochastic_rounding(Some(RandomSource::new(7)));
+        each.set_stochastic_rounding(Some(RandomSource::new(7)));
+        for i in 0..64 {
+            let n = (big << 2) + i;
+            let m = -i64::try_from(n).unwrap();
+            assert_eq!(
+                fpu.from_int::<float32_t, _>(n, RNE),
+                each.f32_from_u64(n, RNE)
+            );
+            let wide = fpu.from_int::<float64_t, _>(m, RNE);
+            assert_eq!(wide, each.f64_from_i64(m, RNE));
+            assert_eq!(
+                fpu.convert::<float64_t, float32_t>(wide, RNE),
+                each.f64_to_f32(wide, RNE)
+            );
+            let back = fpu.convert::<float32_t, float64_t>(narrow, RNE);
+            assert_eq!(back, each.f32_to_f64(narrow, RNE));
+        }
+        assert_eq!(fpu.stochastic_rounding(), each.stochastic_rounding());
+        assert_eq!(fpu.flags, each.flags);
+    }
+
+    #[test]
+    fn test_flag_scopes() {
+        const RNE: RoundingMode = RoundingMode::RneTiesToEven;
+        let zero = float64_t::from_bits(0);
+        let one = float64_t::from_bits(0x3FF0_0000_0000_0000);
+        let three = float64_t::from_bits(0x4008_0000_0000_0000);
+        let mut fpu = crate::FPU::default();
+
+        let _ = fpu.div::<float64_t, _>(one, zero, RNE);
+        let saved = fpu.save_all_flags();
+        assert_eq!(saved, ExceptionFlags::INFINITE);
+        let _ = fpu.div::<float64_t, _>(one, three, RNE);
+        assert!(fpu.test_flags(ExceptionFlags::INEXACT | ExceptionFlags::INVALID));
+        assert!(!fpu.test_flags(ExceptionFlags::INVALID | ExceptionFlags::OVERFLOW));
+        assert!(crate::FPU::test_saved_flags(saved, ExceptionFlags::ALL));
+        assert!(!crate::FPU::test_saved_flags(
+            saved,
+            ExceptionFlags::INEXACT
+        ));
+
+        fpu.raise_flags(ExceptionFlags::INVALID);
+        fpu.restore_flags(saved, ExceptionFlags::INVALID | ExceptionFlags::INFINITE);
+        assert_eq!(
+            fpu.flags,
+            ExceptionFlags::INEXACT | ExceptionFlags::INFINITE
+        );
+        fpu.lower_flags(ExceptionFlags::INFINITE | ExceptionFlags::UNDERFLOW);
+        assert_eq!(fpu.flags, ExceptionFlags::INEXACT);
+
+        // feholdexcept and feupdateenv.
+        let held = fpu.hold_flags();
+        assert_eq!(fpu.flags, ExceptionFlags::default());
+        let _ = fpu.sqrt::<float64_t, _>(float64_t::from_bits(0xBFF0_0000_0000_0000), RNE);
+        fpu.update_flags(held);
+        assert_eq!(fpu.flags, ExceptionFlags::INEXACT | ExceptionFlags::INVALID);
+
+        let (third, raised) = fpu.isolate_flags(|fpu| {
+            assert_eq!(fpu.flags, ExceptionFlags::default());
+            fpu.div::<float64_t, _>(one, three, RNE)
+        });
+        assert_eq!(third.to_bits(), 0x3FD5_5555_5555_5555);
+        assert_eq!(raised, ExceptionFlags::INEXACT);
+        assert_eq!(fpu.flags, ExceptionFlags::INEXACT | ExceptionFlags::INVALID);
+        let ((), raised) = fpu.isolate_flags(|_| {});
+        assert_eq!(raised, ExceptionFlags::default());
+        assert_eq!(ExceptionFlags::ALL.to_bits(), 0x1F);
+    }
+
+    #[test]
+    fn test_limits() {
+        assert_eq!(float32_t::MAX.to_bits(), f32::MAX.to_bits());
+        assert_eq!(
+            float32_t::MIN_POSITIVE.to_bits(),
+            f32::MIN_POSITIVE.to_bits()
+        );
+        assert_eq!(float32_t::EPSILON.to_bits(), f32::EPSILON.to_bits());
+        assert_eq!(float64_t::MAX.to_bits(), f64::MAX.to_bits());
+        assert_eq!(
+            float64_t::MIN_POSITIVE.to_bits(),
+            f64::MIN_POSITIVE.to_bits()
+        );
+        assert_eq!(float64_t::EPSILON.to_bits(), f64::EPSILON.to_bits());
+        assert_eq!(float64_t::MIN_SUBNORMAL.to_bits(), 1);
+
+        let pi = |rnd| float64_t::constant(Constant::Pi, rnd).to_bits();
+        assert_eq!(
+            pi(RoundingMode::RneTiesToEven),
+            core::f64::consts::PI.to_bits()
+        );
+        assert_eq!(pi(RoundingMode::RdnTowardNegative), 0x4009_21FB_5444_2D18);
+        assert_eq!(pi(RoundingMode::RupTowardPositive), 0x4009_21FB_5444_2D19);
+    }
+}
@@ -1,13 +1,33 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use super::super::math::{
+    f32_acos, f32_acosh, f32_addStochastic, f32_asin, f32_asinh, f32_atan, f32_atan2, f32_atan2pi,
+    f32_atanh, f32_canonicalize, f32_cbrt, f32_compound, f32_cos, f32_cosh, f32_divStochastic,
+    f32_dot, f32_erf, f32_erfc, f32_exp, f32_exp2, f32_expm1, f32_fromParts,
+    f32_fromPartsStochastic, f32_gammaSign, f32_getPayload, f32_hypot, f32_isCanonical, f32_lgamma,
+    f32_log, f32_log10, f32_log1p, f32_log2, f32_mulAddStochastic, f32_mulStochastic, f32_pow,
+    f32_pown, f32_powr, f32_rSqrt, f32_rootn, f32_roundToPrecision, f32_roundToPrecisionStochastic,
+    f32_scaledProd, f32_scaledProdDiff, f32_scaledProdSum, f32_setPayload, f32_setPayloadSignaling,
+    f32_sin, f32_sinh, f32_sqrtStochastic, f32_subStochastic, f32_sum, f32_sumAbs, f32_sumSquare,
+    f32_tan, f32_tanh, f32_tgamma, f32_toParts, Constant, Parts, F32_CONSTANTS,
+};
 use super::super::softfloat::{
     f32_add, f32_div, f32_eq, f32_eq_signaling, f32_isSignalingNaN, f32_le, f32_le_quiet, f32_lt,
-    f32_lt_quiet, f32_mul, f32_mulAdd, f32_rem, f32_roundToInt, f32_sqrt, f32_sub, f32_to_f64,
-    f32_to_i32, f32_to_i64, f32_to_ui32, f32_to_ui64, float32_t, float64_t, i32_to_f32, i64_to_f32,
-    ui32_to_f32, ui64_to_f32,
+    f32_lt_quiet, f32_mul, f32_mulAdd, f32_recip7, f32_rem, f32_roundToInt, f32_rsqrte7, f32_sqrt,
+    f32_sub, f32_to_f64, f32_to_fix16, f32_to_fix32, f32_to_fix64, f32_to_i128, f32_to_i16,
+    f32_to_i32, f32_to_i64, f32_to_i8, f32_to_limbs, f32_to_ufix16, f32_to_ufix32, f32_to_ufix64,
+    f32_to_ui128, f32_to_ui16, f32_to_ui32, f32_to_ui64, f32_to_ui8, fix32_to_f32, fix64_to_f32,
+    float32_t, float64_t, i128_to_f32, i32_to_f32, i64_to_f32, limbs_to_f32, ufix32_to_f32,
+    ufix64_to_f32, ui128_to_f32, ui32_to_f32, ui64_to_f32,
+};
+use super::super::text::{
+    f32_fromDecimal, f32_fromHex, f32_toExact, f32_toExponent, f32_toFixed, f32_toHex,
+    f32_toHexDigits, f32_toRational, f32_toShortest, Decimal, Hex, ParseFloatError, Rational,
 };
-use super::{Float, RoundingMode};
+use super::{Float, Precision, RoundingMode, TininessMode};
 use core::borrow::Borrow;
+use core::fmt;
+use core::str::FromStr;
 
 impl float32_t {
     /// Converts primitive `f32` to `float32_t`
@@ -20,8 +40,8 @@ impl float32_t {
     /// Converts primitive `f64` to `float32_t`
     #[inline]
     #[must_use]
-    pub fn from_f64(v: f64, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        float64_t::from_bits(v.to_bits()).to_f32(rnd, detect_tininess)
+    pub fn from_f64(v: f64, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        float64_t::from_bits(v.to_bits()).to_f32(rnd, tininess)
     }
 }
 
@@ -32,6 +52,10 @@ impl Float for float32_t {
     const FRACTION_BIT: Self::Payload = 0x7f_ffff;
     const SIGN_POS: usize = 31;
     const EXPONENT_POS: usize = 23;
+    const MAX: Self = Self { v: 0x7F7F_FFFF };
+    const MIN_POSITIVE: Self = Self { v: 0x0080_0000 };
+    const EPSILON: Self = Self { v: 0x3400_0000 };
+    const MIN_SUBNORMAL: Self = Self { v: 0x1 };
 
     #[inline]
     fn set_payload(&mut self, x: Self::Payload) {
@@ -49,18 +73,33 @@ impl Float for float32_t {
     }
 
     #[inline]
-    fn add<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        f32_add(*self, *x.borrow(), rnd.to_softfloat(), detect_tininess)
+    fn add<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_add(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
     }
 
     #[inline]
-    fn sub<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        f32_sub(*self, *x.borrow(), rnd.to_softfloat(), detect_tininess)
+    fn sub<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_sub(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
     }
 
     #[inline]
-    fn mul<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        f32_mul(*self, *x.borrow(), rnd.to_softfloat(), detect_tininess)
+    fn mul<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_mul(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
     }
 
     #[inline]
@@ -69,30 +108,40 @@ impl Float for float32_t {
         x: T,
         y: T,
         rnd: RoundingMode,
-        detect_tininess: u8,
+        tininess: TininessMode,
     ) -> (Self, u8) {
         f32_mulAdd(
             *self,
             *x.borrow(),
             *y.borrow(),
             rnd.to_softfloat(),
-            detect_tininess,
+            tininess.to_softfloat(),
         )
     }
 
     #[inline]
-    fn div<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        f32_div(*self, *x.borrow(), rnd.to_softfloat(), detect_tininess)
+    fn div<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_div(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
     }
 
     #[inline]
-    fn rem<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        f32_rem(*self, *x.borrow(), rnd.to_softfloat(), detect_tininess)
+    fn rem<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_rem(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
     }
 
     #[inline]
-    fn sqrt(&self, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        f32_sqrt(*self, rnd.to_softfloat(), detect_tininess)
+    fn sqrt(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_sqrt(*self, rnd.to_softfloat(), tininess.to_softfloat())
     }
 
     #[inline]
@@ -131,23 +180,187 @@ impl Float for float32_t {
     }
 
     #[inline]
-    fn from_u32(x: u32, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        ui32_to_f32(x, rnd.to_softfloat(), detect_tininess)
+    fn from_u32(x: u32, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        ui32_to_f32(x, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn from_u64(x: u64, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        ui64_to_f32(x, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn from_i32(x: i32, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        i32_to_f32(x, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn from_i64(x: i64, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        i64_to_f32(x, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn from_u128(x: u128, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        ui128_to_f32(x, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn from_i128(x: i128, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        i128_to_f32(x, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn from_limbs(sign: bool, x: &[u64], rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        limbs_to_f32(sign, x, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn from_parts(
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f32_fromParts(
+            sign,
+            significand,
+            exponent,
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
+    }
+
+    #[inline]
+    fn to_parts(&self) -> Parts {
+        f32_toParts(*self)
     }
 
     #[inline]
-    fn from_u64(x: u64, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        ui64_to_f32(x, rnd.to_softfloat(), detect_tininess)
+    fn constant(c: Constant, rnd: RoundingMode) -> Self {
+        F32_CONSTANTS[c as usize][rnd as usize]
     }
 
     #[inline]
-    fn from_i32(x: i32, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        i32_to_f32(x, rnd.to_softfloat(), detect_tininess)
+    fn from_parts_stochastic(
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f32_fromPartsStochastic(sign, significand, exponent, random, tininess.to_softfloat())
     }
 
     #[inline]
-    fn from_i64(x: i64, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        i64_to_f32(x, rnd.to_softfloat(), detect_tininess)
+    fn round_to_precision_stochastic(
+        &self,
+        precision: Precision,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f32_roundToPrecisionStochastic(
+            *self,
+            precision.sig_bits(),
+            precision.emin(),
+            precision.emax(),
+            random,
+            tininess.to_softfloat(),
+        )
+    }
+
+    #[inline]
+    fn add_stochastic<T: Borrow<Self>>(
+        &self,
+        x: T,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f32_addStochastic(*self, *x.borrow(), random, tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn sub_stochastic<T: Borrow<Self>>(
+        &self,
+        x: T,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f32_subStochastic(*self, *x.borrow(), random, tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn mul_stochastic<T: Borrow<Self>>(
+        &self,
+        x: T,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f32_mulStochastic(*self, *x.borrow(), random, tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn div_stochastic<T: Borrow<Self>>(
+        &self,
+        x: T,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f32_divStochastic(*self, *x.borrow(), random, tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn fused_mul_add_stochastic<T: Borrow<Self>>(
+        &self,
+        x: T,
+        y: T,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f32_mulAddStochastic(
+            *self,
+            *x.borrow(),
+            *y.borrow(),
+            random,
+            tininess.to_softfloat(),
+        )
+    }
+
+    #[inline]
+    fn sqrt_stochastic(&self, random: u64, tininess: TininessMode) -> (Self, u8) {
+        f32_sqrtStochastic(*self, random, tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn to_f32_stochastic(&self, _random: u64, _tininess: TininessMode) -> (float32_t, u8) {
+        (*self, 0)
+    }
+
+    #[inline]
+    fn round_to_precision(
+        &self,
+        precision: Precision,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f32_roundToPrecision(
+            *self,
+            precision.sig_bits(),
+            precision.emin(),
+            precision.emax(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
+    }
+
+    #[inline]
+    fn to_u8(&self, rnd: RoundingMode, exact: bool) -> (u8, u8) {
+        f32_to_ui8(*self, rnd.to_softfloat(), exact)
+    }
+
+    #[inline]
+    fn to_u16(&self, rnd: RoundingMode, exact: bool) -> (u16, u8) {
+        f32_to_ui16(*self, rnd.to_softfloat(), exact)
     }
 
     #[inline]
@@ -160,6 +373,21 @@ impl Float for float32_t {
         f32_to_ui64(*self, rnd.to_softfloat(), exact)
     }
 
+    #[inline]
+    fn to_u128(&self, rnd: RoundingMode, exact: bool) -> (u128, u8) {
+        f32_to_ui128(*self, rnd.to_softfloat(), exact)
+    }
+
+    #[inline]
+    fn to_i8(&self, rnd: RoundingMode, exact: bool) -> (i8, u8) {
+        f32_to_i8(*self, rnd.to_softfloat(), exact)
+    }
+
+    #[inline]
+    fn to_i16(&self, rnd: RoundingMode, exact: bool) -> (i16, u8) {
+        f32_to_i16(*self, rnd.to_softfloat(), exact)
+    }
+
     #[inline]
     fn to_i32(&self, rnd: RoundingMode, exact: bool) -> (i32, u8) {
         f32_to_i32(*self, rnd.to_softfloat(), exact)
@@ -170,18 +398,493 @@ impl Float for float32_t {
         f32_to_i64(*self, rnd.to_softfloat(), exact)
     }
 
+    #[inline]
+    fn to_i128(&self, rnd: RoundingMode, exact: bool) -> (i128, u8) {
+        f32_to_i128(*self, rnd.to_softfloat(), exact)
+    }
+
+    #[inline]
+    fn to_limbs(&self, z: &mut [u64], rnd: RoundingMode, exact: bool) -> (bool, u8) {
+        f32_to_limbs(*self, z, rnd.to_softfloat(), exact)
+    }
+
     #[inline]
     fn round_to_integral(&self, rnd: RoundingMode, exact: bool) -> (Self, u8) {
         f32_roundToInt(*self, rnd.to_softfloat(), exact)
     }
 
     #[inline]
-    fn to_f32(&self, _rnd: RoundingMode, _detect_tininess: u8) -> (float32_t, u8) {
+    fn from_fix32(x: i32, fbits: u8, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        fix32_to_f32(x, fbits, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn from_ufix32(x: u32, fbits: u8, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        ufix32_to_f32(x, fbits, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn from_fix64(x: i64, fbits: u8, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        fix64_to_f32(x, fbits, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn from_ufix64(x: u64, fbits: u8, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        ufix64_to_f32(x, fbits, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn to_fix16(&self, fbits: u8, rnd: RoundingMode, exact: bool) -> (i16, u8) {
+        f32_to_fix16(*self, fbits, rnd.to_softfloat(), exact)
+    }
+
+    #[inline]
+    fn to_ufix16(&self, fbits: u8, rnd: RoundingMode, exact: bool) -> (u16, u8) {
+        f32_to_ufix16(*self, fbits, rnd.to_softfloat(), exact)
+    }
+
+    #[inline]
+    fn to_fix32(&self, fbits: u8, rnd: RoundingMode, exact: bool) -> (i32, u8) {
+        f32_to_fix32(*self, fbits, rnd.to_softfloat(), exact)
+    }
+
+    #[inline]
+    fn to_ufix32(&self, fbits: u8, rnd: RoundingMode, exact: bool) -> (u32, u8) {
+        f32_to_ufix32(*self, fbits, rnd.to_softfloat(), exact)
+    }
+
+    #[inline]
+    fn to_fix64(&self, fbits: u8, rnd: RoundingMode, exact: bool) -> (i64, u8) {
+        f32_to_fix64(*self, fbits, rnd.to_softfloat(), exact)
+    }
+
+    #[inline]
+    fn to_ufix64(&self, fbits: u8, rnd: RoundingMode, exact: bool) -> (u64, u8) {
+        f32_to_ufix64(*self, fbits, rnd.to_softfloat(), exact)
+    }
+
+    #[inline]
+    fn parse(
+        s: &str,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> Result<(Self, u8), ParseFloatError> {
+        f32_fromDecimal(s, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn to_exact(&self) -> Decimal {
+        f32_toExact(*self)
+    }
+
+    #[inline]
+    fn to_rational(&self) -> Option<Rational> {
+        f32_toRational(*self)
+    }
+
+    #[inline]
+    fn to_shortest(&self) -> Decimal {
+        f32_toShortest(*self)
+    }
+
+    #[inline]
+    fn to_fixed(&self, digits: usize, rnd: RoundingMode) -> (Decimal, u8) {
+        f32_toFixed(*self, digits, rnd.to_softfloat())
+    }
+
+    #[inline]
+    fn to_exponent(&self, digits: usize, rnd: RoundingMode) -> (Decimal, u8) {
+        f32_toExponent(*self, digits, rnd.to_softfloat())
+    }
+
+    #[inline]
+    fn parse_hex(
+        s: &str,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> Result<(Self, u8), ParseFloatError> {
+        f32_fromHex(s, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn to_hex(&self) -> Hex {
+        f32_toHex(*self)
+    }
+
+    #[inline]
+    fn to_hex_digits(&self, digits: usize, rnd: RoundingMode) -> (Hex, u8) {
+        f32_toHexDigits(*self, digits, rnd.to_softfloat())
+    }
+
+    #[inline]
+    fn to_f32(&self, _rnd: RoundingMode, _tininess: TininessMode) -> (float32_t, u8) {
         (*self, 0)
     }
 
     #[inline]
-    fn to_f64(&self, _rnd: RoundingMode, _detect_tininess: u8) -> (float64_t, u8) {
+    fn to_f64(&self, _rnd: RoundingMode, _tininess: TininessMode) -> (float64_t, u8) {
         f32_to_f64(*self)
     }
+
+    #[inline]
+    fn from_float<G: Float>(x: &G, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        x.to_f32(rnd, tininess)
+    }
+
+    #[inline]
+    fn from_float_stochastic<G: Float>(x: &G, random: u64, tininess: TininessMode) -> (Self, u8) {
+        x.to_f32_stochastic(random, tininess)
+    }
+
+    #[inline]
+    fn exp(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_exp(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn exp2(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_exp2(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn expm1(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_expm1(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn log(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_log(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn log2(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_log2(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn log10(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_log10(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn log1p(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_log1p(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn sin(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_sin(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn cos(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_cos(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn tan(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_tan(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn asin(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_asin(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn acos(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_acos(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn atan(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_atan(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn atan2<T: Borrow<Self>>(
+        &self,
+        x: T,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f32_atan2(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
+    }
+
+    #[inline]
+    fn atan2pi<T: Borrow<Self>>(
+        &self,
+        x: T,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f32_atan2pi(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
+    }
+
+    #[inline]
+    fn sinh(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_sinh(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn cosh(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_cosh(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn tanh(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_tanh(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn asinh(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_asinh(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn acosh(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_acosh(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn atanh(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_atanh(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn pow<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_pow(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
+    }
+
+    #[inline]
+    fn pown(&self, n: i64, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_pown(*self, n, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn powr<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_powr(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
+    }
+
+    #[inline]
+    fn rootn(&self, n: i64, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_rootn(*self, n, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn compound(&self, n: i64, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_compound(*self, n, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn cbrt(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_cbrt(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn hypot<T: Borrow<Self>>(
+        &self,
+        x: T,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f32_hypot(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
+    }
+
+    #[inline]
+    fn rsqrt(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_rSqrt(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn erf(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_erf(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn erfc(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_erfc(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn tgamma(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_tgamma(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn lgamma(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_lgamma(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn gamma_sign(&self) -> i8 {
+        f32_gammaSign(*self)
+    }
+
+    #[inline]
+    fn recip7(&self, rnd: RoundingMode) -> (Self, u8) {
+        f32_recip7(*self, rnd.to_softfloat())
+    }
+
+    #[inline]
+    fn rsqrte7(&self) -> (Self, u8) {
+        f32_rsqrte7(*self)
+    }
+
+    #[inline]
+    fn nan_payload(&self) -> Self {
+        f32_getPayload(*self)
+    }
+
+    #[inline]
+    fn from_nan_payload<T: Borrow<Self>>(x: T) -> Self {
+        f32_setPayload(*x.borrow())
+    }
+
+    #[inline]
+    fn from_nan_payload_signaling<T: Borrow<Self>>(x: T) -> Self {
+        f32_setPayloadSignaling(*x.borrow())
+    }
+
+    #[inline]
+    fn canonicalize(&self) -> (Self, u8) {
+        f32_canonicalize(*self)
+    }
+
+    #[inline]
+    fn is_canonical(&self) -> bool {
+        f32_isCanonical(*self)
+    }
+
+    #[inline]
+    fn sum(a: &[Self], rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_sum(a, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn dot(a: &[Self], b: &[Self], rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_dot(a, b, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn sum_square(a: &[Self], rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_sumSquare(a, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn sum_abs(a: &[Self], rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f32_sumAbs(a, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn scaled_prod(a: &[Self], rnd: RoundingMode, tininess: TininessMode) -> (Self, i64, u8) {
+        f32_scaledProd(a, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn scaled_prod_sum(
+        a: &[Self],
+        b: &[Self],
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, i64, u8) {
+        f32_scaledProdSum(a, b, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn scaled_prod_diff(
+        a: &[Self],
+        b: &[Self],
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, i64, u8) {
+        f32_scaledProdDiff(a, b, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+}
+
+impl FromStr for float32_t {
+    type Err = ParseFloatError;
+
+    /// Parses a decimal literal, rounding to nearest even.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, RoundingMode::RneTiesToEven, TininessMode::default()).map(|(v, _)| v)
+    }
+}
+
+impl fmt::Display for float32_t {
+    /// Writes the shortest decimal that parses back to the value, or with a
+    /// precision, that many digits after the point rounded to nearest even.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(n) => fmt::Display::fmt(&self.to_fixed(n, RoundingMode::RneTiesToEven).0, f),
+            None => fmt::Display::fmt(&self.to_shortest(), f),
+        }
+    }
+}
+
+impl fmt::LowerExp for float32_t {
+    /// Like [`fmt::Display`], in scientific notation.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(n) => fmt::LowerExp::fmt(&self.to_exponent(n, RoundingMode::RneTiesToEven).0, f),
+            None => fmt::LowerExp::fmt(&self.to_shortest(), f),
+        }
+    }
+}
+
+impl fmt::UpperExp for float32_t {
+    /// Like [`fmt::LowerExp`], with an upper-case `E`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(n) => fmt::UpperExp::fmt(&self.to_exponent(n, RoundingMode::RneTiesToEven).0, f),
+            None => fmt::UpperExp::fmt(&self.to_shortest(), f),
+        }
+    }
+}
+
+impl fmt::LowerHex for float32_t {
+    /// Writes hexadecimal scientific notation like C's `%a`, or with a
+    /// precision, that many digits after the point rounded to nearest even.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(n) => fmt::LowerHex::fmt(&self.to_hex_digits(n, RoundingMode::RneTiesToEven).0, f),
+            None => fmt::LowerHex::fmt(&self.to_hex(), f),
+        }
+    }
+}
+
+impl fmt::UpperHex for float32_t {
+    /// Like [`fmt::LowerHex`], in upper case like C's `%A`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(n) => fmt::UpperHex::fmt(&self.to_hex_digits(n, RoundingMode::RneTiesToEven).0, f),
+            None => fmt::UpperHex::fmt(&self.to_hex(), f),
+        }
+    }
 }
@@ -1,20 +1,41 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use super::super::math::{
+    f64_acos, f64_acosh, f64_addStochastic, f64_asin, f64_asinh, f64_atan, f64_atan2, f64_atan2pi,
+    f64_atanh, f64_canonicalize, f64_cbrt, f64_compound, f64_cos, f64_cosh, f64_divStochastic,
+    f64_dot, f64_erf, f64_erfc, f64_exp, f64_exp2, f64_expm1, f64_fromParts,
+    f64_fromPartsStochastic, f64_gammaSign, f64_getPayload, f64_hypot, f64_isCanonical, f64_lgamma,
+    f64_log, f64_log10, f64_log1p, f64_log2, f64_mulAddStochastic, f64_mulStochastic, f64_pow,
+    f64_pown, f64_powr, f64_rSqrt, f64_rootn, f64_roundToPrecision, f64_roundToPrecisionStochastic,
+    f64_scaledProd, f64_scaledProdDiff, f64_scaledProdSum, f64_setPayload, f64_setPayloadSignaling,
+    f64_sin, f64_sinh, f64_sqrtStochastic, f64_subStochastic, f64_sum, f64_sumAbs, f64_sumSquare,
+    f64_tan, f64_tanh, f64_tgamma, f64_toParts, f64_to_f32Stochastic, Constant, Parts,
+    F64_CONSTANTS,
+};
 use super::super::softfloat::{
     f64_add, f64_div, f64_eq, f64_eq_signaling, f64_isSignalingNaN, f64_le, f64_le_quiet, f64_lt,
-    f64_lt_quiet, f64_mul, f64_mulAdd, f64_rem, f64_roundToInt, f64_sqrt, f64_sub, f64_to_f32,
-    f64_to_i32, f64_to_i64, f64_to_ui32, f64_to_ui64, float32_t, float64_t, i32_to_f64, i64_to_f64,
-    ui32_to_f64, ui64_to_f64,
+    f64_lt_quiet, f64_mul, f64_mulAdd, f64_recip7, f64_rem, f64_roundToInt, f64_rsqrte7, f64_sqrt,
+    f64_sub, f64_to_f32, f64_to_fix16, f64_to_fix32, f64_to_fix64, f64_to_i128, f64_to_i16,
+    f64_to_i32, f64_to_i64, f64_to_i8, f64_to_limbs, f64_to_ufix16, f64_to_ufix32, f64_to_ufix64,
+    f64_to_ui128, f64_to_ui16, f64_to_ui32, f64_to_ui64, f64_to_ui8, fix32_to_f64, fix64_to_f64,
+    float32_t, float64_t, i128_to_f64, i32_to_f64, i64_to_f64, limbs_to_f64, ufix32_to_f64,
+    ufix64_to_f64, ui128_to_f64, ui32_to_f64, ui64_to_f64,
+};
+use super::super::text::{
+    f64_fromDecimal, f64_fromHex, f64_toExact, f64_toExponent, f64_toFixed, f64_toHex,
+    f64_toHexDigits, f64_toRational, f64_toShortest, Decimal, Hex, ParseFloatError, Rational,
 };
-use super::{Float, RoundingMode};
+use super::{Float, Precision, RoundingMode, TininessMode};
 use core::borrow::Borrow;
+use core::fmt;
+use core::str::FromStr;
 
 impl float64_t {
     /// Converts primitive `f64` to `float64_t`
     #[inline]
     #[must_use]
-    pub fn from_f32(v: f32, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        float32_t::from_bits(v.to_bits()).to_f64(rnd, detect_tininess)
+    pub fn from_f32(v: f32, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        float32_t::from_bits(v.to_bits()).to_f64(rnd, tininess)
     }
 
     /// Converts primitive `f64` to `float64_t`
@@ -32,6 +53,16 @@ impl Float for float64_t {
     const FRACTION_BIT: Self::Payload = 0xf_ffff_ffff_ffff;
     const SIGN_POS: usize = 63;
     const EXPONENT_POS: usize = 52;
+    const MAX: Self = Self {
+        v: 0x7FEF_FFFF_FFFF_FFFF,
+    };
+    const MIN_POSITIVE: Self = Self {
+        v: 0x0010_0000_0000_0000,
+    };
+    const EPSILON: Self = Self {
+        v: 0x3CB0_0000_0000_0000,
+    };
+    const MIN_SUBNORMAL: Self = Self { v: 0x1 };
 
     #[inline]
     fn set_payload(&mut self, x: Self::Payload) {
@@ -43,21 +74,175 @@ impl Float for float64_t {
         Self { v }
     }
 
+    #[inline]
+    fn from_parts(
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f64_fromParts(
+            sign,
+            significand,
+            exponent,
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
+    }
+
+    #[inline]
+    fn to_parts(&self) -> Parts {
+        f64_toParts(*self)
+    }
+
+    #[inline]
+    fn constant(c: Constant, rnd: RoundingMode) -> Self {
+        F64_CONSTANTS[c as usize][rnd as usize]
+    }
+
+    #[inline]
+    fn from_parts_stochastic(
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f64_fromPartsStochastic(sign, significand, exponent, random, tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn round_to_precision_stochastic(
+        &self,
+        precision: Precision,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f64_roundToPrecisionStochastic(
+            *self,
+            precision.sig_bits(),
+            precision.emin(),
+            precision.emax(),
+            random,
+            tininess.to_softfloat(),
+        )
+    }
+
+    #[inline]
+    fn add_stochastic<T: Borrow<Self>>(
+        &self,
+        x: T,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f64_addStochastic(*self, *x.borrow(), random, tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn sub_stochastic<T: Borrow<Self>>(
+        &self,
+        x: T,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f64_subStochastic(*self, *x.borrow(), random, tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn mul_stochastic<T: Borrow<Self>>(
+        &self,
+        x: T,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f64_mulStochastic(*self, *x.borrow(), random, tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn div_stochastic<T: Borrow<Self>>(
+        &self,
+        x: T,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f64_divStochastic(*self, *x.borrow(), random, tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn fused_mul_add_stochastic<T: Borrow<Self>>(
+        &self,
+        x: T,
+        y: T,
+        random: u64,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f64_mulAddStochastic(
+            *self,
+            *x.borrow(),
+            *y.borrow(),
+            random,
+            tininess.to_softfloat(),
+        )
+    }
+
+    #[inline]
+    fn sqrt_stochastic(&self, random: u64, tininess: TininessMode) -> (Self, u8) {
+        f64_sqrtStochastic(*self, random, tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn to_f32_stochastic(&self, random: u64, tininess: TininessMode) -> (float32_t, u8) {
+        f64_to_f32Stochastic(*self, random, tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn round_to_precision(
+        &self,
+        precision: Precision,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f64_roundToPrecision(
+            *self,
+            precision.sig_bits(),
+            precision.emin(),
+            precision.emax(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
+    }
+
     #[inline]
     fn to_bits(&self) -> Self::Payload {
         self.v
     }
 
-    fn add<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        f64_add(*self, *x.borrow(), rnd.to_softfloat(), detect_tininess)
+    fn add<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_add(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
     }
 
-    fn sub<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        f64_sub(*self, *x.borrow(), rnd.to_softfloat(), detect_tininess)
+    fn sub<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_sub(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
     }
 
-    fn mul<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        f64_mul(*self, *x.borrow(), rnd.to_softfloat(), detect_tininess)
+    fn mul<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_mul(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
     }
 
     fn fused_mul_add<T: Borrow<Self>>(
@@ -65,27 +250,37 @@ impl Float for float64_t {
         x: T,
         y: T,
         rnd: RoundingMode,
-        detect_tininess: u8,
+        tininess: TininessMode,
     ) -> (Self, u8) {
         f64_mulAdd(
             *self,
             *x.borrow(),
             *y.borrow(),
             rnd.to_softfloat(),
-            detect_tininess,
+            tininess.to_softfloat(),
         )
     }
 
-    fn div<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        f64_div(*self, *x.borrow(), rnd.to_softfloat(), detect_tininess)
+    fn div<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_div(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
     }
 
-    fn rem<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        f64_rem(*self, *x.borrow(), rnd.to_softfloat(), detect_tininess)
+    fn rem<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_rem(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
     }
 
-    fn sqrt(&self, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        f64_sqrt(*self, rnd.to_softfloat(), detect_tininess)
+    fn sqrt(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_sqrt(*self, rnd.to_softfloat(), tininess.to_softfloat())
     }
 
     fn eq<T: Borrow<Self>>(&self, x: T) -> (bool, u8) {
@@ -116,20 +311,40 @@ impl Float for float64_t {
         f64_isSignalingNaN(*self)
     }
 
-    fn from_u32(x: u32, _rnd: RoundingMode, _detect_tininess: u8) -> (Self, u8) {
+    fn from_u32(x: u32, _rnd: RoundingMode, _tininess: TininessMode) -> (Self, u8) {
         (ui32_to_f64(x), 0)
     }
 
-    fn from_u64(x: u64, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        ui64_to_f64(x, rnd.to_softfloat(), detect_tininess)
+    fn from_u64(x: u64, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        ui64_to_f64(x, rnd.to_softfloat(), tininess.to_softfloat())
     }
 
-    fn from_i32(x: i32, _rnd: RoundingMode, _detect_tininess: u8) -> (Self, u8) {
+    fn from_i32(x: i32, _rnd: RoundingMode, _tininess: TininessMode) -> (Self, u8) {
         (i32_to_f64(x), 0)
     }
 
-    fn from_i64(x: i64, rnd: RoundingMode, detect_tininess: u8) -> (Self, u8) {
-        i64_to_f64(x, rnd.to_softfloat(), detect_tininess)
+    fn from_i64(x: i64, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        i64_to_f64(x, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    fn from_u128(x: u128, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        ui128_to_f64(x, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    fn from_i128(x: i128, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        i128_to_f64(x, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    fn from_limbs(sign: bool, x: &[u64], rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        limbs_to_f64(sign, x, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    fn to_u8(&self, rnd: RoundingMode, exact: bool) -> (u8, u8) {
+        f64_to_ui8(*self, rnd.to_softfloat(), exact)
+    }
+
+    fn to_u16(&self, rnd: RoundingMode, exact: bool) -> (u16, u8) {
+        f64_to_ui16(*self, rnd.to_softfloat(), exact)
     }
 
     fn to_u32(&self, rnd: RoundingMode, exact: bool) -> (u32, u8) {
@@ -140,6 +355,18 @@ impl Float for float64_t {
         f64_to_ui64(*self, rnd.to_softfloat(), exact)
     }
 
+    fn to_u128(&self, rnd: RoundingMode, exact: bool) -> (u128, u8) {
+        f64_to_ui128(*self, rnd.to_softfloat(), exact)
+    }
+
+    fn to_i8(&self, rnd: RoundingMode, exact: bool) -> (i8, u8) {
+        f64_to_i8(*self, rnd.to_softfloat(), exact)
+    }
+
+    fn to_i16(&self, rnd: RoundingMode, exact: bool) -> (i16, u8) {
+        f64_to_i16(*self, rnd.to_softfloat(), exact)
+    }
+
     fn to_i32(&self, rnd: RoundingMode, exact: bool) -> (i32, u8) {
         f64_to_i32(*self, rnd.to_softfloat(), exact)
     }
@@ -148,15 +375,470 @@ impl Float for float64_t {
         f64_to_i64(*self, rnd.to_softfloat(), exact)
     }
 
+    fn to_i128(&self, rnd: RoundingMode, exact: bool) -> (i128, u8) {
+        f64_to_i128(*self, rnd.to_softfloat(), exact)
+    }
+
+    fn to_limbs(&self, z: &mut [u64], rnd: RoundingMode, exact: bool) -> (bool, u8) {
+        f64_to_limbs(*self, z, rnd.to_softfloat(), exact)
+    }
+
     fn round_to_integral(&self, rnd: RoundingMode, exact: bool) -> (Self, u8) {
         f64_roundToInt(*self, rnd.to_softfloat(), exact)
     }
 
-    fn to_f32(&self, rnd: RoundingMode, detect_tininess: u8) -> (float32_t, u8) {
-        f64_to_f32(*self, rnd.to_softfloat(), detect_tininess)
+    fn from_fix32(x: i32, fbits: u8, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        fix32_to_f64(x, fbits, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    fn from_ufix32(x: u32, fbits: u8, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        ufix32_to_f64(x, fbits, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    fn from_fix64(x: i64, fbits: u8, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        fix64_to_f64(x, fbits, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    fn from_ufix64(x: u64, fbits: u8, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        ufix64_to_f64(x, fbits, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    fn to_fix16(&self, fbits: u8, rnd: RoundingMode, exact: bool) -> (i16, u8) {
+        f64_to_fix16(*self, fbits, rnd.to_softfloat(), exact)
+    }
+
+    fn to_ufix16(&self, fbits: u8, rnd: RoundingMode, exact: bool) -> (u16, u8) {
+        f64_to_ufix16(*self, fbits, rnd.to_softfloat(), exact)
+    }
+
+    fn to_fix32(&self, fbits: u8, rnd: RoundingMode, exact: bool) -> (i32, u8) {
+        f64_to_fix32(*self, fbits, rnd.to_softfloat(), exact)
+    }
+
+    fn to_ufix32(&self, fbits: u8, rnd: RoundingMode, exact: bool) -> (u32, u8) {
+        f64_to_ufix32(*self, fbits, rnd.to_softfloat(), exact)
+    }
+
+    fn to_fix64(&self, fbits: u8, rnd: RoundingMode, exact: bool) -> (i64, u8) {
+        f64_to_fix64(*self, fbits, rnd.to_softfloat(), exact)
+    }
+
+    fn to_ufix64(&self, fbits: u8, rnd: RoundingMode, exact: bool) -> (u64, u8) {
+        f64_to_ufix64(*self, fbits, rnd.to_softfloat(), exact)
+    }
+
+    fn parse(
+        s: &str,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> Result<(Self, u8), ParseFloatError> {
+        f64_fromDecimal(s, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    fn to_exact(&self) -> Decimal {
+        f64_toExact(*self)
+    }
+
+    fn to_rational(&self) -> Option<Rational> {
+        f64_toRational(*self)
+    }
+
+    fn to_shortest(&self) -> Decimal {
+        f64_toShortest(*self)
+    }
+
+    fn to_fixed(&self, digits: usize, rnd: RoundingMode) -> (Decimal, u8) {
+        f64_toFixed(*self, digits, rnd.to_softfloat())
+    }
+
+    fn to_exponent(&self, digits: usize, rnd: RoundingMode) -> (Decimal, u8) {
+        f64_toExponent(*self, digits, rnd.to_softfloat())
     }
 
-    fn to_f64(&self, _rnd: RoundingMode, _detect_tininess: u8) -> (float64_t, u8) {
+    fn parse_hex(
+        s: &str,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> Result<(Self, u8), ParseFloatError> {
+        f64_fromHex(s, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    fn to_hex(&self) -> Hex {
+        f64_toHex(*self)
+    }
+
+    fn to_hex_digits(&self, digits: usize, rnd: RoundingMode) -> (Hex, u8) {
+        f64_toHexDigits(*self, digits, rnd.to_softfloat())
+    }
+
+    fn to_f32(&self, rnd: RoundingMode, tininess: TininessMode) -> (float32_t, u8) {
+        f64_to_f32(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    fn to_f64(&self, _rnd: RoundingMode, _tininess: TininessMode) -> (float64_t, u8) {
         (*self, 0)
     }
+
+    #[inline]
+    fn from_float<G: Float>(x: &G, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        x.to_f64(rnd, tininess)
+    }
+
+    /// Exact: every format converts to `float64_t` without rounding.
+    #[inline]
+    fn from_float_stochastic<G: Float>(x: &G, _random: u64, tininess: TininessMode) -> (Self, u8) {
+        x.to_f64(RoundingMode::RneTiesToEven, tininess)
+    }
+
+    #[inline]
+    fn exp(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_exp(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn exp2(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_exp2(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn expm1(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_expm1(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn log(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_log(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn log2(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_log2(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn log10(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_log10(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn log1p(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_log1p(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn sin(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_sin(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn cos(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_cos(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn tan(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_tan(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn asin(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_asin(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn acos(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_acos(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn atan(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_atan(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn atan2<T: Borrow<Self>>(
+        &self,
+        x: T,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f64_atan2(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
+    }
+
+    #[inline]
+    fn atan2pi<T: Borrow<Self>>(
+        &self,
+        x: T,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f64_atan2pi(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
+    }
+
+    #[inline]
+    fn sinh(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_sinh(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn cosh(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_cosh(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn tanh(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_tanh(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn asinh(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_asinh(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn acosh(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_acosh(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn atanh(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_atanh(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn pow<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_pow(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
+    }
+
+    #[inline]
+    fn pown(&self, n: i64, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_pown(*self, n, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn powr<T: Borrow<Self>>(&self, x: T, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_powr(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
+    }
+
+    #[inline]
+    fn rootn(&self, n: i64, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_rootn(*self, n, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn compound(&self, n: i64, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_compound(*self, n, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn cbrt(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_cbrt(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn hypot<T: Borrow<Self>>(
+        &self,
+        x: T,
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, u8) {
+        f64_hypot(
+            *self,
+            *x.borrow(),
+            rnd.to_softfloat(),
+            tininess.to_softfloat(),
+        )
+    }
+
+    #[inline]
+    fn rsqrt(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_rSqrt(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn erf(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_erf(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn erfc(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_erfc(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn tgamma(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_tgamma(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn lgamma(&self, rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_lgamma(*self, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn gamma_sign(&self) -> i8 {
+        f64_gammaSign(*self)
+    }
+
+    #[inline]
+    fn recip7(&self, rnd: RoundingMode) -> (Self, u8) {
+        f64_recip7(*self, rnd.to_softfloat())
+    }
+
+    #[inline]
+    fn rsqrte7(&self) -> (Self, u8) {
+        f64_rsqrte7(*self)
+    }
+
+    #[inline]
+    fn nan_payload(&self) -> Self {
+        f64_getPayload(*self)
+    }
+
+    #[inline]
+    fn from_nan_payload<T: Borrow<Self>>(x: T) -> Self {
+        f64_setPayload(*x.borrow())
+    }
+
+    #[inline]
+    fn from_nan_payload_signaling<T: Borrow<Self>>(x: T) -> Self {
+        f64_setPayloadSignaling(*x.borrow())
+    }
+
+    #[inline]
+    fn canonicalize(&self) -> (Self, u8) {
+        f64_canonicalize(*self)
+    }
+
+    #[inline]
+    fn is_canonical(&self) -> bool {
+        f64_isCanonical(*self)
+    }
+
+    #[inline]
+    fn sum(a: &[Self], rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_sum(a, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn dot(a: &[Self], b: &[Self], rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_dot(a, b, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn sum_square(a: &[Self], rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_sumSquare(a, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn sum_abs(a: &[Self], rnd: RoundingMode, tininess: TininessMode) -> (Self, u8) {
+        f64_sumAbs(a, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn scaled_prod(a: &[Self], rnd: RoundingMode, tininess: TininessMode) -> (Self, i64, u8) {
+        f64_scaledProd(a, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn scaled_prod_sum(
+        a: &[Self],
+        b: &[Self],
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, i64, u8) {
+        f64_scaledProdSum(a, b, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+
+    #[inline]
+    fn scaled_prod_diff(
+        a: &[Self],
+        b: &[Self],
+        rnd: RoundingMode,
+        tininess: TininessMode,
+    ) -> (Self, i64, u8) {
+        f64_scaledProdDiff(a, b, rnd.to_softfloat(), tininess.to_softfloat())
+    }
+}
+
+impl FromStr for float64_t {
+    type Err = ParseFloatError;
+
+    /// Parses a decimal literal, rounding to nearest even.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, RoundingMode::RneTiesToEven, TininessMode::default()).map(|(v, _)| v)
+    }
+}
+
+impl fmt::Display for float64_t {
+    /// Writes the shortest decimal that parses back to the value, or with a
+    /// precision, that many digits after the point rounded to nearest even.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(n) => fmt::Display::fmt(&self.to_fixed(n, RoundingMode::RneTiesToEven).0, f),
+            None => fmt::Display::fmt(&self.to_shortest(), f),
+        }
+    }
+}
+
+impl fmt::LowerExp for float64_t {
+    /// Like [`fmt::Display`], in scientific notation.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(n) => fmt::LowerExp::fmt(&self.to_exponent(n, RoundingMode::RneTiesToEven).0, f),
+            None => fmt::LowerExp::fmt(&self.to_shortest(), f),
+        }
+    }
+}
+
+impl fmt::UpperExp for float64_t {
+    /// Like [`fmt::LowerExp`], with an upper-case `E`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(n) => fmt::UpperExp::fmt(&self.to_exponent(n, RoundingMode::RneTiesToEven).0, f),
+            None => fmt::UpperExp::fmt(&self.to_shortest(), f),
+        }
+    }
+}
+
+impl fmt::LowerHex for float64_t {
+    /// Writes hexadecimal scientific notation like C's `%a`, or with a
+    /// precision, that many digits after the point rounded to nearest even.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(n) => fmt::LowerHex::fmt(&self.to_hex_digits(n, RoundingMode::RneTiesToEven).0, f),
+            None => fmt::LowerHex::fmt(&self.to_hex(), f),
+        }
+    }
+}
+
+impl fmt::UpperHex for float64_t {
+    /// Like [`fmt::LowerHex`], in upper case like C's `%A`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(n) => fmt::UpperHex::fmt(&self.to_hex_digits(n, RoundingMode::RneTiesToEven).0, f),
+            None => fmt::UpperHex::fmt(&self.to_hex(), f),
+        }
+    }
 }
@@ -3,5 +3,10 @@
 pub mod common;
 pub mod f32;
 pub mod f64;
+pub mod rounded;
 
-pub use common::{ExceptionFlags, Float, RoundingMode, TininessMode};
+pub use common::{
+    ExceptionFlags, Float, Int, Precision, PrecisionError, RandomSource, RoundingMode,
+    TininessMode,
+};
+pub use rounded::Rounded;
@@ -0,0 +1,287 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::cmp::Ordering;
+use core::iter::{Product, Sum};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use super::super::softfloat::{init_detectTininess, softfloat_round_near_even};
+use super::{ExceptionFlags, Float, RoundingMode, TininessMode};
+
+mod num;
+
+/// A float whose operators round in the softfloat rounding mode `RM`, with
+/// tininess detected as `TININESS`, as in
+/// `Rounded<float64_t, { RoundingMode::RdnTowardNegative.to_softfloat() }>`.
+///
+/// Every value carries the exception flags raised while computing it: the
+/// result of an operator has the flags of both operands and of its own
+/// rounding. The flags of a computation are thus read off its result, with
+/// no global or shared state. Comparisons are quiet and drop their flags.
+#[derive(Copy, Clone, Debug)]
+pub struct Rounded<
+    F,
+    const RM: u8 = { softfloat_round_near_even },
+    const TININESS: u8 = { init_detectTininess },
+> {
+    value: F,
+    flags: ExceptionFlags,
+}
+
+impl<F: Float + Copy, const RM: u8, const TININESS: u8> Rounded<F, RM, TININESS> {
+    /// The rounding mode of the operators; an invalid `RM` fails to compile.
+    pub const ROUNDING_MODE: RoundingMode = match RoundingMode::from_softfloat(RM) {
+        Some(rnd) => rnd,
+        None => panic!("invalid rounding mode"),
+    };
+
+    /// The tininess detection of the operators; an invalid `TININESS` fails
+    /// to compile.
+    pub const TININESS_MODE: TininessMode = match TininessMode::from_softfloat(TININESS) {
+        Some(tininess) => tininess,
+        None => panic!("invalid tininess mode"),
+    };
+
+    /// `value` with no flags raised.
+    #[inline]
+    #[must_use]
+    pub const fn new(value: F) -> Self {
+        Self {
+            value,
+            flags: ExceptionFlags::from_bits(0),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn value(&self) -> F {
+        self.value
+    }
+
+    /// The flags raised while computing this value.
+    #[inline]
+    #[must_use]
+    pub const fn flags(&self) -> ExceptionFlags {
+        self.flags
+    }
+
+    /// This value with its flags cleared, to start a new computation.
+    #[inline]
+    #[must_use]
+    pub const fn without_flags(&self) -> Self {
+        Self::new(self.value)
+    }
+
+    #[inline]
+    const fn with(&self, args: (F, u8)) -> Self {
+        let (value, flags) = args;
+        Self {
+            value,
+            flags: ExceptionFlags::from_bits(self.flags.to_bits() | flags),
+        }
+    }
+
+    #[inline]
+    fn binary(self, rhs: Self, op: fn(&F, F, RoundingMode, TininessMode) -> (F, u8)) -> Self {
+        let mut r = self.with(op(
+            &self.value,
+            rhs.value,
+            Self::ROUNDING_MODE,
+            Self::TININESS_MODE,
+        ));
+        r.flags.merge(rhs.flags.to_bits());
+        r
+    }
+
+    /// `√self`, rounded like the operators.
+    #[inline]
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        self.with(self.value.sqrt(Self::ROUNDING_MODE, Self::TININESS_MODE))
+    }
+
+    /// `self × a + b` with a single rounding.
+    #[inline]
+    #[must_use]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        let mut r = self.with(self.value.fused_mul_add(
+            a.value,
+            b.value,
+            Self::ROUNDING_MODE,
+            Self::TININESS_MODE,
+        ));
+        r.flags.merge(a.flags.to_bits() | b.flags.to_bits());
+        r
+    }
+}
+
+impl<F: Float + Copy, const RM: u8, const TININESS: u8> From<F> for Rounded<F, RM, TININESS> {
+    #[inline]
+    fn from(value: F) -> Self {
+        Self::new(value)
+    }
+}
+
+macro_rules! binary_op {
+    ($op:ident, $fn:ident, $assign:ident, $assign_fn:ident, $float_fn:ident) => {
+        impl<F: Float + Copy, const RM: u8, const TININESS: u8> $op for Rounded<F, RM, TININESS> {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self, rhs: Self) -> Self {
+                self.binary(rhs, |a, b, rnd, tininess| a.$float_fn(b, rnd, tininess))
+            }
+        }
+
+        impl<F: Float + Copy, const RM: u8, const TININESS: u8> $assign
+            for Rounded<F, RM, TININESS>
+        {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = $op::$fn(*self, rhs);
+            }
+        }
+    };
+}
+
+binary_op!(Add, add, AddAssign, add_assign, add);
+binary_op!(Sub, sub, SubAssign, sub_assign, sub);
+binary_op!(Mul, mul, MulAssign, mul_assign, mul);
+binary_op!(Div, div, DivAssign, div_assign, div);
+// The IEEE 754 remainder, like `FPU::rem`, rather than the truncated `fmod`
+// of the primitive types.
+binary_op!(Rem, rem, RemAssign, rem_assign, rem);
+
+impl<F: Float + Copy, const RM: u8, const TININESS: u8> Neg for Rounded<F, RM, TININESS> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            value: Float::neg(&self.value),
+            flags: self.flags,
+        }
+    }
+}
+
+impl<F: Float + Copy, const RM: u8, const TININESS: u8> PartialEq for Rounded<F, RM, TININESS> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value.eq(other.value).0
+    }
+}
+
+impl<F: Float + Copy, const RM: u8, const TININESS: u8> PartialOrd for Rounded<F, RM, TININESS> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.compare_quiet(other.value).0
+    }
+}
+
+/// Adds from the first element on, so that the sum of one value is that
+/// value in every rounding mode; the empty sum is `-0` as for `f64`.
+impl<F: Float + Copy, const RM: u8, const TININESS: u8> Sum for Rounded<F, RM, TININESS> {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+        iter.next().map_or_else(
+            || Self::new(F::negative_zero()),
+            |first| iter.fold(first, Add::add),
+        )
+    }
+}
+
+impl<'a, F: Float + Copy, const RM: u8, const TININESS: u8> Sum<&'a Self>
+    for Rounded<F, RM, TININESS>
+{
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Multiplies from the first element on; the empty product is `1`.
+impl<F: Float + Copy, const RM: u8, const TININESS: u8> Product for Rounded<F, RM, TININESS> {
+    #[inline]
+    fn product<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+        iter.next().map_or_else(
+            || Self::new(F::from_u32(1, RoundingMode::RneTiesToEven, Self::TININESS_MODE).0),
+            |first| iter.fold(first, Mul::mul),
+        )
+    }
+}
+
+impl<'a, F: Float + Copy, const RM: u8, const TININESS: u8> Product<&'a Self>
+    for Rounded<F, RM, TININESS>
+{
+    #[inline]
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::softfloat::{
+        float32_t, float64_t, softfloat_flag_inexact, softfloat_flag_invalid, softfloat_round_min,
+    };
+
+    type Down = Rounded<float64_t, { RoundingMode::RdnTowardNegative.to_softfloat() }>;
+
+    #[test]
+    fn test_rounded_ops() {
+        let one = Rounded::<float64_t>::new(float64_t::from_bits(0x3FF0_0000_0000_0000));
+        let three = one + one + one;
+        let third = one / three;
+        assert_eq!(third.value().to_bits(), 0x3FD5_5555_5555_5555);
+        assert_eq!(third.flags().to_bits(), softfloat_flag_inexact);
+        assert_eq!(three.flags().to_bits(), 0);
+
+        let down = Down::new(one.value()) / Down::new(three.value());
+        assert_eq!(down.value().to_bits(), 0x3FD5_5555_5555_5555);
+        let up = -(-Down::new(one.value()) / Down::new(three.value()));
+        assert_eq!(up.value().to_bits(), 0x3FD5_5555_5555_5556);
+        assert_eq!(Down::ROUNDING_MODE.to_softfloat(), softfloat_round_min);
+
+        let mut x = third;
+        x *= three;
+        x -= one;
+        assert!(x == -x);
+        assert!(third < one && one >= third);
+        let nan = (x.without_flags() / x).without_flags() % one;
+        assert!(nan != nan);
+        assert_eq!(nan.partial_cmp(&one), None);
+        assert_eq!(
+            (x / x).flags().to_bits(),
+            softfloat_flag_inexact | softfloat_flag_invalid
+        );
+    }
+
+    #[test]
+    fn test_rounded_fold() {
+        let tenth = Rounded::<float32_t>::new(float32_t::from_bits(0x3DCC_CCCD));
+        let ten: Rounded<float32_t> = [tenth; 10].iter().sum();
+        assert_eq!(ten.value().to_bits(), 0x3F80_0001);
+        assert!(ten.flags().is_inexact());
+        let empty: [Rounded<float32_t>; 0] = [];
+        assert_eq!(
+            empty.iter().sum::<Rounded<float32_t>>().value().to_bits(),
+            0x8000_0000
+        );
+        assert_eq!(
+            empty
+                .into_iter()
+                .product::<Rounded<float32_t>>()
+                .value()
+                .to_bits(),
+            0x3F80_0000
+        );
+
+        let two = Rounded::<float32_t>::new(float32_t::from_bits(0x4000_0000));
+        let p: Rounded<float32_t> = [two; 3].into_iter().product();
+        assert_eq!(p.value().to_bits(), 0x4100_0000);
+        assert_eq!(two.mul_add(two, two).value().to_bits(), 0x40C0_0000);
+        assert_eq!(p.sqrt().flags().to_bits(), softfloat_flag_inexact);
+    }
+}
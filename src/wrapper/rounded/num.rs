@@ -0,0 +1,818 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! [`num_traits`] numeric traits, so that generic numeric code runs on
+//! [`Rounded`] floats.
+//!
+//! Every operation rounds like the operators, in `RM`, and the mathematical
+//! functions are the correctly rounded ones of [`crate::math`]. Conversions
+//! to integers truncate and return `None` for NaNs and out-of-range values,
+//! as for the primitive floats.
+
+use core::num::FpCategory;
+
+use num_traits::float::FloatCore;
+use num_traits::{FromPrimitive, Num, One, Signed, ToPrimitive, Zero};
+
+use super::{ExceptionFlags, Float, Rounded, RoundingMode, TininessMode};
+use crate::math::{Class, Constant, Parts};
+use crate::softfloat::{float32_t, float64_t, softfloat_flag_invalid};
+use crate::text::ParseFloatError;
+
+impl<F: Float + Copy, const RM: u8, const TININESS: u8> Rounded<F, RM, TININESS> {
+    /// A conversion result, with the flags it raised.
+    #[inline]
+    const fn converted(args: (F, u8)) -> Self {
+        Self::new(args.0).with(args)
+    }
+
+    #[inline]
+    fn unary(self, op: fn(&F, RoundingMode, TininessMode) -> (F, u8)) -> Self {
+        self.with(op(&self.value, Self::ROUNDING_MODE, Self::TININESS_MODE))
+    }
+
+    /// `self` if `keep`, otherwise `other`, with the flags of both.
+    #[inline]
+    const fn choose(self, other: Self, keep: bool) -> Self {
+        let value = if keep { self.value } else { other.value };
+        Self {
+            value,
+            flags: ExceptionFlags::from_bits(self.flags.to_bits() | other.flags.to_bits()),
+        }
+    }
+
+    /// `self` rounded to an integral value in `rnd`, which is never inexact.
+    #[inline]
+    fn integral(self, rnd: RoundingMode) -> Self {
+        self.with(self.value.round_to_integral(rnd, false))
+    }
+
+    /// A float of another format, rounded like the operators. NaNs become
+    /// the default quiet NaN.
+    #[inline]
+    fn from_parts(parts: Parts) -> Self {
+        match parts.class {
+            Class::QuietNaN | Class::SignalingNaN => Self::new(F::quiet_nan()),
+            Class::Infinite if parts.sign => Self::new(F::negative_infinity()),
+            Class::Infinite => Self::new(F::positive_infinity()),
+            _ => Self::converted(F::from_parts(
+                parts.sign,
+                u128::from(parts.significand),
+                parts.exponent,
+                Self::ROUNDING_MODE,
+                Self::TININESS_MODE,
+            )),
+        }
+    }
+
+    #[inline]
+    fn constant(c: Constant) -> Self {
+        Self::new(F::constant(c, Self::ROUNDING_MODE))
+    }
+}
+
+impl<F: Float + Copy, const RM: u8, const TININESS: u8> Zero for Rounded<F, RM, TININESS> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(F::positive_zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+}
+
+impl<F: Float + Copy, const RM: u8, const TININESS: u8> One for Rounded<F, RM, TININESS> {
+    #[inline]
+    fn one() -> Self {
+        Self::new(F::from_u32(1, RoundingMode::RneTiesToEven, Self::TININESS_MODE).0)
+    }
+}
+
+impl<F: Float + Copy, const RM: u8, const TININESS: u8> Num for Rounded<F, RM, TININESS> {
+    type FromStrRadixErr = ParseFloatError;
+
+    /// Parses a decimal literal in radix 10 and a `0x` literal, as for
+    /// [`Float::parse_hex`], in radix 16. Other radixes are not supported.
+    #[inline]
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseFloatError> {
+        let parsed = match radix {
+            10 => F::parse(s, Self::ROUNDING_MODE, Self::TININESS_MODE),
+            16 => F::parse_hex(s, Self::ROUNDING_MODE, Self::TININESS_MODE),
+            _ => Err(ParseFloatError::INVALID),
+        };
+        parsed.map(Self::converted)
+    }
+}
+
+macro_rules! to_int {
+    ($($name:ident: $t:ty),*) => {
+        $(
+            #[inline]
+            fn $name(&self) -> Option<$t> {
+                let (v, flags) = self.value.$name(RoundingMode::RtzTowardZero, false);
+                if flags & softfloat_flag_invalid == 0 {
+                    Some(v)
+                } else {
+                    None
+                }
+            }
+        )*
+    };
+}
+
+impl<F: Float + Copy, const RM: u8, const TININESS: u8> ToPrimitive for Rounded<F, RM, TININESS> {
+    to_int!(
+        to_i8: i8, to_i16: i16, to_i32: i32, to_i64: i64, to_i128: i128,
+        to_u8: u8, to_u16: u16, to_u32: u32, to_u64: u64, to_u128: u128
+    );
+
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        let (v, _) = self.value.to_f32(Self::ROUNDING_MODE, Self::TININESS_MODE);
+        Some(f32::from_bits(v.to_bits()))
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        let (v, _) = self.value.to_f64(Self::ROUNDING_MODE, Self::TININESS_MODE);
+        Some(f64::from_bits(v.to_bits()))
+    }
+}
+
+macro_rules! from_int {
+    ($($name:ident: $t:ty),*) => {
+        $(
+            #[inline]
+            fn $name(n: $t) -> Option<Self> {
+                Some(Self::converted(F::$name(n, Self::ROUNDING_MODE, Self::TININESS_MODE)))
+            }
+        )*
+    };
+}
+
+impl<F: Float + Copy, const RM: u8, const TININESS: u8> FromPrimitive for Rounded<F, RM, TININESS> {
+    from_int!(
+        from_i8: i8, from_i16: i16, from_i32: i32, from_i64: i64, from_i128: i128,
+        from_u8: u8, from_u16: u16, from_u32: u32, from_u64: u64, from_u128: u128
+    );
+
+    #[inline]
+    fn from_f32(n: f32) -> Option<Self> {
+        Some(Self::from_parts(
+            float32_t::from_bits(n.to_bits()).to_parts(),
+        ))
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        Some(Self::from_parts(
+            float64_t::from_bits(n.to_bits()).to_parts(),
+        ))
+    }
+}
+
+impl<F: Float + Copy, const RM: u8, const TININESS: u8> num_traits::NumCast
+    for Rounded<F, RM, TININESS>
+{
+    /// Converts with a single rounding: through `f64`, which holds every
+    /// primitive float and every integer below `2^53` exactly, and through
+    /// `i128` or `u128` for larger integers.
+    #[inline]
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        let v = n.to_f64()?;
+        if v.abs() >= 9_007_199_254_740_992.0 {
+            if let Some(i) = n.to_i128() {
+                return Self::from_i128(i);
+            }
+            if let Some(u) = n.to_u128() {
+                return Self::from_u128(u);
+            }
+        }
+        Self::from_f64(v)
+    }
+}
+
+impl<F: Float + Copy, const RM: u8, const TININESS: u8> Signed for Rounded<F, RM, TININESS> {
+    #[inline]
+    fn abs(&self) -> Self {
+        FloatCore::abs(*self)
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other {
+            Self {
+                value: F::positive_zero(),
+                flags: ExceptionFlags::from_bits(self.flags.to_bits() | other.flags.to_bits()),
+            }
+        } else {
+            *self - *other
+        }
+    }
+
+    #[inline]
+    fn signum(&self) -> Self {
+        FloatCore::signum(*self)
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        self.value.is_positive()
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        self.value.is_negative()
+    }
+}
+
+impl<F: Float + Copy, const RM: u8, const TININESS: u8> FloatCore for Rounded<F, RM, TININESS> {
+    #[inline]
+    fn infinity() -> Self {
+        Self::new(F::positive_infinity())
+    }
+
+    #[inline]
+    fn neg_infinity() -> Self {
+        Self::new(F::negative_infinity())
+    }
+
+    #[inline]
+    fn nan() -> Self {
+        Self::new(F::quiet_nan())
+    }
+
+    #[inline]
+    fn neg_zero() -> Self {
+        Self::new(F::negative_zero())
+    }
+
+    #[inline]
+    fn min_value() -> Self {
+        Self::new(F::MAX.neg())
+    }
+
+    #[inline]
+    fn min_positive_value() -> Self {
+        Self::new(F::MIN_POSITIVE)
+    }
+
+    #[inline]
+    fn epsilon() -> Self {
+        Self::new(F::EPSILON)
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self::new(F::MAX)
+    }
+
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.value.is_nan()
+    }
+
+    #[inline]
+    fn is_infinite(self) -> bool {
+        self.value.to_parts().class == Class::Infinite
+    }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        !self.value.is_nan() && !FloatCore::is_infinite(self)
+    }
+
+    #[inline]
+    fn is_normal(self) -> bool {
+        self.value.to_parts().class == Class::Normal
+    }
+
+    #[inline]
+    fn is_subnormal(self) -> bool {
+        self.value.to_parts().class == Class::Subnormal
+    }
+
+    #[inline]
+    fn classify(self) -> FpCategory {
+        match self.value.to_parts().class {
+            Class::Zero => FpCategory::Zero,
+            Class::Subnormal => FpCategory::Subnormal,
+            Class::Normal => FpCategory::Normal,
+            Class::Infinite => FpCategory::Infinite,
+            Class::QuietNaN | Class::SignalingNaN => FpCategory::Nan,
+        }
+    }
+
+    #[inline]
+    fn floor(self) -> Self {
+        self.integral(RoundingMode::RdnTowardNegative)
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        self.integral(RoundingMode::RupTowardPositive)
+    }
+
+    #[inline]
+    fn round(self) -> Self {
+        self.integral(RoundingMode::RmmTiesToAway)
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        self.integral(RoundingMode::RtzTowardZero)
+    }
+
+    #[inline]
+    fn fract(self) -> Self {
+        self - FloatCore::trunc(self)
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        Self {
+            value: self.value.abs(),
+            flags: self.flags,
+        }
+    }
+
+    /// `±1` with the sign of `self`, or NaN for NaN.
+    #[inline]
+    fn signum(self) -> Self {
+        if self.value.is_nan() {
+            return self;
+        }
+        let one = Self::one().value;
+        Self {
+            value: if self.value.is_negative() {
+                one.neg()
+            } else {
+                one
+            },
+            flags: self.flags,
+        }
+    }
+
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        self.value.is_positive()
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        self.value.is_negative()
+    }
+
+    /// The lesser value, ignoring a NaN operand.
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        self.choose(other, other.value.is_nan() || self <= other)
+    }
+
+    /// The greater value, ignoring a NaN operand.
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        self.choose(other, other.value.is_nan() || self >= other)
+    }
+
+    #[inline]
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        self.with(
+            self.value
+                .pown(i64::from(n), Self::ROUNDING_MODE, Self::TININESS_MODE),
+        )
+    }
+
+    #[inline]
+    fn to_degrees(self) -> Self {
+        self * Self::converted(F::from_u32(180, Self::ROUNDING_MODE, Self::TININESS_MODE))
+            / Self::constant(Constant::Pi)
+    }
+
+    #[inline]
+    fn to_radians(self) -> Self {
+        self * Self::constant(Constant::Pi)
+            / Self::converted(F::from_u32(180, Self::ROUNDING_MODE, Self::TININESS_MODE))
+    }
+
+    /// The significand, exponent and sign of [`Float::to_parts`].
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    fn integer_decode(self) -> (u64, i16, i8) {
+        let parts = self.value.to_parts();
+        let sign = if parts.sign { -1 } else { 1 };
+        // Exponents lie between -1074 and 972.
+        (parts.significand, parts.exponent as i16, sign)
+    }
+}
+
+impl<F: Float + Copy, const RM: u8, const TININESS: u8> num_traits::Float
+    for Rounded<F, RM, TININESS>
+{
+    #[inline]
+    fn nan() -> Self {
+        FloatCore::nan()
+    }
+
+    #[inline]
+    fn infinity() -> Self {
+        FloatCore::infinity()
+    }
+
+    #[inline]
+    fn neg_infinity() -> Self {
+        FloatCore::neg_infinity()
+    }
+
+    #[inline]
+    fn neg_zero() -> Self {
+        FloatCore::neg_zero()
+    }
+
+    #[inline]
+    fn min_value() -> Self {
+        FloatCore::min_value()
+    }
+
+    #[inline]
+    fn min_positive_value() -> Self {
+        FloatCore::min_positive_value()
+    }
+
+    #[inline]
+    fn epsilon() -> Self {
+        FloatCore::epsilon()
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        FloatCore::max_value()
+    }
+
+    #[inline]
+    fn is_nan(self) -> bool {
+        FloatCore::is_nan(self)
+    }
+
+    #[inline]
+    fn is_infinite(self) -> bool {
+        FloatCore::is_infinite(self)
+    }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        FloatCore::is_finite(self)
+    }
+
+    #[inline]
+    fn is_normal(self) -> bool {
+        FloatCore::is_normal(self)
+    }
+
+    #[inline]
+    fn is_subnormal(self) -> bool {
+        FloatCore::is_subnormal(self)
+    }
+
+    #[inline]
+    fn classify(self) -> FpCategory {
+        FloatCore::classify(self)
+    }
+
+    #[inline]
+    fn floor(self) -> Self {
+        FloatCore::floor(self)
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        FloatCore::ceil(self)
+    }
+
+    #[inline]
+    fn round(self) -> Self {
+        FloatCore::round(self)
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        FloatCore::trunc(self)
+    }
+
+    #[inline]
+    fn fract(self) -> Self {
+        FloatCore::fract(self)
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        FloatCore::abs(self)
+    }
+
+    #[inline]
+    fn signum(self) -> Self {
+        FloatCore::signum(self)
+    }
+
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        FloatCore::is_sign_positive(self)
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        FloatCore::is_sign_negative(self)
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Self::mul_add(self, a, b)
+    }
+
+    #[inline]
+    fn recip(self) -> Self {
+        FloatCore::recip(self)
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        FloatCore::powi(self, n)
+    }
+
+    #[inline]
+    fn powf(self, n: Self) -> Self {
+        self.binary(n, F::pow)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        Self::sqrt(self)
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        self.unary(F::exp)
+    }
+
+    #[inline]
+    fn exp2(self) -> Self {
+        self.unary(F::exp2)
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        self.unary(F::log)
+    }
+
+    /// `ln self / ln base`, rounded three times.
+    #[inline]
+    fn log(self, base: Self) -> Self {
+        self.unary(F::log) / base.unary(F::log)
+    }
+
+    #[inline]
+    fn log2(self) -> Self {
+        self.unary(F::log2)
+    }
+
+    #[inline]
+    fn log10(self) -> Self {
+        self.unary(F::log10)
+    }
+
+    #[inline]
+    fn to_degrees(self) -> Self {
+        FloatCore::to_degrees(self)
+    }
+
+    #[inline]
+    fn to_radians(self) -> Self {
+        FloatCore::to_radians(self)
+    }
+
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        FloatCore::max(self, other)
+    }
+
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        FloatCore::min(self, other)
+    }
+
+    #[inline]
+    fn abs_sub(self, other: Self) -> Self {
+        Signed::abs_sub(&self, &other)
+    }
+
+    #[inline]
+    fn cbrt(self) -> Self {
+        self.unary(F::cbrt)
+    }
+
+    #[inline]
+    fn hypot(self, other: Self) -> Self {
+        self.binary(other, F::hypot)
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        self.unary(F::sin)
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        self.unary(F::cos)
+    }
+
+    #[inline]
+    fn tan(self) -> Self {
+        self.unary(F::tan)
+    }
+
+    #[inline]
+    fn asin(self) -> Self {
+        self.unary(F::asin)
+    }
+
+    #[inline]
+    fn acos(self) -> Self {
+        self.unary(F::acos)
+    }
+
+    #[inline]
+    fn atan(self) -> Self {
+        self.unary(F::atan)
+    }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        self.binary(other, F::atan2)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        (self.unary(F::sin), self.unary(F::cos))
+    }
+
+    #[inline]
+    fn exp_m1(self) -> Self {
+        self.unary(F::expm1)
+    }
+
+    #[inline]
+    fn ln_1p(self) -> Self {
+        self.unary(F::log1p)
+    }
+
+    #[inline]
+    fn sinh(self) -> Self {
+        self.unary(F::sinh)
+    }
+
+    #[inline]
+    fn cosh(self) -> Self {
+        self.unary(F::cosh)
+    }
+
+    #[inline]
+    fn tanh(self) -> Self {
+        self.unary(F::tanh)
+    }
+
+    #[inline]
+    fn asinh(self) -> Self {
+        self.unary(F::asinh)
+    }
+
+    #[inline]
+    fn acosh(self) -> Self {
+        self.unary(F::acosh)
+    }
+
+    #[inline]
+    fn atanh(self) -> Self {
+        self.unary(F::atanh)
+    }
+
+    #[inline]
+    fn integer_decode(self) -> (u64, i16, i8) {
+        FloatCore::integer_decode(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_traits::NumCast;
+
+    type Soft = Rounded<float64_t>;
+
+    /// A generic algorithm, written once for any `num_traits::Float`.
+    fn norm_and_angle<T: num_traits::Float>(v: &[T]) -> (T, T) {
+        let sum = v.iter().fold(T::zero(), |acc, &x| x.mul_add(x, acc));
+        (sum.sqrt(), v[1].atan2(v[0]))
+    }
+
+    fn soft(x: f64) -> Soft {
+        <Soft as NumCast>::from(x).unwrap()
+    }
+
+    fn bits(x: Soft) -> u64 {
+        x.value().to_bits()
+    }
+
+    #[test]
+    fn test_num_traits() {
+        let v = [1.5, -2.25, 3.0e-3, 7.0];
+        let (norm, angle) = norm_and_angle(&v);
+        let (soft_norm, soft_angle) = norm_and_angle(&v.map(soft));
+        assert_eq!(soft_norm.to_f64(), Some(norm));
+        assert_eq!(bits(soft_angle), 0xBFEF_730B_D281_F69B);
+        assert!((soft_angle.to_f64().unwrap() - angle).abs() <= f64::EPSILON);
+
+        let one = Soft::one();
+        let e = num_traits::Float::exp(one);
+        assert_eq!(bits(e), 0x4005_BF0A_8B14_5769);
+        assert!(e.flags().is_inexact());
+        assert_eq!(bits(num_traits::Float::ln(e)), 0x3FF0_0000_0000_0000);
+        assert_eq!(
+            bits(num_traits::Float::log2(soft(0.125))),
+            0xC008_0000_0000_0000
+        );
+        assert_eq!(num_traits::Float::log10(soft(1e22)).flags().to_bits(), 0);
+        let pi = Soft::constant(Constant::Pi);
+        assert_eq!(
+            bits(num_traits::Float::to_degrees(pi)),
+            0x4066_8000_0000_0000
+        );
+        let (sin, cos) = num_traits::Float::sin_cos(pi);
+        assert_eq!(bits(sin), 0x3CA1_A626_3314_5C07);
+        assert_eq!(bits(cos), 0xBFF0_0000_0000_0000);
+        assert_eq!(
+            bits(num_traits::Float::powi(soft(-2.0), -3)),
+            0xBFC0_0000_0000_0000
+        );
+
+        let x = soft(-2.5);
+        assert_eq!(FloatCore::floor(x).to_f64(), Some(-3.0));
+        assert_eq!(FloatCore::ceil(x).to_f64(), Some(-2.0));
+        assert_eq!(FloatCore::round(x).to_f64(), Some(-3.0));
+        assert_eq!(FloatCore::trunc(x).to_f64(), Some(-2.0));
+        assert_eq!(FloatCore::fract(x).to_f64(), Some(-0.5));
+        assert_eq!(Signed::abs_sub(&x, &one).to_f64(), Some(0.0));
+        assert_eq!(Signed::signum(&x).to_f64(), Some(-1.0));
+        let nan = Soft::nan();
+        assert_eq!(FloatCore::max(nan, x).to_f64(), Some(-2.5));
+        assert_eq!(FloatCore::min(one, nan).to_f64(), Some(1.0));
+        assert_eq!(FloatCore::classify(nan), FpCategory::Nan);
+        assert_eq!(
+            FloatCore::classify(Soft::min_positive_value()),
+            FpCategory::Normal
+        );
+        assert_eq!(
+            FloatCore::integer_decode(soft(-0.75)),
+            FloatCore::integer_decode(-0.75f64)
+        );
+    }
+
+    #[test]
+    fn test_num_conversions() {
+        assert_eq!(soft(3.75).to_i32(), Some(3));
+        assert_eq!(soft(-0.5).to_u8(), Some(0));
+        assert_eq!(soft(-1.0).to_u8(), None);
+        assert_eq!(soft(1e10).to_i32(), None);
+        assert_eq!(Soft::nan().to_i64(), None);
+        assert_eq!(
+            soft(-1e30).to_i128(),
+            Some(-1_000_000_000_000_000_019_884_624_838_656)
+        );
+
+        // One rounding, even where `f64` would round first.
+        let big = <Rounded<float32_t> as NumCast>::from((1u64 << 40) + (1 << 16) + 1).unwrap();
+        assert_eq!(big.value().to_bits(), 0x5380_0001);
+        assert!(big.flags().is_inexact());
+        let wide = <Soft as NumCast>::from(u128::MAX).unwrap();
+        assert_eq!(bits(wide), 0x47F0_0000_0000_0000);
+        assert_eq!(
+            Rounded::<float32_t>::from_f64(1e300).map(|x| x.value().to_bits()),
+            Some(0x7F80_0000)
+        );
+
+        let tenth = Soft::from_str_radix("0.1", 10).unwrap();
+        assert_eq!(bits(tenth), 0x3FB9_9999_9999_999A);
+        assert!(tenth.flags().is_inexact());
+        assert_eq!(
+            bits(Soft::from_str_radix("-0x1.8p1", 16).unwrap()),
+            0xC008_0000_0000_0000
+        );
+        assert!(Soft::from_str_radix("101", 2).is_err());
+        assert_eq!(soft(0.1).to_f32(), Some(0.1));
+    }
+}